@@ -6,6 +6,8 @@ pub struct QueryParams {
     file: Vec<String>,
     package: Vec<String>,
     all_dependencies: bool,
+    /// urls whose rendered pages are removed from `fastn_core::response_cache`
+    url: Vec<String>,
 }
 
 fn query(uri: &str) -> fastn_core::Result<QueryParams> {
@@ -35,6 +37,16 @@ fn query(uri: &str) -> fastn_core::Result<QueryParams> {
         all_dependencies: query
             .iter()
             .any(|(key, value)| key.eq("all-dependencies") && (value.eq("true") || value.eq("t"))),
+        url: query
+            .iter()
+            .filter_map(|(key, value)| {
+                if key.eq("url") {
+                    Some(value.to_string())
+                } else {
+                    None
+                }
+            })
+            .collect_vec(),
     })
 }
 
@@ -69,6 +81,22 @@ pub async fn clear_(
     query: &QueryParams,
     _req: &fastn_core::http::Request,
 ) -> fastn_core::Result<()> {
+    for url in query.url.iter() {
+        fastn_core::response_cache::purge(url.as_str());
+    }
+
+    // only the rendered pages are to be purged
+    if !query.url.is_empty()
+        && query.file.is_empty()
+        && query.package.is_empty()
+        && !query.all_dependencies
+    {
+        return Ok(());
+    }
+
+    // the source of any page can change after this, none of the cached pages can be trusted
+    fastn_core::response_cache::purge_all();
+
     if config.package.download_base_url.is_none() {
         return Err(fastn_core::Error::APIResponseError(
             "cannot remove anything, package does not have `download_base_url`".to_string(),
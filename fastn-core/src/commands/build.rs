@@ -58,6 +58,11 @@ pub async fn build(
 
     config.download_fonts().await?;
 
+    // Fingerprint images and fonts once every page and asset is in place
+    let (checksums, assets) =
+        fastn_core::fingerprint::fingerprint_build(&config.build_dir(), &cache::assets()).await?;
+    cache::update_fingerprints(&checksums, assets)?;
+
     if check_build {
         return fastn_core::post_build_check(config).await;
     }
@@ -117,6 +122,7 @@ mod cache {
                         ftd_cache: std::collections::BTreeMap::new(),
                         documents: std::collections::BTreeMap::new(),
                         file_checksum: std::collections::BTreeMap::new(),
+                        assets: std::collections::BTreeMap::new(),
                     },
                 )
             }
@@ -134,6 +140,37 @@ mod cache {
         pub(crate) ftd_cache: std::collections::BTreeMap<String, Option<String>>,
         pub(crate) documents: std::collections::BTreeMap<String, Document>,
         pub(crate) file_checksum: std::collections::BTreeMap<String, String>,
+        /// Fingerprinted name of every image and font in the last build, see
+        /// `fastn_core::fingerprint::fingerprint_build`.
+        #[serde(default)]
+        pub(crate) assets: std::collections::BTreeMap<String, String>,
+    }
+
+    /// Asset manifest recorded by the last build.
+    pub(crate) fn assets() -> std::collections::BTreeMap<String, String> {
+        fastn_core::utils::get_cached::<Cache>(FILE_NAME)
+            .map(|c| c.assets)
+            .unwrap_or_default()
+    }
+
+    /// Pages rewritten after they were built get a new checksum, update it in the cache so the
+    /// next build does not treat them as modified. The asset manifest is stored so the next
+    /// build can move pages it does not rebuild to the new name of a changed asset.
+    pub(crate) fn update_fingerprints(
+        checksums: &std::collections::HashMap<String, String>,
+        assets: std::collections::BTreeMap<String, String>,
+    ) -> fastn_core::Result<()> {
+        let mut c: Cache = match fastn_core::utils::get_cached(FILE_NAME) {
+            Some(c) => c,
+            None => return Ok(()),
+        };
+        for doc in c.documents.values_mut() {
+            if let Some(checksum) = checksums.get(&doc.html_checksum) {
+                doc.html_checksum = checksum.to_string();
+            }
+        }
+        c.assets = assets;
+        c.cache_it()
     }
 
     impl Cache {
@@ -2,6 +2,417 @@ pub const INDEX_FILE: &str = "index.html";
 pub const BUILD_FOLDER: &str = ".build";
 pub const IGNORED_DIRECTORIES: [&str; 4] = ["-", "images", "static", "assets"];
 
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Severity {
+    Error,
+    Warning,
+}
+
+/// A problem `fastn check` found, this is what `--json` prints.
+#[derive(Debug, serde::Serialize)]
+struct Problem {
+    severity: Severity,
+    /// What kind of problem it is: `error` for anything the interpreter rejects, like type
+    /// mismatches and unknown components or arguments, else the name of the lint or of the
+    /// accessibility check
+    code: &'static str,
+    file: String,
+    line: Option<usize>,
+    column: Option<usize>,
+    message: String,
+    #[serde(skip)]
+    rendered: String,
+}
+
+impl Problem {
+    /// A warning on `line` of `doc`, about `name` if given, else about the whole line.
+    fn warning(
+        code: &'static str,
+        doc: &fastn_core::Document,
+        line: usize,
+        name: Option<&str>,
+        message: String,
+    ) -> Problem {
+        let mark = name.map(|name| ftd::interpreter::Mark::Name(name.to_string()));
+        let span = ftd::interpreter::Span::new(doc.content.as_str(), line, mark.as_ref());
+        let column = span.as_ref().map(|s| s.column);
+        Problem {
+            severity: Severity::Warning,
+            code,
+            file: doc.id.to_string(),
+            line: Some(line),
+            column,
+            rendered: format!(
+                "warning: {}\n  --> {}:{}:{}",
+                message,
+                doc.id,
+                line,
+                column.unwrap_or(1)
+            ),
+            message,
+        }
+    }
+}
+
+/// Type-checks every ftd document of the package, and the documents they import, without
+/// rendering them: processors are not run, a variable set by a processor is taken to be of its
+/// declared kind. Along with the errors of the interpreter it warns about unused imports and
+/// variables, or-type variants that are never used and accessibility problems (see
+/// `ftd::interpreter::a11y::check`), and reports sitemap entries whose document does not exist.
+/// Nothing is written to `.build`.
+///
+/// With `json` the problems are printed as a JSON list, for editors and CI. Fails if there is any
+/// error, warnings alone do not fail.
+pub async fn check(config: &fastn_core::Config, json: bool) -> fastn_core::Result<()> {
+    use colored::Colorize;
+
+    let documents = documents(config).await?;
+    let problems = problems(config, documents.as_slice()).await?;
+
+    let errors = problems
+        .iter()
+        .filter(|p| p.severity == Severity::Error)
+        .count();
+    let warnings = problems.len() - errors;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&problems)?);
+    } else {
+        for problem in problems.iter() {
+            println!("{}\n", problem.rendered);
+        }
+        let summary = format!(
+            "Checked {} document(s): {} error(s), {} warning(s)",
+            documents.len(),
+            errors,
+            warnings
+        );
+        if errors > 0 {
+            println!("{}", summary.red());
+        } else if warnings > 0 {
+            println!("{}", summary.yellow());
+        } else {
+            println!("{}", summary.green());
+        }
+    }
+
+    if errors > 0 {
+        return Err(fastn_core::Error::UsageError {
+            message: format!("`fastn check` found {} error(s)", errors),
+        });
+    }
+    Ok(())
+}
+
+async fn documents(config: &fastn_core::Config) -> fastn_core::Result<Vec<fastn_core::Document>> {
+    Ok(config
+        .get_files(&config.package)
+        .await?
+        .into_iter()
+        .filter_map(|file| match file {
+            fastn_core::File::Ftd(doc) if !doc.id.eq("FASTN.ftd") => Some(doc),
+            _ => None,
+        })
+        .collect())
+}
+
+async fn problems(
+    config: &fastn_core::Config,
+    documents: &[fastn_core::Document],
+) -> fastn_core::Result<Vec<Problem>> {
+    let mut problems: Vec<Problem> = vec![];
+    let mut interpreted = vec![];
+    for doc in documents.iter() {
+        let (doc_problems, document) = interpret(config, doc).await?;
+        interpreted.push(document);
+        for problem in doc_problems {
+            // an error in an imported document is found by every document importing it
+            if !problems.iter().any(|p| {
+                p.file == problem.file && p.line == problem.line && p.message == problem.message
+            }) {
+                problems.push(problem);
+            }
+        }
+    }
+    problems.extend(sitemap_problems(
+        config.package.sitemap.as_ref(),
+        config.root.as_path(),
+    ));
+    problems.extend(lint(documents, interpreted.as_slice()));
+    Ok(problems)
+}
+
+/// The problems of `doc`, and the interpreted document if it has no errors.
+async fn interpret(
+    config: &fastn_core::Config,
+    doc: &fastn_core::Document,
+) -> fastn_core::Result<(Vec<Problem>, Option<ftd::interpreter::Document>)> {
+    let req = fastn_core::http::Request::default();
+    let mut req_config = fastn_core::RequestConfig::new(config, &req, doc.id.as_str(), "/");
+    req_config.current_document = Some(doc.id.to_string());
+
+    // Get Prefix Body => [AutoImports + Actual Doc content]
+    let mut doc_content =
+        config
+            .package
+            .get_prefixed_body(doc.content.as_str(), doc.id.as_str(), true);
+    // Fix aliased imports to full path (if any)
+    doc_content = config
+        .package
+        .fix_imports_in_body(doc_content.as_str(), doc.id.as_str())?;
+
+    let name = doc.id_with_package();
+    let line_number = doc_content.split('\n').count() - doc.content.split('\n').count();
+    let error = match fastn_core::doc::interpret_without_processors(
+        name.as_str(),
+        doc_content.as_str(),
+        &mut req_config,
+        line_number,
+    )
+    .await
+    {
+        Ok(document) => {
+            // accessibility is checked once the document has no errors
+            let problems = ftd::interpreter::a11y::check(&document)
+                .into_iter()
+                .map(|issue| {
+                    Problem::warning(issue.code, doc, issue.line_number, None, issue.message)
+                })
+                .collect();
+            return Ok((problems, Some(document)));
+        }
+        Err(e) => e,
+    };
+
+    let problems = error
+        .diagnostics()
+        .iter()
+        .map(|diagnostic| {
+            let span = diagnostic.span(name.as_str(), doc.content.as_str());
+            let file = match diagnostic.doc_id {
+                Some(ref doc_id) if span.is_none() => doc_id.to_string(),
+                _ => doc.id.to_string(),
+            };
+            Problem {
+                severity: Severity::Error,
+                code: "error",
+                file,
+                line: span
+                    .as_ref()
+                    .map(|s| s.line_number)
+                    .or(diagnostic.line_number),
+                column: span.as_ref().map(|s| s.column),
+                message: diagnostic.message.to_string(),
+                rendered: diagnostic.render(name.as_str(), doc.content.as_str()),
+            }
+        })
+        .collect();
+    Ok((problems, None))
+}
+
+fn sitemap_problems(
+    sitemap: Option<&fastn_core::sitemap::Sitemap>,
+    root: &camino::Utf8Path,
+) -> Vec<Problem> {
+    let sitemap = match sitemap {
+        Some(sitemap) => sitemap,
+        None => return vec![],
+    };
+    sitemap
+        .get_all_locations()
+        .into_iter()
+        .filter(|(file_location, translation_file_location, _)| {
+            !file_location.exists()
+                && translation_file_location
+                    .as_ref()
+                    .map_or(true, |location| !location.exists())
+        })
+        .map(|(file_location, _, _)| {
+            let message = format!(
+                "sitemap entry points to `{}` which does not exist",
+                file_location.strip_prefix(root).unwrap_or(file_location)
+            );
+            Problem {
+                severity: Severity::Error,
+                code: "missing-sitemap-document",
+                file: "FASTN.ftd".to_string(),
+                line: None,
+                column: None,
+                rendered: format!("error: {}\n  --> FASTN.ftd", message),
+                message,
+            }
+        })
+        .collect()
+}
+
+/// Warnings about imports and variables never used, and or-type variants never used anywhere in
+/// the package. What is used is what the interpreter resolved while interpreting the documents,
+/// `interpreted` has the interpreted document of each of `documents`, or `None` if it has
+/// errors. Nothing is reported for a document with errors, and no variant is reported if any
+/// document has errors, a variant may be used there.
+fn lint(
+    documents: &[fastn_core::Document],
+    interpreted: &[Option<ftd::interpreter::Document>],
+) -> Vec<Problem> {
+    let asts = documents
+        .iter()
+        .map(|doc| {
+            let (sections, _) =
+                ftd::p1::parse_with_errors(doc.content.as_str(), doc.id.as_str(), 0);
+            ftd::ast::AST::from_sections_with_errors(sections.as_slice(), doc.id.as_str()).0
+        })
+        .collect::<Vec<_>>();
+
+    // anything defined in a document other documents import may be used there
+    let imported = asts
+        .iter()
+        .flatten()
+        .filter_map(|ast| match ast {
+            ftd::ast::AST::Import(import) => Some(import.module.trim_matches('/').to_string()),
+            _ => None,
+        })
+        .collect::<std::collections::HashSet<_>>();
+
+    let variants_used = interpreted
+        .iter()
+        .map(|document| document.as_ref().map(used_variants))
+        .collect::<Option<Vec<_>>>()
+        .map(|used| {
+            used.into_iter()
+                .flatten()
+                .collect::<std::collections::HashSet<_>>()
+        });
+
+    let mut problems = vec![];
+    for ((doc, asts), document) in documents.iter().zip(asts.iter()).zip(interpreted.iter()) {
+        let document = match document {
+            Some(document) => document,
+            None => continue,
+        };
+        let tdoc = document.tdoc();
+        let is_imported = imported.contains(doc.id_with_package().trim_matches('/'));
+        for ast in asts {
+            match ast {
+                ftd::ast::AST::Import(import) => {
+                    if import.exports.is_some()
+                        || matches!(import.exposing, Some(ftd::ast::Exposing::All))
+                    {
+                        continue;
+                    }
+                    let module = match document.aliases.get(import.alias.as_str()) {
+                        Some(module) => format!("{}#", module),
+                        None => continue,
+                    };
+                    // processors are run, not resolved, they are only named by the variables
+                    let processor = format!("{}.", import.alias);
+                    let used = document.data.keys().any(|name| name.starts_with(&module))
+                        || asts.iter().any(|ast| match ast {
+                            ftd::ast::AST::VariableDefinition(variable) => variable
+                                .processor
+                                .as_ref()
+                                .is_some_and(|p| p.starts_with(processor.as_str())),
+                            _ => false,
+                        });
+                    if !used {
+                        problems.push(Problem::warning(
+                            "unused-import",
+                            doc,
+                            import.line_number,
+                            Some(import.alias.as_str()),
+                            format!("`{}` is imported but never used", import.alias),
+                        ));
+                    }
+                }
+                ftd::ast::AST::VariableDefinition(variable)
+                    if !is_imported
+                        && variable.flags.always_include != Some(true)
+                        && !document
+                            .data
+                            .contains_key(tdoc.resolve_name(variable.name.as_str()).as_str()) =>
+                {
+                    problems.push(Problem::warning(
+                        "unused-variable",
+                        doc,
+                        variable.line_number,
+                        Some(variable.name.as_str()),
+                        format!("`{}` is defined but never used", variable.name),
+                    ));
+                }
+                ftd::ast::AST::OrType(or_type) => {
+                    let variants_used = match variants_used {
+                        Some(ref variants_used) => variants_used,
+                        None => continue,
+                    };
+                    let or_type_name = tdoc.resolve_name(or_type.name.as_str());
+                    for variant in or_type.variants.iter() {
+                        let name = variant.name();
+                        let full_variant =
+                            without_trailing_slash(format!("{}.{}", or_type_name, name).as_str());
+                        if !variants_used.contains(&full_variant) {
+                            problems.push(Problem::warning(
+                                "unreachable-variant",
+                                doc,
+                                variant.line_number(),
+                                Some(name.as_str()),
+                                format!("variant `{}` of `{}` is never used", name, or_type.name),
+                            ));
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    problems
+}
+
+/// The full names of the or-type variants `document` uses, `<module>#<or-type>.<variant>`. They
+/// are in the values and the kinds of what the interpreter resolved, the definitions of the
+/// or-types are left out.
+fn used_variants(document: &ftd::interpreter::Document) -> Vec<String> {
+    fn find(value: &serde_json::Value, used: &mut Vec<String>) {
+        match value {
+            serde_json::Value::Object(map) => {
+                if let (
+                    Some(serde_json::Value::String(name)),
+                    Some(serde_json::Value::String(variant)),
+                ) = (map.get("name"), map.get("variant"))
+                {
+                    // the variant of a value is `<or-type>.<variant>`, of a kind just `<variant>`
+                    let variant = variant.rsplit('.').next().unwrap_or_default();
+                    used.push(without_trailing_slash(
+                        format!("{}.{}", name, variant).as_str(),
+                    ));
+                }
+                map.values().for_each(|value| find(value, used));
+            }
+            serde_json::Value::Array(values) => values.iter().for_each(|value| find(value, used)),
+            _ => {}
+        }
+    }
+
+    let mut used = vec![];
+    for thing in document.data.values() {
+        if !matches!(thing, ftd::interpreter::Thing::OrType(_)) {
+            find(&serde_json::to_value(thing).unwrap_or_default(), &mut used);
+        }
+    }
+    find(
+        &serde_json::to_value(&document.tree).unwrap_or_default(),
+        &mut used,
+    );
+    used
+}
+
+/// `foo/bar#x` for `foo/bar/#x`, a document is named with a trailing `/` when it is
+/// interpreted, and without one when it is imported.
+fn without_trailing_slash(name: &str) -> String {
+    match name.split_once('#') {
+        Some((module, rest)) => format!("{}#{}", module.trim_end_matches('/'), rest),
+        None => name.to_string(),
+    }
+}
+
 pub async fn post_build_check(config: &fastn_core::Config) -> fastn_core::Result<()> {
     let build_path = config.root.join(BUILD_FOLDER);
     let build_directory = build_path.as_str().to_string();
@@ -67,3 +478,153 @@ async fn check_index_in_folders(
 fn is_ignored_directory(path: &camino::Utf8PathBuf) -> bool {
     IGNORED_DIRECTORIES.iter().any(|dir| path.ends_with(dir))
 }
+
+#[cfg(test)]
+mod tests {
+    fn codes(problems: &[super::Problem]) -> Vec<(&'static str, &str, Option<usize>)> {
+        problems
+            .iter()
+            .map(|p| (p.code, p.file.as_str(), p.line))
+            .collect()
+    }
+
+    /// The warnings come from what the interpreter resolved: `small` is used from another
+    /// document, `unused`, `other` and `large` are not used at all
+    #[tokio::test]
+    async fn lint() {
+        let package = fastn_core::test_package::TestPackage::new(
+            "check-lint-test",
+            "",
+            &[
+                (
+                    "index.ftd",
+                    indoc::indoc! {"
+                        -- import: check-lint-test/lib
+                        -- import: check-lint-test/other
+
+                        -- string unused: never
+
+                        -- ftd.text: $lib.title
+                        if: { lib.current == small }
+                    "},
+                ),
+                // everything defined here can be used by the documents importing it
+                (
+                    "lib.ftd",
+                    indoc::indoc! {"
+                        -- or-type size:
+
+                        -- constant string small: small
+
+                        -- constant string large: large
+
+                        -- end: size
+
+                        -- size current: small
+
+                        -- string title: Hello
+                    "},
+                ),
+                ("other.ftd", "-- string name: Other\n"),
+            ],
+        );
+
+        let config = package.config().await;
+        let documents = super::documents(&config).await.unwrap();
+        let problems = super::problems(&config, documents.as_slice())
+            .await
+            .unwrap();
+        let mut found = codes(problems.as_slice());
+        found.sort_by_key(|(_, file, line)| (file.to_string(), *line));
+        assert_eq!(
+            found,
+            vec![
+                ("unused-import", "index.ftd", Some(2)),
+                ("unused-variable", "index.ftd", Some(4)),
+                ("unreachable-variant", "lib.ftd", Some(5)),
+            ]
+        );
+        let message = |code: &str| {
+            problems
+                .iter()
+                .find(|p| p.code == code)
+                .map(|p| p.message.as_str())
+        };
+        assert_eq!(
+            message("unused-import"),
+            Some("`other` is imported but never used")
+        );
+        assert_eq!(
+            message("unreachable-variant"),
+            Some("variant `large` of `size` is never used")
+        );
+        assert!(problems
+            .iter()
+            .all(|p| p.severity == super::Severity::Warning));
+    }
+
+    #[test]
+    fn sitemap_problems() {
+        let root = camino::Utf8Path::new("/check-test");
+        let sitemap = fastn_core::sitemap::Sitemap {
+            sections: vec![fastn_core::sitemap::section::Section {
+                id: "/missing/".to_string(),
+                file_location: Some(root.join("missing.ftd")),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let problems = super::sitemap_problems(Some(&sitemap), root);
+        assert_eq!(
+            codes(problems.as_slice()),
+            vec![("missing-sitemap-document", "FASTN.ftd", None)]
+        );
+        assert_eq!(
+            problems[0].message,
+            "sitemap entry points to `missing.ftd` which does not exist"
+        );
+        assert!(super::sitemap_problems(None, root).is_empty());
+    }
+
+    /// `fastn check` of a package on disk: an interpreter error, without running processors, and
+    /// the problems as `--json` prints them
+    #[tokio::test]
+    async fn problems() {
+        let package = fastn_core::test_package::TestPackage::new(
+            "check-test",
+            "",
+            &[(
+                "index.ftd",
+                indoc::indoc! {"
+                    -- import: fastn/processors as pr
+
+                    -- string greeting:
+                    $processor$: pr.request-data
+
+                    -- ftd.text: $greeting
+
+                    -- ftd.text: $missing
+                "},
+            )],
+        );
+
+        let config = package.config().await;
+        let documents = super::documents(&config).await.unwrap();
+        let problems = super::problems(&config, documents.as_slice())
+            .await
+            .unwrap();
+
+        // the processor is not run, `greeting` is taken to be a string
+        assert_eq!(
+            codes(problems.as_slice()),
+            vec![("error", "index.ftd", Some(8))]
+        );
+        let json = serde_json::to_value(&problems).unwrap();
+        assert_eq!(json[0]["severity"], "error");
+        assert_eq!(json[0]["code"], "error");
+        assert_eq!(json[0]["file"], "index.ftd");
+        assert_eq!(json[0]["line"], 8);
+        assert!(json[0].get("rendered").is_none());
+    }
+}
@@ -0,0 +1,63 @@
+/// Rewrites the ftd files of the package, or the given `files`, in the canonical layout, see
+/// `ftd::p1::format`. With `check` nothing is written, it fails if any file is not formatted, so
+/// it can be used in CI.
+pub async fn fmt(
+    config: &fastn_core::Config,
+    files: Option<Vec<String>>,
+    check: bool,
+) -> fastn_core::Result<()> {
+    use colored::Colorize;
+
+    let documents = if let Some(ref files) = files {
+        let files = files
+            .iter()
+            .map(|x| config.root.join(x))
+            .collect::<Vec<camino::Utf8PathBuf>>();
+        fastn_core::paths_to_files(config.package.name.as_str(), files, config.root.as_path())
+            .await?
+    } else {
+        config.get_files(&config.package).await?
+    };
+
+    let mut unformatted = vec![];
+    let mut failed = vec![];
+    for file in documents {
+        let doc = match file {
+            fastn_core::File::Ftd(ref doc) => doc,
+            _ => continue,
+        };
+        let formatted = match ftd::p1::format(doc.content.as_str(), doc.id.as_str()) {
+            Ok(formatted) => formatted,
+            Err(e) => {
+                println!("{} {}: {}", "Failed".red(), doc.id, e);
+                failed.push(doc.id.to_string());
+                continue;
+            }
+        };
+        if formatted == doc.content {
+            continue;
+        }
+        if check {
+            println!("{} {}", "Not formatted".yellow(), doc.id);
+        } else {
+            fastn_core::utils::update(file.get_full_path(), formatted.as_bytes()).await?;
+            println!("Formatted {}", doc.id);
+        }
+        unformatted.push(doc.id.to_string());
+    }
+
+    if !failed.is_empty() {
+        return Err(fastn_core::Error::UsageError {
+            message: format!("could not format: {}", failed.join(", ")),
+        });
+    }
+    if check && !unformatted.is_empty() {
+        return Err(fastn_core::Error::UsageError {
+            message: format!(
+                "{} file(s) not formatted, run `fastn fmt` to format them",
+                unformatted.len()
+            ),
+        });
+    }
+    Ok(())
+}
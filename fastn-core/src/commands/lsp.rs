@@ -0,0 +1,801 @@
+// `fastn lsp`: a language server for the `.ftd` files of the package, speaking the Language Server
+// Protocol over stdin and stdout.
+//
+// Every open document is interpreted with `fastn_core::doc::interpret_without_processors`, so
+// imports are resolved through the package's dependencies like `fastn serve` does, but processors
+// are not run, like `fastn check`. The interpreted document is used for go-to-definition, hover,
+// completion and document symbols, and its errors are published as diagnostics. While the document has errors the last document
+// which interpreted fine is used, so navigation keeps working while the user is typing.
+//
+// Positions in LSP are UTF-16 offsets, ftd line numbers are 1 based.
+
+const COMPLETION_ITEM_KIND_KEYWORD: u32 = 14;
+const COMPLETION_ITEM_KIND_FIELD: u32 = 5;
+const COMPLETION_ITEM_KIND_VARIABLE: u32 = 6;
+const COMPLETION_ITEM_KIND_CLASS: u32 = 7;
+
+const SYMBOL_KIND_CLASS: u32 = 5;
+const SYMBOL_KIND_ENUM: u32 = 10;
+const SYMBOL_KIND_FUNCTION: u32 = 12;
+const SYMBOL_KIND_VARIABLE: u32 = 13;
+const SYMBOL_KIND_STRUCT: u32 = 23;
+
+const DIAGNOSTIC_SEVERITY_ERROR: u32 = 1;
+
+const SECTION_KEYWORDS: [&str; 6] = [
+    "import",
+    "component",
+    "record",
+    "or-type",
+    "web-component",
+    "end",
+];
+
+pub async fn lsp(config: &fastn_core::Config) -> fastn_core::Result<()> {
+    let mut stdin = tokio::io::BufReader::new(tokio::io::stdin());
+    let mut stdout = tokio::io::stdout();
+    let mut server = Server {
+        config,
+        documents: Default::default(),
+    };
+
+    while let Some(message) = read_message(&mut stdin).await? {
+        let method = message
+            .get("method")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+        let id = message.get("id").cloned();
+        let params = message.get("params").cloned().unwrap_or_default();
+
+        if method == "exit" {
+            break;
+        }
+
+        for out in server.handle(method.as_str(), id, params).await {
+            write_message(&mut stdout, &out).await?;
+        }
+    }
+
+    Ok(())
+}
+
+struct Server<'a> {
+    config: &'a fastn_core::Config,
+    /// Open documents by uri
+    documents: std::collections::HashMap<String, OpenDocument>,
+}
+
+struct OpenDocument {
+    text: String,
+    analysis: Option<Analysis>,
+}
+
+struct Analysis {
+    document: ftd::interpreter::Document,
+    /// Package of every module the document imports, see `RequestConfig::module_package_map`
+    module_package_map: std::collections::BTreeMap<String, String>,
+}
+
+impl<'a> Server<'a> {
+    /// Messages to send back: the response if `id` is set, and notifications.
+    async fn handle(
+        &mut self,
+        method: &str,
+        id: Option<serde_json::Value>,
+        params: serde_json::Value,
+    ) -> Vec<serde_json::Value> {
+        let uri = params
+            .pointer("/textDocument/uri")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+
+        let result = match method {
+            "initialize" => serde_json::json!({
+                "capabilities": {
+                    // full text of the document is sent on every change
+                    "textDocumentSync": {"openClose": true, "change": 1, "save": true},
+                    "definitionProvider": true,
+                    "hoverProvider": true,
+                    "completionProvider": {"triggerCharacters": ["-", "$", "."]},
+                    "documentSymbolProvider": true,
+                },
+                "serverInfo": {"name": "fastn", "version": env!("CARGO_PKG_VERSION")},
+            }),
+            "shutdown" => serde_json::Value::Null,
+            "textDocument/didOpen" => {
+                let text = params
+                    .pointer("/textDocument/text")
+                    .and_then(serde_json::Value::as_str)
+                    .unwrap_or_default();
+                return self.update(uri, text.to_string()).await;
+            }
+            "textDocument/didChange" => {
+                let text = params
+                    .pointer("/contentChanges")
+                    .and_then(serde_json::Value::as_array)
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change.get("text"))
+                    .and_then(serde_json::Value::as_str);
+                return match text {
+                    Some(text) => self.update(uri, text.to_string()).await,
+                    None => vec![],
+                };
+            }
+            "textDocument/didSave" => {
+                // imported documents may have changed on disk
+                return match self.documents.get(uri.as_str()) {
+                    Some(open) => {
+                        let text = open.text.clone();
+                        self.update(uri, text).await
+                    }
+                    None => vec![],
+                };
+            }
+            "textDocument/didClose" => {
+                self.documents.remove(uri.as_str());
+                return vec![diagnostics_notification(uri.as_str(), vec![])];
+            }
+            "textDocument/definition" => self
+                .definition(uri.as_str(), position(&params))
+                .unwrap_or_default(),
+            "textDocument/hover" => self
+                .hover(uri.as_str(), position(&params))
+                .unwrap_or_default(),
+            "textDocument/completion" => self
+                .completion(uri.as_str(), position(&params))
+                .unwrap_or_default(),
+            "textDocument/documentSymbol" => self.symbols(uri.as_str()).unwrap_or_default(),
+            _ => {
+                return match id {
+                    Some(id) => vec![serde_json::json!({
+                        "jsonrpc": "2.0",
+                        "id": id,
+                        "error": {"code": -32601, "message": format!("method not found: {method}")},
+                    })],
+                    // notifications we do not care about
+                    None => vec![],
+                };
+            }
+        };
+
+        match id {
+            Some(id) => vec![serde_json::json!({"jsonrpc": "2.0", "id": id, "result": result})],
+            None => vec![],
+        }
+    }
+
+    async fn update(&mut self, uri: String, text: String) -> Vec<serde_json::Value> {
+        let (analysis, diagnostics) = match analyse(self.config, uri.as_str(), text.as_str()).await
+        {
+            Ok(analysis) => (Some(analysis), vec![]),
+            Err(e) => {
+                let (_, name) = document_id(self.config, uri.as_str());
+                (
+                    None,
+                    diagnostics(uri.as_str(), text.as_str(), name.as_str(), &e),
+                )
+            }
+        };
+
+        let open = self
+            .documents
+            .entry(uri.clone())
+            .or_insert_with(|| OpenDocument {
+                text: "".to_string(),
+                analysis: None,
+            });
+        open.text = text;
+        if analysis.is_some() {
+            open.analysis = analysis;
+        }
+
+        vec![diagnostics_notification(uri.as_str(), diagnostics)]
+    }
+
+    fn definition(&self, uri: &str, position: (usize, usize)) -> Option<serde_json::Value> {
+        let open = self.documents.get(uri)?;
+        let analysis = open.analysis.as_ref()?;
+        let line = open.text.lines().nth(position.0)?;
+
+        if let Some(module) = import_module(line) {
+            let module = analysis
+                .document
+                .aliases
+                .get(module)
+                .map(String::as_str)
+                .unwrap_or(module);
+            let path = self.module_path(analysis, module)?;
+            return Some(location(path.as_str(), 0));
+        }
+
+        let (module, thing) = self.resolve(analysis, word_at(line, position.1)?)?;
+        let line_number = thing.line_number().saturating_sub(1);
+        if module == analysis.document.name {
+            return Some(serde_json::json!({"uri": uri, "range": range(line_number, 0, 0)}));
+        }
+        let path = self.module_path(analysis, module.as_str())?;
+        Some(location(path.as_str(), line_number))
+    }
+
+    fn hover(&self, uri: &str, position: (usize, usize)) -> Option<serde_json::Value> {
+        let open = self.documents.get(uri)?;
+        let analysis = open.analysis.as_ref()?;
+        let line = open.text.lines().nth(position.0)?;
+        let (_, thing) = self.resolve(analysis, word_at(line, position.1)?)?;
+
+        Some(serde_json::json!({
+            "contents": {
+                "kind": "markdown",
+                "value": format!("```ftd\n{}\n```", signature(&thing)),
+            }
+        }))
+    }
+
+    fn completion(&self, uri: &str, position: (usize, usize)) -> Option<serde_json::Value> {
+        let open = self.documents.get(uri)?;
+        let analysis = open.analysis.as_ref()?;
+        let document = &analysis.document;
+        let lines: Vec<&str> = open.text.lines().collect();
+        let line = lines.get(position.0).copied().unwrap_or_default();
+        let before = &line[..byte_offset(line, position.1)];
+
+        let mut items = vec![];
+        if let Some(section) = before.trim_start().strip_prefix("-- ") {
+            if section.contains(':') {
+                // value of the section, only variables make sense there
+                if before.contains('$') {
+                    items.extend(things(document, COMPLETION_ITEM_KIND_VARIABLE, |t| {
+                        matches!(t, ftd::interpreter::Thing::Variable(_))
+                    }));
+                }
+            } else {
+                items.extend(SECTION_KEYWORDS.iter().map(|keyword| {
+                    serde_json::json!({"label": keyword, "kind": COMPLETION_ITEM_KIND_KEYWORD})
+                }));
+                items.extend(things(document, COMPLETION_ITEM_KIND_CLASS, |t| {
+                    matches!(
+                        t,
+                        ftd::interpreter::Thing::Component(_)
+                            | ftd::interpreter::Thing::WebComponent(_)
+                    )
+                }));
+            }
+        } else if before.contains(':') {
+            if before.contains('$') {
+                items.extend(things(document, COMPLETION_ITEM_KIND_VARIABLE, |t| {
+                    matches!(t, ftd::interpreter::Thing::Variable(_))
+                }));
+            }
+        } else {
+            // header of a section, complete the arguments of the component being invoked
+            let component = lines[..position.0.min(lines.len())]
+                .iter()
+                .rev()
+                .filter_map(|l| l.trim_start().strip_prefix("-- "))
+                .find(|l| !l.starts_with("end:"))
+                .and_then(|l| l.split(':').next())
+                .and_then(|l| l.split_whitespace().last())?;
+            let arguments = match self.resolve(analysis, component)?.1 {
+                ftd::interpreter::Thing::Component(c) => c.arguments,
+                ftd::interpreter::Thing::WebComponent(c) => c.arguments,
+                _ => return None,
+            };
+            items.extend(arguments.iter().map(|argument| {
+                serde_json::json!({
+                    "label": argument.name,
+                    "kind": COMPLETION_ITEM_KIND_FIELD,
+                    "detail": kind_data_name(&argument.kind),
+                })
+            }));
+        }
+
+        Some(serde_json::Value::Array(items))
+    }
+
+    fn symbols(&self, uri: &str) -> Option<serde_json::Value> {
+        let document = &self.documents.get(uri)?.analysis.as_ref()?.document;
+        let prefix = format!("{}#", document.name);
+
+        let symbols = document
+            .data
+            .iter()
+            .filter_map(|(name, thing)| {
+                let kind = match thing {
+                    ftd::interpreter::Thing::Component(_)
+                    | ftd::interpreter::Thing::WebComponent(_) => SYMBOL_KIND_CLASS,
+                    ftd::interpreter::Thing::Record(_) => SYMBOL_KIND_STRUCT,
+                    ftd::interpreter::Thing::OrType(_) => SYMBOL_KIND_ENUM,
+                    ftd::interpreter::Thing::Variable(_) => SYMBOL_KIND_VARIABLE,
+                    ftd::interpreter::Thing::Function(_) => SYMBOL_KIND_FUNCTION,
+                    ftd::interpreter::Thing::OrTypeWithVariant { .. }
+                    | ftd::interpreter::Thing::Export { .. } => return None,
+                };
+                let line_number = thing.line_number().saturating_sub(1);
+                Some(serde_json::json!({
+                    "name": name.strip_prefix(prefix.as_str())?,
+                    "detail": signature(thing).lines().next().unwrap_or_default(),
+                    "kind": kind,
+                    "range": range(line_number, 0, 0),
+                    "selectionRange": range(line_number, 0, 0),
+                }))
+            })
+            .collect();
+
+        Some(serde_json::Value::Array(symbols))
+    }
+
+    /// Module and the thing `name` refers to, in the document or in one of its imports.
+    fn resolve(
+        &self,
+        analysis: &Analysis,
+        name: &str,
+    ) -> Option<(String, ftd::interpreter::Thing)> {
+        let name = name.trim_start_matches('$');
+        let tdoc = analysis.document.tdoc();
+        // `record-variable.field.sub-field` resolves to `record-variable`
+        let mut name = name.to_string();
+        loop {
+            let full_name = tdoc.resolve_name(name.as_str());
+            if let Some(thing) =
+                analysis.document.data.get(full_name.as_str()).or_else(|| {
+                    ftd::interpreter::default::get_default_bag().get(full_name.as_str())
+                })
+            {
+                let module = full_name
+                    .split_once('#')
+                    .map(|(m, _)| m)
+                    .unwrap_or_default();
+                return Some((module.to_string(), thing.clone()));
+            }
+            name = name.rsplit_once('.')?.0.to_string();
+        }
+    }
+
+    /// File of `module`, found through the package the interpreter resolved it to.
+    fn module_path(&self, analysis: &Analysis, module: &str) -> Option<camino::Utf8PathBuf> {
+        let module = module.trim_matches('/');
+        let package_name = analysis.module_package_map.get(module)?;
+        let package = if *package_name == self.config.package.name {
+            self.config.package.clone()
+        } else {
+            self.config
+                .all_packages
+                .borrow()
+                .get(package_name.as_str())?
+                .clone()
+        };
+        let root = self.config.get_root_for_package(&package);
+        let rest = module
+            .strip_prefix(package_name.as_str())
+            .unwrap_or(module)
+            .trim_matches('/');
+
+        let candidates = if rest.is_empty() {
+            vec![root.join("index.ftd")]
+        } else {
+            vec![
+                root.join(format!("{rest}.ftd")),
+                root.join(rest).join("index.ftd"),
+            ]
+        };
+        candidates.into_iter().find(|path| path.exists())
+    }
+}
+
+/// Id of the document at `uri`, relative to the package root, and its name in the interpreter.
+fn document_id(config: &fastn_core::Config, uri: &str) -> (String, String) {
+    let id = url::Url::parse(uri)
+        .ok()
+        .and_then(|uri| uri.to_file_path().ok())
+        .and_then(|path| camino::Utf8PathBuf::from_path_buf(path).ok())
+        .and_then(|path| {
+            path.strip_prefix(config.root.as_path())
+                .ok()
+                .map(|id| id.as_str().replace(std::path::MAIN_SEPARATOR, "/"))
+        })
+        .unwrap_or_else(|| "index.ftd".to_string());
+    let name = fastn_core::Document {
+        package_name: config.package.name.to_string(),
+        id: id.to_string(),
+        content: "".to_string(),
+        parent_path: config.root.to_string(),
+    }
+    .id_with_package();
+    (id, name)
+}
+
+async fn analyse(
+    config: &fastn_core::Config,
+    uri: &str,
+    text: &str,
+) -> Result<Analysis, ftd::interpreter::Error> {
+    let (id, name) = document_id(config, uri);
+
+    let req = fastn_core::http::Request::default();
+    let mut req_config = fastn_core::RequestConfig::new(config, &req, id.as_str(), "/");
+    req_config.current_document = Some(id.to_string());
+
+    // Get Prefix Body => [AutoImports + Actual Doc content]
+    let doc_content = config.package.get_prefixed_body(text, id.as_str(), true);
+    let line_number = doc_content.split('\n').count() - text.split('\n').count();
+    // a variable set by a processor is taken to be of its declared kind
+    let result = fastn_core::doc::interpret_without_processors(
+        name.as_str(),
+        doc_content.as_str(),
+        &mut req_config,
+        line_number,
+    )
+    .await;
+
+    // dependencies downloaded while interpreting, needed to find the files of their modules
+    config.all_packages.borrow_mut().extend(
+        req_config
+            .config
+            .all_packages
+            .borrow()
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone())),
+    );
+
+    Ok(Analysis {
+        document: result?,
+        module_package_map: req_config.module_package_map,
+    })
+}
+
+/// One diagnostic for every error in the document.
+fn diagnostics(
+    uri: &str,
+    text: &str,
+    name: &str,
+    error: &ftd::interpreter::Error,
+) -> Vec<serde_json::Value> {
+    error
+        .diagnostics()
+        .iter()
+        .map(|diagnostic| {
+            let related = diagnostic
+                .labels
+                .iter()
+                .filter_map(|label| {
+                    if label.doc_id.trim_matches('/') != name.trim_matches('/') {
+                        return None;
+                    }
+                    let span =
+                        ftd::interpreter::Span::new(text, label.line_number, label.mark.as_ref())?;
+                    Some(serde_json::json!({
+                        "location": {"uri": uri, "range": span_range(text, &span)},
+                        "message": label.message,
+                    }))
+                })
+                .collect::<Vec<_>>();
+
+            let range = match diagnostic.span(name, text) {
+                Some(span) => span_range(text, &span),
+                // an error in an imported document is shown on its import, or the first line for
+                // auto-imports
+                None => {
+                    let line_number = match (diagnostic.doc_id.as_deref(), diagnostic.line_number) {
+                        (Some(doc_id), _) if doc_id.trim_matches('/') != name.trim_matches('/') => {
+                            text.lines()
+                                .position(|line| {
+                                    import_module(line).map_or(false, |module| {
+                                        doc_id.trim_matches('/').ends_with(module.trim_matches('/'))
+                                    })
+                                })
+                                .unwrap_or_default()
+                        }
+                        (_, Some(line_number)) => line_number.saturating_sub(1),
+                        _ => 0,
+                    };
+                    let length = text
+                        .lines()
+                        .nth(line_number)
+                        .map(|line| line.encode_utf16().count())
+                        .unwrap_or_default();
+                    range(line_number, 0, length)
+                }
+            };
+
+            serde_json::json!({
+                "range": range,
+                "severity": DIAGNOSTIC_SEVERITY_ERROR,
+                "source": "fastn",
+                "message": diagnostic.message,
+                "relatedInformation": related,
+            })
+        })
+        .collect()
+}
+
+/// LSP range of `span`, LSP counts characters in UTF-16.
+fn span_range(text: &str, span: &ftd::interpreter::Span) -> serde_json::Value {
+    let line_start: usize = text
+        .split('\n')
+        .take(span.line_number - 1)
+        .map(|line| line.len() + 1)
+        .sum();
+    let line = text
+        .split('\n')
+        .nth(span.line_number - 1)
+        .unwrap_or_default();
+    let utf16 = |end: usize| {
+        line.get(..end.saturating_sub(line_start))
+            .unwrap_or(line)
+            .encode_utf16()
+            .count()
+    };
+    range(
+        span.line_number - 1,
+        utf16(span.range.start),
+        utf16(span.range.end),
+    )
+}
+
+fn diagnostics_notification(uri: &str, diagnostics: Vec<serde_json::Value>) -> serde_json::Value {
+    serde_json::json!({
+        "jsonrpc": "2.0",
+        "method": "textDocument/publishDiagnostics",
+        "params": {"uri": uri, "diagnostics": diagnostics},
+    })
+}
+
+/// Completion items for the things `filter` accepts, named the way the document can refer to
+/// them.
+fn things<F>(document: &ftd::interpreter::Document, kind: u32, filter: F) -> Vec<serde_json::Value>
+where
+    F: Fn(&ftd::interpreter::Thing) -> bool,
+{
+    document
+        .data
+        .iter()
+        .chain(ftd::interpreter::default::get_default_bag().iter())
+        .filter(|(_, thing)| filter(thing))
+        .filter_map(|(full_name, thing)| {
+            let (module, name) = full_name.split_once('#')?;
+            let label = if module == document.name {
+                name.to_string()
+            } else {
+                let alias = document
+                    .aliases
+                    .iter()
+                    .find(|(_, m)| m.as_str() == module)?
+                    .0;
+                format!("{alias}.{name}")
+            };
+            Some(serde_json::json!({
+                "label": label,
+                "kind": kind,
+                "detail": signature(thing).lines().next().unwrap_or_default(),
+            }))
+        })
+        .collect()
+}
+
+/// The declaration of `thing`, as it would be written in ftd.
+fn signature(thing: &ftd::interpreter::Thing) -> String {
+    fn short(name: &str) -> &str {
+        name.rsplit_once('#').map(|(_, n)| n).unwrap_or(name)
+    }
+
+    fn arguments(arguments: &[ftd::interpreter::Argument]) -> String {
+        arguments
+            .iter()
+            .map(|a| {
+                format!(
+                    "\n{} {}{}:",
+                    kind_data_name(&a.kind),
+                    if a.mutable { "$" } else { "" },
+                    a.name
+                )
+            })
+            .collect()
+    }
+
+    match thing {
+        ftd::interpreter::Thing::Record(r) => {
+            format!("-- record {}:{}", short(&r.name), arguments(&r.fields))
+        }
+        ftd::interpreter::Thing::OrType(o) => format!(
+            "-- or-type {}:{}",
+            short(&o.name),
+            o.variants
+                .iter()
+                .map(|v| format!("\n-- {}:", short(v.name().as_str())))
+                .collect::<String>()
+        ),
+        ftd::interpreter::Thing::OrTypeWithVariant { or_type, variant } => {
+            format!("{}.{}", short(or_type), short(variant.name().as_str()))
+        }
+        ftd::interpreter::Thing::Variable(v) => format!(
+            "-- {} {}{}:",
+            kind_data_name(&v.kind),
+            if v.mutable { "$" } else { "" },
+            short(&v.name)
+        ),
+        ftd::interpreter::Thing::Component(c) => {
+            format!(
+                "-- component {}:{}",
+                short(&c.name),
+                arguments(&c.arguments)
+            )
+        }
+        ftd::interpreter::Thing::WebComponent(w) => format!(
+            "-- web-component {}:{}",
+            short(&w.name),
+            arguments(&w.arguments)
+        ),
+        ftd::interpreter::Thing::Function(f) => format!(
+            "-- {} {}({}):",
+            kind_data_name(&f.return_kind),
+            short(&f.name),
+            f.arguments
+                .iter()
+                .map(|a| format!("{} {}", kind_data_name(&a.kind), a.name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        ftd::interpreter::Thing::Export { from, to, .. } => {
+            format!("{} (exported from {})", short(to), from)
+        }
+    }
+}
+
+fn kind_data_name(kind: &ftd::interpreter::KindData) -> String {
+    let name = kind_name(&kind.kind);
+    match (kind.caption, kind.body) {
+        (true, true) => format!("caption or body {name}"),
+        (true, false) => format!("caption {name}"),
+        (false, true) => format!("body {name}"),
+        (false, false) => name,
+    }
+}
+
+fn kind_name(kind: &ftd::interpreter::Kind) -> String {
+    match kind {
+        ftd::interpreter::Kind::List { kind } => format!("{} list", kind_name(kind)),
+        ftd::interpreter::Kind::Optional { kind } => format!("optional {}", kind_name(kind)),
+        ftd::interpreter::Kind::Constant { kind } => format!("constant {}", kind_name(kind)),
+        ftd::interpreter::Kind::UI { .. } => "ftd.ui".to_string(),
+        ftd::interpreter::Kind::Record { name, .. }
+        | ftd::interpreter::Kind::OrType { name, .. } => name.replace('#', "."),
+        k => k.get_name(),
+    }
+}
+
+/// Module of an `-- import:` line, without the `as` alias.
+fn import_module(line: &str) -> Option<&str> {
+    let module = line.trim().strip_prefix("-- import:")?.trim();
+    Some(module.split(" as ").next().unwrap_or(module).trim())
+}
+
+/// The name under the cursor: a component, a variable reference, `alias.name` or a module path.
+fn word_at(line: &str, character: usize) -> Option<&str> {
+    let is_word = |c: char| c.is_alphanumeric() || "-_.#/$".contains(c);
+    let offset = byte_offset(line, character);
+    let start = line[..offset]
+        .char_indices()
+        .rev()
+        .find(|(_, c)| !is_word(*c))
+        .map(|(i, c)| i + c.len_utf8())
+        .unwrap_or(0);
+    let end = line[offset..]
+        .find(|c: char| !is_word(c))
+        .map(|i| offset + i)
+        .unwrap_or(line.len());
+    let word = line[start..end].trim_end_matches('.');
+    if word.is_empty() {
+        None
+    } else {
+        Some(word)
+    }
+}
+
+/// Byte offset of the UTF-16 `character` offset in `line`.
+fn byte_offset(line: &str, character: usize) -> usize {
+    let mut utf16 = 0;
+    for (i, c) in line.char_indices() {
+        if utf16 >= character {
+            return i;
+        }
+        utf16 += c.len_utf16();
+    }
+    line.len()
+}
+
+fn position(params: &serde_json::Value) -> (usize, usize) {
+    let get = |key: &str| {
+        params
+            .pointer(format!("/position/{key}").as_str())
+            .and_then(serde_json::Value::as_u64)
+            .unwrap_or_default() as usize
+    };
+    (get("line"), get("character"))
+}
+
+fn range(line: usize, start: usize, end: usize) -> serde_json::Value {
+    serde_json::json!({
+        "start": {"line": line, "character": start},
+        "end": {"line": line, "character": end},
+    })
+}
+
+fn location(path: &str, line: usize) -> serde_json::Value {
+    let uri = url::Url::from_file_path(path)
+        .map(|u| u.to_string())
+        .unwrap_or_else(|_| format!("file://{path}"));
+    serde_json::json!({"uri": uri, "range": range(line, 0, 0)})
+}
+
+async fn read_message<R>(reader: &mut R) -> fastn_core::Result<Option<serde_json::Value>>
+where
+    R: tokio::io::AsyncBufRead + Unpin,
+{
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt};
+
+    let mut content_length = None;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header).await? == 0 {
+            // the client has closed stdin
+            return Ok(None);
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                content_length = Some(value.trim().parse::<usize>()?);
+            }
+        }
+    }
+
+    let content_length = content_length.ok_or_else(|| fastn_core::Error::UsageError {
+        message: "lsp: message without a Content-Length header".to_string(),
+    })?;
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).await?;
+    Ok(Some(serde_json::from_slice(&body)?))
+}
+
+async fn write_message<W>(writer: &mut W, message: &serde_json::Value) -> fastn_core::Result<()>
+where
+    W: tokio::io::AsyncWrite + Unpin,
+{
+    use tokio::io::AsyncWriteExt;
+
+    let body = serde_json::to_vec(message)?;
+    writer
+        .write_all(format!("Content-Length: {}\r\n\r\n", body.len()).as_bytes())
+        .await?;
+    writer.write_all(&body).await?;
+    writer.flush().await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn word_at() {
+        let line = "-- ftd.text: $lib.title";
+        assert_eq!(super::word_at(line, 5), Some("ftd.text"));
+        assert_eq!(super::word_at(line, 16), Some("$lib.title"));
+        assert_eq!(super::word_at(line, 12), None);
+        assert_eq!(
+            super::import_module("-- import: fastn-community.github.io/doc-site as ds"),
+            Some("fastn-community.github.io/doc-site")
+        );
+    }
+
+    #[tokio::test]
+    async fn read_message() {
+        let input = "Content-Length: 17\r\n\r\n{\"method\":\"exit\"}";
+        let mut reader = tokio::io::BufReader::new(input.as_bytes());
+        let message = super::read_message(&mut reader).await.unwrap().unwrap();
+        assert_eq!(message["method"], "exit");
+        assert!(super::read_message(&mut reader).await.unwrap().is_none());
+    }
+}
@@ -8,6 +8,8 @@ pub mod create_cr;
 pub mod create_package;
 pub mod diff;
 pub mod edit;
+pub mod fmt;
+pub mod lsp;
 pub mod mark_resolved;
 pub mod mark_upto_date;
 pub mod merge;
@@ -38,56 +38,27 @@ async fn serve_file(
 
     let f = match config.get_file_and_package_by_id(path.as_str()).await {
         Ok(f) => f,
-        Err(e) => {
-            tracing::error!(
-                msg = "fastn-error path not found",
-                path = path.as_str(),
-                error = %e
-            );
-            return fastn_core::not_found!("fastn-Error: path: {}, {:?}", path, e);
-        }
+        Err(e) => match get_fingerprinted_file(config, path).await {
+            Some(f) => f,
+            None => {
+                tracing::error!(
+                    msg = "fastn-error path not found",
+                    path = path.as_str(),
+                    error = %e
+                );
+                return fastn_core::not_found!("fastn-Error: path: {}, {:?}", path, e);
+            }
+        },
     };
 
+    let if_none_match = config.request.if_none_match().map(ToString::to_string);
+    let immutable = fastn_core::fingerprint::is_fingerprinted(path.as_str());
+
     // Auth Stuff
     if !f.is_static() {
-        match config.can_read(path.as_str(), true).await {
-            Ok(can_read) => {
-                if !can_read {
-                    tracing::error!(
-                        msg = "unauthorized-error: can not read",
-                        path = path.as_str()
-                    );
-                    return fastn_core::unauthorised!("You are unauthorized to access: {}", path);
-                }
-            }
-            Err(e) => {
-                tracing::error!(msg = "can_read-error", path = path.as_str());
-                return fastn_core::server_error!("fastn-Error: can_read error: {}, {:?}", path, e);
-            }
-        };
-
-        match fastn_core::package::app::can_read(config, path.as_str()).await {
-            Ok(can_read) => {
-                if !can_read {
-                    tracing::error!(
-                        msg = "unauthorized-error: can not access app",
-                        path = path.as_str()
-                    );
-                    return fastn_core::unauthorised!("You are unauthorized to access: {}", path);
-                }
-            }
-            Err(err) => {
-                tracing::error!(
-                    msg = "app::can_read-error: can not access app",
-                    path = path.as_str()
-                );
-                return fastn_core::server_error!(
-                    "fastn-Error: can_read error: {}, {:?}",
-                    path,
-                    err
-                );
-            }
-        };
+        if let Some(response) = read_denied(config, path).await {
+            return response;
+        }
     }
 
     match f {
@@ -116,14 +87,23 @@ async fn serve_file(
                 }
             }
         }
-        fastn_core::File::Image(image) => fastn_core::http::ok_with_content_type(
+        fastn_core::File::Image(image) => fastn_core::http::ok_with_etag(
             image.content,
             guess_mime_type(image.id.as_str()),
+            if_none_match.as_deref(),
+            immutable,
         ),
-        fastn_core::File::Static(s) => fastn_core::http::ok(s.content),
-        fastn_core::File::Code(s) => fastn_core::http::ok_with_content_type(
+        fastn_core::File::Static(s) => fastn_core::http::ok_with_etag(
+            s.content,
+            guess_mime_type(s.id.as_str()),
+            if_none_match.as_deref(),
+            immutable,
+        ),
+        fastn_core::File::Code(s) => fastn_core::http::ok_with_etag(
             s.content.into_bytes(),
             guess_mime_type(s.id.as_str()),
+            if_none_match.as_deref(),
+            immutable,
         ),
         _ => {
             tracing::error!(msg = "unknown handler", path = path.as_str());
@@ -132,6 +112,148 @@ async fn serve_file(
     }
 }
 
+/// The error response if the current user can not read `path`, because of the readers of the
+/// document in the sitemap or of the app it is in.
+async fn read_denied(
+    config: &fastn_core::RequestConfig,
+    path: &camino::Utf8Path,
+) -> Option<fastn_core::http::Response> {
+    match config.can_read(path.as_str(), true).await {
+        Ok(can_read) => {
+            if !can_read {
+                tracing::error!(
+                    msg = "unauthorized-error: can not read",
+                    path = path.as_str()
+                );
+                return Some(fastn_core::unauthorised!(
+                    "You are unauthorized to access: {}",
+                    path
+                ));
+            }
+        }
+        Err(e) => {
+            tracing::error!(msg = "can_read-error", path = path.as_str());
+            return Some(fastn_core::server_error!(
+                "fastn-Error: can_read error: {}, {:?}",
+                path,
+                e
+            ));
+        }
+    };
+
+    match fastn_core::package::app::can_read(config, path.as_str()).await {
+        Ok(can_read) => {
+            if !can_read {
+                tracing::error!(
+                    msg = "unauthorized-error: can not access app",
+                    path = path.as_str()
+                );
+                return Some(fastn_core::unauthorised!(
+                    "You are unauthorized to access: {}",
+                    path
+                ));
+            }
+        }
+        Err(err) => {
+            tracing::error!(
+                msg = "app::can_read-error: can not access app",
+                path = path.as_str()
+            );
+            return Some(fastn_core::server_error!(
+                "fastn-Error: can_read error: {}, {:?}",
+                path,
+                err
+            ));
+        }
+    };
+
+    None
+}
+
+/// `fastn build` copies images and fonts to `<name>.<hash>.<ext>`, when such a url is requested
+/// from `fastn serve` we serve the original file, if its content still has the same hash.
+async fn get_fingerprinted_file(
+    config: &mut fastn_core::RequestConfig,
+    path: &camino::Utf8Path,
+) -> Option<fastn_core::File> {
+    let original = fastn_core::fingerprint::strip(path.as_str())?;
+    let f = config
+        .get_file_and_package_by_id(original.as_str())
+        .await
+        .ok()?;
+    let content = match &f {
+        fastn_core::File::Image(image) => image.content.as_slice(),
+        fastn_core::File::Static(s) => s.content.as_slice(),
+        _ => return None,
+    };
+    if fastn_core::fingerprint::name(original.as_str(), content) != path.as_str() {
+        return None;
+    }
+    Some(f)
+}
+
+#[tracing::instrument(skip_all)]
+async fn serve_cached_file(
+    config: &fastn_core::Config,
+    req_config: &mut fastn_core::RequestConfig,
+    path: &camino::Utf8Path,
+    only_js: bool,
+    cache_config: &fastn_core::response_cache::CacheConfig,
+) -> fastn_core::Result<fastn_core::http::Response> {
+    // a cached page must only be served to users who can read it
+    if let Some(response) = read_denied(req_config, path).await {
+        return Ok(response);
+    }
+    // a page only some users can read may show what only they can see, it is cached only when
+    // the route says the page depends on nothing but the user's groups
+    if !cache_config.user_group && req_config.has_readers(path.as_str()) {
+        let response = serve_file(req_config, path, only_js).await;
+        return Ok(with_processor_cookies(req_config, response));
+    }
+
+    let key = cache_config.key(req_config, path.as_str(), only_js).await?;
+
+    match fastn_core::response_cache::get(key.as_str()) {
+        fastn_core::response_cache::Lookup::Fresh(response) => return Ok(response),
+        fastn_core::response_cache::Lookup::Stale(response) => {
+            // serve the stale page right away, and render the fresh one in the background
+            let config = config.clone();
+            let request = req_config.request.clone();
+            let path = path.to_path_buf();
+            let cache_config = cache_config.clone();
+            actix_web::rt::spawn(async move {
+                let _lock = LOCK.read().await;
+                let mut req_config = fastn_core::RequestConfig::new(&config, &request, "", "/");
+                let response = serve_file(&mut req_config, &path, only_js).await;
+                let response = with_processor_cookies(&mut req_config, response);
+                fastn_core::response_cache::store(&key, path.as_str(), &cache_config, response)
+                    .await;
+            });
+            return Ok(response);
+        }
+        fastn_core::response_cache::Lookup::Miss => {}
+    }
+
+    let response = serve_file(req_config, path, only_js).await;
+    let response = with_processor_cookies(req_config, response);
+    Ok(fastn_core::response_cache::store(&key, path.as_str(), cache_config, response).await)
+}
+
+/// Moves the cookies set by processors to the response, `response_cache::store` does not cache
+/// responses setting cookies.
+fn with_processor_cookies(
+    req_config: &mut fastn_core::RequestConfig,
+    mut response: fastn_core::http::Response,
+) -> fastn_core::http::Response {
+    for cookie in std::mem::take(&mut req_config.processor_set_cookies) {
+        response.headers_mut().append(
+            actix_web::http::header::SET_COOKIE,
+            actix_web::http::header::HeaderValue::from_str(cookie.as_str()).unwrap(),
+        );
+    }
+    response
+}
+
 async fn serve_cr_file(
     req_config: &mut fastn_core::RequestConfig,
     path: &camino::Utf8Path,
@@ -213,23 +335,33 @@ async fn serve_fastn_file(config: &fastn_core::Config) -> fastn_core::http::Resp
     fastn_core::http::ok_with_content_type(response, mime_guess::mime::APPLICATION_OCTET_STREAM)
 }
 
-async fn favicon() -> fastn_core::Result<fastn_core::http::Response> {
+async fn favicon(
+    req: &fastn_core::http::Request,
+) -> fastn_core::Result<fastn_core::http::Response> {
     let mut path = camino::Utf8PathBuf::from("favicon.ico");
     if !path.exists() {
         path = camino::Utf8PathBuf::from("static/favicon.ico");
     }
-    Ok(static_file(path).await)
+    Ok(static_file(path, req.if_none_match()).await)
 }
 
 #[tracing::instrument(skip_all)]
-async fn static_file(file_path: camino::Utf8PathBuf) -> fastn_core::http::Response {
+async fn static_file(
+    file_path: camino::Utf8PathBuf,
+    if_none_match: Option<&str>,
+) -> fastn_core::http::Response {
     if !file_path.exists() {
         tracing::error!(msg = "no such static file ({})", path = file_path.as_str());
         return fastn_core::not_found!("no such static file ({})", file_path);
     }
 
     match fastn_core::tokio_fs::read(file_path.as_path()).await {
-        Ok(r) => fastn_core::http::ok_with_content_type(r, guess_mime_type(file_path.as_str())),
+        Ok(r) => fastn_core::http::ok_with_etag(
+            r,
+            guess_mime_type(file_path.as_str()),
+            if_none_match,
+            false,
+        ),
         Err(e) => {
             tracing::error!(
                 msg = "file-system-error ({})",
@@ -241,24 +373,82 @@ async fn static_file(file_path: camino::Utf8PathBuf) -> fastn_core::http::Respon
     }
 }
 
+/// Renders `502.ftd`, or `504.ftd` if the upstream timed out, from the package. If the package
+/// does not have the page, a plain text response is sent.
+#[tracing::instrument(skip(config, req))]
+async fn upstream_error_page(
+    config: &fastn_core::Config,
+    req: &fastn_core::http::Request,
+    message: &str,
+    timed_out: bool,
+) -> fastn_core::http::Response {
+    let status = if timed_out {
+        actix_web::http::StatusCode::GATEWAY_TIMEOUT
+    } else {
+        actix_web::http::StatusCode::BAD_GATEWAY
+    };
+    tracing::error!(
+        msg = "upstream-error",
+        status = status.as_u16(),
+        error = message
+    );
+
+    let mut req_config = fastn_core::RequestConfig::new(config, req, "", "/");
+    let page = camino::Utf8PathBuf::from(format!("{}/", status.as_u16()));
+    let mut response = serve_file(&mut req_config, page.as_path(), false).await;
+    if response.status() == actix_web::http::StatusCode::OK {
+        *response.status_mut() = status;
+        return response;
+    }
+
+    actix_web::HttpResponse::build(status)
+        .content_type(mime_guess::mime::TEXT_PLAIN_UTF_8)
+        .body(status.to_string())
+}
+
 #[tracing::instrument(skip_all)]
 pub async fn serve(
     config: &fastn_core::Config,
     req: fastn_core::http::Request,
 ) -> fastn_core::Result<fastn_core::http::Response> {
-    serve_helper(config, req, false).await
+    if !config.package.spa {
+        return serve_helper(config, req, false).await;
+    }
+
+    // the client side router fetches the next page's JS from the same url as the page itself
+    let only_js = req.is_spa_navigation();
+    let mut resp = serve_helper(config, req, only_js).await?;
+    resp.headers_mut().append(
+        actix_web::http::header::VARY,
+        actix_web::http::header::HeaderValue::from_static(fastn_core::http::SPA_HEADER),
+    );
+    Ok(resp)
 }
 
 #[tracing::instrument(skip_all)]
 pub async fn serve_helper(
     config: &fastn_core::Config,
-    req: fastn_core::http::Request,
+    mut req: fastn_core::http::Request,
     only_js: bool,
 ) -> fastn_core::Result<fastn_core::http::Response> {
     let _lock = LOCK.read().await;
 
-    let mut req_config = fastn_core::RequestConfig::new(config, &req, "", "/");
     let path: camino::Utf8PathBuf = req.path().replacen('/', "", 1).parse()?;
+    // The processors of a page can read the body, so it is read for the documents of the package.
+    // The body of a request which goes to the upstream is passed on as it comes in, see
+    // `fastn_core::proxy::get_out`.
+    if req.has_pending_body() {
+        let mut lookup = fastn_core::RequestConfig::new(config, &req, "", "/");
+        if lookup
+            .get_file_and_package_by_id(path.as_str())
+            .await
+            .is_ok()
+        {
+            req.read_body().await?;
+        }
+    }
+
+    let mut req_config = fastn_core::RequestConfig::new(config, &req, "", "/");
 
     let mut resp = if path.eq(&camino::Utf8PathBuf::new().join("FASTN.ftd")) {
         serve_fastn_file(config).await
@@ -317,11 +507,40 @@ pub async fn serve_helper(
             }
         }
 
+        // Routes served by the WASM backend of the package, see `fastn_core::wasm`. The readers
+        // of the path in the sitemap and of its app apply to them like to a document.
+        #[cfg(feature = "wasm")]
+        if let Some(route) = fastn_core::wasm::route_for(config, path.as_str()).await? {
+            if let Some(response) = read_denied(&req_config, path.as_path()).await {
+                return Ok(response);
+            }
+            req.read_body().await?;
+            return Ok(fastn_core::wasm::handle_wasm(config, &req, route).await);
+        }
+
         // if request goes with mount-point /todos/api/add-todo/
         // so it should say not found and pass it to proxy
         let cookies = req_config.request.cookies().clone();
 
-        let file_response = serve_file(&mut req_config, path.as_path(), only_js).await;
+        // Rendered pages can be cached per route, see `fastn_core::response_cache`
+        let cache_config = if req_method.as_str() == "GET" {
+            fastn_core::response_cache::config_for(&config.package, path.as_str())?
+        } else {
+            None
+        };
+        let file_response = match cache_config {
+            Some(cache_config) => {
+                serve_cached_file(
+                    config,
+                    &mut req_config,
+                    path.as_path(),
+                    only_js,
+                    &cache_config,
+                )
+                .await?
+            }
+            None => serve_file(&mut req_config, path.as_path(), only_js).await,
+        };
         // If path is not present in sitemap then pass it to proxy
         // TODO: Need to handle other package URL as well, and that will start from `-`
         // and all the static files starts with `-`
@@ -369,35 +588,25 @@ pub async fn serve_helper(
                 }
             }
 
-            return fastn_core::proxy::get_out(
+            let proxy_settings = config.package.proxy_settings(url.as_str());
+            return match fastn_core::proxy::get_out(
                 host.as_str(),
                 req,
                 url.path(),
                 package_name.as_str(),
                 &conf,
+                &proxy_settings,
             )
-            .await;
+            .await
+            {
+                Err(fastn_core::Error::UpstreamError { message, timed_out }) => Ok(
+                    upstream_error_page(config, &req_config.request, message.as_str(), timed_out)
+                        .await,
+                ),
+                response => response,
+            };
         }
 
-        // Fallback to WASM execution in case of no successful response
-        // TODO: This is hacky. Use the sitemap eventually.
-        // if file_response.status() == actix_web::http::StatusCode::NOT_FOUND {
-        //     let package = config.find_package_by_id(path.as_str()).await.unwrap().1;
-        //     let wasm_module = config.get_root_for_package(&package).join("backend.wasm");
-        //     // Set the temp path to and do `get_file_and_package_by_id` to retrieve the backend.wasm
-        //     // File for the particular dependency package
-        //     let wasm_module_path = format!("-/{}/backend.wasm", package.name,);
-        //     config
-        //         .get_file_and_package_by_id(wasm_module_path.as_str())
-        //         .await?;
-        //     let req = if let Some(r) = config.request {
-        //         r
-        //     } else {
-        //         return Ok(fastn_core::server_error!("request not set"));
-        //     };
-        //     fastn_core::wasm::handle_wasm(req, wasm_module, config.package.backend_headers).await
-        // }
-
         file_response
     };
 
@@ -556,50 +765,35 @@ fn handle_default_route(
     req: &actix_web::HttpRequest,
     package_name: &str,
 ) -> Option<fastn_core::http::Response> {
-    if req
+    let (content, content_type): (String, mime_guess::Mime) = if req
         .path()
         .ends_with(fastn_core::utils::hashed_default_css_name())
     {
-        return Some(
-            actix_web::HttpResponse::Ok()
-                .content_type(mime_guess::mime::TEXT_CSS)
-                .append_header(("Cache-Control", "public, max-age=31536000"))
-                .body(ftd::css()),
-        );
+        (ftd::css().to_string(), mime_guess::mime::TEXT_CSS)
     } else if req
         .path()
         .ends_with(fastn_core::utils::hashed_default_js_name())
     {
-        return Some(
-            actix_web::HttpResponse::Ok()
-                .content_type(mime_guess::mime::TEXT_JAVASCRIPT)
-                .append_header(("Cache-Control", "public, max-age=31536000"))
-                .body(format!(
-                    "{}\n\n{}",
-                    ftd::build_js(),
-                    fastn_core::fastn_2022_js()
-                )),
-        );
+        (
+            format!("{}\n\n{}", ftd::build_js(), fastn_core::fastn_2022_js()),
+            mime_guess::mime::TEXT_JAVASCRIPT,
+        )
     } else if req
         .path()
         .ends_with(fastn_core::utils::hashed_default_ftd_js(package_name))
     {
-        return Some(
-            actix_web::HttpResponse::Ok()
-                .content_type(mime_guess::mime::TEXT_JAVASCRIPT)
-                .append_header(("Cache-Control", "public, max-age=31536000"))
-                .body(ftd::js::all_js_without_test(package_name)),
-        );
+        (
+            ftd::js::all_js_without_test(package_name),
+            mime_guess::mime::TEXT_JAVASCRIPT,
+        )
     } else if req
         .path()
         .ends_with(fastn_core::utils::hashed_markdown_js())
     {
-        return Some(
-            actix_web::HttpResponse::Ok()
-                .content_type(mime_guess::mime::TEXT_JAVASCRIPT)
-                .append_header(("Cache-Control", "public, max-age=31536000"))
-                .body(ftd::markdown_js()),
-        );
+        (
+            ftd::markdown_js().to_string(),
+            mime_guess::mime::TEXT_JAVASCRIPT,
+        )
     } else if let Some(theme) =
         fastn_core::utils::hashed_code_theme_css()
             .iter()
@@ -611,30 +805,27 @@ fn handle_default_route(
                 }
             })
     {
-        let theme_css = ftd::theme_css();
-        return theme_css.get(theme).cloned().map(|theme| {
-            actix_web::HttpResponse::Ok()
-                .content_type(mime_guess::mime::TEXT_CSS)
-                .append_header(("Cache-Control", "public, max-age=31536000"))
-                .body(theme)
-        });
+        (
+            ftd::theme_css().get(theme).cloned()?,
+            mime_guess::mime::TEXT_CSS,
+        )
     } else if req.path().ends_with(fastn_core::utils::hashed_prism_js()) {
-        return Some(
-            actix_web::HttpResponse::Ok()
-                .content_type(mime_guess::mime::TEXT_JAVASCRIPT)
-                .append_header(("Cache-Control", "public, max-age=31536000"))
-                .body(ftd::prism_js()),
-        );
+        (ftd::prism_js(), mime_guess::mime::TEXT_JAVASCRIPT)
     } else if req.path().ends_with(fastn_core::utils::hashed_prism_css()) {
-        return Some(
-            actix_web::HttpResponse::Ok()
-                .content_type(mime_guess::mime::TEXT_CSS)
-                .append_header(("Cache-Control", "public, max-age=31536000"))
-                .body(ftd::prism_css()),
-        );
-    }
+        (ftd::prism_css(), mime_guess::mime::TEXT_CSS)
+    } else {
+        return None;
+    };
 
-    None
+    // all default files have the hash of their content in their name
+    Some(fastn_core::http::ok_with_etag(
+        content.into_bytes(),
+        content_type,
+        req.headers()
+            .get(actix_web::http::header::IF_NONE_MATCH)
+            .and_then(|v| v.to_str().ok()),
+        true,
+    ))
 }
 
 #[tracing::instrument(skip_all)]
@@ -653,7 +844,7 @@ async fn test() -> fastn_core::Result<fastn_core::http::Response> {
 async fn actual_route(
     config: &fastn_core::Config,
     req: actix_web::HttpRequest,
-    body: actix_web::web::Bytes,
+    payload: actix_web::web::Payload,
     package_name: &str,
 ) -> fastn_core::Result<fastn_core::http::Response> {
     tracing::info!(method = req.method().as_str(), uri = req.path());
@@ -663,26 +854,39 @@ async fn actual_route(
         return Ok(default_response);
     }
 
-    let req = fastn_core::http::Request::from_actix(req, body);
+    // only the routes which use the body read it, a request for a page can pass its body on to an
+    // upstream as it comes in, see `serve_helper`
+    let req = fastn_core::http::Request::from_actix_payload(req, payload);
     match (req.method().to_lowercase().as_str(), req.path()) {
-        ("post", "/-/sync/") if cfg!(feature = "remote") => sync(config, req).await,
-        ("post", "/-/sync2/") if cfg!(feature = "remote") => sync2(config, req).await,
+        ("post", "/-/sync/") if cfg!(feature = "remote") => {
+            sync(config, req.with_body().await?).await
+        }
+        ("post", "/-/sync2/") if cfg!(feature = "remote") => {
+            sync2(config, req.with_body().await?).await
+        }
         ("get", "/-/clone/") if cfg!(feature = "remote") => clone(config).await,
         ("get", t) if t.starts_with("/-/view-src/") => view_source(config, req).await,
         ("get", t) if t.starts_with("/-/edit-src/") => edit_source(config, req).await,
-        (_, t) if t.starts_with("/-/auth/") => fastn_core::auth::routes::handle_auth(req).await,
-        ("post", "/-/edit/") => edit(config, req).await,
-        ("post", "/-/revert/") => revert(config, req).await,
+        (_, t) if t.starts_with("/-/auth/") => {
+            fastn_core::auth::routes::handle_auth(req.with_body().await?).await
+        }
+        ("post", "/-/edit/") => edit(config, req.with_body().await?).await,
+        ("post", "/-/revert/") => revert(config, req.with_body().await?).await,
         ("get", "/-/editor-sync/") => editor_sync(config).await,
-        ("post", "/-/create-cr/") => create_cr(config, req).await,
+        ("post", "/-/create-cr/") => create_cr(config, req.with_body().await?).await,
         ("get", "/-/create-cr-page/") => create_cr_page(config, req).await,
         ("get", "/-/clear-cache/") => clear_cache(config, req).await,
         ("get", "/-/poll/") => fastn_core::watcher::poll().await,
-        ("get", "/favicon.ico") => favicon().await,
+        (_, t) if t.starts_with(fastn_core::live::PREFIX) => {
+            fastn_core::live::handle(req.with_body().await?).await
+        }
+        ("get", "/favicon.ico") => favicon(&req).await,
         ("get", "/test/") => test().await,
         ("get", "/-/pwd/") => fastn_core::tutor::pwd().await,
         ("get", "/-/tutor.js") => fastn_core::tutor::js().await,
-        ("post", "/-/tutor/start/") => fastn_core::tutor::start(req.json()?).await,
+        ("post", "/-/tutor/start/") => {
+            fastn_core::tutor::start(req.with_body().await?.json()?).await
+        }
         ("get", "/-/tutor/stop/") => fastn_core::tutor::stop().await,
         (_, _) => serve(config, req).await,
     }
@@ -691,11 +895,11 @@ async fn actual_route(
 #[tracing::instrument(skip_all)]
 async fn route(
     req: actix_web::HttpRequest,
-    body: actix_web::web::Bytes,
+    payload: actix_web::web::Payload,
     app_data: actix_web::web::Data<AppData>,
 ) -> fastn_core::Result<fastn_core::http::Response> {
     let (config, package_name) = fastn_core::tutor::config(&app_data).await?;
-    actual_route(&config, req, body, package_name.as_str()).await
+    actual_route(&config, req, payload, package_name.as_str()).await
 }
 
 //noinspection HttpUrlsUsage
@@ -759,6 +963,8 @@ You can try without providing port, it will automatically pick unused port."#,
         }
     }
 
+    fastn_core::live::listen_postgres();
+
     let app = move || {
         actix_web::App::new()
             .app_data(actix_web::web::Data::new(AppData {
@@ -800,3 +1006,76 @@ You can try without providing port, it will automatically pick unused port."#,
 // cargo install --features controller --path=.
 // FASTN_CONTROLLER=http://127.0.0.1:8000 FASTN_INSTANCE_ID=12345 fastn serve 8001
 // TRACING=INFO fastn serve
+
+#[cfg(test)]
+mod tests {
+    fn request(spa: bool) -> fastn_core::http::Request {
+        let mut req = actix_web::test::TestRequest::default().uri("/");
+        if spa {
+            req = req.insert_header((fastn_core::http::SPA_HEADER, "true"));
+        }
+        fastn_core::http::Request::from_actix(req.to_http_request(), Default::default())
+    }
+
+    fn varies_on_spa_header(resp: &fastn_core::http::Response) -> bool {
+        resp.headers()
+            .get_all(actix_web::http::header::VARY)
+            .any(|v| v == fastn_core::http::SPA_HEADER)
+    }
+
+    /// A `spa` package answers the client side router with the page's JS and the names of its
+    /// `$persist$` variables, and full page loads with the HTML
+    #[tokio::test]
+    async fn spa_navigation() {
+        use actix_web::body::MessageBody;
+
+        let package = fastn_core::test_package::TestPackage::new(
+            "spa-test",
+            "spa: true\n",
+            &[(
+                "index.ftd",
+                indoc::indoc! {"
+                    -- integer $cart-count: 0
+                    $persist$: true
+
+                    -- integer $clicks: 0
+
+                    -- ftd.integer: $cart-count
+
+                    -- ftd.integer: $clicks
+                "},
+            )],
+        );
+
+        let config = package.config().await;
+        let spa = super::serve(&config, request(true)).await.unwrap();
+        let page = super::serve(&config, request(false)).await.unwrap();
+
+        assert!(varies_on_spa_header(&spa));
+        assert!(varies_on_spa_header(&page));
+        assert_eq!(
+            page.headers()
+                .get(actix_web::http::header::CONTENT_TYPE)
+                .unwrap(),
+            "text/html; charset=utf-8"
+        );
+        assert_eq!(
+            spa.headers()
+                .get(actix_web::http::header::CONTENT_TYPE)
+                .unwrap(),
+            "application/json"
+        );
+
+        let body = spa.into_body().try_into_bytes().unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert!(payload["js"]
+            .as_str()
+            .unwrap()
+            .contains("let main = function"));
+        assert!(payload["scripts"].is_string());
+        // only the JS name of `cart-count`, `clicks` starts afresh on every page
+        let persist = payload["persist"].as_array().unwrap();
+        assert_eq!(persist.len(), 1);
+        assert!(persist[0].as_str().unwrap().ends_with("__cart_count"));
+    }
+}
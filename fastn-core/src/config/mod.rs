@@ -270,6 +270,62 @@ impl RequestConfig {
         Ok(true)
     }
 
+    /// ids of the reader groups (from sitemap) of `document_path`, the current user belongs to
+    pub(crate) async fn user_groups(&self, document_path: &str) -> fastn_core::Result<Vec<String>> {
+        use itertools::Itertools;
+        let document_name = self.document_name_with_default(document_path);
+        let sitemap = match &self.config.package.sitemap {
+            Some(sitemap) => sitemap,
+            None => return Ok(vec![]),
+        };
+        let (document_readers, _) =
+            sitemap.readers(document_name.as_str(), &self.config.package.groups);
+        if document_readers.is_empty() {
+            return Ok(vec![]);
+        }
+        let access_identities = fastn_core::user_group::access_identities(
+            &self.config,
+            &self.request,
+            &document_name,
+            true,
+        )
+        .await?;
+        let access_identities = access_identities.iter().collect_vec();
+
+        let mut groups = vec![];
+        for group in document_readers {
+            if group.belongs_to(&self.config, access_identities.as_slice())? {
+                groups.push(group.id.to_string());
+            }
+        }
+        groups.sort();
+        groups.dedup();
+        Ok(groups)
+    }
+
+    /// If only some users can read `document_path`: it has readers in the sitemap, or it is in
+    /// an app with readers.
+    pub(crate) fn has_readers(&self, document_path: &str) -> bool {
+        let document_name = self.document_name_with_default(document_path);
+        let in_sitemap = self
+            .config
+            .package
+            .sitemap
+            .as_ref()
+            .map_or(false, |sitemap| {
+                !sitemap
+                    .readers(document_name.as_str(), &self.config.package.groups)
+                    .0
+                    .is_empty()
+            });
+        let in_app = matches!(
+            self.config
+                .get_mountpoint_sanitized_path(&self.config.package, document_path),
+            Some((_, _, _, Some(app))) if !app.readers.is_empty()
+        );
+        in_sitemap || in_app
+    }
+
     #[tracing::instrument(skip(self))]
     pub(crate) async fn can_write(&self, document_path: &str) -> fastn_core::Result<bool> {
         use itertools::Itertools;
@@ -112,6 +112,109 @@ pub async fn interpret_helper(
     Ok(document)
 }
 
+/// Like `interpret_helper`, but processors are not run: a variable set by a processor gets a
+/// placeholder value of its declared kind. This type-checks the document without a request,
+/// database or network, it is used by `fastn check`.
+pub async fn interpret_without_processors(
+    name: &str,
+    source: &str,
+    lib: &mut fastn_core::Library2022,
+    line_number: usize,
+) -> ftd::interpreter::Result<ftd::interpreter::Document> {
+    let doc = cached_parse(name, source, line_number)?;
+    let mut s = ftd::interpreter::interpret_with_line_number(name, doc)?;
+    lib.module_package_map.insert(
+        name.trim_matches('/').to_string(),
+        lib.config.package.name.to_string(),
+    );
+    loop {
+        match s {
+            ftd::interpreter::Interpreter::Done { document } => return Ok(document),
+            ftd::interpreter::Interpreter::StuckOnImport {
+                module,
+                state: mut st,
+                caller_module,
+            } => {
+                let (source, _path, foreign_variable, foreign_function, ignore_line_numbers) =
+                    resolve_import_2022(lib, &mut st, module.as_str(), caller_module.as_str())
+                        .await?;
+                let doc = cached_parse(module.as_str(), source.as_str(), ignore_line_numbers)?;
+                s = st.continue_after_import(
+                    module.as_str(),
+                    doc,
+                    foreign_variable,
+                    foreign_function,
+                    ignore_line_numbers,
+                )?;
+            }
+            ftd::interpreter::Interpreter::StuckOnProcessor {
+                state, ast, module, ..
+            } => {
+                let doc = state.get_current_processing_module().ok_or(
+                    ftd::interpreter::Error::ValueNotFound {
+                        doc_id: module,
+                        line_number: ast.line_number(),
+                        message: "Cannot find the module".to_string(),
+                    },
+                )?;
+                let (_processor, _value, kind) = fastn_core::library2022::get_processor_data(
+                    ast.clone(),
+                    &mut state.tdoc(doc.as_str(), ast.line_number())?,
+                )?;
+                s = state.continue_after_processor(placeholder_value(kind), ast)?;
+            }
+            ftd::interpreter::Interpreter::StuckOnForeignVariable {
+                state,
+                module,
+                variable,
+                caller_module,
+            } => {
+                let value = resolve_foreign_variable2022(
+                    variable.as_str(),
+                    module.as_str(),
+                    lib,
+                    "/",
+                    false,
+                    caller_module.as_str(),
+                )
+                .await?;
+                s = state.continue_after_variable(module.as_str(), variable.as_str(), value)?;
+            }
+        }
+    }
+}
+
+/// An empty value of `kind`, kinds without one, like records and or-types, get `NULL`.
+fn placeholder_value(kind: ftd::interpreter::Kind) -> ftd::interpreter::Value {
+    match kind {
+        ftd::interpreter::Kind::String => ftd::interpreter::Value::String {
+            text: "".to_string(),
+        },
+        ftd::interpreter::Kind::Integer => ftd::interpreter::Value::Integer { value: 0 },
+        ftd::interpreter::Kind::Decimal => ftd::interpreter::Value::Decimal { value: 0.0 },
+        ftd::interpreter::Kind::Boolean => ftd::interpreter::Value::Boolean { value: false },
+        ftd::interpreter::Kind::DateTime => ftd::interpreter::Value::DateTime { value: 0 },
+        ftd::interpreter::Kind::Date => ftd::interpreter::Value::Date { value: 0 },
+        ftd::interpreter::Kind::Duration => ftd::interpreter::Value::Duration { value: 0 },
+        ftd::interpreter::Kind::List { kind } => ftd::interpreter::Value::List {
+            data: vec![],
+            kind: kind.into_kind_data(),
+        },
+        ftd::interpreter::Kind::Map { kind } => ftd::interpreter::Value::Map {
+            data: Default::default(),
+            kind: kind.into_kind_data(),
+        },
+        ftd::interpreter::Kind::Optional { kind } => ftd::interpreter::Value::Optional {
+            data: Box::new(None),
+            kind: kind.into_kind_data(),
+        },
+        kind => ftd::interpreter::Value::Optional {
+            data: Box::new(None),
+            kind: kind.into_kind_data(),
+        },
+    }
+}
+
 pub async fn resolve_import(
     lib: &mut fastn_core::Library2,
     state: &mut ftd::ftd2021::InterpreterState,
@@ -57,6 +57,9 @@ pub enum Error {
     #[error("ParseBoolError: {}", _0)]
     ParseBoolError(#[from] std::str::ParseBoolError),
 
+    #[error("UpstreamError: {message}")]
+    UpstreamError { message: String, timed_out: bool },
+
     #[error("APIResponseError: {}", _0)]
     APIResponseError(String),
 
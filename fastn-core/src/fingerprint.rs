@@ -0,0 +1,229 @@
+/// Cache-Control value for files whose name contains the hash of their content. Such a file
+/// never changes, a new version of the content gets a new name.
+pub const IMMUTABLE_CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+
+/// `fastn-js.js` + content => `fastn-js.<HASH>.js`
+pub fn name(file_name: &str, content: impl AsRef<[u8]>) -> String {
+    let hash = fastn_core::utils::generate_hash(content);
+    match file_name.rsplit_once('.') {
+        Some((stem, ext)) if !stem.is_empty() && !stem.ends_with('/') && !ext.contains('/') => {
+            format!("{stem}.{hash}.{ext}")
+        }
+        _ => format!("{file_name}.{hash}"),
+    }
+}
+
+/// `-/foo.com/static/logo.<HASH>.png` => `Some("-/foo.com/static/logo.png")`
+///
+/// Returns `None` if the file name does not contain a content hash.
+pub fn strip(path: &str) -> Option<String> {
+    let path = path.trim_end_matches('/');
+    let (rest, last) = path.rsplit_once('.')?;
+    if is_hash(last) {
+        return Some(rest.to_string());
+    }
+    let (stem, hash) = rest.rsplit_once('.')?;
+    if !is_hash(hash) || stem.is_empty() || stem.ends_with('/') {
+        return None;
+    }
+    Some(format!("{stem}.{last}"))
+}
+
+pub fn is_fingerprinted(path: &str) -> bool {
+    strip(path).is_some()
+}
+
+fn is_hash(s: &str) -> bool {
+    s.len() == 64 && s.chars().all(|c| matches!(c, '0'..='9' | 'A'..='F'))
+}
+
+/// Only images and fonts are fingerprinted, everything else in `.build` is either a page, or
+/// already has a hashed name (default js and css files).
+fn is_asset(path: &camino::Utf8Path) -> bool {
+    let mime = mime_guess::from_path(path).first_or_octet_stream();
+    mime.type_() == mime_guess::mime::IMAGE
+        || mime.type_() == mime_guess::mime::FONT
+        || matches!(
+            path.extension(),
+            Some("eot" | "woff" | "woff2" | "ttf" | "otf")
+        )
+}
+
+/// Copies every image and font in `build_dir` to its fingerprinted name and rewrites all
+/// references to it in the generated html pages. The original file is kept so that old links,
+/// and links not generated by fastn, keep working.
+///
+/// `previous` is the manifest returned by the last build. Pages that were not rebuilt still
+/// refer to the fingerprinted name the asset had back then, those references are moved to the
+/// new name if the content of the asset has changed since.
+///
+/// Returns the old and the new checksum of every page that got rewritten, and the manifest of
+/// this build, original path => fingerprinted path.
+#[tracing::instrument(skip(previous))]
+pub(crate) async fn fingerprint_build(
+    build_dir: &camino::Utf8Path,
+    previous: &std::collections::BTreeMap<String, String>,
+) -> fastn_core::Result<(
+    std::collections::HashMap<String, String>,
+    std::collections::BTreeMap<String, String>,
+)> {
+    let mut checksums = std::collections::HashMap::new();
+    let mut manifest = std::collections::BTreeMap::new();
+    let mut pages = vec![];
+
+    for path in find_all_files_recursively(build_dir)? {
+        let relative = match path.strip_prefix(build_dir) {
+            Ok(v) => v.as_str().replace('\\', "/"),
+            Err(_) => continue,
+        };
+        if path.extension() == Some("html") {
+            pages.push(path);
+            continue;
+        }
+        if !is_asset(&path) || is_fingerprinted(relative.as_str()) {
+            continue;
+        }
+        let content = fastn_core::tokio_fs::read(&path).await?;
+        let hashed = name(relative.as_str(), content.as_slice());
+        let hashed_path = build_dir.join(hashed.as_str());
+        if !hashed_path.exists() {
+            fastn_core::utils::update(&hashed_path, content.as_slice()).await?;
+        }
+        manifest.insert(relative, hashed);
+    }
+
+    let mut references: std::collections::HashMap<&str, &str> = manifest
+        .iter()
+        .map(|(original, hashed)| (original.as_str(), hashed.as_str()))
+        .collect();
+    for (original, old) in previous {
+        match manifest.get(original) {
+            Some(hashed) if hashed != old => {
+                references.insert(old.as_str(), hashed.as_str());
+            }
+            _ => {}
+        }
+    }
+
+    if references.is_empty() {
+        return Ok((checksums, manifest));
+    }
+
+    for page in pages {
+        let content = fastn_core::tokio_fs::read_to_string(&page).await?;
+        let rewritten = rewrite(content.as_str(), &references);
+        if rewritten != content {
+            fastn_core::utils::update(&page, rewritten.as_bytes()).await?;
+            checksums.insert(
+                fastn_core::utils::generate_hash(content.as_str()),
+                fastn_core::utils::generate_hash(rewritten.as_str()),
+            );
+        }
+    }
+
+    Ok((checksums, manifest))
+}
+
+/// Replaces every reference to a path in `references` with the path it maps to. A reference
+/// must not be preceded by a character that can be a part of a file name, so `static/logo.png`
+/// does not match inside `other-static/logo.png`, and must not be followed by one either.
+///
+/// The page is scanned once: every run of file name characters is a candidate, and so is every
+/// part of it that starts after a `/`. The longest candidate found in `references` wins.
+fn rewrite(content: &str, references: &std::collections::HashMap<&str, &str>) -> String {
+    let is_path_char = |c: char| c.is_alphanumeric() || matches!(c, '-' | '_' | '.' | '/');
+    let mut result = String::with_capacity(content.len());
+    let mut rest = content;
+    while let Some(start) = rest.find(is_path_char) {
+        let (before, after) = rest.split_at(start);
+        result.push_str(before);
+        let end = after
+            .find(|c: char| !is_path_char(c))
+            .unwrap_or(after.len());
+        let (candidate, after) = after.split_at(end);
+        let found = std::iter::once(0)
+            .chain(candidate.match_indices('/').map(|(i, _)| i + 1))
+            .find_map(|i| references.get(&candidate[i..]).map(|v| (i, *v)));
+        match found {
+            Some((i, replacement)) => {
+                result.push_str(&candidate[..i]);
+                result.push_str(replacement);
+            }
+            None => result.push_str(candidate),
+        }
+        rest = after;
+    }
+    result.push_str(rest);
+    result
+}
+
+fn find_all_files_recursively(
+    dir: &camino::Utf8Path,
+) -> fastn_core::Result<Vec<camino::Utf8PathBuf>> {
+    let mut files = vec![];
+    for entry in std::fs::read_dir(dir)? {
+        let path = camino::Utf8PathBuf::try_from(entry?.path())?;
+        if path.is_dir() {
+            files.extend(find_all_files_recursively(&path)?);
+        } else {
+            files.push(path)
+        }
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    const HASH: &str = "0123456789ABCDEF0123456789ABCDEF0123456789ABCDEF0123456789ABCDEF";
+
+    #[test]
+    fn strip() {
+        assert_eq!(
+            super::strip(format!("-/foo.com/logo.{HASH}.png").as_str()),
+            Some("-/foo.com/logo.png".to_string())
+        );
+        assert_eq!(
+            super::strip(format!("fastn-js.{HASH}.js").as_str()),
+            Some("fastn-js.js".to_string())
+        );
+        assert_eq!(super::strip("-/foo.com/logo.png"), None);
+        assert_eq!(
+            super::strip(format!("-/foo.com/.{HASH}.png").as_str()),
+            None
+        );
+    }
+
+    #[test]
+    fn rewrite() {
+        let hashed = format!("-/foo.com/logo.{HASH}.png");
+        let references = std::collections::HashMap::from([
+            ("-/foo.com/logo.png", hashed.as_str()),
+            ("logo.png", "logo.NEW.png"),
+        ]);
+        assert_eq!(
+            super::rewrite(
+                r#"<img src="/-/foo.com/logo.png"> <img src="/x-/foo.com/logo.png.bak"> <img src='logo.png?v=1'>"#,
+                &references
+            ),
+            format!(
+                r#"<img src="/-/foo.com/logo.{HASH}.png"> <img src="/x-/foo.com/logo.png.bak"> <img src='logo.NEW.png?v=1'>"#
+            )
+        );
+    }
+
+    #[test]
+    fn rewrite_stale_fingerprint() {
+        let old = format!("logo.{HASH}.png");
+        let references = std::collections::HashMap::from([
+            ("logo.png", "logo.NEW.png"),
+            (old.as_str(), "logo.NEW.png"),
+        ]);
+        assert_eq!(
+            super::rewrite(
+                format!(r#"url("/{old}") other-logo.png"#).as_str(),
+                &references
+            ),
+            r#"url("/logo.NEW.png") other-logo.png"#
+        );
+    }
+}
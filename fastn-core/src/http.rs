@@ -74,6 +74,50 @@ pub fn ok_with_content_type(
         .body(data)
 }
 
+/// Static file response with an `ETag`, answers with `304 Not Modified` if `if_none_match` (the
+/// value of the `If-None-Match` request header) already has the current version. `immutable`
+/// files (fingerprinted file names) can be cached forever, everything else must be revalidated.
+pub fn ok_with_etag(
+    data: Vec<u8>,
+    content_type: mime_guess::Mime,
+    if_none_match: Option<&str>,
+    immutable: bool,
+) -> fastn_core::http::Response {
+    let etag = format!("\"{}\"", fastn_core::utils::generate_hash(data.as_slice()));
+    let cache_control = if immutable {
+        fastn_core::fingerprint::IMMUTABLE_CACHE_CONTROL
+    } else {
+        "no-cache"
+    };
+
+    if let Some(if_none_match) = if_none_match {
+        if if_none_match.split(',').any(|v| {
+            let v = v.trim();
+            v == "*" || v.trim_start_matches("W/") == etag
+        }) {
+            return actix_web::HttpResponse::NotModified()
+                .insert_header((actix_web::http::header::ETAG, etag))
+                .insert_header((actix_web::http::header::CACHE_CONTROL, cache_control))
+                .finish();
+        }
+    }
+
+    actix_web::HttpResponse::Ok()
+        .content_type(content_type)
+        .insert_header((actix_web::http::header::ETAG, etag))
+        .insert_header((actix_web::http::header::CACHE_CONTROL, cache_control))
+        .body(data)
+}
+
+/// Largest body [Request::read_body] reads, the default limit of actix for a body in memory
+pub const BODY_LIMIT: usize = 262_144;
+
+/// The body of a request which is not read yet. The actix payload can not leave the thread of the
+/// request, so its chunks are passed on through a channel, see [Request::from_actix_payload].
+type PendingBody = std::sync::Arc<
+    std::sync::Mutex<Option<tokio::sync::mpsc::Receiver<std::io::Result<actix_web::web::Bytes>>>>,
+>;
+
 #[derive(Debug, Clone, Default)]
 pub struct Request {
     method: String,
@@ -84,6 +128,7 @@ pub struct Request {
     headers: reqwest::header::HeaderMap,
     query: std::collections::HashMap<String, serde_json::Value>,
     body: actix_web::web::Bytes,
+    pending_body: PendingBody,
     ip: Option<String>,
     scheme: String,
     host: String,
@@ -105,6 +150,7 @@ impl Request {
         return Request {
             cookies: get_cookies(&headers),
             body,
+            pending_body: Default::default(),
             method: req.method().to_string(),
             uri: req.uri().to_string(),
             path: req.path().to_string(),
@@ -142,6 +188,82 @@ impl Request {
         }
     }
 
+    /// A request whose body is not read yet: it is read with [Request::read_body], or passed on
+    /// to an upstream as it comes in with [Request::take_body_stream].
+    pub fn from_actix_payload(
+        req: actix_web::HttpRequest,
+        payload: actix_web::web::Payload,
+    ) -> Self {
+        let mut request = Request::from_actix(req, actix_web::web::Bytes::new());
+        let has_body = request.headers.contains_key("transfer-encoding")
+            || request
+                .headers
+                .get("content-length")
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.parse::<u64>().ok())
+                .map_or(false, |v| v > 0);
+        if has_body {
+            let (sender, receiver) = tokio::sync::mpsc::channel(16);
+            actix_web::rt::spawn(forward_payload(payload, sender));
+            request.pending_body = std::sync::Arc::new(std::sync::Mutex::new(Some(receiver)));
+        }
+        return request;
+
+        async fn forward_payload(
+            mut payload: actix_web::web::Payload,
+            sender: tokio::sync::mpsc::Sender<std::io::Result<actix_web::web::Bytes>>,
+        ) {
+            use futures::StreamExt;
+
+            while let Some(chunk) = payload.next().await {
+                let chunk = chunk.map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e));
+                let failed = chunk.is_err();
+                // the receiver is gone if the request is done without its body
+                if sender.send(chunk).await.is_err() || failed {
+                    break;
+                }
+            }
+        }
+    }
+
+    pub fn has_pending_body(&self) -> bool {
+        self.pending_body.lock().unwrap().is_some()
+    }
+
+    /// Reads the body which is not read yet, up to [BODY_LIMIT] bytes, for [Request::body] and
+    /// [Request::json].
+    pub async fn read_body(&mut self) -> fastn_core::Result<()> {
+        let mut receiver = match self.pending_body.lock().unwrap().take() {
+            Some(receiver) => receiver,
+            None => return Ok(()),
+        };
+        let mut body = actix_web::web::BytesMut::new();
+        while let Some(chunk) = receiver.recv().await {
+            let chunk = chunk?;
+            if body.len() + chunk.len() > BODY_LIMIT {
+                return Err(fastn_core::Error::UsageError {
+                    message: format!("request body is larger than {BODY_LIMIT} bytes"),
+                });
+            }
+            body.extend_from_slice(&chunk);
+        }
+        self.body = body.freeze();
+        Ok(())
+    }
+
+    pub async fn with_body(mut self) -> fastn_core::Result<Self> {
+        self.read_body().await?;
+        Ok(self)
+    }
+
+    /// The body which is not read yet, as a stream, for a request passed on to an upstream
+    pub fn take_body_stream(&self) -> Option<reqwest::Body> {
+        let mut receiver = self.pending_body.lock().unwrap().take()?;
+        Some(reqwest::Body::wrap_stream(futures::stream::poll_fn(
+            move |cx| receiver.poll_recv(cx),
+        )))
+    }
+
     pub fn json<T: serde::de::DeserializeOwned>(&self) -> fastn_core::Result<T> {
         Ok(serde_json::from_slice(&self.body)?)
     }
@@ -175,6 +297,10 @@ impl Request {
         &self.body
     }
 
+    pub fn body_bytes(&self) -> actix_web::web::Bytes {
+        self.body.clone()
+    }
+
     pub fn method(&self) -> &str {
         self.method.as_str()
     }
@@ -249,6 +375,18 @@ impl Request {
         &self.headers
     }
 
+    pub fn if_none_match(&self) -> Option<&str> {
+        self.headers
+            .get(reqwest::header::IF_NONE_MATCH)
+            .and_then(|v| v.to_str().ok())
+    }
+
+    /// Page fetches made by the client side router carry the `x-fastn-spa` header, and get
+    /// the page's compiled JS instead of the full HTML.
+    pub fn is_spa_navigation(&self) -> bool {
+        self.headers.contains_key(SPA_HEADER)
+    }
+
     pub fn query(&self) -> &std::collections::HashMap<String, serde_json::Value> {
         &self.query
     }
@@ -273,65 +411,7 @@ impl Request {
     }
 }
 
-pub(crate) struct ResponseBuilder {
-    // headers: std::collections::HashMap<String, String>,
-    // code: u16,
-    // remaining
-}
-
-// We will no do stream, data is going to less from services
-impl ResponseBuilder {
-    // chain implementation
-    // .build
-    // response from string, json, bytes etc
-
-    pub async fn from_reqwest(
-        response: reqwest::Response,
-        _package_name: &str,
-    ) -> fastn_core::http::Response {
-        let status = response.status();
-
-        // Remove `Connection` as per
-        // https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Connection#Directives
-        let mut response_builder = actix_web::HttpResponse::build(status);
-        for header in response
-            .headers()
-            .iter()
-            .filter(|(h, _)| *h != "connection")
-        {
-            response_builder.insert_header(header);
-        }
-
-        // if status == actix_web::http::StatusCode::FOUND && false {
-        //     response_builder.status(actix_web::http::StatusCode::OK);
-        //     if let Some(location) = response.headers().get(actix_web::http::header::LOCATION) {
-        //         let redirect = location.to_str().unwrap();
-        //         let path = if redirect.trim_matches('/').is_empty() {
-        //             format!("/-/{}/", package_name)
-        //         } else {
-        //             // if it contains query-params so url should not end with /
-        //             if redirect.contains('?') {
-        //                 format!("/-/{}/{}", package_name, redirect.trim_matches('/'))
-        //             } else {
-        //                 format!("/-/{}/{}/", package_name, redirect.trim_matches('/'))
-        //             }
-        //         };
-        //         let t = serde_json::json!({"redirect": path.as_str()}).to_string();
-        //         return response_builder.body(t);
-        //     }
-        // }
-
-        let content = match response.bytes().await {
-            Ok(b) => b,
-            Err(e) => {
-                return actix_web::HttpResponse::from(actix_web::error::ErrorInternalServerError(
-                    fastn_core::Error::HttpError(e),
-                ))
-            }
-        };
-        response_builder.body(content)
-    }
-}
+pub(crate) const SPA_HEADER: &str = "x-fastn-spa";
 
 pub(crate) fn url_regex() -> regex::Regex {
     regex::Regex::new(
@@ -778,4 +858,16 @@ mod test {
 
         Ok(())
     }
+    #[test]
+    fn is_spa_navigation() {
+        let page = actix_web::test::TestRequest::default().to_http_request();
+        let spa = actix_web::test::TestRequest::default()
+            .insert_header((fastn_core::http::SPA_HEADER, "true"))
+            .to_http_request();
+
+        assert!(
+            !fastn_core::http::Request::from_actix(page, Default::default()).is_spa_navigation()
+        );
+        assert!(fastn_core::http::Request::from_actix(spa, Default::default()).is_spa_navigation());
+    }
 }
@@ -12,6 +12,7 @@ mod cr;
 mod db;
 mod doc;
 mod file;
+mod fingerprint;
 mod font;
 mod history;
 mod package;
@@ -24,16 +25,23 @@ mod ds;
 mod error;
 mod i18n;
 pub mod library;
+pub mod live;
 mod proxy;
+pub(crate) mod response_cache;
 mod schema;
 pub mod sitemap;
 mod snapshot;
 mod sync_utils;
+#[cfg(test)]
+mod test_package;
 mod track;
 mod tracker;
 mod translation;
+pub(crate) mod upstream;
 mod version;
-// mod wasm;
+#[cfg(feature = "wasm")]
+mod wasm;
+
 pub mod catch_panic;
 pub(crate) mod google_sheets;
 mod library2022;
@@ -43,12 +51,12 @@ mod workspace;
 
 pub(crate) use auto_import::AutoImport;
 pub use commands::{
-    abort_merge::abort_merge, add::add, build::build, check::post_build_check, clone::clone,
-    close_cr::close_cr, create_cr::create_cr, create_package::create_package, diff::diff,
-    edit::edit, mark_resolved::mark_resolved, mark_upto_date::mark_upto_date, merge::merge,
-    query::query, resolve_conflict::resolve_conflict, revert::revert, rm::rm, serve::listen,
-    start_tracking::start_tracking, status::status, sync2::sync2, test::test,
-    translation_status::translation_status, update::update,
+    abort_merge::abort_merge, add::add, build::build, check::check, check::post_build_check,
+    clone::clone, close_cr::close_cr, create_cr::create_cr, create_package::create_package,
+    diff::diff, edit::edit, fmt::fmt, lsp::lsp, mark_resolved::mark_resolved,
+    mark_upto_date::mark_upto_date, merge::merge, query::query, resolve_conflict::resolve_conflict,
+    revert::revert, rm::rm, serve::listen, start_tracking::start_tracking, status::status,
+    sync2::sync2, test::test, translation_status::translation_status, update::update,
 };
 pub use config::{Config, FTDEdition, RequestConfig};
 pub use error::Error;
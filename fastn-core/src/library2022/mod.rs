@@ -280,7 +280,7 @@ impl Library2022 {
     }
 }
 
-fn get_processor_data(
+pub(crate) fn get_processor_data(
     ast: ftd::ast::AST,
     doc: &mut ftd::interpreter::TDoc,
 ) -> ftd::interpreter::Result<(String, ftd::ast::VariableValue, ftd::interpreter::Kind)> {
@@ -124,7 +124,7 @@ fn row_to_value(
     row: &DataRow,
     schema: &[DataColumn],
 ) -> ftd::interpreter::Result<ftd::interpreter::Value> {
-    if let ftd::interpreter::Kind::Record { name } = kind {
+    if let ftd::interpreter::Kind::Record { name, .. } = kind {
         return row_to_record(doc, name, value, row, schema);
     }
 
@@ -89,8 +89,17 @@ pub async fn process(
     doc: &ftd::interpreter::TDoc<'_>,
 ) -> ftd::interpreter::Result<ftd::interpreter::Value> {
     let (headers, query) = super::sqlite::get_p1_data("pg", &value, doc.name)?;
+    process_query(value, kind, doc, headers, query.as_str()).await
+}
 
-    let query_response = execute_query(query.as_str(), doc, value.line_number(), headers).await;
+pub(crate) async fn process_query(
+    value: ftd::ast::VariableValue,
+    kind: ftd::interpreter::Kind,
+    doc: &ftd::interpreter::TDoc<'_>,
+    headers: ftd::ast::HeaderValues,
+    query: &str,
+) -> ftd::interpreter::Result<ftd::interpreter::Value> {
+    let query_response = execute_query(query, doc, value.line_number(), headers).await;
 
     match query_response {
         Ok(result) => {
@@ -164,6 +173,44 @@ fn resolve_variable_from_doc(
         (&postgres_types::Type::BOOL, ftd::interpreter::Value::Boolean { value, .. }) => {
             Box::new(value)
         }
+        (
+            &postgres_types::Type::TIMESTAMP | &postgres_types::Type::TIMESTAMPTZ,
+            ftd::interpreter::Value::DateTime { value, .. },
+        ) => {
+            let datetime = match chrono::DateTime::<chrono::Utc>::from_timestamp(value, 0) {
+                Some(datetime) => datetime,
+                None => {
+                    return ftd::interpreter::utils::e2(
+                        format!("for {} datetime {} is out of range", var, value),
+                        doc.name,
+                        line_number,
+                    )
+                }
+            };
+            if e.eq(&postgres_types::Type::TIMESTAMP) {
+                Box::new(datetime.naive_utc())
+            } else {
+                Box::new(datetime)
+            }
+        }
+        (&postgres_types::Type::DATE, ftd::interpreter::Value::Date { value, .. }) => {
+            let days = chrono::Days::new(value.unsigned_abs());
+            let date = if value < 0 {
+                unix_epoch_date().checked_sub_days(days)
+            } else {
+                unix_epoch_date().checked_add_days(days)
+            };
+            match date {
+                Some(date) => Box::new(date),
+                None => {
+                    return ftd::interpreter::utils::e2(
+                        format!("for {} date {} is out of range", var, value),
+                        doc.name,
+                        line_number,
+                    )
+                }
+            }
+        }
         (e, a) => {
             return ftd::interpreter::utils::e2(
                 format!("for {} postgresql expected ${:?}, found {:?}", var, e, a),
@@ -311,7 +358,7 @@ fn prepare_args(
     Ok(QueryArgs { args })
 }
 
-async fn execute_query(
+pub(crate) async fn execute_query(
     query: &str,
     doc: &ftd::interpreter::TDoc<'_>,
     line_number: usize,
@@ -322,10 +369,28 @@ async fn execute_query(
     let (query, query_args) = super::sql::extract_arguments(query)?;
     let client = pool().await.as_ref().unwrap().get().await.unwrap();
 
-    let stmt = client.prepare_cached(query.as_str()).await.unwrap();
+    let stmt = match client.prepare_cached(query.as_str()).await {
+        Ok(stmt) => stmt,
+        Err(e) => {
+            return ftd::interpreter::utils::e2(
+                format!("Failed to prepare query: {}", e),
+                doc.name,
+                line_number,
+            )
+        }
+    };
 
     let args = prepare_args(query_args, stmt.params(), doc, line_number, headers)?;
-    let rows = client.query(&stmt, &args.pg_args()).await.unwrap();
+    let rows = match client.query(&stmt, &args.pg_args()).await {
+        Ok(rows) => rows,
+        Err(e) => {
+            return ftd::interpreter::utils::e2(
+                format!("Failed to execute query: {}", e),
+                doc.name,
+                line_number,
+            )
+        }
+    };
     let mut result: Vec<Vec<serde_json::Value>> = vec![];
 
     for r in rows {
@@ -335,6 +400,11 @@ async fn execute_query(
     Ok(result)
 }
 
+/// `date` values are days since this day
+fn unix_epoch_date() -> chrono::NaiveDate {
+    chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()
+}
+
 fn row_to_json(
     r: tokio_postgres::Row,
     doc_name: &str,
@@ -364,6 +434,30 @@ fn row_to_json(
             &postgres_types::Type::CHAR => row.push(serde_json::Value::String(r.get(i))),
             &postgres_types::Type::VARCHAR => row.push(serde_json::Value::String(r.get(i))),
             &postgres_types::Type::JSON => row.push(r.get(i)),
+            &postgres_types::Type::TIMESTAMP => {
+                let seconds = r
+                    .get::<usize, chrono::NaiveDateTime>(i)
+                    .and_utc()
+                    .timestamp();
+                row.push(serde_json::Value::String(
+                    ftd::interpreter::datetime::datetime_to_string(seconds),
+                ))
+            }
+            &postgres_types::Type::TIMESTAMPTZ => {
+                let seconds = r.get::<usize, chrono::DateTime<chrono::Utc>>(i).timestamp();
+                row.push(serde_json::Value::String(
+                    ftd::interpreter::datetime::datetime_to_string(seconds),
+                ))
+            }
+            &postgres_types::Type::DATE => {
+                let days = r
+                    .get::<usize, chrono::NaiveDate>(i)
+                    .signed_duration_since(unix_epoch_date())
+                    .num_days();
+                row.push(serde_json::Value::String(
+                    ftd::interpreter::datetime::date_to_string(days),
+                ))
+            }
 
             t => {
                 return ftd::interpreter::utils::e2(
@@ -47,10 +47,90 @@ pub async fn process(
         None => fastn_core::library2022::processor::sql::get_db_config()?,
     };
 
+    let page_size =
+        match headers.get_optional_string_by_key("page-size", doc.name, value.line_number())? {
+            Some(page_size) if matches!(db_config.db_type.as_str(), "postgres" | "sqlite") => {
+                Some(page_size.trim().parse::<i64>().map_err(|_| {
+                    ftd::interpreter::Error::ParseError {
+                        message: format!("`page-size` must be an integer, found: `{}`", page_size),
+                        doc_id: doc.name.to_string(),
+                        line_number: value.line_number(),
+                    }
+                })?)
+            }
+            Some(_) => {
+                return ftd::interpreter::utils::e2(
+                    format!("`page-size` is not supported for {}", db_config.db_type),
+                    doc.name,
+                    value.line_number(),
+                )
+            }
+            None => None,
+        };
+
+    if let Some(page_size) = page_size {
+        let page_param =
+            headers.get_optional_string_by_key("page-param", doc.name, value.line_number())?;
+        let paginate = |sort| {
+            paginate_query(
+                query.as_str(),
+                page_size,
+                page_param.as_deref(),
+                config.request.query(),
+                sort,
+            )
+            .map_err(|message| ftd::interpreter::Error::ParseError {
+                message,
+                doc_id: doc.name.to_string(),
+                line_number: value.line_number(),
+            })
+        };
+        let (sorted, unsorted) = (paginate(true)?, paginate(false)?);
+
+        // a `sort` that is not a column of the query fails it, the page is then shown unsorted
+        let result = match execute_query(
+            &db_config,
+            sorted.as_str(),
+            doc,
+            config,
+            headers.clone(),
+            value.line_number(),
+        )
+        .await
+        {
+            Err(_) if sorted != unsorted => {
+                execute_query(
+                    &db_config,
+                    unsorted.as_str(),
+                    doc,
+                    config,
+                    headers,
+                    value.line_number(),
+                )
+                .await
+            }
+            result => result,
+        };
+
+        return match result {
+            Ok(result) => super::sqlite::result_to_value(Ok(result), kind, doc, &value, STATUS_OK),
+            Err(e) => {
+                super::sqlite::result_to_value(Err(e.to_string()), kind, doc, &value, STATUS_ERROR)
+            }
+        };
+    }
+
     let db_type = db_config.db_type.as_str();
 
     match db_type {
-        "postgres" => Ok(fastn_core::library2022::processor::pg::process(value, kind, doc).await?),
+        "postgres" => Ok(fastn_core::library2022::processor::pg::process_query(
+            value,
+            kind,
+            doc,
+            headers,
+            query.as_str(),
+        )
+        .await?),
         "sqlite" => Ok(fastn_core::library2022::processor::sqlite::process(
             value,
             kind,
@@ -74,6 +154,109 @@ pub async fn process(
     }
 }
 
+/// Runs a paginated query, only postgres and sqlite databases take `page-size`
+async fn execute_query(
+    db_config: &DatabaseConfig,
+    query: &str,
+    doc: &ftd::interpreter::TDoc<'_>,
+    config: &fastn_core::RequestConfig,
+    headers: ftd::ast::HeaderValues,
+    line_number: usize,
+) -> ftd::interpreter::Result<Vec<Vec<serde_json::Value>>> {
+    if db_config.db_type == "postgres" {
+        return super::pg::execute_query(query, doc, line_number, headers).await;
+    }
+    super::sqlite::execute_query(
+        &config.config.root.join(&db_config.db_url),
+        query,
+        doc,
+        headers,
+        line_number,
+    )
+    .await
+}
+
+/// `ftd.table` with `server-pagination` loads the page again with `page`,
+/// `sort` and `order` in the query string. A `sql` query with a `page-size`
+/// header reads them and only returns that page:
+///
+/// ```ftd
+/// -- product list products:
+/// $processor$: sql
+/// page-size: 20
+/// page-param: products
+///
+/// SELECT * FROM product;
+/// ```
+///
+/// With `page-param`, the names in the query string are prefixed with it,
+/// `products-page` and so on, so that two tables on a page, each given its
+/// own `page-param`, are paged separately.
+///
+/// `sort` is the `field` of the table column, with `-` standing for `_` in
+/// the column name. A `sort` that is not a plain name is ignored, as is any
+/// `sort` when `sort` is `false`. The total number of rows, for the table's
+/// `total`, needs a separate `SELECT count(*)` query.
+pub(crate) fn paginate_query(
+    query: &str,
+    page_size: i64,
+    page_param: Option<&str>,
+    request_query: &std::collections::HashMap<String, serde_json::Value>,
+    sort: bool,
+) -> Result<String, String> {
+    if page_size < 1 {
+        return Err(format!(
+            "`page-size` must be at least 1, found: `{}`",
+            page_size
+        ));
+    }
+
+    let param = |name: &str| {
+        let name = match page_param {
+            Some(prefix) => format!("{}-{}", prefix, name),
+            None => name.to_string(),
+        };
+        request_query.get(name.as_str())
+    };
+
+    let page = match param("page") {
+        Some(serde_json::Value::Number(n)) => n.as_i64(),
+        Some(serde_json::Value::String(s)) => s.trim().parse::<i64>().ok(),
+        _ => None,
+    }
+    .unwrap_or(1)
+    .max(1);
+
+    // the column name ends up in the query, so only plain names are used
+    let column = match param("sort").and_then(|v| v.as_str()) {
+        Some(field) if sort => Some(field.replace('-', "_")).filter(|column| {
+            !column.is_empty()
+                && column
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }),
+        _ => None,
+    };
+    let order_by = match column {
+        Some(column) => {
+            let order = match param("order").and_then(|v| v.as_str()) {
+                Some("desc") => "DESC",
+                _ => "ASC",
+            };
+            format!(" ORDER BY \"{}\" {}", column, order)
+        }
+        None => "".to_string(),
+    };
+
+    Ok(format!(
+        "SELECT * FROM ({}) AS fastn_page{} LIMIT {} OFFSET {}",
+        query.trim().trim_end_matches(';').trim_end(),
+        order_by,
+        page_size,
+        (page - 1).saturating_mul(page_size)
+    ))
+}
+
 pub const STATUS_OK: usize = 0;
 pub const STATUS_ERROR: usize = 1;
 const BACKSLASH: char = '\\';
@@ -171,6 +354,132 @@ mod test {
         assert_eq!(arguments, a);
     }
 
+    #[track_caller]
+    fn p(query: &[(&str, &str)], o: &str) {
+        let query = query
+            .iter()
+            .map(|(k, v)| (k.to_string(), serde_json::Value::String(v.to_string())))
+            .collect();
+        assert_eq!(
+            super::paginate_query("SELECT * FROM product;\n", 20, None, &query, true).unwrap(),
+            o
+        );
+    }
+
+    #[test]
+    fn paginate_query() {
+        p(
+            &[],
+            "SELECT * FROM (SELECT * FROM product) AS fastn_page LIMIT 20 OFFSET 0",
+        );
+        p(
+            &[("page", "3")],
+            "SELECT * FROM (SELECT * FROM product) AS fastn_page LIMIT 20 OFFSET 40",
+        );
+        p(
+            &[("page", "0")],
+            "SELECT * FROM (SELECT * FROM product) AS fastn_page LIMIT 20 OFFSET 0",
+        );
+        p(
+            &[("page", "2"), ("sort", "unit-price"), ("order", "desc")],
+            "SELECT * FROM (SELECT * FROM product) AS fastn_page \
+            ORDER BY \"unit_price\" DESC LIMIT 20 OFFSET 20",
+        );
+        p(
+            &[("sort", "name"), ("order", "sideways")],
+            "SELECT * FROM (SELECT * FROM product) AS fastn_page \
+            ORDER BY \"name\" ASC LIMIT 20 OFFSET 0",
+        );
+
+        // not a column name, the page is not sorted
+        p(
+            &[("sort", "name\"; DROP TABLE product; --")],
+            "SELECT * FROM (SELECT * FROM product) AS fastn_page LIMIT 20 OFFSET 0",
+        );
+
+        // with `page-param`, only its own names are read
+        let query = [
+            ("page", "2"),
+            ("products-page", "3"),
+            ("products-sort", "name"),
+        ]
+        .iter()
+        .map(|(k, v)| (k.to_string(), serde_json::Value::String(v.to_string())))
+        .collect();
+        assert_eq!(
+            super::paginate_query("SELECT * FROM product", 20, Some("products"), &query, true)
+                .unwrap(),
+            "SELECT * FROM (SELECT * FROM product) AS fastn_page \
+            ORDER BY \"name\" ASC LIMIT 20 OFFSET 40",
+        );
+        assert_eq!(
+            super::paginate_query("SELECT * FROM product", 20, Some("products"), &query, false)
+                .unwrap(),
+            "SELECT * FROM (SELECT * FROM product) AS fastn_page LIMIT 20 OFFSET 40",
+        );
+        assert!(super::paginate_query("SELECT 1", 0, None, &Default::default(), true).is_err());
+    }
+
+    async fn products(config: &fastn_core::Config, uri: &str) -> serde_json::Value {
+        let req = actix_web::test::TestRequest::with_uri(uri).to_http_request();
+        let req = fastn_core::http::Request::from_actix(req, Default::default());
+        let mut req_config = fastn_core::RequestConfig::new(config, &req, "index.ftd", "/");
+        let source = indoc::indoc! {"
+            -- import: fastn/processors as pr
+
+            -- record product:
+            string name:
+            integer price:
+
+            -- product list products:
+            $processor$: pr.sql
+            db: shop.sqlite
+            page-size: 2
+            page-param: products
+
+            SELECT name, price FROM product;
+        "};
+        fastn_core::doc::interpret_helper("sql-test/index", source, &mut req_config, "/", false, 0)
+            .await
+            .unwrap()
+            .json("products")
+            .unwrap()
+    }
+
+    /// The `sql` processor with `page-size` reads the page and the sort of its own
+    /// `page-param`, and shows the page unsorted when the sort is not a column
+    #[tokio::test]
+    async fn process_page() {
+        let package = fastn_core::test_package::TestPackage::new("sql-test", "", &[]);
+        rusqlite::Connection::open(package.root.join("shop.sqlite"))
+            .unwrap()
+            .execute_batch(
+                "CREATE TABLE product (name TEXT, price INTEGER);
+                INSERT INTO product VALUES ('pen', 3), ('ink', 1), ('pad', 2);",
+            )
+            .unwrap();
+        let config = package.config().await;
+
+        let product = |name: &str, price: i64| serde_json::json!({"name": name, "price": price});
+        assert_eq!(
+            products(&config, "/").await,
+            serde_json::json!([product("pen", 3), product("ink", 1)])
+        );
+        assert_eq!(
+            products(&config, "/?products-page=2").await,
+            serde_json::json!([product("pad", 2)])
+        );
+        // `page` is for a table without `page-param`
+        assert_eq!(
+            products(&config, "/?page=2&products-sort=price&products-order=desc").await,
+            serde_json::json!([product("pen", 3), product("pad", 2)])
+        );
+        assert_eq!(
+            products(&config, "/?products-sort=colour").await,
+            serde_json::json!([product("pen", 3), product("ink", 1)])
+        );
+    }
+
     #[test]
     fn extract_arguments() {
         e("SELECT $val::FLOAT8;", "SELECT $1::FLOAT8;", vec!["val"]);
@@ -99,7 +99,7 @@ pub(crate) fn result_to_value(
                 value: status as i64,
             }),
             "string" => Ok(ftd::interpreter::Value::String { text: (e) }),
-            _ => unimplemented!(),
+            _ => ftd::interpreter::utils::e2(e, doc.name, value.line_number()),
         },
     }
 }
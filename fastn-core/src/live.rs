@@ -0,0 +1,456 @@
+// Publish/subscribe hub of `fastn serve`, the server side of `ftd.subscribe`.
+//
+// `GET /-/live/<topic>/` is a server sent events stream of the topic: the latest value published
+// to the topic, if any, and then every value published after it. Values are JSON. Only the latest
+// value is kept, a subscriber which falls too far behind misses some values.
+//
+// `POST /-/live/<topic>/` publishes its JSON body to the topic. The token in
+// `FASTN_LIVE_PUBLISH_TOKEN` has to be sent as a bearer token, without it no one can publish.
+// `FASTN_LIVE_PUBLISH_LOOPBACK=true` lets requests from the same machine (say a backend running
+// next to fastn) publish without the token, do not set it if fastn is behind a reverse proxy on
+// the same machine: every request would come from there.
+//
+// With `FASTN_LIVE_PG_CHANNEL` set, `fastn serve` also listens on that channel of the
+// `FASTN_DB_URL` database, so SQL triggers can publish with
+// `pg_notify('<channel>', json_build_object('topic', 'orders', 'value', ...)::text)`.
+//
+// Topics are not access controlled, anyone who can reach the server can subscribe to them. A topic
+// is kept while it has subscribers or a published value.
+
+pub(crate) const PREFIX: &str = "/-/live/";
+const KEEP_ALIVE: std::time::Duration = std::time::Duration::from_secs(15);
+const RECONNECT_AFTER: std::time::Duration = std::time::Duration::from_secs(5);
+const CAPACITY: usize = 64;
+const MAX_TOPIC_LENGTH: usize = 128;
+
+static TOPICS: once_cell::sync::Lazy<antidote::Mutex<std::collections::HashMap<String, Topic>>> =
+    once_cell::sync::Lazy::new(|| antidote::Mutex::new(Default::default()));
+
+struct Topic {
+    sender: tokio::sync::broadcast::Sender<Message>,
+    latest: Option<Message>,
+}
+
+impl Topic {
+    fn new() -> Topic {
+        Topic {
+            sender: tokio::sync::broadcast::channel(CAPACITY).0,
+            latest: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Message {
+    /// Increases with every message of the topic, sent as the event id
+    id: u64,
+    data: std::sync::Arc<str>,
+}
+
+impl Message {
+    fn event(&self) -> actix_web::web::Bytes {
+        // `serde_json` does not put new lines in the data, so it is a single `data:` line
+        format!("id: {}\ndata: {}\n\n", self.id, self.data).into()
+    }
+}
+
+/// Sends `value` to every subscriber of `topic`, and keeps it for the ones joining later.
+pub fn publish(topic: &str, value: &serde_json::Value) {
+    let mut topics = TOPICS.lock();
+    let topic = topics.entry(topic.to_string()).or_insert_with(Topic::new);
+    let message = Message {
+        id: topic.latest.as_ref().map(|m| m.id + 1).unwrap_or(1),
+        data: value.to_string().into(),
+    };
+    topic.latest = Some(message.clone());
+    // no one listening is fine
+    let _ = topic.sender.send(message);
+}
+
+/// A subscriber of a topic, the topic is dropped with its last subscriber if nothing was published
+/// to it.
+struct Subscription {
+    topic: String,
+    receiver: tokio::sync::broadcast::Receiver<Message>,
+}
+
+impl Subscription {
+    fn new(topic: &str) -> (Option<Message>, Subscription) {
+        let mut topics = TOPICS.lock();
+        let entry = topics.entry(topic.to_string()).or_insert_with(Topic::new);
+        let subscription = Subscription {
+            topic: topic.to_string(),
+            receiver: entry.sender.subscribe(),
+        };
+        (entry.latest.clone(), subscription)
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        let mut topics = TOPICS.lock();
+        // the receiver of this subscription is the last one
+        if topics
+            .get(self.topic.as_str())
+            .is_some_and(|t| t.latest.is_none() && t.sender.receiver_count() == 1)
+        {
+            topics.remove(self.topic.as_str());
+        }
+    }
+}
+
+#[tracing::instrument(skip_all)]
+pub(crate) async fn handle(
+    req: fastn_core::http::Request,
+) -> fastn_core::Result<fastn_core::http::Response> {
+    let topic = req.path()[PREFIX.len()..].trim_matches('/');
+    if topic.is_empty() {
+        return Ok(fastn_core::not_found!("live: no topic in {}", req.path()));
+    }
+    if topic.len() > MAX_TOPIC_LENGTH {
+        return Ok(actix_web::HttpResponse::BadRequest().body(format!(
+            "live: topic is longer than {MAX_TOPIC_LENGTH} bytes"
+        )));
+    }
+
+    match req.method().to_lowercase().as_str() {
+        "get" => Ok(stream(topic, last_event_id(&req))),
+        "post" => {
+            if !can_publish(&req) {
+                return Ok(fastn_core::unauthorised!(
+                    "live: can not publish to {topic}"
+                ));
+            }
+            publish(topic, &req.json::<serde_json::Value>()?);
+            Ok(actix_web::HttpResponse::NoContent().finish())
+        }
+        _ => Ok(actix_web::HttpResponse::MethodNotAllowed().finish()),
+    }
+}
+
+/// On reconnect the browser sends the id of the last event it got.
+fn last_event_id(req: &fastn_core::http::Request) -> Option<u64> {
+    req.headers()
+        .get("last-event-id")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse().ok())
+}
+
+fn can_publish(req: &fastn_core::http::Request) -> bool {
+    if let Ok(token) = std::env::var("FASTN_LIVE_PUBLISH_TOKEN") {
+        if !token.is_empty()
+            && req
+                .headers()
+                .get(reqwest::header::AUTHORIZATION)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.strip_prefix("Bearer "))
+                == Some(token.as_str())
+        {
+            return true;
+        }
+    }
+
+    std::env::var("FASTN_LIVE_PUBLISH_LOOPBACK") == Ok("true".to_string())
+        && req
+            .get_ip()
+            .and_then(|ip| ip.parse::<std::net::IpAddr>().ok())
+            .map(|ip| ip.is_loopback())
+            .unwrap_or(false)
+}
+
+fn stream(topic: &str, last_event_id: Option<u64>) -> fastn_core::http::Response {
+    let (latest, subscription) = Subscription::new(topic);
+    // a reconnecting browser which already has the latest value does not get it again
+    let latest = latest.filter(|m| last_event_id != Some(m.id));
+
+    let body = futures::stream::unfold(
+        (latest, subscription),
+        |(latest, mut subscription)| async move {
+            if let Some(message) = latest {
+                return Some((
+                    Ok::<_, std::convert::Infallible>(message.event()),
+                    (None, subscription),
+                ));
+            }
+            loop {
+                match tokio::time::timeout(KEEP_ALIVE, subscription.receiver.recv()).await {
+                    Ok(Ok(message)) => return Some((Ok(message.event()), (None, subscription))),
+                    // missed some values, the next ones are newer anyway
+                    Ok(Err(tokio::sync::broadcast::error::RecvError::Lagged(_))) => continue,
+                    Ok(Err(tokio::sync::broadcast::error::RecvError::Closed)) => return None,
+                    // a comment, so proxies do not close an idle connection
+                    Err(_) => {
+                        return Some((
+                            Ok(actix_web::web::Bytes::from_static(b": keep-alive\n\n")),
+                            (None, subscription),
+                        ))
+                    }
+                }
+            }
+        },
+    );
+
+    actix_web::HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header((actix_web::http::header::CACHE_CONTROL, "no-cache"))
+        // the compression middleware would hold the events back
+        .insert_header((actix_web::http::header::CONTENT_ENCODING, "identity"))
+        .streaming(body)
+}
+
+/// Publishes the notifications of the `FASTN_LIVE_PG_CHANNEL` postgres channel, if it is set.
+/// The connection is opened again whenever it is lost.
+pub(crate) fn listen_postgres() {
+    let channel = match std::env::var("FASTN_LIVE_PG_CHANNEL") {
+        Ok(channel) => channel,
+        Err(_) => return,
+    };
+
+    tokio::spawn(async move {
+        loop {
+            if let Err(e) = listen_postgres_(channel.as_str()).await {
+                fastn_core::warning!("live: postgres channel {}: {}", channel, e);
+            }
+            tokio::time::sleep(RECONNECT_AFTER).await;
+        }
+    });
+}
+
+async fn listen_postgres_(channel: &str) -> fastn_core::Result<()> {
+    let db_url = std::env::var("FASTN_DB_URL")?;
+
+    // same settings as the `pg` processor
+    if std::env::var("FASTN_PG_DANGER_DISABLE_SSL") == Ok("false".to_string()) {
+        let (client, connection) = tokio_postgres::connect(db_url.as_str(), tokio_postgres::NoTls)
+            .await
+            .map_err(db_error)?;
+        return forward_notifications(channel, client, connection).await;
+    }
+
+    let mut connector = native_tls::TlsConnector::builder();
+    if std::env::var("FASTN_PG_DANGER_ALLOW_UNVERIFIED_CERTIFICATE") == Ok("true".to_string()) {
+        connector.danger_accept_invalid_certs(true);
+    }
+    let tls = postgres_native_tls::MakeTlsConnector::new(connector.build().map_err(|e| {
+        fastn_core::Error::DatabaseError {
+            message: e.to_string(),
+        }
+    })?);
+    let (client, connection) = tokio_postgres::connect(db_url.as_str(), tls)
+        .await
+        .map_err(db_error)?;
+    forward_notifications(channel, client, connection).await
+}
+
+async fn forward_notifications<S, T>(
+    channel: &str,
+    client: tokio_postgres::Client,
+    mut connection: tokio_postgres::Connection<S, T>,
+) -> fastn_core::Result<()>
+where
+    S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+    T: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+{
+    use futures::StreamExt;
+
+    let query = format!("LISTEN \"{}\"", channel.replace('"', "\"\""));
+    let listen = client.batch_execute(query.as_str());
+    let mut listen = std::pin::pin!(listen);
+    let mut listening = false;
+    // the connection does the actual work, `LISTEN` only goes through while it is polled
+    let mut messages = futures::stream::poll_fn(move |cx| connection.poll_message(cx));
+
+    loop {
+        tokio::select! {
+            result = &mut listen, if !listening => {
+                result.map_err(db_error)?;
+                listening = true;
+            }
+            message = messages.next() => match message {
+                Some(Ok(tokio_postgres::AsyncMessage::Notification(n))) => {
+                    publish_notification(n.payload())
+                }
+                Some(Ok(_)) => {}
+                Some(Err(e)) => return Err(db_error(e)),
+                None => return Ok(()),
+            }
+        }
+    }
+}
+
+/// The payload is `{"topic": "<topic>", "value": <value>}`.
+fn publish_notification(payload: &str) {
+    #[derive(serde::Deserialize)]
+    struct Notification {
+        topic: String,
+        value: serde_json::Value,
+    }
+
+    match serde_json::from_str::<Notification>(payload) {
+        Ok(n) => publish(n.topic.as_str(), &n.value),
+        Err(e) => fastn_core::warning!("live: invalid notification {:?}: {}", payload, e),
+    }
+}
+
+fn db_error(e: tokio_postgres::Error) -> fastn_core::Error {
+    fastn_core::Error::DatabaseError {
+        message: e.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn request(
+        method: actix_web::http::Method,
+        topic: &str,
+        headers: &[(&str, &str)],
+        body: &'static str,
+    ) -> fastn_core::http::Request {
+        let mut req = actix_web::test::TestRequest::default()
+            .method(method)
+            .uri(format!("{}{topic}/", super::PREFIX).as_str());
+        for header in headers {
+            req = req.insert_header(*header);
+        }
+        fastn_core::http::Request::from_actix(req.to_http_request(), body.into())
+    }
+
+    /// The next event of a server sent events response
+    async fn next_event(body: &mut actix_web::body::BoxBody) -> String {
+        use actix_web::body::MessageBody;
+
+        let chunk = futures::future::poll_fn(|cx| std::pin::Pin::new(&mut *body).poll_next(cx))
+            .await
+            .unwrap()
+            .unwrap();
+        String::from_utf8(chunk.to_vec()).unwrap()
+    }
+
+    fn has_topic(topic: &str) -> bool {
+        super::TOPICS.lock().contains_key(topic)
+    }
+
+    #[actix_web::test]
+    async fn get_latest_value() {
+        super::publish("get-latest", &serde_json::json!({"count": 1}));
+        let response = super::handle(request(actix_web::http::Method::GET, "get-latest", &[], ""))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), actix_web::http::StatusCode::OK);
+        let mut body = response.into_body();
+        assert_eq!(
+            next_event(&mut body).await,
+            "id: 1\ndata: {\"count\":1}\n\n"
+        );
+
+        super::publish("get-latest", &serde_json::json!({"count": 2}));
+        assert_eq!(
+            next_event(&mut body).await,
+            "id: 2\ndata: {\"count\":2}\n\n"
+        );
+    }
+
+    #[actix_web::test]
+    async fn get_skips_last_event_id() {
+        super::publish("get-skip", &serde_json::json!(1));
+        let mut body = super::handle(request(
+            actix_web::http::Method::GET,
+            "get-skip",
+            &[("last-event-id", "1")],
+            "",
+        ))
+        .await
+        .unwrap()
+        .into_body();
+        super::publish("get-skip", &serde_json::json!(2));
+        assert_eq!(next_event(&mut body).await, "id: 2\ndata: 2\n\n");
+    }
+
+    #[actix_web::test]
+    async fn topic_without_value_is_dropped_with_its_subscribers() {
+        let response = super::handle(request(
+            actix_web::http::Method::GET,
+            "get-nothing",
+            &[],
+            "",
+        ))
+        .await
+        .unwrap();
+        assert!(has_topic("get-nothing"));
+        drop(response);
+        assert!(!has_topic("get-nothing"));
+
+        let response = super::handle(request(
+            actix_web::http::Method::GET,
+            "a".repeat(super::MAX_TOPIC_LENGTH + 1).as_str(),
+            &[],
+            "",
+        ))
+        .await
+        .unwrap();
+        assert_eq!(response.status(), actix_web::http::StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn post_needs_token() {
+        // the only test which sets the token
+        std::env::set_var("FASTN_LIVE_PUBLISH_TOKEN", "secret");
+
+        for headers in [vec![], vec![("authorization", "Bearer wrong")]] {
+            let response = super::handle(request(
+                actix_web::http::Method::POST,
+                "post-token",
+                headers.as_slice(),
+                "42",
+            ))
+            .await
+            .unwrap();
+            assert_eq!(response.status(), actix_web::http::StatusCode::UNAUTHORIZED);
+        }
+        assert!(!has_topic("post-token"));
+
+        let response = super::handle(request(
+            actix_web::http::Method::POST,
+            "post-token",
+            &[("authorization", "Bearer secret")],
+            "42",
+        ))
+        .await
+        .unwrap();
+        assert_eq!(response.status(), actix_web::http::StatusCode::NO_CONTENT);
+        assert_eq!(
+            super::TOPICS.lock()["post-token"]
+                .latest
+                .as_ref()
+                .map(|m| m.data.to_string()),
+            Some("42".to_string())
+        );
+    }
+
+    #[actix_web::test]
+    async fn other_methods_are_not_allowed() {
+        let response = super::handle(request(actix_web::http::Method::PUT, "put", &[], "42"))
+            .await
+            .unwrap();
+        assert_eq!(
+            response.status(),
+            actix_web::http::StatusCode::METHOD_NOT_ALLOWED
+        );
+    }
+
+    #[test]
+    fn publish_notification() {
+        super::publish_notification(r#"{"topic": "pg-orders", "value": {"id": 7}}"#);
+        assert_eq!(
+            super::TOPICS.lock()["pg-orders"]
+                .latest
+                .as_ref()
+                .map(|m| m.data.to_string()),
+            Some(r#"{"id":7}"#.to_string())
+        );
+
+        super::publish_notification(r#"{"value": 1}"#);
+        super::publish_notification("not json");
+        assert!(!has_topic(""));
+    }
+}
@@ -1,6 +1,7 @@
 pub mod app;
 pub mod dependency;
 pub mod package_doc;
+pub mod proxy;
 pub mod redirects;
 pub mod user_group;
 
@@ -63,9 +64,15 @@ pub struct Package {
     /// Headers for the WASM backend
     pub backend_headers: Option<Vec<fastn_package::old_fastn::BackendHeader>>,
 
+    /// Navigate between the package's pages on the client, without full page loads
+    pub spa: bool,
+
     /// Installed Apps
     pub apps: Vec<app::App>,
 
+    /// Settings for proxying requests to the `endpoint` and app `end-point`s
+    pub proxies: Vec<proxy::ProxySettings>,
+
     /// Package Icon
     pub icon: Option<ftd::ImageSrc>,
 
@@ -107,7 +114,9 @@ impl Package {
             endpoint: None,
             backend: false,
             backend_headers: None,
+            spa: false,
             apps: vec![],
+            proxies: vec![],
             icon: None,
             redirects: None,
             system: None,
@@ -728,6 +737,11 @@ impl Package {
         package.fonts = fastn_doc.get("fastn#font")?;
         package.sitemap_temp = fastn_doc.get("fastn#sitemap")?;
         package.dynamic_urls_temp = fastn_doc.get("fastn#dynamic-urls")?;
+        package.proxies = fastn_doc
+            .get::<Vec<proxy::ProxySettingsTemp>>("fastn#proxy")?
+            .into_iter()
+            .map(|p| p.into_proxy_settings())
+            .collect::<fastn_core::Result<Vec<proxy::ProxySettings>>>()?;
 
         // TODO: resolve group dependent packages, there may be imported group from foreign package
         //   We need to make sure to resolve that package as well before moving ahead
@@ -1019,7 +1033,9 @@ impl PackageTempIntoPackage for fastn_package::old_fastn::PackageTemp {
             endpoint: self.endpoint,
             backend: self.backend,
             backend_headers: self.backend_headers,
+            spa: self.spa,
             apps: vec![],
+            proxies: vec![],
             icon: self.icon,
             redirects: None,
             system: self.system,
@@ -332,12 +332,13 @@ pub(crate) fn file_id_to_names(id: &str) -> Vec<String> {
 pub enum FTDResult {
     Html(Vec<u8>),
     Redirect { url: String, code: i32 },
+    Json(Vec<u8>),
 }
 
 impl FTDResult {
     pub fn html(&self) -> Vec<u8> {
         match self {
-            FTDResult::Html(d) => d.to_vec(),
+            FTDResult::Html(d) | FTDResult::Json(d) => d.to_vec(),
             FTDResult::Redirect { url, .. } => {
                 // Note: this is a hack to redirect to a html page, we can not handle code in this
                 // case
@@ -358,6 +359,9 @@ impl From<FTDResult> for fastn_core::http::Response {
                 fastn_core::http::ok_with_content_type(body, mime_guess::mime::TEXT_HTML_UTF_8)
             }
             FTDResult::Redirect { url, code } => fastn_core::http::redirect_with_code(url, code),
+            FTDResult::Json(body) => {
+                fastn_core::http::ok_with_content_type(body, mime_guess::mime::APPLICATION_JSON)
+            }
         }
     }
 }
@@ -437,7 +441,12 @@ pub(crate) async fn read_ftd_2022(
     {
         Ok(v) => v,
         Err(e) => {
-            tracing::error!(msg = "failed to parse", doc = main.id.as_str());
+            tracing::error!(
+                msg = "failed to parse",
+                doc = main.id.as_str(),
+                "\n{}",
+                e.render(main.id_with_package().as_str(), main.content.as_str())
+            );
             return Err(fastn_core::Error::PackageError {
                 message: format!("failed to parse {:?}", &e),
             });
@@ -506,7 +515,12 @@ pub(crate) async fn read_ftd_2023(
     {
         Ok(v) => v,
         Err(e) => {
-            tracing::error!(msg = "failed to parse", doc = main.id.as_str());
+            tracing::error!(
+                msg = "failed to parse",
+                doc = main.id.as_str(),
+                "\n{}",
+                e.render(main.id_with_package().as_str(), main.content.as_str())
+            );
             return Err(fastn_core::Error::PackageError {
                 message: format!("failed to parse {:?}", &e),
             });
@@ -516,8 +530,21 @@ pub(crate) async fn read_ftd_2023(
         return Ok(FTDResult::Redirect { url, code });
     }
 
+    let spa_navigation = only_js && config.request.is_spa_navigation();
+    let persist = if spa_navigation {
+        persisted_variables(&main_ftd_doc)
+    } else {
+        vec![]
+    };
     let js_ast_data = ftd::js::document_into_js_ast(main_ftd_doc);
     let js_document_script = fastn_js::to_js(js_ast_data.asts.as_slice(), package_name.as_str());
+    if spa_navigation {
+        return spa_page(
+            js_document_script.as_str(),
+            js_ast_data.scripts.as_slice(),
+            persist.as_slice(),
+        );
+    }
     let js_ftd_script = fastn_js::to_js(
         ftd::js::default_bag_into_js_ast().as_slice(),
         package_name.as_str(),
@@ -547,6 +574,37 @@ pub(crate) async fn read_ftd_2023(
     Ok(FTDResult::Html(file_content.into()))
 }
 
+/// The payload for the client side router: the document script (what `ftd-js.html` puts inside
+/// its IIFE), the external scripts and stylesheets of the page, and the JS names of the
+/// variables marked `$persist$: true`. The router re-creates every other variable on each visit,
+/// like a full page load does.
+fn spa_page(
+    js_document_script: &str,
+    scripts: &[String],
+    persist: &[String],
+) -> fastn_core::Result<FTDResult> {
+    let page = serde_json::json!({
+        "js": format!("{js_document_script}{}", fastn_core::utils::available_code_themes()),
+        "scripts": scripts.join(""),
+        "persist": persist,
+    });
+
+    Ok(FTDResult::Json(serde_json::to_vec(&page)?))
+}
+
+fn persisted_variables(document: &ftd::interpreter::Document) -> Vec<String> {
+    document
+        .data
+        .values()
+        .filter_map(|thing| match thing {
+            ftd::interpreter::Thing::Variable(v) if v.persist => {
+                Some(fastn_js::utils::name_to_js_(v.name.as_str()))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
 pub(crate) async fn process_ftd(
     config: &mut fastn_core::RequestConfig,
     main: &fastn_core::Document,
@@ -0,0 +1,238 @@
+/// Settings for requests `fastn serve` proxies to an upstream: the package `endpoint` or the
+/// `end-point` of an app.
+///
+/// ```ftd
+/// -- fastn.proxy: http://127.0.0.1:8000
+/// connect-timeout: 5s
+/// timeout: 30s
+/// retries: 2
+/// block-request-headers: authorization
+/// forward-response-headers: content-type, set-cookie, location
+/// ```
+///
+/// Settings apply to every upstream url starting with the caption, when more than one entry
+/// matches the longest one wins.
+///
+/// The caption can also be a name for a group of replicas, requests are then balanced across
+/// the `upstreams`, see `fastn_core::upstream`:
+///
+/// ```ftd
+/// -- fastn.proxy: http://todo-backend
+/// upstreams: http://10.0.0.1:8000
+/// upstreams: http://10.0.0.2:8000
+/// load-balancing: least-connections
+/// health-check: /healthz/
+/// health-check-interval: 10s
+/// sticky-cookie: todo-upstream
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProxySettings {
+    pub endpoint: String,
+    pub connect_timeout: std::time::Duration,
+    /// Time allowed to the upstream to send the response headers. The body is streamed and is
+    /// not bound by this.
+    pub timeout: std::time::Duration,
+    /// Number of times a request is retried if the upstream can not be reached or does not
+    /// respond in time. Only idempotent requests (`GET`, `HEAD`, `OPTIONS`, `PUT`, `DELETE`,
+    /// `TRACE`) are retried.
+    pub retries: usize,
+    /// If not empty, only these request headers are sent to the upstream.
+    pub forward_request_headers: Vec<String>,
+    pub block_request_headers: Vec<String>,
+    /// If not empty, only these response headers are sent back to the browser.
+    pub forward_response_headers: Vec<String>,
+    pub block_response_headers: Vec<String>,
+    /// Replicas of the endpoint, if empty the request goes to the endpoint itself.
+    pub upstreams: Vec<String>,
+    pub load_balancing: LoadBalancing,
+    /// Path requested on every upstream, an upstream not responding with a success status is
+    /// not sent any request till it does.
+    pub health_check: Option<String>,
+    pub health_check_interval: std::time::Duration,
+    /// Name of the cookie used to send all requests of a browser to the same upstream.
+    pub sticky_cookie: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum LoadBalancing {
+    #[default]
+    RoundRobin,
+    LeastConnections,
+}
+
+impl std::str::FromStr for LoadBalancing {
+    type Err = fastn_core::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "round-robin" => Ok(LoadBalancing::RoundRobin),
+            "least-connections" => Ok(LoadBalancing::LeastConnections),
+            t => Err(fastn_core::Error::PackageError {
+                message: format!(
+                    "package-config-error, unknown load-balancing `{}`, expected `round-robin` or `least-connections`",
+                    t
+                ),
+            }),
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Debug, Clone)]
+pub struct ProxySettingsTemp {
+    pub endpoint: String,
+    #[serde(rename = "connect-timeout")]
+    pub connect_timeout: Option<String>,
+    pub timeout: Option<String>,
+    pub retries: i64,
+    #[serde(rename = "forward-request-headers")]
+    pub forward_request_headers: Vec<String>,
+    #[serde(rename = "block-request-headers")]
+    pub block_request_headers: Vec<String>,
+    #[serde(rename = "forward-response-headers")]
+    pub forward_response_headers: Vec<String>,
+    #[serde(rename = "block-response-headers")]
+    pub block_response_headers: Vec<String>,
+    pub upstreams: Vec<String>,
+    #[serde(rename = "load-balancing")]
+    pub load_balancing: Option<String>,
+    #[serde(rename = "health-check")]
+    pub health_check: Option<String>,
+    #[serde(rename = "health-check-interval")]
+    pub health_check_interval: Option<String>,
+    #[serde(rename = "sticky-cookie")]
+    pub sticky_cookie: Option<String>,
+}
+
+const DEFAULT_CONNECT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+const DEFAULT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
+const DEFAULT_HEALTH_CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(10);
+
+impl Default for ProxySettings {
+    fn default() -> Self {
+        ProxySettings {
+            endpoint: "".to_string(),
+            connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+            timeout: DEFAULT_TIMEOUT,
+            retries: 0,
+            forward_request_headers: vec![],
+            block_request_headers: vec![],
+            forward_response_headers: vec![],
+            block_response_headers: vec![],
+            upstreams: vec![],
+            load_balancing: LoadBalancing::RoundRobin,
+            health_check: None,
+            health_check_interval: DEFAULT_HEALTH_CHECK_INTERVAL,
+            sticky_cookie: None,
+        }
+    }
+}
+
+impl ProxySettingsTemp {
+    pub fn into_proxy_settings(self) -> fastn_core::Result<ProxySettings> {
+        let retries =
+            usize::try_from(self.retries).map_err(|_| fastn_core::Error::PackageError {
+                message: format!(
+                    "package-config-error, `retries` of `fastn.proxy: {}` can not be negative",
+                    self.endpoint
+                ),
+            })?;
+
+        Ok(ProxySettings {
+            connect_timeout: self
+                .connect_timeout
+                .map(|v| fastn_core::response_cache::parse_duration(v.as_str()))
+                .transpose()?
+                .unwrap_or(DEFAULT_CONNECT_TIMEOUT),
+            timeout: self
+                .timeout
+                .map(|v| fastn_core::response_cache::parse_duration(v.as_str()))
+                .transpose()?
+                .unwrap_or(DEFAULT_TIMEOUT),
+            retries,
+            forward_request_headers: headers(self.forward_request_headers),
+            block_request_headers: headers(self.block_request_headers),
+            forward_response_headers: headers(self.forward_response_headers),
+            block_response_headers: headers(self.block_response_headers),
+            upstreams: self
+                .upstreams
+                .iter()
+                .map(|v| v.trim().trim_end_matches('/').to_string())
+                .filter(|v| !v.is_empty())
+                .collect(),
+            load_balancing: self
+                .load_balancing
+                .map(|v| v.parse())
+                .transpose()?
+                .unwrap_or_default(),
+            health_check: self.health_check,
+            health_check_interval: self
+                .health_check_interval
+                .map(|v| fastn_core::response_cache::parse_duration(v.as_str()))
+                .transpose()?
+                .unwrap_or(DEFAULT_HEALTH_CHECK_INTERVAL),
+            sticky_cookie: self.sticky_cookie,
+            endpoint: self.endpoint,
+        })
+    }
+}
+
+/// Header lists can be given as `string list` entries, or comma separated in one entry.
+fn headers(list: Vec<String>) -> Vec<String> {
+    list.iter()
+        .flat_map(|v| v.split(','))
+        .map(|v| v.trim().to_lowercase())
+        .filter(|v| !v.is_empty())
+        .collect()
+}
+
+impl ProxySettings {
+    pub fn allows_request_header(&self, name: &str) -> bool {
+        allows(
+            &self.forward_request_headers,
+            &self.block_request_headers,
+            name,
+        )
+    }
+
+    pub fn allows_response_header(&self, name: &str) -> bool {
+        allows(
+            &self.forward_response_headers,
+            &self.block_response_headers,
+            name,
+        )
+    }
+}
+
+fn allows(forward: &[String], block: &[String], name: &str) -> bool {
+    let name = name.to_lowercase();
+    (forward.is_empty() || forward.contains(&name)) && !block.contains(&name)
+}
+
+impl fastn_core::Package {
+    /// Proxy settings for the upstream `url`.
+    pub fn proxy_settings(&self, url: &str) -> ProxySettings {
+        self.proxies
+            .iter()
+            .filter(|p| url.starts_with(p.endpoint.trim_end_matches('/')))
+            .max_by_key(|p| p.endpoint.trim_end_matches('/').len())
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn allows() {
+        let settings = super::ProxySettings {
+            block_request_headers: super::headers(vec!["Authorization, cookie".to_string()]),
+            forward_response_headers: super::headers(vec!["content-type".to_string()]),
+            ..Default::default()
+        };
+        assert!(settings.allows_request_header("accept"));
+        assert!(!settings.allows_request_header("Cookie"));
+        assert!(!settings.allows_request_header("authorization"));
+        assert!(settings.allows_response_header("Content-Type"));
+        assert!(!settings.allows_response_header("set-cookie"));
+    }
+}
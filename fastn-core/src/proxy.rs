@@ -1,20 +1,57 @@
-fn client_builder() -> reqwest::Client {
+fn client_builder(connect_timeout: std::time::Duration) -> reqwest::Client {
     // TODO: Connection Pool, It by default holds the connection pool internally
     reqwest::ClientBuilder::new()
         .http2_adaptive_window(true)
         .tcp_keepalive(std::time::Duration::new(150, 0))
         .tcp_nodelay(true)
-        .connect_timeout(std::time::Duration::new(150, 0))
-        .connection_verbose(true)
+        .connect_timeout(connect_timeout)
         .redirect(reqwest::redirect::Policy::none())
         .build()
         .unwrap()
 }
 
-static CLIENT: once_cell::sync::Lazy<std::sync::Arc<reqwest::Client>> =
-    once_cell::sync::Lazy::new(|| std::sync::Arc::new(client_builder()));
+// One client per connect timeout, `reqwest::Client` is cheap to clone and shares its pool
+static CLIENTS: once_cell::sync::Lazy<
+    antidote::Mutex<std::collections::HashMap<std::time::Duration, reqwest::Client>>,
+> = once_cell::sync::Lazy::new(|| antidote::Mutex::new(Default::default()));
+
+pub(crate) fn client(connect_timeout: std::time::Duration) -> reqwest::Client {
+    CLIENTS
+        .lock()
+        .entry(connect_timeout)
+        .or_insert_with(|| client_builder(connect_timeout))
+        .clone()
+}
+
+// https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers#hop-by-hop_headers
+// These are meant for a single connection, so are never forwarded in either direction
+const HOP_BY_HOP_HEADERS: [&str; 8] = [
+    "connection",
+    "keep-alive",
+    "proxy-authenticate",
+    "proxy-authorization",
+    "te",
+    "trailer",
+    "transfer-encoding",
+    "upgrade",
+];
+
+fn is_idempotent(method: &reqwest::Method) -> bool {
+    [
+        reqwest::Method::GET,
+        reqwest::Method::HEAD,
+        reqwest::Method::OPTIONS,
+        reqwest::Method::PUT,
+        reqwest::Method::DELETE,
+        reqwest::Method::TRACE,
+    ]
+    .contains(method)
+}
 
 // This method will connect client request to the out of the world
+//
+// Upstream failures are returned as `fastn_core::Error::UpstreamError`, so the caller can show
+// the package's 502 or 504 page.
 #[tracing::instrument(skip_all)]
 pub(crate) async fn get_out(
     host: &str,
@@ -22,42 +59,48 @@ pub(crate) async fn get_out(
     path: &str,
     package_name: &str,
     req_headers: &std::collections::HashMap<String, String>,
+    settings: &fastn_core::package::proxy::ProxySettings,
 ) -> fastn_core::Result<fastn_core::http::Response> {
-    let headers = req.headers();
     // TODO: It should be part of fastn_core::Request::uri()
     // let path = &req.uri().to_string()[1..];
 
     tracing::info!("proxy_request: {} {} {}", req.method(), path, host);
 
-    let mut proxy_request = reqwest::Request::new(
-        match req.method() {
-            "GET" => reqwest::Method::GET,
-            "POST" => reqwest::Method::POST,
-            "PUT" => reqwest::Method::PUT,
-            "DELETE" => reqwest::Method::DELETE,
-            "PATCH" => reqwest::Method::PATCH,
-            "HEAD" => reqwest::Method::HEAD,
-            "OPTIONS" => reqwest::Method::OPTIONS,
-            "TRACE" => reqwest::Method::TRACE,
-            "CONNECT" => reqwest::Method::CONNECT,
-            _ => reqwest::Method::GET,
-        },
-        reqwest::Url::parse(
-            format!(
-                "{}/{}{}",
-                host.trim_end_matches('/'),
-                path.trim_start_matches('/'),
-                if req.query_string().is_empty() {
-                    "".to_string()
-                } else {
-                    format!("?{}", req.query_string())
-                }
-            )
-            .as_str(),
-        )?,
-    );
+    let method = match req.method() {
+        "GET" => reqwest::Method::GET,
+        "POST" => reqwest::Method::POST,
+        "PUT" => reqwest::Method::PUT,
+        "DELETE" => reqwest::Method::DELETE,
+        "PATCH" => reqwest::Method::PATCH,
+        "HEAD" => reqwest::Method::HEAD,
+        "OPTIONS" => reqwest::Method::OPTIONS,
+        "TRACE" => reqwest::Method::TRACE,
+        "CONNECT" => reqwest::Method::CONNECT,
+        _ => reqwest::Method::GET,
+    };
+    let url = reqwest::Url::parse(
+        format!(
+            "{}/{}{}",
+            host.trim_end_matches('/'),
+            path.trim_start_matches('/'),
+            if req.query_string().is_empty() {
+                "".to_string()
+            } else {
+                format!("?{}", req.query_string())
+            }
+        )
+        .as_str(),
+    )?;
 
-    *proxy_request.headers_mut() = headers.to_owned();
+    // Cookies are sent as the `cookie` header, so they follow the same allow and deny lists
+    let mut headers = reqwest::header::HeaderMap::new();
+    for (name, value) in req.headers().iter().filter(|(name, _)| {
+        !HOP_BY_HOP_HEADERS.contains(&name.as_str())
+            && !fastn_core::utils::ignore_headers().contains(&name.as_str())
+            && settings.allows_request_header(name.as_str())
+    }) {
+        headers.append(name.clone(), value.clone());
+    }
 
     // TODO: Some extra headers, possibly Authentication header
     // Authentication header can come from system environment variable
@@ -73,43 +116,150 @@ pub(crate) async fn get_out(
     // `/api/movie/?id=<id>` of movie-db service, this will happen while fastn is converting ftd code
     // to html, so all this happening on server side. So we can say server side rendering.
 
-    // headers
+    // headers from the app config are set by the package author, so these are always sent
 
     for (header_key, header_value) in req_headers {
-        proxy_request.headers_mut().insert(
+        headers.insert(
             reqwest::header::HeaderName::from_bytes(header_key.as_bytes()).unwrap(),
             reqwest::header::HeaderValue::from_str(header_value.as_str()).unwrap(),
         );
     }
 
-    proxy_request.headers_mut().insert(
+    headers.insert(
         reqwest::header::USER_AGENT,
         reqwest::header::HeaderValue::from_static("fastn"),
     );
 
     if let Some(ip) = req.get_ip() {
-        proxy_request.headers_mut().insert(
+        headers.insert(
             reqwest::header::FORWARDED,
             reqwest::header::HeaderValue::from_str(ip.as_str()).unwrap(),
         );
     }
 
-    if let Some(cookies) = req.cookies_string() {
-        proxy_request.headers_mut().insert(
-            reqwest::header::COOKIE,
-            reqwest::header::HeaderValue::from_str(cookies.as_str()).unwrap(),
-        );
+    let client = client(settings.connect_timeout);
+    // A body which is still coming in is passed on as it comes, so it can only be sent once. It is
+    // read already if fastn tried to render the path itself, see `serve_helper`.
+    let mut body_stream = req.take_body_stream();
+    let retries = if is_idempotent(&method) && body_stream.is_none() {
+        settings.retries
+    } else {
+        0
+    };
+    let body = req.body_bytes();
+    let pool = fastn_core::upstream::pool(settings);
+    let sticky = settings
+        .sticky_cookie
+        .as_ref()
+        .and_then(|name| req.cookie(name.as_str()));
+
+    let mut attempt = 0;
+    let (response, lease) = loop {
+        // with replicas every attempt can go to a different upstream
+        let (url, lease) = match pool {
+            Some(ref pool) => {
+                let lease = pool.lease(sticky.as_deref()).ok_or_else(|| {
+                    fastn_core::Error::UpstreamError {
+                        message: format!(
+                            "{package_name}: {}: no healthy upstream",
+                            settings.endpoint
+                        ),
+                        timed_out: false,
+                    }
+                })?;
+                (lease.url(settings.endpoint.as_str(), &url)?, Some(lease))
+            }
+            None => (url.clone(), None),
+        };
+
+        let mut proxy_request = reqwest::Request::new(method.clone(), url.clone());
+        *proxy_request.headers_mut() = headers.clone();
+        if let Some(body_stream) = body_stream.take() {
+            *proxy_request.body_mut() = Some(body_stream);
+        } else if !body.is_empty() {
+            // `Bytes` is reference counted, the body is not copied for every attempt
+            *proxy_request.body_mut() = Some(body.clone().into());
+        }
+
+        let error =
+            match tokio::time::timeout(settings.timeout, client.execute(proxy_request)).await {
+                Ok(Ok(response)) => break (response, lease),
+                Ok(Err(e)) => fastn_core::Error::UpstreamError {
+                    message: format!("{package_name}: {method} {url}: {e}"),
+                    timed_out: e.is_timeout(),
+                },
+                Err(_) => fastn_core::Error::UpstreamError {
+                    message: format!(
+                        "{package_name}: {method} {url}: no response in {:?}",
+                        settings.timeout
+                    ),
+                    timed_out: true,
+                },
+            };
+
+        if let Some(lease) = lease {
+            lease.failed(settings.health_check.is_some());
+        }
+        if attempt >= retries {
+            return Err(error);
+        }
+        attempt += 1;
+        tracing::warn!("proxy_request: retry {}/{}, {}", attempt, retries, error);
+    };
+
+    let sticky_cookie = match (settings.sticky_cookie.as_ref(), lease.as_ref()) {
+        (Some(name), Some(lease)) if sticky.as_deref() != Some(lease.sticky_id()) => Some(
+            actix_web::cookie::Cookie::build(name.to_string(), lease.sticky_id().to_string())
+                .path("/")
+                .http_only(true)
+                .same_site(actix_web::cookie::SameSite::Lax)
+                .finish(),
+        ),
+        _ => None,
+    };
+
+    let mut response = into_response(response, settings, lease);
+    if let Some(cookie) = sticky_cookie {
+        response.add_cookie(&cookie).ok();
     }
+    Ok(response)
+}
 
-    for header in fastn_core::utils::ignore_headers() {
-        proxy_request.headers_mut().remove(header);
+/// The upstream response with its body streamed to the browser as it arrives. The `lease` on the
+/// upstream is held till the body is sent.
+fn into_response(
+    response: reqwest::Response,
+    settings: &fastn_core::package::proxy::ProxySettings,
+    lease: Option<fastn_core::upstream::Lease>,
+) -> fastn_core::http::Response {
+    let mut response_builder = actix_web::HttpResponse::build(response.status());
+    for (name, value) in response.headers().iter().filter(|(name, _)| {
+        !HOP_BY_HOP_HEADERS.contains(&name.as_str())
+            && *name != reqwest::header::CONTENT_LENGTH
+            && settings.allows_response_header(name.as_str())
+    }) {
+        // `append` as there can be more than one `set-cookie`
+        response_builder.append_header((name.clone(), value.clone()));
     }
 
-    *proxy_request.body_mut() = Some(req.body().to_vec().into());
+    let content_length = response.content_length();
+    let body = Box::pin(futures::stream::unfold(
+        Some((response, lease)),
+        |state| async move {
+            let (mut response, lease) = state?;
+            match response.chunk().await {
+                Ok(Some(chunk)) => Some((Ok(chunk), Some((response, lease)))),
+                Ok(None) => None,
+                Err(e) => {
+                    tracing::error!("proxy_response: {}", e);
+                    Some((Err(e), None))
+                }
+            }
+        },
+    ));
 
-    Ok(fastn_core::http::ResponseBuilder::from_reqwest(
-        CLIENT.execute(proxy_request).await?,
-        package_name,
-    )
-    .await)
+    match content_length {
+        Some(length) => response_builder.body(actix_web::body::SizedStream::new(length, body)),
+        None => response_builder.streaming(body),
+    }
 }
@@ -0,0 +1,398 @@
+// Response cache for pages rendered by `fastn serve`.
+//
+// Caching is opt-in per route, using key value attributes in the sitemap (or dynamic-urls):
+//
+// ```ftd
+// -- fastn.sitemap:
+//
+// # Dashboard: /dashboard/
+//   cache: 5m
+//   cache-query: page, sort
+//   cache-cookies: theme
+//   cache-user-group: true
+//   stale-while-revalidate: 1m
+// ```
+//
+// `cache` is how long a rendered page is fresh. `cache-query` lists the query parameters that
+// change the page, if not given, the whole query string is part of the key. `cache-cookies`
+// lists the cookies that change the page. If `cache-user-group` is true the reader groups (from
+// sitemap) the current user belongs to are part of the key. For `stale-while-revalidate` after
+// the page expires, the stale page is served while a fresh one is rendered in the background.
+//
+// Readers are checked before the cache is looked up. A page with readers, in the sitemap or in
+// its app, is not cached unless `cache-user-group` is true: it may show what only the current
+// user can see, with `cache-user-group` the page is taken to depend only on the user's groups.
+//
+// Entries can be purged using `/-/clear-cache/?url=/dashboard/`, clearing any file or package
+// with `/-/clear-cache/` purges the whole cache.
+
+const MAX_ENTRIES: usize = 1000;
+
+static CACHE: once_cell::sync::Lazy<
+    antidote::RwLock<std::collections::HashMap<String, CachedResponse>>,
+> = once_cell::sync::Lazy::new(|| antidote::RwLock::new(Default::default()));
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CacheConfig {
+    pub ttl: std::time::Duration,
+    pub stale_while_revalidate: Option<std::time::Duration>,
+    pub query: Option<Vec<String>>,
+    pub cookies: Vec<String>,
+    pub user_group: bool,
+}
+
+#[derive(Debug, Clone)]
+struct CachedResponse {
+    path: String,
+    status: actix_web::http::StatusCode,
+    headers: Vec<(
+        actix_web::http::header::HeaderName,
+        actix_web::http::header::HeaderValue,
+    )>,
+    body: actix_web::web::Bytes,
+    stored_at: std::time::Instant,
+    config: CacheConfig,
+    revalidating: bool,
+}
+
+pub enum Lookup {
+    Fresh(fastn_core::http::Response),
+    /// The cached response has expired but can still be served, the caller must render the
+    /// page again and `store` it.
+    Stale(fastn_core::http::Response),
+    Miss,
+}
+
+impl CacheConfig {
+    pub fn from_extra_data(
+        extra_data: &std::collections::BTreeMap<String, String>,
+    ) -> fastn_core::Result<Option<CacheConfig>> {
+        let ttl = match extra_data.get("cache") {
+            Some(v) => parse_duration(v)?,
+            None => return Ok(None),
+        };
+
+        Ok(Some(CacheConfig {
+            ttl,
+            stale_while_revalidate: extra_data
+                .get("stale-while-revalidate")
+                .map(|v| parse_duration(v))
+                .transpose()?,
+            query: extra_data.get("cache-query").map(|v| split_list(v)),
+            cookies: extra_data
+                .get("cache-cookies")
+                .map(|v| split_list(v))
+                .unwrap_or_default(),
+            user_group: extra_data
+                .get("cache-user-group")
+                .map(|v| v.parse::<bool>())
+                .transpose()?
+                .unwrap_or(false),
+        }))
+    }
+
+    /// Cache key for the current request: path, the selected query parameters and cookies, the
+    /// selected language and user groups the user belongs to.
+    pub async fn key(
+        &self,
+        req_config: &fastn_core::RequestConfig,
+        path: &str,
+        only_js: bool,
+    ) -> fastn_core::Result<String> {
+        let req = &req_config.request;
+        let query = match self.query {
+            Some(ref keys) => {
+                let query = fastn_core::utils::query(req.uri())?;
+                keys.iter()
+                    .flat_map(|k| {
+                        query
+                            .iter()
+                            .filter(move |(name, _)| name == k)
+                            .map(|(name, value)| format!("{name}={value}"))
+                    })
+                    .collect::<Vec<_>>()
+                    .join("&")
+            }
+            None => req.query_string().to_string(),
+        };
+
+        let cookies = self
+            .cookies
+            .iter()
+            .map(String::as_str)
+            .chain(std::iter::once("fastn-lang"))
+            .map(|name| format!("{name}={}", req.cookie(name).unwrap_or_default()))
+            .collect::<Vec<_>>()
+            .join(";");
+
+        let groups = if self.user_group {
+            req_config.user_groups(path).await?.join(",")
+        } else {
+            "".to_string()
+        };
+
+        Ok(format!(
+            "{path}?{query}|{cookies}|{groups}|{}",
+            if !only_js {
+                "html"
+            } else if req.is_spa_navigation() {
+                "spa"
+            } else {
+                "js"
+            }
+        ))
+    }
+}
+
+/// Cache configuration of `path`, from the sitemap or the dynamic urls of the package.
+pub fn config_for(
+    package: &fastn_core::Package,
+    path: &str,
+) -> fastn_core::Result<Option<CacheConfig>> {
+    if let Some(extra_data) = package
+        .sitemap
+        .as_ref()
+        .and_then(|sitemap| sitemap.extra_data(path))
+    {
+        return CacheConfig::from_extra_data(extra_data);
+    }
+
+    if let Some(dynamic_urls) = package.dynamic_urls.as_ref() {
+        let (document, _, extra_data) = dynamic_urls.resolve_document(path)?;
+        if document.is_some() {
+            return CacheConfig::from_extra_data(&extra_data);
+        }
+    }
+
+    Ok(None)
+}
+
+pub fn get(key: &str) -> Lookup {
+    let mut cache = CACHE.write();
+    let entry = match cache.get_mut(key) {
+        Some(entry) => entry,
+        None => return Lookup::Miss,
+    };
+
+    let age = entry.stored_at.elapsed();
+    if age < entry.config.ttl {
+        return Lookup::Fresh(entry.to_response("HIT"));
+    }
+
+    match entry.config.stale_while_revalidate {
+        Some(stale) if age < entry.config.ttl + stale => {
+            if entry.revalidating {
+                // some other request is already rendering this page
+                return Lookup::Fresh(entry.to_response("STALE"));
+            }
+            entry.revalidating = true;
+            Lookup::Stale(entry.to_response("STALE"))
+        }
+        _ => {
+            cache.remove(key);
+            Lookup::Miss
+        }
+    }
+}
+
+/// Caches `response` if it is a successful one, and returns the response to send to the client.
+/// Responses setting cookies are user specific, and are never cached.
+pub async fn store(
+    key: &str,
+    path: &str,
+    config: &CacheConfig,
+    response: fastn_core::http::Response,
+) -> fastn_core::http::Response {
+    if response.status() != actix_web::http::StatusCode::OK
+        || response
+            .headers()
+            .contains_key(actix_web::http::header::SET_COOKIE)
+    {
+        if let Some(entry) = CACHE.write().get_mut(key) {
+            entry.revalidating = false;
+        }
+        return response;
+    }
+
+    let status = response.status();
+    let headers = response
+        .headers()
+        .iter()
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect();
+    let body = match actix_web::body::to_bytes(response.into_body()).await {
+        Ok(body) => body,
+        Err(e) => {
+            return fastn_core::server_error!("fastn-Error: path: {}, {:?}", path, e);
+        }
+    };
+
+    let entry = CachedResponse {
+        path: normalise(path),
+        status,
+        headers,
+        body,
+        stored_at: std::time::Instant::now(),
+        config: config.clone(),
+        revalidating: false,
+    };
+    let response = entry.to_response("MISS");
+
+    let mut cache = CACHE.write();
+    if cache.len() >= MAX_ENTRIES {
+        cache.retain(|_, v| v.is_usable());
+    }
+    if cache.len() >= MAX_ENTRIES {
+        if let Some(oldest) = cache
+            .iter()
+            .min_by_key(|(_, v)| v.stored_at)
+            .map(|(k, _)| k.to_string())
+        {
+            cache.remove(&oldest);
+        }
+    }
+    cache.insert(key.to_string(), entry);
+
+    response
+}
+
+/// Removes every cached response of `path`, for all query parameters, cookies and groups.
+pub fn purge(path: &str) {
+    let path = normalise(path);
+    CACHE.write().retain(|_, v| v.path != path);
+}
+
+pub fn purge_all() {
+    CACHE.write().clear();
+}
+
+impl CachedResponse {
+    fn is_usable(&self) -> bool {
+        self.stored_at.elapsed()
+            < self.config.ttl + self.config.stale_while_revalidate.unwrap_or_default()
+    }
+
+    fn to_response(&self, status: &'static str) -> fastn_core::http::Response {
+        let mut response = actix_web::HttpResponse::build(self.status);
+        for header in self.headers.iter() {
+            response.insert_header(header.clone());
+        }
+        response.insert_header(("X-Fastn-Cache", status));
+        response.body(self.body.clone())
+    }
+}
+
+fn normalise(path: &str) -> String {
+    format!("/{}/", path.trim_matches('/')).replace("//", "/")
+}
+
+fn split_list(v: &str) -> Vec<String> {
+    v.split(',')
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .collect()
+}
+
+/// `30s`, `5m`, `2h`, `1d`, a number without unit is seconds.
+pub fn parse_duration(v: &str) -> fastn_core::Result<std::time::Duration> {
+    let v = v.trim();
+    let (number, multiplier) = match v.char_indices().last() {
+        Some((i, 's')) => (&v[..i], 1),
+        Some((i, 'm')) => (&v[..i], 60),
+        Some((i, 'h')) => (&v[..i], 60 * 60),
+        Some((i, 'd')) => (&v[..i], 24 * 60 * 60),
+        _ => (v, 1),
+    };
+    let number: u64 = number
+        .trim()
+        .parse()
+        .map_err(|_| fastn_core::Error::UsageError {
+            message: format!("invalid duration `{v}`, expected something like `30s`, `5m`, `1h`"),
+        })?;
+    Ok(std::time::Duration::from_secs(number * multiplier))
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn parse_duration() {
+        assert_eq!(
+            super::parse_duration("5m").unwrap(),
+            std::time::Duration::from_secs(300)
+        );
+        assert_eq!(
+            super::parse_duration("90").unwrap(),
+            std::time::Duration::from_secs(90)
+        );
+        assert_eq!(
+            super::parse_duration("1d").unwrap(),
+            std::time::Duration::from_secs(86400)
+        );
+        assert!(super::parse_duration("five minutes").is_err());
+    }
+
+    #[test]
+    fn from_extra_data() {
+        let extra_data = std::collections::BTreeMap::from([
+            ("cache".to_string(), "5m".to_string()),
+            ("cache-query".to_string(), "page, sort".to_string()),
+            ("stale-while-revalidate".to_string(), "30s".to_string()),
+        ]);
+        assert_eq!(
+            super::CacheConfig::from_extra_data(&extra_data).unwrap(),
+            Some(super::CacheConfig {
+                ttl: std::time::Duration::from_secs(300),
+                stale_while_revalidate: Some(std::time::Duration::from_secs(30)),
+                query: Some(vec!["page".to_string(), "sort".to_string()]),
+                cookies: vec![],
+                user_group: false,
+            })
+        );
+    }
+    async fn key(
+        config: &fastn_core::Config,
+        cache_config: &super::CacheConfig,
+        req: &fastn_core::http::Request,
+        only_js: bool,
+    ) -> String {
+        let req_config = fastn_core::RequestConfig::new(config, req, "", "/");
+        cache_config
+            .key(&req_config, "/shop/", only_js)
+            .await
+            .unwrap()
+    }
+
+    /// Pages, the JS of a page and the payload of the client side router are cached separately,
+    /// as they are served from the same url
+    #[tokio::test]
+    async fn key_by_response_kind() {
+        let package = fastn_core::test_package::TestPackage::new("cache-test", "", &[]);
+        let config = package.config().await;
+
+        let cache_config = super::CacheConfig {
+            ttl: std::time::Duration::from_secs(300),
+            stale_while_revalidate: None,
+            query: None,
+            cookies: vec![],
+            user_group: false,
+        };
+        let page = actix_web::test::TestRequest::with_uri("/shop/?page=2").to_http_request();
+        let spa = actix_web::test::TestRequest::with_uri("/shop/?page=2")
+            .insert_header((fastn_core::http::SPA_HEADER, "true"))
+            .to_http_request();
+        let page = fastn_core::http::Request::from_actix(page, Default::default());
+        let spa = fastn_core::http::Request::from_actix(spa, Default::default());
+
+        let html = key(&config, &cache_config, &page, false).await;
+        assert_eq!(html, "/shop/?page=2|fastn-lang=||html");
+        assert_eq!(key(&config, &cache_config, &spa, false).await, html);
+        assert_eq!(
+            key(&config, &cache_config, &page, true).await,
+            "/shop/?page=2|fastn-lang=||js"
+        );
+        assert_eq!(
+            key(&config, &cache_config, &spa, true).await,
+            "/shop/?page=2|fastn-lang=||spa"
+        );
+    }
+}
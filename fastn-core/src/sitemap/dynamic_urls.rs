@@ -5,6 +5,12 @@ pub(crate) type ResolveDocOutput = (
     std::collections::BTreeMap<String, String>,
 );
 
+// path-parameters and attributes
+pub(crate) type ResolveKeyOutput = (
+    Vec<(String, ftd::Value)>,
+    std::collections::BTreeMap<String, String>,
+);
+
 #[derive(Debug, serde::Deserialize, Clone)]
 pub struct DynamicUrlsTemp {
     #[serde(rename = "dynamic-urls-body")]
@@ -107,6 +113,68 @@ impl DynamicUrls {
         false
     }
 
+    /// Path parameters and attributes of the first entry matching `path` which has the `key`
+    /// attribute. Unlike `resolve_document` the entry does not need a document, this is used for
+    /// routes served by a WASM backend (`backend` attribute).
+    #[tracing::instrument(name = "dynamic-urls-resolve-with-key", skip(self))]
+    pub fn resolve_with_key(
+        &self,
+        path: &str,
+        key: &str,
+    ) -> fastn_core::Result<Option<ResolveKeyOutput>> {
+        fn check(
+            path_parameters: &[fastn_core::sitemap::PathParams],
+            extra_data: &std::collections::BTreeMap<String, String>,
+            path: &str,
+            key: &str,
+        ) -> fastn_core::Result<Option<ResolveKeyOutput>> {
+            if path_parameters.is_empty() || !extra_data.contains_key(key) {
+                return Ok(None);
+            }
+            let (matched, params) = fastn_core::sitemap::utils::url_match(path, path_parameters)?;
+            Ok(matched.then(|| (params, extra_data.clone())))
+        }
+
+        fn resolve_in_toc(
+            toc: &fastn_core::sitemap::toc::TocItem,
+            path: &str,
+            key: &str,
+        ) -> fastn_core::Result<Option<ResolveKeyOutput>> {
+            if let Some(found) = check(&toc.path_parameters, &toc.extra_data, path, key)? {
+                return Ok(Some(found));
+            }
+            for child in toc.children.iter() {
+                if let Some(found) = resolve_in_toc(child, path, key)? {
+                    return Ok(Some(found));
+                }
+            }
+            Ok(None)
+        }
+
+        for section in self.sections.iter() {
+            if let Some(found) = check(&section.path_parameters, &section.extra_data, path, key)? {
+                return Ok(Some(found));
+            }
+            for sub_section in section.subsections.iter() {
+                if let Some(found) = check(
+                    &sub_section.path_parameters,
+                    &sub_section.extra_data,
+                    path,
+                    key,
+                )? {
+                    return Ok(Some(found));
+                }
+                for toc in sub_section.toc.iter() {
+                    if let Some(found) = resolve_in_toc(toc, path, key)? {
+                        return Ok(Some(found));
+                    }
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
     #[tracing::instrument(name = "dynamic-urls-resolve-document")]
     pub fn resolve_document<'a>(&'a self, path: &str) -> fastn_core::Result<ResolveDocOutput> {
         fn resolve_in_toc(
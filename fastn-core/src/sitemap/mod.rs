@@ -1526,6 +1526,54 @@ impl Sitemap {
         None
     }
 
+    /// Key value data of the section, subsection or toc item matching `path`, unlike
+    /// `resolve_document` this does not require the item to have a `document`.
+    pub fn extra_data(&self, path: &str) -> Option<&std::collections::BTreeMap<String, String>> {
+        fn find_in_toc<'a>(
+            toc: &'a toc::TocItem,
+            path: &str,
+        ) -> Option<&'a std::collections::BTreeMap<String, String>> {
+            if fastn_core::utils::ids_matches(toc.id.as_str(), path) {
+                return Some(&toc.extra_data);
+            }
+            toc.children
+                .iter()
+                .find_map(|child| find_in_toc(child, path))
+        }
+
+        fn find_in_sub_section<'a>(
+            sub_section: &'a section::Subsection,
+            path: &str,
+        ) -> Option<&'a std::collections::BTreeMap<String, String>> {
+            if let Some(id) = sub_section.id.as_ref() {
+                if fastn_core::utils::ids_matches(id.as_str(), path) {
+                    return Some(&sub_section.extra_data);
+                }
+            }
+            sub_section
+                .toc
+                .iter()
+                .find_map(|toc| find_in_toc(toc, path))
+        }
+
+        fn find_in_section<'a>(
+            section: &'a section::Section,
+            path: &str,
+        ) -> Option<&'a std::collections::BTreeMap<String, String>> {
+            if fastn_core::utils::ids_matches(section.id.as_str(), path) {
+                return Some(&section.extra_data);
+            }
+            section
+                .subsections
+                .iter()
+                .find_map(|sub_section| find_in_sub_section(sub_section, path))
+        }
+
+        self.sections
+            .iter()
+            .find_map(|section| find_in_section(section, path))
+    }
+
     pub fn has_path_params(&self) -> bool {
         section::Section::contains_named_params(&self.sections)
     }
@@ -0,0 +1,50 @@
+/// A package written to a directory of its own under the temp directory, for tests that need a
+/// `fastn_core::Config`. The directory is removed when the package is dropped, so a failing
+/// assertion does not leave it behind.
+pub(crate) struct TestPackage {
+    pub(crate) root: camino::Utf8PathBuf,
+}
+
+impl TestPackage {
+    /// `fastn_ftd` is what follows `-- fastn.package: <name>` in `FASTN.ftd`, `files` are the
+    /// other files of the package, by path relative to the package root
+    pub(crate) fn new(name: &str, fastn_ftd: &str, files: &[(&str, &str)]) -> TestPackage {
+        let root = camino::Utf8PathBuf::from_path_buf(std::env::temp_dir())
+            .unwrap()
+            .join(format!("fastn-{}-{}", name, std::process::id()));
+        if root.exists() {
+            std::fs::remove_dir_all(&root).unwrap();
+        }
+        let package = TestPackage { root };
+        package.write(
+            "FASTN.ftd",
+            format!(
+                "-- import: fastn\n\n-- fastn.package: {}\n{}",
+                name, fastn_ftd
+            )
+            .as_str(),
+        );
+        for (path, content) in files {
+            package.write(path, content);
+        }
+        package
+    }
+
+    pub(crate) fn write(&self, path: &str, content: &str) {
+        let path = self.root.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    pub(crate) async fn config(&self) -> fastn_core::Config {
+        fastn_core::Config::read(Some(self.root.to_string()), false)
+            .await
+            .unwrap()
+    }
+}
+
+impl Drop for TestPackage {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.root);
+    }
+}
@@ -0,0 +1,268 @@
+// Load balancing across the replicas (`upstreams`) of a proxied endpoint, see
+// `fastn_core::package::proxy::ProxySettings`.
+//
+// There is one pool per endpoint. Every proxied request takes a `Lease` on an upstream, which is
+// held till the response body is sent, so `least-connections` counts streaming responses too.
+// If a health check is configured, a background task requests it on every upstream, failing
+// upstreams get no requests till the check succeeds again. An upstream which can not be reached
+// while proxying is marked unhealthy right away.
+
+static POOLS: once_cell::sync::Lazy<
+    antidote::RwLock<std::collections::HashMap<String, std::sync::Arc<Pool>>>,
+> = once_cell::sync::Lazy::new(|| antidote::RwLock::new(Default::default()));
+
+#[derive(Debug)]
+pub(crate) struct Pool {
+    upstreams: Vec<Upstream>,
+    load_balancing: fastn_core::package::proxy::LoadBalancing,
+    next: std::sync::atomic::AtomicUsize,
+}
+
+#[derive(Debug)]
+struct Upstream {
+    url: String,
+    /// Sent in the sticky cookie, so the upstream address is not exposed to the browser
+    id: String,
+    healthy: std::sync::atomic::AtomicBool,
+    active: std::sync::atomic::AtomicUsize,
+}
+
+pub(crate) struct Lease {
+    pool: std::sync::Arc<Pool>,
+    index: usize,
+}
+
+/// Pool of the endpoint, `None` if the endpoint does not list any upstreams.
+pub(crate) fn pool(
+    settings: &fastn_core::package::proxy::ProxySettings,
+) -> Option<std::sync::Arc<Pool>> {
+    if settings.upstreams.is_empty() {
+        return None;
+    }
+
+    if let Some(pool) = POOLS.read().get(settings.endpoint.as_str()) {
+        if pool.matches(settings) {
+            return Some(pool.clone());
+        }
+    }
+
+    // First request to the endpoint, or FASTN.ftd has changed. The health check task of the old
+    // pool stops once the requests using it are done.
+    let pool = std::sync::Arc::new(Pool {
+        upstreams: settings
+            .upstreams
+            .iter()
+            .map(|url| Upstream {
+                url: url.to_string(),
+                id: fastn_core::utils::generate_hash(url)[..16].to_lowercase(),
+                healthy: std::sync::atomic::AtomicBool::new(true),
+                active: std::sync::atomic::AtomicUsize::new(0),
+            })
+            .collect(),
+        load_balancing: settings.load_balancing,
+        next: std::sync::atomic::AtomicUsize::new(0),
+    });
+    if let Some(ref path) = settings.health_check {
+        start_health_checks(&pool, path.as_str(), settings);
+    }
+    POOLS
+        .write()
+        .insert(settings.endpoint.to_string(), pool.clone());
+    Some(pool)
+}
+
+impl Pool {
+    fn matches(&self, settings: &fastn_core::package::proxy::ProxySettings) -> bool {
+        self.load_balancing == settings.load_balancing
+            && self
+                .upstreams
+                .iter()
+                .map(|u| u.url.as_str())
+                .eq(settings.upstreams.iter().map(String::as_str))
+    }
+
+    /// Picks a healthy upstream, the one `sticky` cookie points to if it is healthy.
+    pub(crate) fn lease(self: &std::sync::Arc<Pool>, sticky: Option<&str>) -> Option<Lease> {
+        use std::sync::atomic::Ordering;
+
+        let healthy = |i: &usize| self.upstreams[*i].healthy.load(Ordering::Relaxed);
+        let count = self.upstreams.len();
+
+        let index = sticky
+            .and_then(|id| self.upstreams.iter().position(|u| u.id == id))
+            .filter(healthy)
+            .or_else(|| match self.load_balancing {
+                fastn_core::package::proxy::LoadBalancing::RoundRobin => {
+                    let start = self.next.fetch_add(1, Ordering::Relaxed);
+                    (0..count).map(|i| (start + i) % count).find(healthy)
+                }
+                fastn_core::package::proxy::LoadBalancing::LeastConnections => (0..count)
+                    .filter(healthy)
+                    .min_by_key(|i| self.upstreams[*i].active.load(Ordering::Relaxed)),
+            })?;
+
+        self.upstreams[index].active.fetch_add(1, Ordering::Relaxed);
+        Some(Lease {
+            pool: self.clone(),
+            index,
+        })
+    }
+}
+
+impl Lease {
+    fn upstream(&self) -> &Upstream {
+        &self.pool.upstreams[self.index]
+    }
+
+    pub(crate) fn sticky_id(&self) -> &str {
+        self.upstream().id.as_str()
+    }
+
+    /// `url` of the endpoint, with the endpoint replaced by the leased upstream.
+    pub(crate) fn url(&self, endpoint: &str, url: &url::Url) -> fastn_core::Result<url::Url> {
+        upstream_url(endpoint, self.upstream().url.as_str(), url)
+    }
+
+    /// The upstream could not be reached, it gets no more requests till its health check
+    /// succeeds. Without a health check there is nothing to bring it back, so it is kept.
+    pub(crate) fn failed(&self, has_health_check: bool) {
+        if has_health_check {
+            tracing::warn!("upstream: {} marked unhealthy", self.upstream().url);
+            self.upstream()
+                .healthy
+                .store(false, std::sync::atomic::Ordering::Relaxed);
+        }
+    }
+}
+
+impl Drop for Lease {
+    fn drop(&mut self) {
+        self.upstream()
+            .active
+            .fetch_sub(1, std::sync::atomic::Ordering::Relaxed);
+    }
+}
+
+fn upstream_url(endpoint: &str, upstream: &str, url: &url::Url) -> fastn_core::Result<url::Url> {
+    let endpoint_path = url::Url::parse(endpoint)?
+        .path()
+        .trim_end_matches('/')
+        .to_string();
+    let rest = url
+        .path()
+        .strip_prefix(endpoint_path.as_str())
+        .unwrap_or(url.path());
+    let mut upstream_url = url::Url::parse(
+        format!(
+            "{}/{}",
+            upstream.trim_end_matches('/'),
+            rest.trim_start_matches('/')
+        )
+        .as_str(),
+    )?;
+    upstream_url.set_query(url.query());
+    Ok(upstream_url)
+}
+
+fn start_health_checks(
+    pool: &std::sync::Arc<Pool>,
+    path: &str,
+    settings: &fastn_core::package::proxy::ProxySettings,
+) {
+    let pool = std::sync::Arc::downgrade(pool);
+    let path = path.to_string();
+    let timeout = settings.timeout;
+    let client = fastn_core::proxy::client(settings.connect_timeout);
+    let period = settings
+        .health_check_interval
+        .max(std::time::Duration::from_secs(1));
+
+    actix_web::rt::spawn(async move {
+        let mut interval = tokio::time::interval(period);
+        loop {
+            interval.tick().await;
+            let pool = match pool.upgrade() {
+                Some(pool) => pool,
+                None => break,
+            };
+            for upstream in pool.upstreams.iter() {
+                let url = format!(
+                    "{}/{}",
+                    upstream.url.trim_end_matches('/'),
+                    path.trim_start_matches('/')
+                );
+                let healthy = matches!(
+                    tokio::time::timeout(timeout, client.get(url.as_str()).send()).await,
+                    Ok(Ok(response)) if response.status().is_success()
+                );
+                let was_healthy = upstream
+                    .healthy
+                    .swap(healthy, std::sync::atomic::Ordering::Relaxed);
+                if was_healthy != healthy {
+                    tracing::warn!(
+                        "upstream: {} is {}",
+                        upstream.url,
+                        if healthy { "healthy" } else { "unhealthy" }
+                    );
+                }
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    fn settings(
+        load_balancing: fastn_core::package::proxy::LoadBalancing,
+    ) -> fastn_core::package::proxy::ProxySettings {
+        fastn_core::package::proxy::ProxySettings {
+            endpoint: format!("http://{:?}", load_balancing).to_lowercase(),
+            upstreams: vec!["http://a".to_string(), "http://b".to_string()],
+            load_balancing,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn round_robin() {
+        let pool = super::pool(&settings(
+            fastn_core::package::proxy::LoadBalancing::RoundRobin,
+        ))
+        .unwrap();
+        let a = pool.lease(None).unwrap();
+        let b = pool.lease(None).unwrap();
+        assert_ne!(a.sticky_id(), b.sticky_id());
+
+        // the sticky upstream is used while it is healthy
+        let sticky = pool.lease(Some(a.sticky_id())).unwrap();
+        assert_eq!(sticky.sticky_id(), a.sticky_id());
+        a.failed(true);
+        let other = pool.lease(Some(a.sticky_id())).unwrap();
+        assert_eq!(other.sticky_id(), b.sticky_id());
+    }
+
+    #[test]
+    fn least_connections() {
+        let pool = super::pool(&settings(
+            fastn_core::package::proxy::LoadBalancing::LeastConnections,
+        ))
+        .unwrap();
+        let a = pool.lease(None).unwrap();
+        let b = pool.lease(None).unwrap();
+        assert_ne!(a.sticky_id(), b.sticky_id());
+        drop(b);
+        let c = pool.lease(None).unwrap();
+        assert_ne!(a.sticky_id(), c.sticky_id());
+    }
+
+    #[test]
+    fn upstream_url() {
+        let url = url::Url::parse("http://todo-backend/api/todos/?page=2").unwrap();
+        assert_eq!(
+            super::upstream_url("http://todo-backend/api", "http://10.0.0.1:8000/", &url)
+                .unwrap()
+                .as_str(),
+            "http://10.0.0.1:8000/todos/?page=2"
+        );
+    }
+}
@@ -752,8 +752,10 @@ pub fn get_fastn_package_data(package: &fastn_core::Package) -> String {
     format!(
         indoc::indoc! {"
         let __fastn_package_name__ = \"{package_name}\";
+        let __fastn_spa__ = {spa};
     "},
-        package_name = package.name
+        package_name = package.name,
+        spa = package.spa,
     )
 }
 
@@ -989,16 +991,16 @@ pub fn generate_hash(content: impl AsRef<[u8]>) -> String {
 }
 
 static CSS_HASH: once_cell::sync::Lazy<String> =
-    once_cell::sync::Lazy::new(|| format!("default-{}.css", generate_hash(ftd::css())));
+    once_cell::sync::Lazy::new(|| fastn_core::fingerprint::name("default.css", ftd::css()));
 
 pub fn hashed_default_css_name() -> &'static str {
     &CSS_HASH
 }
 
 static JS_HASH: once_cell::sync::Lazy<String> = once_cell::sync::Lazy::new(|| {
-    format!(
-        "default-{}.js",
-        generate_hash(format!("{}\n\n{}", ftd::build_js(), fastn_core::fastn_2022_js()).as_str())
+    fastn_core::fingerprint::name(
+        "default.js",
+        format!("{}\n\n{}", ftd::build_js(), fastn_core::fastn_2022_js()),
     )
 });
 
@@ -1010,30 +1012,26 @@ static FTD_JS_HASH: once_cell::sync::OnceCell<String> = once_cell::sync::OnceCel
 
 pub fn hashed_default_ftd_js(package_name: &str) -> &'static str {
     FTD_JS_HASH.get_or_init(|| {
-        format!(
-            "default-{}.js",
-            generate_hash(ftd::js::all_js_without_test(package_name).as_str())
-        )
+        fastn_core::fingerprint::name("fastn-js.js", ftd::js::all_js_without_test(package_name))
     })
 }
 
 static MARKDOWN_HASH: once_cell::sync::Lazy<String> =
-    once_cell::sync::Lazy::new(|| format!("markdown-{}.js", generate_hash(ftd::markdown_js()),));
+    once_cell::sync::Lazy::new(|| fastn_core::fingerprint::name("markdown.js", ftd::markdown_js()));
 
 pub fn hashed_markdown_js() -> &'static str {
     &MARKDOWN_HASH
 }
 
 static PRISM_JS_HASH: once_cell::sync::Lazy<String> =
-    once_cell::sync::Lazy::new(|| format!("prism-{}.js", generate_hash(ftd::prism_js().as_str()),));
+    once_cell::sync::Lazy::new(|| fastn_core::fingerprint::name("prism.js", ftd::prism_js()));
 
 pub fn hashed_prism_js() -> &'static str {
     &PRISM_JS_HASH
 }
 
-static PRISM_CSS_HASH: once_cell::sync::Lazy<String> = once_cell::sync::Lazy::new(|| {
-    format!("prism-{}.css", generate_hash(ftd::prism_css().as_str()),)
-});
+static PRISM_CSS_HASH: once_cell::sync::Lazy<String> =
+    once_cell::sync::Lazy::new(|| fastn_core::fingerprint::name("prism.css", ftd::prism_css()));
 
 pub fn hashed_prism_css() -> &'static str {
     &PRISM_CSS_HASH
@@ -1043,7 +1041,10 @@ static CODE_THEME_HASH: once_cell::sync::Lazy<ftd::Map<String>> =
     once_cell::sync::Lazy::new(|| {
         ftd::theme_css()
             .into_iter()
-            .map(|(k, v)| (k, format!("code-theme-{}.css", generate_hash(v.as_str()))))
+            .map(|(k, v)| {
+                let name = fastn_core::fingerprint::name("code-theme.css", v.as_str());
+                (k, name)
+            })
             .collect()
     });
 
@@ -1,58 +1,66 @@
-use std::str::FromStr;
-
-#[derive(Default)]
-pub struct HostExports {}
-
-impl fastn_utils::backend_host_export::host::Host for HostExports {
-    fn http(
-        &mut self,
-        request: fastn_utils::backend_host_export::host::Httprequest<'_>,
-    ) -> fastn_utils::backend_host_export::host::Httpresponse {
-        let url = request.path.to_string();
-        let request_method = request.method.to_string();
-        let request_body = request.payload.to_string();
-        let mut headers = reqwest::header::HeaderMap::new();
-        request
-            .headers
-            .clone()
-            .into_iter()
-            .for_each(|(header_key, header_val)| {
-                headers.insert(
-                    reqwest::header::HeaderName::from_str(header_key).unwrap(),
-                    reqwest::header::HeaderValue::from_str(header_val).unwrap(),
-                );
-            });
-        let resp = std::thread::spawn(move || {
-            let request_client = reqwest::blocking::Client::new();
-            match request_method.as_str() {
-                "GET" => request_client.get(url).headers(headers),
-                "POST" => request_client.post(url).headers(headers).body(request_body),
-                "PATCH" => request_client
-                    .patch(url)
-                    .headers(headers)
-                    .body(request_body),
-                _ => panic!("METHOD not allowed"),
-            }
-            .send()
-            .unwrap()
-            .text()
-            .unwrap()
-        })
-        .join()
-        .unwrap();
-        fastn_utils::backend_host_export::host::Httpresponse { data: resp }
+// Routes served by a WASM module shipped inside the package (`backend.wasm` by default).
+//
+// A route is any sitemap or dynamic-urls entry with a `backend` attribute, naming the function
+// exported by the module. The package must have `backend: true`. The routes of an app come from
+// the sitemap of the app's package, and its module from the package's directory in `.packages`.
+//
+// ```ftd
+// -- fastn.dynamic-urls:
+//
+// # Todo: /api/todo/<integer:id>/
+//   backend: get_todo
+//   backend-module: todo.wasm
+//   backend-fuel: 10000000
+//   backend-memory: 32MB
+// ```
+//
+// The module must export `memory`, `alloc(len: i32) -> i32` and the function, which is called
+// as `function(request_ptr: i32, request_len: i32, body_ptr: i32, body_len: i32)`. The request
+// is JSON: `{"method", "path", "query", "headers", "params"}`, `params` being the path
+// parameters of dynamic urls. The body is passed as is. The function builds the response by
+// calling the host functions `fastn.set_status(status: i32)`,
+// `fastn.add_header(name_ptr, name_len, value_ptr, value_len)` and
+// `fastn.write_body(ptr, len)`, the status is 200 if not set.
+//
+// Modules are compiled once and kept till the file changes. Every request gets a fresh instance
+// with its own fuel (roughly the number of instructions it may run) and memory limit.
+
+const DEFAULT_MODULE: &str = "backend.wasm";
+const DEFAULT_FUEL: u64 = 100_000_000;
+const DEFAULT_MEMORY: usize = 64 * 1024 * 1024;
+
+static ENGINE: once_cell::sync::Lazy<wasmtime::Engine> = once_cell::sync::Lazy::new(|| {
+    let mut wasm_config = wasmtime::Config::new();
+    wasm_config.consume_fuel(true);
+    wasm_config.wasm_backtrace_details(wasmtime::WasmBacktraceDetails::Disable);
+    if let Err(e) = wasm_config.cache_config_load_default() {
+        tracing::warn!("wasm: compilation cache disabled: {}", e);
     }
-}
+    wasmtime::Engine::new(&wasm_config).expect("wasm: failed to create engine")
+});
 
-pub struct Context<I, E> {
-    pub imports: I,
-    pub exports: E,
-}
+static MODULES: once_cell::sync::Lazy<
+    antidote::Mutex<
+        std::collections::HashMap<
+            camino::Utf8PathBuf,
+            (std::time::SystemTime, wasmtime::InstancePre<State>),
+        >,
+    >,
+> = once_cell::sync::Lazy::new(|| antidote::Mutex::new(Default::default()));
+
+static DEPENDENCIES: once_cell::sync::Lazy<
+    antidote::Mutex<
+        std::collections::HashMap<
+            camino::Utf8PathBuf,
+            (std::time::SystemTime, std::sync::Arc<fastn_core::Package>),
+        >,
+    >,
+> = once_cell::sync::Lazy::new(|| antidote::Mutex::new(Default::default()));
 
 #[derive(thiserror::Error, Debug)]
 pub enum WASMError {
     #[error("Wasmtime Error: {}", _0)]
-    WasmTime(#[from] wit_bindgen_host_wasmtime_rust::anyhow::Error),
+    WasmTime(#[from] wasmtime::Error),
 
     #[error("JSON Parsing Error: {}", _0)]
     SerdeJson(#[from] serde_json::Error),
@@ -63,104 +71,375 @@ pub enum WASMError {
 
 pub type WasmRunnerResult<T> = std::result::Result<T, WASMError>;
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackendRoute {
+    /// Path of the module, in the directory of the package declaring the route
+    pub module: camino::Utf8PathBuf,
+    pub function: String,
+    pub fuel: u64,
+    pub memory: usize,
+    pub params: serde_json::Map<String, serde_json::Value>,
+}
+
+struct State {
+    limits: wasmtime::StoreLimits,
+    status: Option<u16>,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl BackendRoute {
+    fn from_extra_data(
+        extra_data: &std::collections::BTreeMap<String, String>,
+        params: &[(String, ftd::Value)],
+        package_root: &camino::Utf8Path,
+    ) -> fastn_core::Result<Option<BackendRoute>> {
+        let function = match extra_data.get("backend") {
+            Some(v) => v.trim().to_string(),
+            None => return Ok(None),
+        };
+
+        Ok(Some(BackendRoute {
+            module: package_root.join(
+                extra_data
+                    .get("backend-module")
+                    .map(|v| v.trim())
+                    .unwrap_or(DEFAULT_MODULE),
+            ),
+            function,
+            fuel: extra_data
+                .get("backend-fuel")
+                .map(|v| v.trim().parse::<u64>())
+                .transpose()?
+                .unwrap_or(DEFAULT_FUEL),
+            memory: extra_data
+                .get("backend-memory")
+                .map(|v| parse_size(v))
+                .transpose()?
+                .unwrap_or(DEFAULT_MEMORY),
+            params: params
+                .iter()
+                .map(|(name, value)| (name.to_string(), param_value(value)))
+                .collect(),
+        }))
+    }
+}
+
+/// Backend route of `path`, from the sitemap or the dynamic urls of the package declaring it: the
+/// package being served, or the app mounted at the start of `path`.
+pub async fn route_for(
+    config: &fastn_core::Config,
+    path: &str,
+) -> fastn_core::Result<Option<BackendRoute>> {
+    let (package, path) = match config.get_mountpoint_sanitized_path(&config.package, path) {
+        Some((_, package, remaining_path, _)) => (package, remaining_path),
+        None => (&config.package, path.to_string()),
+    };
+    if !package.backend {
+        return Ok(None);
+    }
+    let dependency;
+    let package = if package.name != config.package.name {
+        dependency = with_sitemap(config, package).await?;
+        dependency.as_ref()
+    } else {
+        package
+    };
+    let package_root = config.get_root_for_package(package);
+
+    if let Some(extra_data) = package
+        .sitemap
+        .as_ref()
+        .and_then(|sitemap| sitemap.extra_data(path.as_str()))
+    {
+        if let Some(route) = BackendRoute::from_extra_data(extra_data, &[], &package_root)? {
+            return Ok(Some(route));
+        }
+    }
+
+    if let Some(dynamic_urls) = package.dynamic_urls.as_ref() {
+        if let Some((params, extra_data)) =
+            dynamic_urls.resolve_with_key(path.as_str(), "backend")?
+        {
+            return BackendRoute::from_extra_data(&extra_data, params.as_slice(), &package_root);
+        }
+    }
+
+    Ok(None)
+}
+
+/// `package`, a dependency, with its sitemap and dynamic urls, read from its `FASTN.ftd` again if
+/// the file has changed since.
+async fn with_sitemap(
+    config: &fastn_core::Config,
+    package: &fastn_core::Package,
+) -> fastn_core::Result<std::sync::Arc<fastn_core::Package>> {
+    let fastn_path = config.packages_root.join(&package.name).join("FASTN.ftd");
+    let modified = |path: &camino::Utf8Path| std::fs::metadata(path).and_then(|m| m.modified());
+    if let Ok(modified) = modified(&fastn_path) {
+        if let Some((read_at, package)) = DEPENDENCIES.lock().get(&fastn_path) {
+            if *read_at == modified {
+                return Ok(package.clone());
+            }
+        }
+    }
+
+    // `update_sitemap` downloads the package if it is not in `.packages` yet
+    let package = std::sync::Arc::new(config.update_sitemap(package).await?);
+    if let Ok(modified) = modified(&fastn_path) {
+        DEPENDENCIES
+            .lock()
+            .insert(fastn_path, (modified, package.clone()));
+    }
+    Ok(package)
+}
+
+fn param_value(value: &ftd::Value) -> serde_json::Value {
+    match value {
+        ftd::Value::String { text, .. } => text.as_str().into(),
+        ftd::Value::Integer { value } => (*value).into(),
+        ftd::Value::Decimal { value } => (*value).into(),
+        ftd::Value::Boolean { value } => (*value).into(),
+        _ => serde_json::Value::Null,
+    }
+}
+
+/// `512KB`, `64MB`, `1GB`, a number without unit is bytes.
+fn parse_size(v: &str) -> fastn_core::Result<usize> {
+    let v = v.trim();
+    let upper = v.to_uppercase();
+    let (number, multiplier) = if let Some(n) = upper.strip_suffix("KB") {
+        (n, 1024)
+    } else if let Some(n) = upper.strip_suffix("MB") {
+        (n, 1024 * 1024)
+    } else if let Some(n) = upper.strip_suffix("GB") {
+        (n, 1024 * 1024 * 1024)
+    } else {
+        (upper.as_str(), 1)
+    };
+    let number: usize = number
+        .trim()
+        .parse()
+        .map_err(|_| fastn_core::Error::UsageError {
+            message: format!("invalid size `{v}`, expected something like `512KB`, `64MB`"),
+        })?;
+    Ok(number * multiplier)
+}
+
+/// Compiled module at `path`, compiled again if the file has changed since.
+fn instance_pre(path: &camino::Utf8Path) -> WasmRunnerResult<wasmtime::InstancePre<State>> {
+    let modified = std::fs::metadata(path)
+        .and_then(|m| m.modified())
+        .map_err(wasmtime::Error::from)?;
+    if let Some((compiled_at, instance_pre)) = MODULES.lock().get(path) {
+        if *compiled_at == modified {
+            return Ok(instance_pre.clone());
+        }
+    }
+
+    let module =
+        fastn_core::time("WASM Compile").it(wasmtime::Module::from_file(&ENGINE, path.as_str())?);
+    let instance_pre = linker()?.instantiate_pre(&module)?;
+    MODULES
+        .lock()
+        .insert(path.to_path_buf(), (modified, instance_pre.clone()));
+    Ok(instance_pre)
+}
+
+fn linker() -> wasmtime::Result<wasmtime::Linker<State>> {
+    let mut linker = wasmtime::Linker::new(&ENGINE);
+    linker.func_wrap(
+        "fastn",
+        "set_status",
+        |mut caller: wasmtime::Caller<'_, State>, status: i32| -> wasmtime::Result<()> {
+            caller.data_mut().status = Some(u16::try_from(status)?);
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "fastn",
+        "add_header",
+        |mut caller: wasmtime::Caller<'_, State>,
+         name_ptr: i32,
+         name_len: i32,
+         value_ptr: i32,
+         value_len: i32|
+         -> wasmtime::Result<()> {
+            let name = String::from_utf8(read(&mut caller, name_ptr, name_len)?)?;
+            let value = String::from_utf8(read(&mut caller, value_ptr, value_len)?)?;
+            caller.data_mut().headers.push((name, value));
+            Ok(())
+        },
+    )?;
+    linker.func_wrap(
+        "fastn",
+        "write_body",
+        |mut caller: wasmtime::Caller<'_, State>, ptr: i32, len: i32| -> wasmtime::Result<()> {
+            let bytes = read(&mut caller, ptr, len)?;
+            caller.data_mut().body.extend(bytes);
+            Ok(())
+        },
+    )?;
+    Ok(linker)
+}
+
+fn read(caller: &mut wasmtime::Caller<'_, State>, ptr: i32, len: i32) -> wasmtime::Result<Vec<u8>> {
+    let memory = caller
+        .get_export("memory")
+        .and_then(|e| e.into_memory())
+        .ok_or_else(|| wasmtime::Error::msg("module does not export `memory`"))?;
+    let mut buffer = vec![0; usize::try_from(len)?];
+    memory.read(&*caller, usize::try_from(ptr)?, &mut buffer)?;
+    Ok(buffer)
+}
+
+fn call(
+    instance_pre: wasmtime::InstancePre<State>,
+    route: &BackendRoute,
+    request: &[u8],
+    body: &[u8],
+) -> WasmRunnerResult<State> {
+    let mut store = wasmtime::Store::new(
+        &ENGINE,
+        State {
+            limits: wasmtime::StoreLimitsBuilder::new()
+                .memory_size(route.memory)
+                .instances(1)
+                .build(),
+            status: None,
+            headers: vec![],
+            body: vec![],
+        },
+    );
+    store.limiter(|state| &mut state.limits);
+    store.set_fuel(route.fuel)?;
+
+    let instance = instance_pre.instantiate(&mut store)?;
+    let memory = instance
+        .get_memory(&mut store, "memory")
+        .ok_or_else(|| WASMError::WasmFunctionInvoke("module does not export `memory`".into()))?;
+    let alloc = instance.get_typed_func::<i32, i32>(&mut store, "alloc")?;
+    let function =
+        instance.get_typed_func::<(i32, i32, i32, i32), ()>(&mut store, route.function.as_str())?;
+
+    let mut write = |bytes: &[u8]| -> WasmRunnerResult<(i32, i32)> {
+        if bytes.is_empty() {
+            return Ok((0, 0));
+        }
+        let len = i32::try_from(bytes.len())
+            .map_err(|_| WASMError::WasmFunctionInvoke("request too large".into()))?;
+        let ptr = alloc.call(&mut store, len)?;
+        memory
+            .write(&mut store, ptr as usize, bytes)
+            .map_err(wasmtime::Error::from)?;
+        Ok((ptr, len))
+    };
+    let (request_ptr, request_len) = write(request)?;
+    let (body_ptr, body_len) = write(body)?;
+
+    if let Err(e) = function.call(&mut store, (request_ptr, request_len, body_ptr, body_len)) {
+        if matches!(
+            e.downcast_ref::<wasmtime::Trap>(),
+            Some(wasmtime::Trap::OutOfFuel)
+        ) {
+            return Err(WASMError::WasmFunctionInvoke(format!(
+                "{} ran out of fuel ({})",
+                route.function, route.fuel
+            )));
+        }
+        return Err(e.into());
+    }
+
+    Ok(store.into_data())
+}
+
 pub async fn handle_wasm(
-    req: fastn_core::http::Request,
-    wasm_module: camino::Utf8PathBuf,
-    backend_headers: Option<Vec<fastn_core::package::BackendHeader>>,
+    config: &fastn_core::Config,
+    req: &fastn_core::http::Request,
+    route: BackendRoute,
 ) -> fastn_core::http::Response {
-    pub async fn inner(
-        req: fastn_core::http::Request,
-        wasm_module: camino::Utf8PathBuf,
-        backend_headers: Option<Vec<fastn_core::package::BackendHeader>>,
+    async fn inner(
+        config: &fastn_core::Config,
+        req: &fastn_core::http::Request,
+        route: BackendRoute,
     ) -> WasmRunnerResult<actix_web::HttpResponse> {
-        let mut wasm_config = wit_bindgen_host_wasmtime_rust::wasmtime::Config::new();
-        wasm_config.cache_config_load_default().unwrap();
-        wasm_config.wasm_backtrace_details(
-            wit_bindgen_host_wasmtime_rust::wasmtime::WasmBacktraceDetails::Disable,
-        );
+        let mut headers = serde_json::Map::new();
+        for (name, value) in req.headers().iter() {
+            if let Ok(value) = value.to_str() {
+                headers.insert(name.as_str().to_string(), value.into());
+            }
+        }
+        for header in config.package.backend_headers.iter().flatten() {
+            headers.insert(
+                format!("X-fastn-{}", header.header_key),
+                header.header_value.as_str().into(),
+            );
+        }
 
-        let engine = wit_bindgen_host_wasmtime_rust::wasmtime::Engine::new(&wasm_config)?;
-        let module = wit_bindgen_host_wasmtime_rust::wasmtime::Module::from_file(
-            &engine,
-            wasm_module.as_str(),
-        )?;
-
-        let mut linker: wit_bindgen_host_wasmtime_rust::wasmtime::Linker<
-            fastn_core::wasm::Context<
-                fastn_core::wasm::HostExports,
-                fastn_utils::backend_host_import::guest_backend::GuestBackendData,
-            >,
-        > = wit_bindgen_host_wasmtime_rust::wasmtime::Linker::new(&engine);
-        let mut store = wit_bindgen_host_wasmtime_rust::wasmtime::Store::new(
-            &engine,
-            fastn_core::wasm::Context {
-                imports: fastn_core::wasm::HostExports {},
-                exports: fastn_utils::backend_host_import::guest_backend::GuestBackendData {},
-            },
-        );
+        let request = serde_json::to_vec(&serde_json::json!({
+            "method": req.method(),
+            "path": req.path(),
+            "query": req.query_string(),
+            "headers": headers,
+            "params": route.params,
+        }))?;
+        // The body is sent as bytes, it does not have to be utf-8
+        let body = req.body_bytes();
+        let module = route.module.clone();
 
-        fastn_utils::backend_host_export::host::add_to_linker(&mut linker, |cx| &mut cx.imports)?;
-
-        let (import, _i) =
-            fastn_utils::backend_host_import::guest_backend::GuestBackend::instantiate(
-                &mut store,
-                &module,
-                &mut linker,
-                |cx| &mut cx.exports,
-            )?;
-
-        let uri = req.uri().to_string();
-        // TODO: Fix body
-        let b = req.body().to_vec();
-        let body_str = if let Ok(b) = std::str::from_utf8(&b) {
-            b
-        } else {
-            ""
-        };
-        let mut headers = vec![];
-
-        req.headers()
-            .iter()
-            .for_each(|(header_name, header_value)| {
-                headers.push((
-                    header_name.as_str().to_string(),
-                    header_value
-                        .to_str()
-                        .expect("Unable to parse header value")
-                        .to_string(),
-                ));
-            });
-        if let Some(b_headers) = backend_headers {
-            b_headers.into_iter().for_each(|header| {
-                let hk = header.header_key;
-                headers.push((format!("X-fastn-{hk}"), header.header_value));
-            })
-        };
-        let headers: Vec<(&str, &str)> = headers
-            .iter()
-            .map(|(k, v)| (k.as_str(), v.as_str()))
-            .collect();
-        let request = fastn_utils::backend_host_import::guest_backend::Httprequest {
-            path: uri.as_str(),
-            headers: &(headers)[..],
-            querystring: req.query_string(),
-            method: req.method(),
-            payload: body_str,
-        };
-        fastn_core::time("WASM Guest function").it(match import.handlerequest(&mut store, request) {
-            Ok(data) => Ok(actix_web::HttpResponse::Ok()
-                .content_type(actix_web::http::header::ContentType::json())
-                .status(if data.success {
-                    actix_web::http::StatusCode::OK
-                } else {
-                    actix_web::http::StatusCode::BAD_REQUEST
-                })
-                .body(data.data)),
-            Err(err) => Err(WASMError::WasmFunctionInvoke(err.to_string())),
+        let state = tokio::task::spawn_blocking(move || {
+            call(instance_pre(&module)?, &route, &request, &body)
         })
+        .await
+        .map_err(|e| WASMError::WasmFunctionInvoke(e.to_string()))??;
+
+        let mut response = actix_web::HttpResponse::build(
+            actix_web::http::StatusCode::from_u16(state.status.unwrap_or(200))
+                .map_err(|e| WASMError::WasmFunctionInvoke(e.to_string()))?,
+        );
+        for (name, value) in state.headers {
+            response.append_header((name, value));
+        }
+        Ok(response.body(state.body))
     }
-    fastn_core::time("WASM Execution: ").it(match inner(req, wasm_module, backend_headers).await {
+
+    fastn_core::time("WASM Execution: ").it(match inner(config, req, route).await {
         Ok(resp) => resp,
         Err(err) => fastn_core::server_error!("{}", err.to_string()),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn parse_size() {
+        assert_eq!(super::parse_size("512").unwrap(), 512);
+        assert_eq!(super::parse_size("64MB").unwrap(), 64 * 1024 * 1024);
+        assert_eq!(super::parse_size("16kb").unwrap(), 16 * 1024);
+        assert!(super::parse_size("lots").is_err());
+    }
+
+    #[test]
+    fn from_extra_data() {
+        let extra_data = std::collections::BTreeMap::from([
+            ("backend".to_string(), "get_todo".to_string()),
+            ("backend-fuel".to_string(), "1000".to_string()),
+        ]);
+        let params = vec![("id".to_string(), ftd::Value::Integer { value: 5 })];
+        assert_eq!(
+            super::BackendRoute::from_extra_data(
+                &extra_data,
+                params.as_slice(),
+                camino::Utf8Path::new(".packages/todo.fifthtry.site")
+            )
+            .unwrap(),
+            Some(super::BackendRoute {
+                module: ".packages/todo.fifthtry.site/backend.wasm".into(),
+                function: "get_todo".to_string(),
+                fuel: 1000,
+                memory: super::DEFAULT_MEMORY,
+                params: serde_json::Map::from_iter([("id".to_string(), 5.into())]),
+            })
+        );
+    }
+}
@@ -1,6 +1,8 @@
 extern crate self as fastn_grammar;
 
 pub mod evalexpr;
+pub mod match_expression;
+pub mod statement;
 
 pub fn add(left: usize, right: usize) -> usize {
     left + right
@@ -0,0 +1,125 @@
+//! The expression form of `-- match:`, a value picked by the variant an or-type value holds:
+//!
+//! ```ftd
+//! -- ftd.text: $match $size { small => Smol, medium => "A, B", _ => Big }
+//! ```
+//!
+//! The subject and the values of the arms are `evalexpr` expressions, the patterns are variant
+//! names, or `_` for any other variant.
+
+use fastn_grammar::evalexpr::{EvalexprResult, ExprNode};
+use fastn_grammar::statement::{error, node, Parser};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Match {
+    pub subject: ExprNode,
+    pub arms: Vec<Arm>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Arm {
+    /// The variant name, `None` for `_`
+    pub variant: Option<String>,
+    pub value: ExprNode,
+}
+
+/// Parses `match <subject> { <variant> => <value>, .. }`, a trailing `,` is allowed.
+pub fn build_match(source: &str) -> EvalexprResult<Match> {
+    let mut parser = Parser::new(source);
+    parser.skip_whitespace();
+    if parser.peek_word().as_deref() != Some("match") {
+        return Err(error("Expected `match <value> { .. }`"));
+    }
+    parser.position += 5;
+
+    let (subject, stop) = parser.expression(&['{']);
+    if stop.is_none() {
+        return Err(error("Expected `{` after `match <value>`"));
+    }
+    parser.position += 1;
+    let subject = node(subject.as_str())?;
+
+    let mut arms = vec![];
+    loop {
+        parser.skip_whitespace();
+        if parser.eat('}') {
+            break;
+        }
+
+        let (pattern, stop) = parser.expression(&['=', ',', '}']);
+        if stop != Some('=') || parser.chars.get(parser.position + 1) != Some(&'>') {
+            return Err(error(
+                format!(
+                    "Expected `<variant> => <value>`, found: `{}`",
+                    pattern.trim()
+                )
+                .as_str(),
+            ));
+        }
+        parser.position += 2;
+        let variant = match pattern.trim() {
+            "_" => None,
+            name if is_variant_name(name) => Some(name.to_string()),
+            name => return Err(error(format!("Invalid variant name: `{}`", name).as_str())),
+        };
+
+        let (value, stop) = parser.expression(&[',', '}']);
+        if stop.is_none() {
+            return Err(error("Expected `}` at the end of match"));
+        }
+        parser.eat(',');
+        arms.push(Arm {
+            variant,
+            value: node(value.as_str())?,
+        });
+    }
+
+    parser.skip_whitespace();
+    if parser.peek().is_some() {
+        return Err(error("Unexpected text after the `}` of match"));
+    }
+    if arms.is_empty() {
+        return Err(error("Expected at least one case in match"));
+    }
+
+    Ok(Match { subject, arms })
+}
+
+fn is_variant_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+}
+
+#[cfg(test)]
+mod test {
+    use fastn_grammar::evalexpr::build_operator_tree;
+
+    #[test]
+    fn build_match() {
+        let m = super::build_match(
+            "match $size { small => Smol, extra-large => \"A, B } C\", _ => (1 + 2), }",
+        )
+        .unwrap();
+        assert_eq!(m.subject, build_operator_tree("$size").unwrap());
+        assert_eq!(
+            m.arms
+                .iter()
+                .map(|v| v.variant.as_deref())
+                .collect::<Vec<_>>(),
+            vec![Some("small"), Some("extra-large"), None]
+        );
+        assert_eq!(
+            m.arms[1].value,
+            build_operator_tree("\"A, B } C\"").unwrap()
+        );
+        assert_eq!(m.arms[2].value, build_operator_tree("(1 + 2)").unwrap());
+
+        assert!(super::build_match("match $size { small Smol }").is_err());
+        assert!(super::build_match("match $size { small => Smol").is_err());
+        assert!(super::build_match("match $size { }").is_err());
+        assert!(super::build_match("match $size { small => Smol } x").is_err());
+    }
+}
@@ -0,0 +1,480 @@
+//! Statements in the body of a user defined function.
+//!
+//! A function body is either a single `evalexpr` expression, like `a + " " + b` or
+//! `a = a + 1; a`, or a list of statements:
+//!
+//! ```ftd
+//! let total: integer = 0;
+//! for item in items {
+//!     if item > limit {
+//!         return limit;
+//!     }
+//!     total = total + item;
+//! }
+//! total
+//! ```
+//!
+//! Expressions inside statements are still parsed by `evalexpr`, only `let`, `for`, `if` /
+//! `else` and `return` are understood here. A body ending with an expression that is not
+//! followed by `;` returns the value of that expression.
+
+use fastn_grammar::evalexpr::{build_operator_tree, EvalexprError, EvalexprResult, ExprNode};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Statement {
+    /// `let total: integer = 0;`, the kind is optional when it can be inferred from the value.
+    Let {
+        name: String,
+        kind: Option<String>,
+        value: ExprNode,
+        line_number: usize,
+    },
+    /// `for item in items { .. }`
+    For {
+        name: String,
+        list: ExprNode,
+        body: Vec<Statement>,
+        line_number: usize,
+    },
+    /// `if condition { .. } else { .. }`, `else if` is an `If` inside `otherwise`.
+    If {
+        condition: ExprNode,
+        then: Vec<Statement>,
+        otherwise: Vec<Statement>,
+        line_number: usize,
+    },
+    /// `return value;`, or `return;` in a function that does not return anything.
+    Return {
+        value: Option<ExprNode>,
+        line_number: usize,
+    },
+    /// Any other expression, e.g. an assignment `total = total + item;`.
+    Expression { value: ExprNode, line_number: usize },
+}
+
+impl Statement {
+    /// The line of the statement, counted from the first line of the body (starting at 0).
+    pub fn line_number(&self) -> usize {
+        match self {
+            Statement::Let { line_number, .. }
+            | Statement::For { line_number, .. }
+            | Statement::If { line_number, .. }
+            | Statement::Return { line_number, .. }
+            | Statement::Expression { line_number, .. } => *line_number,
+        }
+    }
+
+    /// Returns `true` if every path through `statements` ends in a `return`.
+    pub fn always_returns(statements: &[Statement]) -> bool {
+        match statements.last() {
+            Some(Statement::Return { .. }) => true,
+            Some(Statement::If {
+                then, otherwise, ..
+            }) => Statement::always_returns(then) && Statement::always_returns(otherwise),
+            _ => false,
+        }
+    }
+}
+
+/// Parses a function body.
+///
+/// A body without any statement keyword is returned as a single `Statement::Expression`
+/// holding the whole body, so plain expression bodies evaluate exactly like before.
+pub fn build_statements(body: &str) -> EvalexprResult<Vec<Statement>> {
+    let mut parser = Parser::new(body);
+    let (mut statements, trailing_expression) = parser.block(false)?;
+
+    if statements
+        .iter()
+        .all(|v| matches!(v, Statement::Expression { .. }))
+    {
+        return Ok(vec![Statement::Expression {
+            value: build_operator_tree(body)?,
+            line_number: 0,
+        }]);
+    }
+
+    if trailing_expression {
+        match statements.pop() {
+            Some(Statement::Expression { value, line_number }) if !is_assignment(&value) => {
+                statements.push(Statement::Return {
+                    value: Some(value),
+                    line_number,
+                })
+            }
+            Some(statement) => statements.push(statement),
+            None => {}
+        }
+    }
+
+    Ok(statements)
+}
+
+pub(crate) struct Parser {
+    pub(crate) chars: Vec<char>,
+    pub(crate) position: usize,
+}
+
+impl Parser {
+    pub(crate) fn new(source: &str) -> Parser {
+        Parser {
+            chars: source.chars().collect(),
+            position: 0,
+        }
+    }
+
+    /// Parses statements until the end of the body, or until the `}` closing a nested block.
+    /// The returned flag tells if the last statement is an expression not followed by `;`.
+    fn block(&mut self, nested: bool) -> EvalexprResult<(Vec<Statement>, bool)> {
+        let mut statements = vec![];
+        let mut trailing_expression = false;
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                None if nested => return Err(error("Expected `}` at the end of the block")),
+                None => break,
+                Some('}') if nested => {
+                    self.position += 1;
+                    break;
+                }
+                Some('}') => return Err(error("Found `}` without a matching `{`")),
+                Some(';') => {
+                    self.position += 1;
+                    continue;
+                }
+                _ => {}
+            }
+
+            let line_number = self.line_number();
+            trailing_expression = false;
+            let statement = match self.peek_word().as_deref() {
+                Some("let") => {
+                    self.position += 3;
+                    self.let_statement(line_number)?
+                }
+                Some("return") => {
+                    self.position += 6;
+                    let (value, _) = self.expression(&[';', '}']);
+                    self.eat(';');
+                    Statement::Return {
+                        value: optional_node(value.as_str())?,
+                        line_number,
+                    }
+                }
+                Some("for") if self.starts_block(3) => {
+                    self.position += 3;
+                    self.for_statement(line_number)?
+                }
+                Some("if") if self.starts_block(2) => {
+                    self.position += 2;
+                    self.if_statement(line_number)?
+                }
+                _ => {
+                    let (value, _) = self.expression(&[';', '}']);
+                    trailing_expression = !self.eat(';');
+                    Statement::Expression {
+                        value: node(value.as_str())?,
+                        line_number,
+                    }
+                }
+            };
+            statements.push(statement);
+        }
+        Ok((statements, trailing_expression))
+    }
+
+    fn let_statement(&mut self, line_number: usize) -> EvalexprResult<Statement> {
+        let (declaration, stop) = self.expression(&['=', ';', '}']);
+        if stop != Some('=') || self.chars.get(self.position + 1).eq(&Some(&'=')) {
+            return Err(error(
+                format!("Expected `=` after `let {}`", declaration.trim()).as_str(),
+            ));
+        }
+        self.position += 1;
+
+        let (name, kind) = match declaration.split_once(':') {
+            Some((name, kind)) => (name.trim(), Some(kind.trim().to_string())),
+            None => (declaration.trim(), None),
+        };
+        if !is_identifier(name) {
+            return Err(error(format!("Invalid variable name: `{}`", name).as_str()));
+        }
+
+        let (value, _) = self.expression(&[';', '}']);
+        self.eat(';');
+        Ok(Statement::Let {
+            name: name.to_string(),
+            kind: kind.filter(|v| !v.is_empty()),
+            value: node(value.as_str())?,
+            line_number,
+        })
+    }
+
+    fn for_statement(&mut self, line_number: usize) -> EvalexprResult<Statement> {
+        let (header, _) = self.expression(&['{']);
+        self.position += 1;
+        let (name, list) = match header.trim().split_once(char::is_whitespace) {
+            Some((name, rest)) if rest.trim_start().starts_with("in ") => {
+                (name, rest.trim_start().trim_start_matches("in "))
+            }
+            _ => {
+                return Err(error(
+                    format!(
+                        "Expected `for <item> in <list>`, found: `for {}`",
+                        header.trim()
+                    )
+                    .as_str(),
+                ))
+            }
+        };
+        if !is_identifier(name) {
+            return Err(error(format!("Invalid variable name: `{}`", name).as_str()));
+        }
+        let list = node(list)?;
+        let (body, _) = self.block(true)?;
+        Ok(Statement::For {
+            name: name.to_string(),
+            list,
+            body,
+            line_number,
+        })
+    }
+
+    fn if_statement(&mut self, line_number: usize) -> EvalexprResult<Statement> {
+        let (condition, _) = self.expression(&['{']);
+        self.position += 1;
+        let condition = node(condition.as_str())?;
+        let (then, _) = self.block(true)?;
+
+        let position = self.position;
+        self.skip_whitespace();
+        let otherwise = if self.peek_word().as_deref() == Some("else") {
+            self.position += 4;
+            self.skip_whitespace();
+            let line_number = self.line_number();
+            if self.peek_word().as_deref() == Some("if") && self.starts_block(2) {
+                self.position += 2;
+                vec![self.if_statement(line_number)?]
+            } else if self.eat('{') {
+                self.block(true)?.0
+            } else {
+                return Err(error("Expected `{` or `if` after `else`"));
+            }
+        } else {
+            self.position = position;
+            vec![]
+        };
+
+        Ok(Statement::If {
+            condition,
+            then,
+            otherwise,
+            line_number,
+        })
+    }
+
+    /// Reads an expression until one of `stops` is found outside of strings and parentheses.
+    /// The stop character is not consumed.
+    pub(crate) fn expression(&mut self, stops: &[char]) -> (String, Option<char>) {
+        let mut value = String::new();
+        let mut depth = 0;
+        let mut in_string = false;
+        while let Some(c) = self.peek() {
+            if in_string {
+                if c == '\\' {
+                    value.push(c);
+                    self.position += 1;
+                    if let Some(c) = self.peek() {
+                        value.push(c);
+                        self.position += 1;
+                    }
+                    continue;
+                }
+                if c == '"' {
+                    in_string = false;
+                }
+            } else if c == '"' {
+                in_string = true;
+            } else if c == '(' {
+                depth += 1;
+            } else if c == ')' {
+                depth -= 1;
+            } else if depth == 0 && stops.contains(&c) {
+                return (value, Some(c));
+            }
+            value.push(c);
+            self.position += 1;
+        }
+        (value, None)
+    }
+
+    /// Returns `true` if the keyword of length `keyword_length` at the current position is
+    /// followed by a `{` block, so `if` statements are not confused with the `if(..)` function.
+    fn starts_block(&mut self, keyword_length: usize) -> bool {
+        let position = self.position;
+        self.position += keyword_length;
+        let (_, stop) = self.expression(&['{', ';', '}']);
+        self.position = position;
+        stop == Some('{')
+    }
+
+    pub(crate) fn peek_word(&self) -> Option<String> {
+        let word = self.chars[self.position..]
+            .iter()
+            .take_while(|c| c.is_alphanumeric() || **c == '_')
+            .collect::<String>();
+        if word.is_empty() {
+            None
+        } else {
+            Some(word)
+        }
+    }
+
+    pub(crate) fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    pub(crate) fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub(crate) fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.position += 1;
+        }
+    }
+
+    fn line_number(&self) -> usize {
+        self.chars[..self.position]
+            .iter()
+            .filter(|c| **c == '\n')
+            .count()
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+fn is_assignment(node: &ExprNode) -> bool {
+    use fastn_grammar::evalexpr::Operator;
+
+    match node.operator() {
+        Operator::RootNode => node.children().len() == 1 && is_assignment(&node.children()[0]),
+        Operator::Assign
+        | Operator::AddAssign
+        | Operator::SubAssign
+        | Operator::MulAssign
+        | Operator::DivAssign
+        | Operator::ModAssign
+        | Operator::ExpAssign
+        | Operator::AndAssign
+        | Operator::OrAssign
+        | Operator::Chain => true,
+        _ => false,
+    }
+}
+
+pub(crate) fn node(value: &str) -> EvalexprResult<ExprNode> {
+    if value.trim().is_empty() {
+        return Err(error("Expected an expression"));
+    }
+    build_operator_tree(value)
+}
+
+fn optional_node(value: &str) -> EvalexprResult<Option<ExprNode>> {
+    if value.trim().is_empty() {
+        Ok(None)
+    } else {
+        build_operator_tree(value).map(Some)
+    }
+}
+
+pub(crate) fn error(message: &str) -> EvalexprError {
+    EvalexprError::CustomMessage(message.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use fastn_grammar::statement::Statement;
+
+    #[test]
+    fn expression_body() {
+        let statements = fastn_grammar::statement::build_statements("a = a + 1; a").unwrap();
+        assert_eq!(statements.len(), 1);
+        assert!(matches!(statements[0], Statement::Expression { .. }));
+
+        // `if(..)` is the builtin function, not a statement
+        let statements =
+            fastn_grammar::statement::build_statements("if(a > 1, \"big\", \"small\")").unwrap();
+        assert!(matches!(statements[0], Statement::Expression { .. }));
+    }
+
+    #[test]
+    fn statements() {
+        let statements = fastn_grammar::statement::build_statements(
+            "let total: integer = 0;
+            for item in items {
+                if item > limit {
+                    return limit;
+                } else if item < 0 {
+                    total = total - item;
+                } else {
+                    total = total + item;
+                }
+            }
+            total",
+        )
+        .unwrap();
+
+        assert_eq!(statements.len(), 3);
+        assert!(matches!(
+            &statements[0],
+            Statement::Let { name, kind: Some(kind), line_number: 0, .. }
+                if name == "total" && kind == "integer"
+        ));
+        match &statements[1] {
+            Statement::For {
+                name,
+                body,
+                line_number,
+                ..
+            } => {
+                assert_eq!(name, "item");
+                assert_eq!(*line_number, 1);
+                match &body[0] {
+                    Statement::If {
+                        then, otherwise, ..
+                    } => {
+                        assert!(matches!(then[0], Statement::Return { value: Some(_), .. }));
+                        assert!(matches!(otherwise[0], Statement::If { .. }));
+                    }
+                    t => panic!("Expected if, found: {:?}", t),
+                }
+            }
+            t => panic!("Expected for, found: {:?}", t),
+        }
+        assert!(matches!(
+            statements[2],
+            Statement::Return {
+                value: Some(_),
+                line_number: 10
+            }
+        ));
+        assert!(Statement::always_returns(&statements));
+    }
+
+    #[test]
+    fn errors() {
+        assert!(fastn_grammar::statement::build_statements("let x == 1; x").is_err());
+        assert!(fastn_grammar::statement::build_statements("for item of items { item }").is_err());
+        assert!(fastn_grammar::statement::build_statements("if a { return 1;").is_err());
+    }
+}
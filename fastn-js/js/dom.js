@@ -5,6 +5,16 @@ fastn_dom.styleClasses = "";
 fastn_dom.InternalClass = {
     FT_COLUMN: "ft_column",
     FT_ROW: "ft_row",
+    FT_GRID: "ft_grid",
+    FT_RADIO_GROUP: "ft_radio_group",
+    FT_TABLE: "ft_table",
+    FT_TABLE_STICKY: "ft_table_sticky",
+    FT_TABLE_PAGER: "ft_table_pager",
+    FT_DIALOG: "ft_dialog",
+    FT_POPOVER: "ft_popover",
+    FT_TOASTS: "ft_toasts",
+    FT_TOAST: "ft_toast",
+    FT_MOTION: "ft_motion",
     FT_FULL_SIZE: "ft_full_size",
 };
 
@@ -24,12 +34,26 @@ fastn_dom.wrapperNode = "wrapper";
 fastn_dom.commentMessage = "***FASTN***";
 fastn_dom.webComponentArgument = "args";
 
+// `File` objects picked in an `ftd.file-input`, keyed by their `ftd.file`
+// record, so `ftd.http` can upload them.
+fastn_dom.uploadedFiles = new WeakMap();
+fastn_dom.radio_group_count = 0;
+// `ftd.form` and the fields inside it, keyed by their DOM node.
+fastn_dom.formNodes = new WeakMap();
+// The `ftd.form` whose `$on-submit$` handlers are running, if any.
+fastn_dom.submittingForm = null;
+// Open modal `ftd.dialog`s, the page scroll is locked while there are any.
+fastn_dom.scrollLocks = 0;
+// Non-modal `ftd.dialog`s opened later are stacked above earlier ones.
+fastn_dom.overlayZIndex = 1000;
+
 fastn_dom.classes = {};
 fastn_dom.unsanitised_classes = {};
 fastn_dom.class_count = 0;
 fastn_dom.propertyMap = {
     "align-items": "ali",
     "align-self": "as",
+    animation: "an",
     "background-color": "bgc",
     "background-image": "bgi",
     "background-position": "bgp",
@@ -62,6 +86,14 @@ fastn_dom.propertyMap = {
     cursor: "cur",
     display: "d",
     "flex-wrap": "fw",
+    "grid-template-columns": "gtc",
+    "grid-template-rows": "gtr",
+    "grid-template-areas": "gta",
+    "grid-column": "gc",
+    "grid-row": "gr",
+    "grid-area": "ga",
+    "row-gap": "rg",
+    "column-gap": "cg",
     "font-style": "fst",
     "font-weight": "fwt",
     gap: "g",
@@ -102,6 +134,7 @@ fastn_dom.propertyMap = {
     "text-decoration": "td",
     "text-transform": "tt",
     top: "t",
+    transition: "tr",
     width: "w",
     "z-index": "z",
     "-webkit-box-orient": "wbo",
@@ -136,7 +169,29 @@ fastn_dom.getClassesAsStringWithoutStyleTag = function () {
     return classes.join("\n\t");
 };
 
+// The `@keyframes` of an `ftd.animation`, equal keyframes share one name
+fastn_dom.keyframes = {};
+
+fastn_dom.getKeyframesName = function (rules) {
+    let name = fastn_dom.keyframes[rules];
+    if (!fastn_utils.isNull(name)) {
+        return name;
+    }
+    name = `ft_kf_${Object.keys(fastn_dom.keyframes).length + 1}`;
+    fastn_dom.keyframes[rules] = name;
+    let cssClass = `@keyframes ${name}`;
+    let obj = { rules };
+    fastn_dom.classes[cssClass] = obj;
+    if (!ssr && !doubleBuffering) {
+        fastn_utils.createStyle(cssClass, obj);
+    }
+    return name;
+};
+
 function getClassAsString(className, obj) {
+    if (!fastn_utils.isNull(obj.rules)) {
+        return `${className} { ${obj.rules} }`;
+    }
     if (typeof obj.value === "object" && obj.value !== null) {
         let value = "";
         for (let key in obj.value) {
@@ -182,6 +237,16 @@ fastn_dom.ElementKind = {
         return [17, [webcomponent, args]];
     },
     Video: 18,
+    Grid: 19,
+    Select: 20,
+    RadioGroup: 21,
+    Slider: 22,
+    FileInput: 23,
+    Form: 24,
+    Table: 25,
+    Dialog: 26,
+    Popover: 27,
+    Subscribe: 28,
 };
 
 fastn_dom.PropertyKind = {
@@ -310,6 +375,58 @@ fastn_dom.PropertyKind = {
     Mask: 120,
     TextInputValue: 121,
     FetchPriority: 122,
+    GridTemplateColumns: 123,
+    GridTemplateRows: 124,
+    GridTemplateAreas: 125,
+    Gap: 126,
+    RowGap: 127,
+    ColumnGap: 128,
+    GridColumn: 129,
+    GridRow: 130,
+    GridArea: 131,
+    FormValue: 132,
+    Options: 133,
+    Min: 134,
+    Max: 135,
+    Step: 136,
+    Accept: 137,
+    Multiple: 138,
+    Name: 139,
+    AriaLabel: 140,
+    Required: 141,
+    Pattern: 142,
+    MinLength: 143,
+    CustomError: 144,
+    FieldState: 145,
+    Valid: 146,
+    TableData: 147,
+    TableColumns: 148,
+    TableFilter: 149,
+    TablePageSize: 150,
+    TablePage: 151,
+    TableServerPagination: 152,
+    TableTotal: 153,
+    TableStickyHeader: 154,
+    DragData: 155,
+    Open: 156,
+    Modal: 157,
+    Dismissible: 158,
+    PopoverAnchor: 159,
+    PopoverPlacement: 160,
+    Transition: 161,
+    Animation: 162,
+    EnterAnimation: 163,
+    ExitAnimation: 164,
+    SubscribeUrl: 165,
+    SubscribeValue: 166,
+    AriaRole: 167,
+    AriaDescribedBy: 168,
+    AriaLive: 169,
+    TabIndex: 170,
+    TablePreviousLabel: 171,
+    TableNextLabel: 172,
+    TablePageLabel: 173,
+    TablePageParam: 174,
 };
 
 fastn_dom.Loading = {
@@ -356,6 +473,13 @@ fastn_dom.Region = {
     H4: "h4",
     H5: "h5",
     H6: "h6",
+    Main: "main",
+    Nav: "nav",
+    Header: "header",
+    Footer: "footer",
+    Aside: "aside",
+    Section: "section",
+    Article: "article",
 };
 
 fastn_dom.Anchor = {
@@ -809,6 +933,18 @@ fastn_dom.Event = {
     Change: 7,
     Blur: 8,
     Focus: 9,
+    Submit: 10,
+    KeyDown: (val) => {
+        return [11, val];
+    },
+    Scroll: 12,
+    Visible: 13,
+    Hidden: 14,
+    DragStart: 15,
+    Drop: 16,
+    Swipe: (val) => {
+        return [17, val];
+    },
 };
 
 class PropertyValueAsClosure {
@@ -902,6 +1038,38 @@ class Node2 {
                 attributes,
             );
         }
+        if (kind === fastn_dom.ElementKind.Form && !ssr) {
+            fastn_dom.formNodes.set(this.#node, this);
+            this.#node.onsubmit = (event) => {
+                event.preventDefault();
+                this.submitForm();
+            };
+        } else if (kind === fastn_dom.ElementKind.Dialog && !ssr) {
+            // Escape closes the dialog unless it isn't `dismissible`
+            this.#node.oncancel = (event) => {
+                if (this.#extraData.dismissible === false) {
+                    event.preventDefault();
+                }
+            };
+            this.#node.onclose = () => this.onOverlayClosed();
+            this.#node.onclick = (event) => {
+                if (
+                    this.#extraData.dismissible !== false &&
+                    this.isBackdropClick(event)
+                ) {
+                    this.#node.close();
+                }
+            };
+        } else if (kind === fastn_dom.ElementKind.Popover && !ssr) {
+            if (!(this.#node.showPopover instanceof Function)) {
+                this.#node.hidden = true;
+            }
+            this.#node.ontoggle = (event) => {
+                if (event.newState === "closed") {
+                    this.onOverlayClosed();
+                }
+            };
+        }
     }
     getTagName() {
         return this.#tagName;
@@ -946,6 +1114,698 @@ class Node2 {
             this.attachAttribute("value", this.#rawInnerValue);
         }
     }
+    // `ftd.select` options and `ftd.radio` buttons are rendered as inner html
+    // so they are part of the server rendered page as well
+    updateFormOptions() {
+        let options = this.#extraData.options || [];
+        let value = this.#extraData.value;
+        let html = "";
+        if (this.#kind === fastn_dom.ElementKind.Select) {
+            let placeholder = this.#extraData.placeholder;
+            if (!fastn_utils.isNull(placeholder)) {
+                html += `<option value="" disabled${
+                    fastn_utils.isNull(value) ? " selected" : ""
+                }>${fastn_utils.escapeHtml(placeholder)}</option>`;
+            }
+        }
+        for (let option of options) {
+            option = fastn_utils.getStaticValue(option.item);
+            let optionValue = fastn_utils.getStaticValue(option.get("value"));
+            let label = fastn_utils.escapeHtml(
+                fastn_utils.getStaticValue(option.get("label")),
+            );
+            let selected = optionValue === value;
+            let disabled = !fastn_utils.getStaticValue(option.get("enabled"));
+            optionValue = fastn_utils.escapeHtml(optionValue);
+            if (this.#kind === fastn_dom.ElementKind.Select) {
+                html += `<option value="${optionValue}"${
+                    selected ? " selected" : ""
+                }${disabled ? " disabled" : ""}>${label}</option>`;
+            } else {
+                let name = this.getRadioGroupName();
+                html += `<label><input type="radio" name="${name}" value="${optionValue}"${
+                    selected ? " checked" : ""
+                }${disabled ? " disabled" : ""}${
+                    this.#extraData.required ? " required" : ""
+                }>${label}</label>`;
+            }
+        }
+        this.#node.innerHTML = html;
+        this.updateFormValue();
+    }
+    getRadioGroupName() {
+        if (fastn_utils.isNull(this.#extraData.name)) {
+            this.#extraData.name = `ft_radio_${++fastn_dom.radio_group_count}`;
+        }
+        return this.#extraData.name;
+    }
+    updateFormValue() {
+        let value = this.#extraData.value;
+        if (this.#kind === fastn_dom.ElementKind.Slider) {
+            this.attachAttribute("value", value);
+        }
+        if (ssr) {
+            return;
+        }
+        if (this.#kind === fastn_dom.ElementKind.Select) {
+            this.#node.value = fastn_utils.isNull(value) ? "" : value;
+        } else if (this.#kind === fastn_dom.ElementKind.RadioGroup) {
+            for (let input of this.#node.querySelectorAll("input")) {
+                input.checked = input.value === value;
+            }
+        } else if (this.#kind === fastn_dom.ElementKind.Slider) {
+            if (!fastn_utils.isNull(value)) {
+                this.#node.value = value;
+            }
+        } else if (
+            this.#kind === fastn_dom.ElementKind.FileInput &&
+            (fastn_utils.isNull(value) || value.length === 0)
+        ) {
+            // a file input can only be cleared from code
+            this.#node.value = "";
+        }
+    }
+    // The value picked by the user, in the kind of the `$value` argument
+    getFormValue() {
+        if (this.#kind === fastn_dom.ElementKind.RadioGroup) {
+            let checked = this.#node.querySelector("input:checked");
+            return fastn_utils.isNull(checked) ? null : checked.value;
+        } else if (this.#kind === fastn_dom.ElementKind.Slider) {
+            return parseFloat(this.#node.value);
+        } else if (this.#kind === fastn_dom.ElementKind.FileInput) {
+            return Array.from(this.#node.files).map((file) => {
+                let record = fastn.recordInstance({
+                    name: file.name,
+                    size: file.size,
+                    type: file.type,
+                });
+                fastn_dom.uploadedFiles.set(record, file);
+                return record;
+            });
+        }
+        return this.#node.value === "" ? null : this.#node.value;
+    }
+    // Writes the user's choice back to the mutable passed as `$value`. This
+    // runs before any `$on-change$`/`$on-input$` handler of the control.
+    bindFormValue(value) {
+        if (
+            ssr ||
+            !(
+                value instanceof fastn.mutableClass ||
+                value instanceof fastn.mutableListClass
+            )
+        ) {
+            return;
+        }
+        let setValue = () => value.set(this.getFormValue());
+        if (this.#kind === fastn_dom.ElementKind.Slider) {
+            this.#node.oninput = this.mergeFnCalls(
+                setValue,
+                this.#node.oninput,
+            );
+        } else {
+            this.#node.onchange = this.mergeFnCalls(
+                setValue,
+                this.#node.onchange,
+            );
+        }
+    }
+    // A field of an `ftd.form`. The browser checks its constraints, and the
+    // result is written to its `$state` and to the `$valid` of the form.
+    registerField() {
+        if (ssr || !fastn_utils.isNull(this.#extraData.field)) {
+            return;
+        }
+        this.#extraData.field = {
+            dirty: false,
+            touched: false,
+            valid: true,
+            error: null,
+            serverError: null,
+        };
+        fastn_dom.formNodes.set(this.#node, this);
+        let onEdit = () => {
+            let field = this.#extraData.field;
+            field.dirty = true;
+            field.serverError = null;
+            if (this.#kind === fastn_dom.ElementKind.RadioGroup) {
+                // a radio group never gets the focus itself
+                field.touched = true;
+            }
+            this.validateField();
+        };
+        this.#node.oninput = this.mergeFnCalls(onEdit, this.#node.oninput);
+        this.#node.onchange = this.mergeFnCalls(onEdit, this.#node.onchange);
+        this.#node.onblur = this.mergeFnCalls(
+            () => this.touchField(),
+            this.#node.onblur,
+        );
+    }
+    getFieldControl() {
+        if (this.#kind === fastn_dom.ElementKind.RadioGroup) {
+            return this.#node.querySelector("input");
+        }
+        return this.#node;
+    }
+    validateField() {
+        let field = this.#extraData.field;
+        if (fastn_utils.isNull(field)) {
+            return;
+        }
+        let error = this.#extraData.customError || field.serverError || "";
+        let control = this.getFieldControl();
+        if (fastn_utils.isNull(control)) {
+            field.valid = error === "";
+            field.error = field.valid ? null : error;
+        } else {
+            control.setCustomValidity(error);
+            field.valid = control.validity.valid;
+            field.error = field.valid ? null : control.validationMessage;
+        }
+        this.updateFieldState();
+        let form = fastn_dom.formNodes.get(this.#node.form);
+        if (!fastn_utils.isNull(form)) {
+            form.updateFormValidity();
+        }
+    }
+    updateFieldState() {
+        let state = this.#extraData.fieldState;
+        let field = this.#extraData.field;
+        if (fastn_utils.isNull(state)) {
+            return;
+        }
+        let value = {
+            dirty: field.dirty,
+            touched: field.touched,
+            valid: field.valid,
+            error: field.error,
+        };
+        if (state instanceof fastn.mutableClass) {
+            if (!(state.get() instanceof fastn.recordInstanceClass)) {
+                state.set(fastn.recordInstance(value));
+                return;
+            }
+            state = state.get();
+        }
+        for (let [key, fieldValue] of Object.entries(value)) {
+            if (fastn_utils.getStaticValue(state.get(key)) !== fieldValue) {
+                state.set(key, fieldValue);
+            }
+        }
+    }
+    isFieldValid() {
+        return fastn_utils.isNull(this.#extraData.field)
+            ? true
+            : this.#extraData.field.valid;
+    }
+    touchField() {
+        if (fastn_utils.isNull(this.#extraData.field)) {
+            return;
+        }
+        this.#extraData.field.touched = true;
+        this.validateField();
+    }
+    // The error stays on the field until the user edits it again
+    setServerError(message) {
+        if (fastn_utils.isNull(this.#extraData.field)) {
+            return;
+        }
+        this.#extraData.field.serverError = message;
+        this.touchField();
+    }
+    attachAnimation() {
+        let animations = [
+            this.#extraData.enterAnimation,
+            this.#extraData.animation,
+        ].filter((animation) => !fastn_utils.isNull(animation));
+        this.attachCss(
+            "animation",
+            animations.length === 0 ? undefined : animations.join(", "),
+        );
+        this.updateMotionClass();
+    }
+    // Transitions and animations are turned off by this class for users who
+    // prefer reduced motion
+    updateMotionClass() {
+        let hasMotion = [
+            this.#extraData.transition,
+            this.#extraData.animation,
+            this.#extraData.enterAnimation,
+        ].some((value) => !fastn_utils.isNull(value));
+        if (hasMotion) {
+            this.#node.classList.add(fastn_dom.InternalClass.FT_MOTION);
+        } else {
+            this.#node.classList.remove(fastn_dom.InternalClass.FT_MOTION);
+        }
+    }
+    // Plays `exit-animation` on the node, which is removed once it is done
+    removeWithExitAnimation(node) {
+        let exitAnimation = this.#extraData.exitAnimation;
+        if (
+            ssr ||
+            fastn_utils.isNull(exitAnimation) ||
+            fastn_utils.prefersReducedMotion()
+        ) {
+            node.remove();
+            return;
+        }
+        let duration =
+            fastn_utils.getStaticValue(exitAnimation.get("duration")) +
+            fastn_utils.getStaticValue(exitAnimation.get("delay"));
+        node.style.animation = fastn_utils.getAnimationValue(
+            exitAnimation,
+            "forwards",
+        );
+        node.style.pointerEvents = "none";
+        let remove = () => node.remove();
+        node.addEventListener("animationend", remove, { once: true });
+        // in case `animationend` never fires, e.g. the node is hidden
+        setTimeout(remove, duration + 50);
+    }
+    // Shows or hides an `ftd.dialog` or `ftd.popover` as its `open` says
+    toggleOverlay(open) {
+        if (ssr || fastn_utils.isNull(this.#node)) {
+            return;
+        }
+        open = open === true || open === "true";
+        this.#extraData.overlayWanted = open;
+        if (open === !!this.#extraData.overlayShown) {
+            return;
+        }
+        if (!this.#node.isConnected) {
+            // `showModal` and `showPopover` need the node in the document
+            setTimeout(
+                () => this.toggleOverlay(this.#extraData.overlayWanted),
+                0,
+            );
+            return;
+        }
+        if (open) {
+            this.showOverlay();
+        } else {
+            this.hideOverlay();
+        }
+    }
+    showOverlay() {
+        this.#extraData.overlayShown = true;
+        if (this.#kind === fastn_dom.ElementKind.Popover) {
+            if (this.#node.showPopover instanceof Function) {
+                this.#node.showPopover();
+            } else {
+                this.#node.hidden = false;
+            }
+            this.#extraData.reposition = () => this.positionPopover();
+            window.addEventListener("resize", this.#extraData.reposition);
+            window.addEventListener("scroll", this.#extraData.reposition, true);
+            this.positionPopover();
+            return;
+        }
+        this.#extraData.returnFocus = document.activeElement;
+        if (this.#extraData.modal === false) {
+            this.#node.show();
+            this.#node.style.zIndex = ++fastn_dom.overlayZIndex;
+        } else {
+            // a modal dialog makes the rest of the page inert, so focus
+            // stays inside it
+            this.#node.showModal();
+            fastn_utils.lockScroll(true);
+            this.#extraData.scrollLocked = true;
+        }
+    }
+    hideOverlay() {
+        if (this.#kind === fastn_dom.ElementKind.Dialog) {
+            this.#node.close();
+        } else if (this.#node.hidePopover instanceof Function) {
+            this.#node.hidePopover();
+        } else {
+            this.#node.hidden = true;
+        }
+        this.onOverlayClosed();
+    }
+    // Also called when the browser closes the overlay, on Escape or a click
+    // outside it, so `open` is set back to false
+    onOverlayClosed() {
+        if (!this.#extraData.overlayShown) {
+            return;
+        }
+        this.#extraData.overlayShown = false;
+        this.#extraData.overlayWanted = false;
+        if (this.#extraData.scrollLocked) {
+            fastn_utils.lockScroll(false);
+            this.#extraData.scrollLocked = false;
+        }
+        let reposition = this.#extraData.reposition;
+        if (!fastn_utils.isNull(reposition)) {
+            window.removeEventListener("resize", reposition);
+            window.removeEventListener("scroll", reposition, true);
+            this.#extraData.reposition = null;
+        }
+        let returnFocus = this.#extraData.returnFocus;
+        this.#extraData.returnFocus = null;
+        if (
+            !fastn_utils.isNull(returnFocus) &&
+            returnFocus.focus instanceof Function
+        ) {
+            returnFocus.focus();
+        }
+        let open = this.#extraData.open;
+        if (open instanceof fastn.mutableClass && open.get() !== false) {
+            open.set(false);
+        }
+    }
+    isBackdropClick(event) {
+        if (event.target !== this.#node) {
+            return false;
+        }
+        let rect = this.#node.getBoundingClientRect();
+        return (
+            event.clientX < rect.left ||
+            event.clientX > rect.right ||
+            event.clientY < rect.top ||
+            event.clientY > rect.bottom
+        );
+    }
+    positionPopover() {
+        let anchor = document.getElementById(this.#extraData.anchor);
+        if (fastn_utils.isNull(anchor)) {
+            return;
+        }
+        let anchorRect = anchor.getBoundingClientRect();
+        let rect = this.#node.getBoundingClientRect();
+        let top = anchorRect.bottom;
+        let left = anchorRect.left;
+        switch (this.#extraData.placement) {
+            case "top":
+                top = anchorRect.top - rect.height;
+                break;
+            case "left":
+                top = anchorRect.top;
+                left = anchorRect.left - rect.width;
+                break;
+            case "right":
+                top = anchorRect.top;
+                left = anchorRect.right;
+                break;
+        }
+        this.#node.style.top = `${Math.max(0, top)}px`;
+        this.#node.style.left = `${Math.max(0, left)}px`;
+    }
+    getFormFields() {
+        let fields = [];
+        for (let element of this.#node.elements) {
+            let field = fastn_dom.formNodes.get(element);
+            if (!fastn_utils.isNull(field) && !fields.includes(field)) {
+                fields.push(field);
+            }
+        }
+        return fields;
+    }
+    updateFormValidity() {
+        let valid = this.#extraData.valid;
+        if (ssr || !(valid instanceof fastn.mutableClass)) {
+            return;
+        }
+        let isValid = this.getFormFields().every((field) =>
+            field.isFieldValid(),
+        );
+        if (valid.get() !== isValid) {
+            valid.set(isValid);
+        }
+    }
+    // `$on-submit$` handlers only run when all the fields are valid.
+    // Otherwise every field is marked touched, so its error shows up, and
+    // the first invalid one is focused.
+    submitForm() {
+        let invalid = null;
+        for (let field of this.getFormFields()) {
+            field.touchField();
+            if (invalid === null && !field.isFieldValid()) {
+                invalid = field;
+            }
+        }
+        this.updateFormValidity();
+        if (invalid !== null) {
+            let control = invalid.getFieldControl();
+            if (!fastn_utils.isNull(control)) {
+                control.focus();
+            }
+            return;
+        }
+        // `ftd.http` calls made by the handlers send their server errors to
+        // the fields of this form only
+        fastn_dom.submittingForm = this;
+        try {
+            for (let handler of this.#extraData.submitHandlers || []) {
+                handler();
+            }
+        } finally {
+            fastn_dom.submittingForm = null;
+        }
+    }
+    // `ftd.table` rows are filtered, sorted and paged here. With
+    // `server-pagination` the rows are a single page already, and paging or
+    // sorting loads the page again with `page`, `sort` and `order` set in the
+    // query string, which the `sql` processor reads when it has `page-size`.
+    // With `page-param` these names are prefixed with it, `<page-param>-page`
+    // and so on.
+    renderTable() {
+        let data = this.#extraData.data;
+        let columns = this.#extraData.columns;
+        if (fastn_utils.isNull(data) || fastn_utils.isNull(columns)) {
+            return;
+        }
+        for (let cellNode of this.#extraData.cellNodes || []) {
+            cellNode.destroy();
+        }
+        this.#extraData.cellNodes = [];
+        this.#node.replaceChildren();
+
+        columns = columns.map((column) =>
+            fastn_utils.getStaticValue(column.item),
+        );
+        let rows = this.getTableRows(columns);
+        let server = !!this.#extraData.serverPagination;
+        let pageSize = this.#extraData.pageSize;
+        let page = this.#extraData.page || 1;
+        let pageCount = 1;
+        if (!fastn_utils.isNull(pageSize) && pageSize > 0) {
+            let total = server ? this.#extraData.total : rows.length;
+            if (fastn_utils.isNull(total)) {
+                total = rows.length;
+            }
+            pageCount = Math.max(1, Math.ceil(total / pageSize));
+            page = Math.min(Math.max(page, 1), pageCount);
+            if (!server) {
+                rows = rows.slice((page - 1) * pageSize, page * pageSize);
+            }
+        }
+        this.#extraData.page = page;
+
+        let sort = this.#extraData.sort;
+        let headerRow = this.createTableNode(
+            this.createTableNode(this.#node, "thead"),
+            "tr",
+        );
+        for (let column of columns) {
+            let th = this.createTableNode(headerRow, "th", { scope: "col" });
+            let width = fastn_utils.getStaticValue(column.get("width"));
+            if (!fastn_utils.isNull(width)) {
+                th.style["width"] = width;
+            }
+            let header = fastn_utils.escapeHtml(
+                fastn_utils.getStaticValue(column.get("header")),
+            );
+            let field = fastn_utils.getStaticValue(column.get("field"));
+            if (
+                fastn_utils.isNull(field) ||
+                !fastn_utils.getStaticValue(column.get("sortable"))
+            ) {
+                th.innerHTML = header;
+                continue;
+            }
+            let sorted = !fastn_utils.isNull(sort) && sort.field === field;
+            th.setAttribute(
+                "aria-sort",
+                !sorted ? "none" : sort.descending ? "descending" : "ascending",
+            );
+            let button = this.createTableNode(th, "button", { type: "button" });
+            button.innerHTML = header;
+            if (!ssr) {
+                button.onclick = () => this.sortTable(field);
+            }
+        }
+
+        let tbody = this.createTableNode(this.#node, "tbody");
+        for (let row of rows) {
+            let tr = this.createTableNode(tbody, "tr");
+            for (let column of columns) {
+                let td = this.createTableNode(tr, "td");
+                let cell = fastn_utils.getStaticValue(column.get("cell"));
+                if (fastn_utils.isNull(cell)) {
+                    td.innerHTML = fastn_utils.escapeHtml(
+                        this.getTableCellText(row, column),
+                    );
+                    continue;
+                }
+                this.#extraData.cellNodes.push(
+                    cell(td, this.#extraData.inherited, { row }),
+                );
+            }
+        }
+
+        if (fastn_utils.isNull(pageSize)) {
+            return;
+        }
+        let tfoot = this.createTableNode(this.#node, "tfoot");
+        let pagerCell = this.createTableNode(
+            this.createTableNode(tfoot, "tr"),
+            "td",
+            { colspan: columns.length },
+        );
+        let pager = this.createTableNode(pagerCell, "div", {}, [
+            fastn_dom.InternalClass.FT_TABLE_PAGER,
+        ]);
+        // `{page}` and `{pages}` in `page-label` are the current page and the
+        // number of pages
+        let pageLabel = this.#extraData.pageLabel;
+        if (!fastn_utils.isNull(pageLabel)) {
+            pageLabel = String(pageLabel)
+                .replace(/\{page\}/g, page)
+                .replace(/\{pages\}/g, pageCount);
+        }
+        for (let [label, target] of [
+            [this.#extraData.previousLabel, page - 1],
+            [pageLabel, null],
+            [this.#extraData.nextLabel, page + 1],
+        ]) {
+            label = fastn_utils.isNull(label)
+                ? ""
+                : fastn_utils.escapeHtml(String(label));
+            if (target === null) {
+                this.createTableNode(pager, "span").innerHTML = label;
+                continue;
+            }
+            let button = this.createTableNode(pager, "button", {
+                type: "button",
+            });
+            button.innerHTML = label;
+            if (target < 1 || target > pageCount) {
+                button.setAttribute("disabled", "");
+            } else if (!ssr) {
+                button.onclick = () => this.setTablePage(target);
+            }
+        }
+    }
+    createTableNode(parent, tagName, attributes = {}, classes = []) {
+        let [_, node] = fastn_utils.createNodeHelper(
+            tagName,
+            classes,
+            attributes,
+        );
+        parent.appendChild(node);
+        return node;
+    }
+    getTableRows(columns) {
+        let rows = this.#extraData.data.map((row) =>
+            fastn_utils.getStaticValue(row.item),
+        );
+        let filter = this.#extraData.filter;
+        if (!fastn_utils.isNull(filter) && filter !== "") {
+            filter = filter.toLowerCase();
+            rows = rows.filter((row) =>
+                columns.some((column) =>
+                    this.getTableCellText(row, column)
+                        .toLowerCase()
+                        .includes(filter),
+                ),
+            );
+        }
+        let sort = this.#extraData.sort;
+        if (fastn_utils.isNull(sort) || this.#extraData.serverPagination) {
+            return rows;
+        }
+        let column = columns.find(
+            (column) =>
+                fastn_utils.getStaticValue(column.get("field")) === sort.field,
+        );
+        if (fastn_utils.isNull(column)) {
+            return rows;
+        }
+        let compare = (a, b) => {
+            if (a === b) {
+                return 0;
+            } else if (fastn_utils.isNull(a)) {
+                return 1;
+            } else if (fastn_utils.isNull(b)) {
+                return -1;
+            } else if (typeof a === "number" && typeof b === "number") {
+                return a - b;
+            }
+            return String(a).localeCompare(String(b));
+        };
+        // `sort` is stable, so equal rows keep their order
+        return rows.sort(
+            (a, b) =>
+                compare(
+                    this.getTableCellValue(a, column),
+                    this.getTableCellValue(b, column),
+                ) * (sort.descending ? -1 : 1),
+        );
+    }
+    // A row that is not a record, e.g. in a `string list`, is its own value
+    getTableCellValue(row, column) {
+        if (!(row instanceof fastn.recordInstanceClass)) {
+            return row;
+        }
+        let field = fastn_utils.getStaticValue(column.get("field"));
+        if (fastn_utils.isNull(field)) {
+            return null;
+        }
+        return fastn_utils.getStaticValue(row.get(field.replace(/-/g, "_")));
+    }
+    getTableCellText(row, column) {
+        let value = this.getTableCellValue(row, column);
+        return fastn_utils.isNull(value) ? "" : String(value);
+    }
+    sortTable(field) {
+        let sort = this.#extraData.sort;
+        let descending =
+            !fastn_utils.isNull(sort) &&
+            sort.field === field &&
+            !sort.descending;
+        this.#extraData.sort = { field, descending };
+        if (this.#extraData.serverPagination) {
+            fastn_utils.navigateWithQuery(
+                this.getTableQuery({
+                    sort: field,
+                    order: descending ? "desc" : "asc",
+                    page: 1,
+                }),
+            );
+            return;
+        }
+        this.#extraData.page = 1;
+        this.renderTable();
+    }
+    setTablePage(page) {
+        if (this.#extraData.serverPagination) {
+            fastn_utils.navigateWithQuery(this.getTableQuery({ page }));
+            return;
+        }
+        this.#extraData.page = page;
+        this.renderTable();
+    }
+    getTableParam(name) {
+        let prefix = this.#extraData.pageParam;
+        return fastn_utils.isNull(prefix) ? name : `${prefix}-${name}`;
+    }
+    getTableQuery(params) {
+        let query = {};
+        for (let name in params) {
+            query[this.getTableParam(name)] = params[name];
+        }
+        return query;
+    }
     // for attaching inline attributes
     attachAttribute(property, value) {
         // If the value is null, undefined, or false, the attribute will be removed.
@@ -976,6 +1836,9 @@ class Node2 {
                 newElement[eventType] = eventListeners[eventType];
             }
             this.#parent.replaceChild(newElement, this.#node);
+            if (fastn_dom.formNodes.has(this.#node)) {
+                fastn_dom.formNodes.set(newElement, this);
+            }
             this.#node = newElement;
         }
     }
@@ -1649,6 +2512,41 @@ class Node2 {
         );
         this.#mutables.push(value);
     }
+    attachResponsiveCss(property, value, inherited, transform) {
+        if (fastn_utils.isNull(value)) {
+            this.attachCss(property, value);
+            return;
+        }
+        transform = transform || ((v) => v);
+        value.addClosure(
+            fastn
+                .closure(() => {
+                    let desktopValue = transform(
+                        fastn_utils.getStaticValue(value.get("desktop")),
+                    );
+                    let mobileValue = transform(
+                        fastn_utils.getStaticValue(value.get("mobile")),
+                    );
+                    if (desktopValue === mobileValue) {
+                        this.attachCss(property, desktopValue, true);
+                    } else {
+                        let desktopClass = this.attachCss(
+                            property,
+                            desktopValue,
+                            true,
+                        );
+                        this.attachCss(
+                            property,
+                            mobileValue,
+                            true,
+                            `body.mobile .${desktopClass}`,
+                        );
+                    }
+                })
+                .addNodeProperty(this, null, inherited),
+        );
+        this.#mutables.push(value);
+    }
     attachTextStyles(styles) {
         if (fastn_utils.isNull(styles)) {
             this.attachCss("font-style", styles);
@@ -1676,7 +2574,12 @@ class Node2 {
             this.attachCss("justify-content", value);
             return;
         }
-        if (node_kind === fastn_dom.ElementKind.Column) {
+        if (
+            node_kind === fastn_dom.ElementKind.Column ||
+            node_kind === fastn_dom.ElementKind.Form ||
+            node_kind === fastn_dom.ElementKind.Dialog ||
+            node_kind === fastn_dom.ElementKind.Popover
+        ) {
             switch (value) {
                 case "top-left":
                     this.attachCss("justify-content", "start");
@@ -2150,6 +3053,37 @@ class Node2 {
                     );
                     break;
             }
+        } else if (kind === fastn_dom.PropertyKind.GridTemplateColumns) {
+            this.attachResponsiveCss(
+                "grid-template-columns",
+                staticValue,
+                inherited,
+            );
+        } else if (kind === fastn_dom.PropertyKind.GridTemplateRows) {
+            this.attachResponsiveCss(
+                "grid-template-rows",
+                staticValue,
+                inherited,
+            );
+        } else if (kind === fastn_dom.PropertyKind.GridTemplateAreas) {
+            this.attachResponsiveCss(
+                "grid-template-areas",
+                staticValue,
+                inherited,
+                fastn_utils.getGridTemplateAreas,
+            );
+        } else if (kind === fastn_dom.PropertyKind.Gap) {
+            this.attachCss("gap", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.RowGap) {
+            this.attachCss("row-gap", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.ColumnGap) {
+            this.attachCss("column-gap", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.GridColumn) {
+            this.attachResponsiveCss("grid-column", staticValue, inherited);
+        } else if (kind === fastn_dom.PropertyKind.GridRow) {
+            this.attachResponsiveCss("grid-row", staticValue, inherited);
+        } else if (kind === fastn_dom.PropertyKind.GridArea) {
+            this.attachResponsiveCss("grid-area", staticValue, inherited);
         } else if (kind === fastn_dom.PropertyKind.Wrap) {
             // sticky is boolean type
             switch (staticValue) {
@@ -2271,16 +3205,195 @@ class Node2 {
         } else if (kind === fastn_dom.PropertyKind.TextInputValue) {
             this.#rawInnerValue = staticValue;
             this.updateTextInputValue();
+            this.validateField();
         } else if (kind === fastn_dom.PropertyKind.DefaultTextInputValue) {
             if (!fastn_utils.isNull(this.#rawInnerValue)) {
                 return;
             }
             this.#rawInnerValue = staticValue;
             this.updateTextInputValue();
+            this.validateField();
         } else if (kind === fastn_dom.PropertyKind.InputMaxLength) {
             this.attachAttribute("maxlength", staticValue);
+        } else if (
+            kind === fastn_dom.PropertyKind.Placeholder &&
+            this.#kind === fastn_dom.ElementKind.Select
+        ) {
+            this.#extraData.placeholder = staticValue;
+            this.updateFormOptions();
         } else if (kind === fastn_dom.PropertyKind.Placeholder) {
             this.attachAttribute("placeholder", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Options) {
+            this.#extraData.options = staticValue;
+            this.updateFormOptions();
+            this.validateField();
+        } else if (kind === fastn_dom.PropertyKind.FormValue) {
+            this.#extraData.value = staticValue;
+            if (
+                ssr &&
+                (this.#kind === fastn_dom.ElementKind.Select ||
+                    this.#kind === fastn_dom.ElementKind.RadioGroup)
+            ) {
+                this.updateFormOptions();
+            } else {
+                this.updateFormValue();
+            }
+            this.validateField();
+        } else if (kind === fastn_dom.PropertyKind.Min) {
+            this.attachAttribute("min", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Max) {
+            this.attachAttribute("max", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Step) {
+            this.attachAttribute("step", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Accept) {
+            this.attachAttribute("accept", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Multiple) {
+            this.attachAttribute("multiple", staticValue ? "" : null);
+        } else if (kind === fastn_dom.PropertyKind.Name) {
+            if (this.#kind === fastn_dom.ElementKind.RadioGroup) {
+                this.#extraData.name = staticValue;
+                this.updateFormOptions();
+            } else {
+                this.attachAttribute("name", staticValue);
+            }
+            this.registerField();
+        } else if (kind === fastn_dom.PropertyKind.AriaLabel) {
+            this.attachAttribute("aria-label", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Required) {
+            if (this.#kind === fastn_dom.ElementKind.RadioGroup) {
+                this.#extraData.required = staticValue;
+                this.updateFormOptions();
+            } else {
+                this.attachAttribute("required", staticValue ? "" : null);
+            }
+            this.registerField();
+            this.validateField();
+        } else if (kind === fastn_dom.PropertyKind.Pattern) {
+            this.attachAttribute("pattern", staticValue);
+            this.registerField();
+            this.validateField();
+        } else if (kind === fastn_dom.PropertyKind.MinLength) {
+            this.attachAttribute("minlength", staticValue);
+            this.registerField();
+            this.validateField();
+        } else if (kind === fastn_dom.PropertyKind.CustomError) {
+            this.#extraData.customError = staticValue;
+            this.registerField();
+            this.validateField();
+        } else if (kind === fastn_dom.PropertyKind.TableData) {
+            this.#extraData.data = staticValue;
+            this.#extraData.inherited = inherited;
+            this.renderTable();
+        } else if (kind === fastn_dom.PropertyKind.TableColumns) {
+            this.#extraData.columns = staticValue;
+            this.renderTable();
+        } else if (kind === fastn_dom.PropertyKind.TableFilter) {
+            this.#extraData.filter = staticValue;
+            if (!this.#extraData.serverPagination) {
+                this.#extraData.page = 1;
+            }
+            this.renderTable();
+        } else if (kind === fastn_dom.PropertyKind.TablePageSize) {
+            this.#extraData.pageSize = staticValue;
+            this.renderTable();
+        } else if (kind === fastn_dom.PropertyKind.TablePage) {
+            this.#extraData.page = staticValue;
+            this.renderTable();
+        } else if (kind === fastn_dom.PropertyKind.TableServerPagination) {
+            this.#extraData.serverPagination = staticValue;
+            if (staticValue && !ssr) {
+                // the sort the current page was loaded with
+                let query = new URL(window.location.href).searchParams;
+                let sort = query.get(this.getTableParam("sort"));
+                if (!fastn_utils.isNull(sort)) {
+                    this.#extraData.sort = {
+                        field: sort,
+                        descending:
+                            query.get(this.getTableParam("order")) === "desc",
+                    };
+                }
+            }
+            this.renderTable();
+        } else if (kind === fastn_dom.PropertyKind.TableTotal) {
+            this.#extraData.total = staticValue;
+            this.renderTable();
+        } else if (kind === fastn_dom.PropertyKind.TablePreviousLabel) {
+            this.#extraData.previousLabel = staticValue;
+            this.renderTable();
+        } else if (kind === fastn_dom.PropertyKind.TableNextLabel) {
+            this.#extraData.nextLabel = staticValue;
+            this.renderTable();
+        } else if (kind === fastn_dom.PropertyKind.TablePageLabel) {
+            this.#extraData.pageLabel = staticValue;
+            this.renderTable();
+        } else if (kind === fastn_dom.PropertyKind.TablePageParam) {
+            this.#extraData.pageParam = staticValue;
+        } else if (kind === fastn_dom.PropertyKind.TableStickyHeader) {
+            if (staticValue) {
+                this.#node.classList.add(
+                    fastn_dom.InternalClass.FT_TABLE_STICKY,
+                );
+            } else {
+                this.#node.classList.remove(
+                    fastn_dom.InternalClass.FT_TABLE_STICKY,
+                );
+            }
+        } else if (kind === fastn_dom.PropertyKind.SubscribeUrl) {
+            this.subscribe(staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Open) {
+            this.toggleOverlay(staticValue);
+        } else if (kind === fastn_dom.PropertyKind.Modal) {
+            this.#extraData.modal = staticValue;
+        } else if (kind === fastn_dom.PropertyKind.Dismissible) {
+            this.#extraData.dismissible = staticValue;
+        } else if (kind === fastn_dom.PropertyKind.PopoverAnchor) {
+            this.#extraData.anchor = staticValue;
+            if (this.#extraData.overlayShown) {
+                this.positionPopover();
+            }
+        } else if (kind === fastn_dom.PropertyKind.PopoverPlacement) {
+            this.#extraData.placement = staticValue;
+            if (this.#extraData.overlayShown) {
+                this.positionPopover();
+            }
+        } else if (kind === fastn_dom.PropertyKind.Transition) {
+            this.#extraData.transition = fastn_utils.isNull(staticValue)
+                ? undefined
+                : fastn_utils.getTransitionValue(staticValue);
+            this.attachCss("transition", this.#extraData.transition);
+            this.updateMotionClass();
+        } else if (kind === fastn_dom.PropertyKind.Animation) {
+            this.#extraData.animation = fastn_utils.getAnimationValue(
+                staticValue,
+                "none",
+            );
+            this.attachAnimation();
+        } else if (kind === fastn_dom.PropertyKind.EnterAnimation) {
+            this.#extraData.enterAnimation = fastn_utils.getAnimationValue(
+                staticValue,
+                "backwards",
+            );
+            this.attachAnimation();
+        } else if (kind === fastn_dom.PropertyKind.ExitAnimation) {
+            this.#extraData.exitAnimation = staticValue;
+        } else if (kind === fastn_dom.PropertyKind.DragData) {
+            let isNew = fastn_utils.isNull(this.#extraData.dragData);
+            this.#extraData.dragData = staticValue;
+            if (fastn_utils.isNull(staticValue)) {
+                this.attachAttribute("draggable", null);
+                return;
+            }
+            this.attachAttribute("draggable", "true");
+            if (isNew) {
+                this.#node.ondragstart = this.mergeFnCalls(
+                    this.#node.ondragstart,
+                    (e) =>
+                        e.dataTransfer.setData(
+                            "text/plain",
+                            this.#extraData.dragData,
+                        ),
+                );
+            }
         } else if (kind === fastn_dom.PropertyKind.Multiline) {
             switch (staticValue) {
                 case "true":
@@ -2325,7 +3438,8 @@ class Node2 {
             this.attachTextStyles(styles);
         } else if (kind === fastn_dom.PropertyKind.Region) {
             this.updateTagName(staticValue);
-            if (this.#node.innerHTML) {
+            // landmarks like `main` are not headings, they get no ids
+            if (/^h[1-6]$/.test(staticValue) && this.#node.innerHTML) {
                 this.#node.id = fastn_utils.slugify(this.#rawInnerValue);
             }
         } else if (kind === fastn_dom.PropertyKind.AlignContent) {
@@ -2372,6 +3486,14 @@ class Node2 {
             this.#mutables.push(ftd.dark_mode);
         } else if (kind === fastn_dom.PropertyKind.Alt) {
             this.attachAttribute("alt", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.AriaRole) {
+            this.attachAttribute("role", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.AriaDescribedBy) {
+            this.attachAttribute("aria-describedby", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.AriaLive) {
+            this.attachAttribute("aria-live", staticValue);
+        } else if (kind === fastn_dom.PropertyKind.TabIndex) {
+            this.attachAttribute("tabindex", staticValue);
         } else if (kind === fastn_dom.PropertyKind.VideoSrc) {
             ftd.dark_mode.addClosure(
                 fastn
@@ -2608,6 +3730,27 @@ class Node2 {
         }
     }
     setProperty(kind, value, inherited) {
+        if (kind === fastn_dom.PropertyKind.FormValue) {
+            this.bindFormValue(value);
+        }
+        // `$state` of a field and `$valid` of a form are written, not read
+        if (kind === fastn_dom.PropertyKind.FieldState) {
+            this.#extraData.fieldState = value;
+            this.registerField();
+            this.validateField();
+            return;
+        } else if (kind === fastn_dom.PropertyKind.Valid) {
+            this.#extraData.valid = value;
+            this.updateFormValidity();
+            return;
+        } else if (kind === fastn_dom.PropertyKind.SubscribeValue) {
+            // `$value` of `ftd.subscribe` is written by the stream
+            this.#extraData.subscribeValue = value;
+            return;
+        } else if (kind === fastn_dom.PropertyKind.Open) {
+            // set back to false when the overlay is closed by the browser
+            this.#extraData.open = value;
+        }
         if (value instanceof fastn.mutableClass) {
             this.setDynamicProperty(
                 kind,
@@ -2617,6 +3760,20 @@ class Node2 {
                 },
                 inherited,
             );
+        } else if (
+            value instanceof fastn.mutableListClass &&
+            (kind === fastn_dom.PropertyKind.FormValue ||
+                kind === fastn_dom.PropertyKind.TableData ||
+                kind === fastn_dom.PropertyKind.TableColumns)
+        ) {
+            this.setDynamicProperty(
+                kind,
+                [value],
+                () => {
+                    return value.getList();
+                },
+                inherited,
+            );
         } else if (value instanceof PropertyValueAsClosure) {
             this.setDynamicProperty(
                 kind,
@@ -2649,17 +3806,198 @@ class Node2 {
     getChildren() {
         return this.#children;
     }
+    // Enter and space click a focused element the way they click a button,
+    // elements like `a` and `button` already do it
+    clickOnKey(e) {
+        if (
+            e.target !== this.#node ||
+            (e.key !== "Enter" && e.key !== " ") ||
+            // the tag name changes for links and regions
+            ["input", "textarea", "select", "button", "a"].includes(
+                this.#node.tagName.toLowerCase(),
+            )
+        ) {
+            return;
+        }
+        e.preventDefault();
+        this.#node.click();
+    }
     mergeFnCalls(current, newFunc) {
-        return () => {
-            if (current instanceof Function) current();
-            if (newFunc instanceof Function) newFunc();
+        return (...args) => {
+            if (current instanceof Function) current(...args);
+            if (newFunc instanceof Function) newFunc(...args);
+        };
+    }
+    // Calls `func` with `$VALUE` of this node resolving to `value`
+    callWithEventValue(func, value) {
+        this.#extraData.eventValue = value;
+        try {
+            func();
+        } finally {
+            delete this.#extraData.eventValue;
+        }
+    }
+    observeVisibility(visible, func) {
+        if (ssr || typeof IntersectionObserver === "undefined") {
+            return;
+        }
+        let handlers = this.#extraData.visibilityHandlers;
+        if (fastn_utils.isNull(handlers)) {
+            handlers = { visible: [], hidden: [] };
+            this.#extraData.visibilityHandlers = handlers;
+            let isVisible = false;
+            this.#extraData.visibilityObserver = new IntersectionObserver(
+                (entries) => {
+                    for (let entry of entries) {
+                        if (entry.isIntersecting === isVisible) {
+                            continue;
+                        }
+                        isVisible = entry.isIntersecting;
+                        let key = isVisible ? "visible" : "hidden";
+                        handlers[key].forEach((handler) => handler());
+                    }
+                },
+            );
+            this.#extraData.visibilityObserver.observe(this.#node);
+        }
+        handlers[visible ? "visible" : "hidden"].push(func);
+    }
+    /**
+     * Opens the stream of `ftd.subscribe`: server sent events, or a WebSocket
+     * for `ws://` and `wss://` urls.
+     *
+     * @param {string} url
+     */
+    subscribe(url) {
+        this.closeSubscription();
+        if (ssr || fastn_utils.isNull(url)) {
+            return;
+        }
+        let subscription = { url, source: null, retries: 0, timer: null };
+        this.#extraData.subscription = subscription;
+        if (/^wss?:\/\//.test(url)) {
+            this.openWebSocket(subscription);
+            return;
+        }
+        let source = new EventSource(url);
+        source.onmessage = (event) => this.onSubscriptionMessage(event.data);
+        source.onerror = () => {
+            // EventSource reconnects by itself, unless the node is gone
+            if (!this.#node || !this.#node.isConnected) {
+                this.closeSubscription();
+            }
+        };
+        subscription.source = source;
+    }
+    openWebSocket(subscription) {
+        let socket = new WebSocket(subscription.url);
+        socket.onopen = () => {
+            subscription.retries = 0;
         };
+        socket.onmessage = (event) => this.onSubscriptionMessage(event.data);
+        socket.onclose = () => {
+            if (
+                this.#extraData.subscription !== subscription ||
+                !this.#node ||
+                !this.#node.isConnected
+            ) {
+                return;
+            }
+            // reconnect, waiting twice as long after every failure, up to
+            // 30 seconds
+            let delay = Math.min(1000 * 2 ** subscription.retries, 30000);
+            subscription.retries += 1;
+            subscription.timer = setTimeout(
+                () => this.openWebSocket(subscription),
+                delay,
+            );
+        };
+        subscription.source = socket;
+    }
+    /**
+     * Every message is a JSON encoded value, which replaces `$value`.
+     *
+     * @param {string} data
+     */
+    onSubscriptionMessage(data) {
+        if (!this.#node || !this.#node.isConnected) {
+            // the page moved on without destroying the node, e.g. after a
+            // client side navigation
+            this.closeSubscription();
+            return;
+        }
+        let value;
+        try {
+            value = JSON.parse(data);
+        } catch {
+            value = data;
+        }
+        if (value instanceof Object) {
+            value = fastn_utils.staticToMutables(value);
+        }
+        fastn_utils.setter(this.#extraData.subscribeValue, value);
+    }
+    closeSubscription() {
+        let subscription = this.#extraData.subscription;
+        if (fastn_utils.isNull(subscription)) {
+            return;
+        }
+        this.#extraData.subscription = null;
+        clearTimeout(subscription.timer);
+        if (!!subscription.source) {
+            subscription.source.close();
+        }
+    }
+    addSwipeHandler(direction, func) {
+        let handlers = this.#extraData.swipeHandlers;
+        if (fastn_utils.isNull(handlers)) {
+            handlers = { left: [], right: [], up: [], down: [] };
+            this.#extraData.swipeHandlers = handlers;
+            let start = null;
+            this.#node.ontouchstart = this.mergeFnCalls(
+                this.#node.ontouchstart,
+                (event) => {
+                    let touch = event.changedTouches[0];
+                    start = [touch.clientX, touch.clientY];
+                },
+            );
+            this.#node.ontouchend = this.mergeFnCalls(
+                this.#node.ontouchend,
+                (event) => {
+                    if (start === null) {
+                        return;
+                    }
+                    let touch = event.changedTouches[0];
+                    let dx = touch.clientX - start[0];
+                    let dy = touch.clientY - start[1];
+                    start = null;
+                    if (Math.max(Math.abs(dx), Math.abs(dy)) < 50) {
+                        return;
+                    }
+                    let swipe =
+                        Math.abs(dx) > Math.abs(dy)
+                            ? dx < 0
+                                ? "left"
+                                : "right"
+                            : dy < 0
+                              ? "up"
+                              : "down";
+                    handlers[swipe].forEach((handler) => handler());
+                },
+            );
+        }
+        handlers[direction].push(func);
     }
     addEventHandler(event, func) {
         if (event === fastn_dom.Event.Click) {
             let onclickEvents = this.mergeFnCalls(this.#node.onclick, func);
-            if (fastn_utils.isNull(this.#node.onclick))
+            if (fastn_utils.isNull(this.#node.onclick)) {
                 this.attachCss("cursor", "pointer");
+                this.#node.onkeydown = this.mergeFnCalls(
+                    this.#node.onkeydown,
+                    (e) => this.clickOnKey(e),
+                );
+            }
             this.#node.onclick = onclickEvents;
         } else if (event === fastn_dom.Event.MouseEnter) {
             let mouseEnterEvents = this.mergeFnCalls(
@@ -2694,17 +4032,86 @@ class Node2 {
         } else if (event === fastn_dom.Event.Focus) {
             let onFocusEvents = this.mergeFnCalls(this.#node.onfocus, func);
             this.#node.onfocus = onFocusEvents;
+        } else if (event === fastn_dom.Event.Submit) {
+            if (this.#kind === fastn_dom.ElementKind.Form) {
+                // called by `submitForm` once the fields are valid
+                this.#extraData.submitHandlers = (
+                    this.#extraData.submitHandlers || []
+                ).concat(func);
+            } else {
+                let onSubmitEvents = this.mergeFnCalls(
+                    this.#node.onsubmit,
+                    func,
+                );
+                this.#node.onsubmit = onSubmitEvents;
+            }
+        } else if (!!event[0] && event[0] === fastn_dom.Event.KeyDown()[0]) {
+            let keys = event[1];
+            if (
+                !["input", "textarea", "select", "button", "a"].includes(
+                    this.getTagName(),
+                ) &&
+                fastn_utils.isNull(this.#node.getAttribute("tabindex"))
+            ) {
+                // so the element can be focused and receive key events
+                this.attachAttribute("tabindex", "0");
+            }
+            this.#node.onkeydown = this.mergeFnCalls(
+                this.#node.onkeydown,
+                (e) => {
+                    if (fastn_utils.matchesKeys(e, keys)) {
+                        this.callWithEventValue(
+                            func,
+                            fastn_utils.getEventKey(e),
+                        );
+                    }
+                },
+            );
+        } else if (event === fastn_dom.Event.Scroll) {
+            let onScrollEvents = this.mergeFnCalls(this.#node.onscroll, func);
+            this.#node.onscroll = onScrollEvents;
+        } else if (event === fastn_dom.Event.Visible) {
+            this.observeVisibility(true, func);
+        } else if (event === fastn_dom.Event.Hidden) {
+            this.observeVisibility(false, func);
+        } else if (event === fastn_dom.Event.DragStart) {
+            this.attachAttribute("draggable", "true");
+            this.#node.ondragstart = this.mergeFnCalls(
+                this.#node.ondragstart,
+                () => this.callWithEventValue(func, this.#extraData.dragData),
+            );
+        } else if (event === fastn_dom.Event.Drop) {
+            // the browser only allows dropping if `dragover` is cancelled
+            this.#node.ondragover = (e) => e.preventDefault();
+            this.#node.ondrop = this.mergeFnCalls(this.#node.ondrop, (e) => {
+                e.preventDefault();
+                this.callWithEventValue(
+                    func,
+                    e.dataTransfer.getData("text/plain"),
+                );
+            });
+        } else if (!!event[0] && event[0] === fastn_dom.Event.Swipe()[0]) {
+            this.addSwipeHandler(event[1], func);
         }
     }
     destroy() {
         for (let i = 0; i < this.#mutables.length; i++) {
             this.#mutables[i].unlinkNode(this);
         }
+        if (!fastn_utils.isNull(this.#extraData.visibilityObserver)) {
+            this.#extraData.visibilityObserver.disconnect();
+        }
+        this.closeSubscription();
+        if (this.#extraData.overlayShown) {
+            // the overlay goes away with the node, `open` stays as it is
+            this.#extraData.open = null;
+            this.hideOverlay();
+        }
         // Todo: We don't need this condition as after destroying this node
         //  ConditionalDom reset this.#conditionUI to null or some different
         //  value. Not sure why this is still needed.
         if (!fastn_utils.isNull(this.#node)) {
-            this.#node.remove();
+            this.removeWithExitAnimation(this.#node);
         }
         this.#mutables = [];
         this.#parent = null;
@@ -2779,6 +4186,84 @@ class ConditionalDom {
     }
 }
 
+class MatchDom {
+    #marker;
+    #parent;
+    #caseIndex;
+    #value;
+    #caseUI;
+
+    constructor(parent, deps, value, select_case, cases) {
+        this.#marker = fastn_dom.createKernel(
+            parent,
+            fastn_dom.ElementKind.Comment,
+        );
+        this.#parent = parent;
+        this.#caseIndex = null;
+        this.#value = undefined;
+        this.#caseUI = null;
+
+        let closure = fastn.closure(() => {
+            let current = fastn_utils.getStaticValue(value());
+            let caseIndex = select_case(current);
+            if (caseIndex === this.#caseIndex && current === this.#value) {
+                return;
+            }
+            fastn_utils.resetFullHeight();
+            this.#destroyCaseUI();
+            this.#caseIndex = caseIndex;
+            this.#value = current;
+            if (caseIndex !== null) {
+                let item = fastn_utils.orTypePayload(current);
+                // Each child is placed after the previous one
+                let sibiling = this.#marker;
+                this.#caseUI = cases[caseIndex].map((node_constructor) => {
+                    let ui = node_constructor(
+                        new ParentNodeWithSibiling(this.#parent, sibiling),
+                        item,
+                    );
+                    if (
+                        !Array.isArray(ui) &&
+                        fastn_utils.isWrapperNode(ui.getTagName())
+                    ) {
+                        ui = ui.getChildren();
+                    }
+                    if (!Array.isArray(ui) || ui.length > 0) {
+                        sibiling = ui;
+                    }
+                    return ui;
+                });
+            }
+            fastn_utils.setFullHeight();
+        });
+        deps.forEach((dep) => {
+            if (!fastn_utils.isNull(dep) && dep.addClosure) {
+                dep.addClosure(closure);
+            }
+        });
+    }
+
+    #destroyCaseUI() {
+        if (!this.#caseUI) {
+            return;
+        }
+        let caseUI = fastn_utils.flattenArray(this.#caseUI);
+        while (caseUI.length > 0) {
+            let poppedElement = caseUI.pop();
+            poppedElement.destroy();
+        }
+        this.#caseUI = null;
+    }
+
+    getParent() {
+        let nodes = [this.#marker];
+        if (this.#caseUI) {
+            nodes.push(this.#caseUI);
+        }
+        return nodes;
+    }
+}
+
 fastn_dom.createKernel = function (parent, kind) {
     return new Node2(parent, kind);
 };
@@ -2792,6 +4277,10 @@ fastn_dom.conditionalDom = function (
     return new ConditionalDom(parent, deps, condition, node_constructor);
 };
 
+fastn_dom.matchDom = function (parent, deps, value, select_case, cases) {
+    return new MatchDom(parent, deps, value, select_case, cases);
+};
+
 class ParentNodeWithSibiling {
     #parent;
     #sibiling;
@@ -2843,7 +4332,7 @@ class ForLoop {
                 this.#nodes[index - 1],
             );
         }
-        let v = this.#list.get(index);
+        let v = this.#list.getList()[index];
         let node = this.#node_constructor(parentWithSibiling, v.item, v.index);
         this.#nodes.splice(index, 0, node);
         if (resizeBodyHeight) {
@@ -2887,3 +4376,27 @@ class ForLoop {
 fastn_dom.forLoop = function (parent, node_constructor, list) {
     return new ForLoop(parent, node_constructor, list);
 };
+
+// Errors returned by the server for a submitted form, keyed by the `name` of
+// the field they belong to. Only the fields of that form get the error, other
+// forms on the page may have fields with the same name.
+fastn_dom.setServerError = function (form, name, message) {
+    if (ssr || fastn_utils.isNull(form)) {
+        return;
+    }
+    for (let element of form.getNode().elements) {
+        if (element.name !== name) {
+            continue;
+        }
+        let field = fastn_dom.formNodes.get(element);
+        if (fastn_utils.isNull(field)) {
+            // radio buttons belong to the `ftd.radio` fieldset
+            field = fastn_dom.formNodes.get(
+                element.closest(`.${fastn_dom.InternalClass.FT_RADIO_GROUP}`),
+            );
+        }
+        if (!fastn_utils.isNull(field)) {
+            field.setServerError(message);
+        }
+    }
+};
@@ -70,6 +70,7 @@ const fastn = (function (fastn) {
                 !fastn_utils.isNull(key) &&
                 (this.#value instanceof RecordInstance ||
                     this.#value instanceof MutableList ||
+                    this.#value instanceof MutableMap ||
                     this.#value instanceof Mutable)
             ) {
                 return this.#value.get(key);
@@ -168,6 +169,7 @@ const fastn = (function (fastn) {
                 !!key &&
                 (this.#cached_value instanceof RecordInstance ||
                     this.#cached_value instanceof MutableList ||
+                    this.#cached_value instanceof MutableMap ||
                     this.#cached_value instanceof Mutable)
             ) {
                 return this.#cached_value.get(key);
@@ -303,6 +305,95 @@ const fastn = (function (fastn) {
         }
     }
 
+    /**
+     * Keys are kept in insertion order, the loop counter of a `for` over a
+     * map is the key of the entry.
+     */
+    class MutableMap {
+        #entries;
+        #watchers;
+        #closures;
+        constructor(obj) {
+            this.#entries = [];
+            for (let key in obj) {
+                this.#entries.push({
+                    item: fastn.wrapMutable(obj[key]),
+                    index: new Mutable(key),
+                });
+            }
+            this.#watchers = [];
+            this.#closures = [];
+        }
+        addClosure(closure) {
+            this.#closures.push(closure);
+        }
+        unlinkNode(node) {
+            this.#closures = this.#closures.filter(
+                (closure) => closure.getNode() !== node,
+            );
+        }
+        forLoop(root, dom_constructor) {
+            let l = fastn_dom.forLoop(root, dom_constructor, this);
+            this.#watchers.push(l);
+            return l;
+        }
+        getList() {
+            return this.#entries;
+        }
+        getLength() {
+            return this.#entries.length;
+        }
+        getEntry(key) {
+            key = `${fastn_utils.getFlattenStaticValue(key)}`;
+            // references to a key are generated with the js name of the key
+            return (
+                this.#entries.find((entry) => entry.index.get() === key) ??
+                this.#entries.find(
+                    (entry) => fastn_utils.nameToJs(entry.index.get()) === key,
+                )
+            );
+        }
+        get(key) {
+            if (fastn_utils.isNull(key)) {
+                return this.getList();
+            }
+            return this.getEntry(key)?.item;
+        }
+        set(key, value) {
+            if (value === undefined) {
+                value = key;
+                if (!(value instanceof MutableMap)) {
+                    value = new MutableMap(value);
+                }
+                this.#entries = [...value.#entries];
+                for (let i in this.#watchers) {
+                    this.#watchers[i].createAllNode();
+                }
+            } else {
+                let entry = this.getEntry(key);
+                if (entry) {
+                    entry.item.set(value);
+                } else {
+                    this.#entries.push({
+                        item: fastn.wrapMutable(value),
+                        index: new Mutable(fastn_utils.getFlattenStaticValue(key)),
+                    });
+                    for (let i in this.#watchers) {
+                        this.#watchers[i].createNode(this.#entries.length - 1);
+                    }
+                }
+            }
+            this.#closures.forEach((closure) => closure.update());
+        }
+        getClone() {
+            let obj = {};
+            for (let entry of this.#entries) {
+                obj[entry.index.get()] = fastn_utils.clone(entry.item);
+            }
+            return new MutableMap(obj);
+        }
+    }
+
     fastn.mutable = function (val) {
         return new Mutable(val);
     };
@@ -341,7 +432,8 @@ const fastn = (function (fastn) {
         if (
             !(obj instanceof Mutable) &&
             !(obj instanceof RecordInstance) &&
-            !(obj instanceof MutableList)
+            !(obj instanceof MutableList) &&
+            !(obj instanceof MutableMap)
         ) {
             obj = new Mutable(obj);
         }
@@ -352,6 +444,10 @@ const fastn = (function (fastn) {
         return new MutableList(list);
     };
 
+    fastn.mutableMap = function (obj) {
+        return new MutableMap(obj);
+    };
+
     class RecordInstance {
         #fields;
         #closures;
@@ -378,7 +474,8 @@ const fastn = (function (fastn) {
                 if (
                     field_value instanceof fastn.recordInstanceClass ||
                     field_value instanceof fastn.mutableClass ||
-                    field_value instanceof fastn.mutableListClass
+                    field_value instanceof fastn.mutableListClass ||
+                    field_value instanceof fastn.mutableMapClass
                 ) {
                     clonedFields[key] = this.#fields[key].getClone();
                 } else {
@@ -458,6 +555,32 @@ const fastn = (function (fastn) {
         }
     }
 
+    // A value of an or-type variant that carries a value, like `shape.circle`.
+    // Constant variants are kept as their plain values.
+    class OrTypeValue {
+        #variant;
+        #value;
+        constructor(variant, value) {
+            this.#variant = variant;
+            this.#value = value;
+        }
+
+        getVariant() {
+            return this.#variant;
+        }
+
+        getValue() {
+            return this.#value;
+        }
+
+        getClone() {
+            return new OrTypeValue(
+                this.#variant,
+                fastn_utils.clone(this.#value),
+            );
+        }
+    }
+
     class Module {
         #name;
         #global;
@@ -479,13 +602,21 @@ const fastn = (function (fastn) {
         return new RecordInstance(obj);
     };
 
+    fastn.orTypeValue = function (variant) {
+        return function (value) {
+            return new OrTypeValue(variant, value);
+        };
+    };
+
     fastn.color = function (r, g, b) {
         return `rgb(${r},${g},${b})`;
     };
 
     fastn.mutableClass = Mutable;
     fastn.mutableListClass = MutableList;
+    fastn.mutableMapClass = MutableMap;
     fastn.recordInstanceClass = RecordInstance;
+    fastn.orTypeValueClass = OrTypeValue;
     fastn.module = function (name, global) {
         return new Module(name, global);
     };
@@ -54,6 +54,71 @@ const ftd = (function () {
         );
     };
 
+    // Submits the `ftd.form` the calling element is in, e.g. from the
+    // `$on-click$` of a button inside the form.
+    exports.submit_form = (args, node) => {
+        let form = node.getNode().closest("form");
+        if (!fastn_utils.isNull(form)) {
+            form.requestSubmit();
+        }
+    };
+
+    // Toasts wait here while `maxToasts` of them are already on screen
+    const toastQueue = [];
+    const maxToasts = 3;
+    let toastContainer = null;
+    let visibleToasts = 0;
+
+    // Shows `message` in the corner of the page for `duration` milliseconds
+    // (4 seconds by default), and announces it to screen readers.
+    exports.show_toast = (args) => {
+        let duration = fastn_utils.getStaticValue(args.duration);
+        toastQueue.push([
+            fastn_utils.getStaticValue(args.message),
+            fastn_utils.isNull(duration) ? 4000 : duration,
+        ]);
+        showNextToast();
+    };
+
+    function showNextToast() {
+        if (visibleToasts >= maxToasts || toastQueue.length === 0) {
+            return;
+        }
+        if (toastContainer === null) {
+            toastContainer = document.createElement("div");
+            toastContainer.className = fastn_dom.InternalClass.FT_TOASTS;
+            toastContainer.setAttribute("role", "status");
+            toastContainer.setAttribute("aria-live", "polite");
+            // in the top layer, so toasts show above open modal dialogs
+            toastContainer.setAttribute("popover", "manual");
+            document.body.appendChild(toastContainer);
+        }
+        let [message, duration] = toastQueue.shift();
+        let toast = document.createElement("div");
+        toast.className = fastn_dom.InternalClass.FT_TOAST;
+        toast.textContent = message;
+        toastContainer.appendChild(toast);
+        visibleToasts += 1;
+        if (toastContainer.showPopover instanceof Function) {
+            // shown again to move above dialogs opened since the last toast
+            if (toastContainer.matches(":popover-open")) {
+                toastContainer.hidePopover();
+            }
+            toastContainer.showPopover();
+        }
+        setTimeout(() => {
+            toast.remove();
+            visibleToasts -= 1;
+            if (
+                visibleToasts === 0 &&
+                toastContainer.hidePopover instanceof Function
+            ) {
+                toastContainer.hidePopover();
+            }
+            showNextToast();
+        }, duration);
+    }
+
     // Todo: Implement this (Remove highlighter)
     exports.clean_code = (args) => args.a;
 
@@ -203,7 +268,12 @@ const ftd = (function () {
 
         if (url instanceof fastn.mutableClass) url = url.get();
         method = method.trim().toUpperCase();
+        // set while an `ftd.form` is being submitted, the errors in the
+        // response belong to its fields
+        let form = fastn_dom.submittingForm;
         let request_json = {};
+        // files picked in `ftd.file-input`, sent as multipart/form-data
+        let request_files = {};
 
         const init = {
             method,
@@ -231,6 +301,11 @@ const ftd = (function () {
                     for (let [header, value] of Object.entries(body)) {
                         let [key, val] =
                             value.length === 2 ? value : [header, value];
+                        let files = fastn_utils.getUploadedFiles(val);
+                        if (!fastn_utils.isNull(files)) {
+                            request_files[key] = files;
+                            continue;
+                        }
                         new_json[key] = fastn_utils.getStaticValue(val);
                     }
                     json = new_json;
@@ -239,7 +314,27 @@ const ftd = (function () {
             }
         }
 
-        init.body = JSON.stringify(request_json);
+        if (Object.keys(request_files).length !== 0) {
+            // the browser sets the content type along with the boundary
+            delete init.headers["Content-Type"];
+            let form_data = new FormData();
+            for (let [key, value] of Object.entries(request_json)) {
+                form_data.append(
+                    key,
+                    typeof value === "object" && value !== null
+                        ? JSON.stringify(value)
+                        : value,
+                );
+            }
+            for (let [key, files] of Object.entries(request_files)) {
+                for (let file of files) {
+                    form_data.append(key, file);
+                }
+            }
+            init.body = form_data;
+        } else {
+            init.body = JSON.stringify(request_json);
+        }
 
         let json;
         fetch(url, init)
@@ -270,6 +365,7 @@ const ftd = (function () {
                                 // django returns a list of strings
                                 value = value.join(" ");
                             }
+                            fastn_dom.setServerError(form, key, value);
                             // also django does not append `-error`
                             key = key + "-error";
                             key = fastn_module + "#" + key;
@@ -308,12 +404,230 @@ const ftd = (function () {
         }
         let query_string = query_parameters.toString();
         if (query_string) {
-            window.location.href = url + "?" + query_parameters.toString();
+            url = url + "?" + query_parameters.toString();
+        }
+        if (spa.enabled && isSpaUrl(new URL(url, window.location.href))) {
+            spaVisit(url, { push: true, scroll: 0 });
         } else {
             window.location.href = url;
         }
     };
 
+    // Client side navigation, enabled by `spa: true` in the package
+    // declaration. Same-origin page links are fetched with the `x-fastn-spa`
+    // header, the server answers with the compiled JS of the page, which is
+    // rendered in place of the current page. Variables start afresh, like on
+    // a full load, except the ones marked `$persist$: true` (say the cart in
+    // a `store.ftd` imported by every page), which keep their values.
+    const spa = {
+        enabled: false,
+        // JS names of the `$persist$` variables of the page being rendered
+        persist: new Set(),
+        rendering: false,
+        controller: null,
+        scrollTimer: null,
+    };
+
+    exports.spa_init = function () {
+        if (typeof __fastn_spa__ === "undefined" || !__fastn_spa__) {
+            return;
+        }
+        spa.enabled = true;
+        if ("scrollRestoration" in history) {
+            history.scrollRestoration = "manual";
+        }
+        history.replaceState({ scroll: window.scrollY }, "");
+        document.addEventListener("click", onSpaLinkClick);
+        window.addEventListener("popstate", function (event) {
+            let scroll = !!event.state ? event.state.scroll : 0;
+            spaVisit(window.location.href, { push: false, scroll });
+        });
+        window.addEventListener(
+            "scroll",
+            function () {
+                clearTimeout(spa.scrollTimer);
+                spa.scrollTimer = setTimeout(saveSpaScroll, 100);
+            },
+            { passive: true },
+        );
+    };
+
+    /**
+     * Whether the variable `path`, being created by the page the router is
+     * rendering, already exists and has to keep its value.
+     *
+     * @param {string} path - JS name of the variable
+     * @returns {boolean}
+     */
+    exports.spa_keeps = function (path) {
+        if (!spa.rendering) {
+            return false;
+        }
+        let name = fastn_utils.private.addUnderscoreToStart(
+            path.split(".")[0],
+        );
+        return spa.persist.has(name) && global.hasOwnProperty(name);
+    };
+
+    function saveSpaScroll() {
+        history.replaceState({ ...history.state, scroll: window.scrollY }, "");
+    }
+
+    function isSpaUrl(url) {
+        if (url.origin !== window.location.origin) {
+            return false;
+        }
+        // static files are not pages
+        let last = url.pathname.split("/").pop();
+        return !last.includes(".") || last.endsWith(".ftd");
+    }
+
+    function onSpaLinkClick(event) {
+        if (
+            event.defaultPrevented ||
+            event.button !== 0 ||
+            event.metaKey ||
+            event.ctrlKey ||
+            event.shiftKey ||
+            event.altKey
+        ) {
+            return;
+        }
+        let link = event.target.closest && event.target.closest("a[href]");
+        if (
+            !link ||
+            (!!link.target && link.target !== "_self") ||
+            link.hasAttribute("download") ||
+            link.getAttribute("rel") === "external"
+        ) {
+            return;
+        }
+        let url = new URL(link.href, window.location.href);
+        if (!isSpaUrl(url)) {
+            return;
+        }
+        let current = window.location;
+        if (
+            !!url.hash &&
+            url.pathname === current.pathname &&
+            url.search === current.search
+        ) {
+            // in-page anchor, the browser scrolls to it
+            return;
+        }
+        event.preventDefault();
+        spaVisit(url.href, { push: true, scroll: 0 });
+    }
+
+    async function spaVisit(url, options) {
+        if (options.push) {
+            saveSpaScroll();
+        }
+        if (!!spa.controller) {
+            spa.controller.abort();
+        }
+        let controller = new AbortController();
+        spa.controller = controller;
+        let page;
+        try {
+            let response = await fetch(url, {
+                headers: { "x-fastn-spa": "true" },
+                signal: controller.signal,
+            });
+            let contentType = response.headers.get("content-type") || "";
+            if (!response.ok || !contentType.includes("application/json")) {
+                throw new Error("not a fastn page: " + response.status);
+            }
+            page = await response.json();
+            // redirects are followed by fetch, show the url we landed on
+            url = response.url || url;
+        } catch (error) {
+            if (error.name === "AbortError") {
+                return;
+            }
+            // error pages, pages of other packages, redirects to other
+            // origins: let the browser load them
+            window.location.href = url;
+            return;
+        }
+        if (spa.controller !== controller) {
+            return;
+        }
+        spa.controller = null;
+        if (options.push) {
+            history.pushState({ scroll: 0 }, "", url);
+        }
+        renderSpaPage(page);
+        let hash = new URL(url, window.location.href).hash;
+        let target = !!hash
+            ? document.getElementById(decodeURIComponent(hash.slice(1)))
+            : null;
+        if (!!target) {
+            target.scrollIntoView();
+        } else {
+            window.scrollTo(0, options.scroll);
+        }
+    }
+
+    function renderSpaPage(page) {
+        addSpaScripts(page.scripts);
+        ftd.clickOutsideEvents = [];
+        ftd.globalKeyEvents = [];
+        ftd.globalKeySeqEvents = [];
+        onLoadListeners.clear();
+        fastnLoaded = false;
+
+        spa.persist = new Set(page.persist || []);
+        spa.rendering = true;
+        try {
+            // `main` of the page, `inherited` is the one of the default
+            // bag, as in `ftd-js.html`
+            let main = new Function(`${page.js}\nreturn main;`)();
+            fastnVirtual.doubleBuffer(function (parent) {
+                let parenti0 = fastn_dom.createKernel(
+                    parent,
+                    fastn_dom.ElementKind.Column,
+                );
+                parenti0.setProperty(
+                    fastn_dom.PropertyKind.Width,
+                    fastn_dom.Resizing.FillContainer,
+                    inherited,
+                );
+                parenti0.setProperty(
+                    fastn_dom.PropertyKind.Height,
+                    fastn_dom.Resizing.FillContainer,
+                    inherited,
+                );
+                main(parenti0);
+            });
+        } finally {
+            spa.rendering = false;
+        }
+        fastn_utils.resetFullHeight();
+        fastn_utils.setFullHeight();
+        exports.emit_on_load();
+    }
+
+    function addSpaScripts(scripts) {
+        let template = document.createElement("template");
+        template.innerHTML = scripts;
+        for (let element of Array.from(template.content.children)) {
+            let attribute = element.tagName === "LINK" ? "href" : "src";
+            let value = element.getAttribute(attribute);
+            let selector = `${element.tagName}[${attribute}="${value}"]`;
+            if (!value || !!document.head.querySelector(selector)) {
+                continue;
+            }
+            let node = element;
+            if (element.tagName === "SCRIPT") {
+                // scripts parsed via `innerHTML` are not executed
+                node = document.createElement("script");
+                node.src = value;
+            }
+            document.head.appendChild(node);
+        }
+    }
+
     exports.toggle_dark_mode = function () {
         const is_dark_mode = exports.get(exports.dark_mode);
         if (is_dark_mode) {
@@ -475,6 +789,170 @@ const ftd = (function () {
         }
     };
 
+    // Date and time functions ----------------------------------------------
+    // `datetime` and `date` values are ISO-8601 strings, `duration` is seconds.
+    // `Intl` is used when available, otherwise (e.g. during server side
+    // rendering) the output matches `ftd::interpreter::datetime` in English.
+    const MONTHS = [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ];
+    const WEEKDAYS = [
+        "Sunday",
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+    ];
+
+    function hasIntl() {
+        return !ssr && typeof Intl !== "undefined";
+    }
+
+    function defaultLocale(locale) {
+        locale = fastn_utils.getStaticValue(locale);
+        if (!fastn_utils.isNull(locale)) {
+            return locale;
+        }
+        if (!ssr && document.documentElement.lang) {
+            return document.documentElement.lang;
+        }
+        return "en-US";
+    }
+
+    function toDate(value) {
+        value = fastn_utils.getStaticValue(value);
+        if (/^\d{4}-\d{2}-\d{2}$/.test(value)) {
+            value = `${value}T00:00:00Z`;
+        }
+        return new Date(value);
+    }
+
+    // `+05:30` style offsets in minutes, `null` for named time zones
+    function offsetInMinutes(timezone) {
+        if (fastn_utils.isNull(timezone) || timezone.toUpperCase() === "UTC") {
+            return 0;
+        }
+        let match = /^([+-])(\d{2}):?(\d{2})?$/.exec(timezone);
+        if (!match) {
+            return null;
+        }
+        let minutes = parseInt(match[2]) * 60 + parseInt(match[3] || "0");
+        return match[1] === "-" ? -minutes : minutes;
+    }
+
+    function fallbackFormatDate(date, style) {
+        let [year, month, day] = [
+            date.getUTCFullYear(),
+            date.getUTCMonth(),
+            date.getUTCDate(),
+        ];
+        switch (style) {
+            case "short":
+                return `${month + 1}/${day}/${String(year % 100).padStart(2, "0")}`;
+            case "long":
+                return `${MONTHS[month]} ${day}, ${year}`;
+            case "full":
+                return `${WEEKDAYS[date.getUTCDay()]}, ${MONTHS[month]} ${day}, ${year}`;
+            default:
+                return `${MONTHS[month].substring(0, 3)} ${day}, ${year}`;
+        }
+    }
+
+    exports.format_datetime = function (args) {
+        let date = toDate(args.d);
+        let style = fastn_utils.getStaticValue(args.style);
+        let timezone = fastn_utils.getStaticValue(args.timezone);
+        if (style === "iso") {
+            return date.toISOString().replace(/\.\d{3}Z$/, "Z");
+        }
+        let offset = offsetInMinutes(timezone);
+        if (offset !== null) {
+            // Offsets are applied here, `Intl` only knows named time zones
+            date = new Date(date.getTime() + offset * 60 * 1000);
+            timezone = "UTC";
+        }
+        if (hasIntl()) {
+            let options = { dateStyle: style, timeStyle: "short" };
+            if (!fastn_utils.isNull(timezone)) {
+                options.timeZone = timezone;
+            }
+            return new Intl.DateTimeFormat(
+                defaultLocale(args.locale),
+                options,
+            ).format(date);
+        }
+        let [hour, minute] = [date.getUTCHours(), date.getUTCMinutes()];
+        return `${fallbackFormatDate(date, style)}, ${hour % 12 || 12}:${String(
+            minute,
+        ).padStart(2, "0")} ${hour < 12 ? "AM" : "PM"}`;
+    };
+
+    exports.format_date = function (args) {
+        let date = toDate(args.d);
+        let style = fastn_utils.getStaticValue(args.style);
+        if (style === "iso") {
+            return date.toISOString().substring(0, 10);
+        }
+        if (hasIntl()) {
+            return new Intl.DateTimeFormat(defaultLocale(args.locale), {
+                dateStyle: style,
+                timeZone: "UTC",
+            }).format(date);
+        }
+        return fallbackFormatDate(date, style);
+    };
+
+    exports.relative_time = function (args) {
+        let seconds = Math.round((toDate(args.d).getTime() - Date.now()) / 1000);
+        let abs = Math.abs(seconds);
+        let [value, unit] =
+            abs < 60
+                ? [abs, "second"]
+                : abs < 3600
+                ? [Math.floor(abs / 60), "minute"]
+                : abs < 86400
+                ? [Math.floor(abs / 3600), "hour"]
+                : abs < 7 * 86400
+                ? [Math.floor(abs / 86400), "day"]
+                : abs < 30 * 86400
+                ? [Math.floor(abs / (7 * 86400)), "week"]
+                : abs < 365 * 86400
+                ? [Math.floor(abs / (30 * 86400)), "month"]
+                : [Math.floor(abs / (365 * 86400)), "year"];
+        if (hasIntl() && Intl.RelativeTimeFormat) {
+            return new Intl.RelativeTimeFormat(defaultLocale(args.locale), {
+                numeric: "always",
+            }).format(seconds < 0 ? -value : value, unit);
+        }
+        let units = value === 1 ? unit : `${unit}s`;
+        return seconds < 0 ? `${value} ${units} ago` : `in ${value} ${units}`;
+    };
+
+    exports.add_duration = function (args) {
+        let date = toDate(args.d);
+        let by = fastn_utils.getStaticValue(args.by);
+        return new Date(date.getTime() + by * 1000)
+            .toISOString()
+            .replace(/\.\d{3}Z$/, "Z");
+    };
+
+    exports.diff = function (args) {
+        return Math.round((toDate(args.a).getTime() - toDate(args.b).getTime()) / 1000);
+    };
+
     // Language related functions ---------------------------------------------
     exports.set_current_language = function (language) {
         language = fastn_utils.getStaticValue(language);
@@ -248,6 +248,7 @@ ftd.post_init = function () {
     initialise_dark_mode();
     initialise_click_outside_events();
     initialise_global_key_events();
+    ftd.spa_init();
     fastn_utils.resetFullHeight();
     fastn_utils.setFullHeight();
 };
@@ -10,6 +10,8 @@ let fastn_utils = {
             css.push(fastn_dom.InternalClass.FT_FULL_SIZE);
         } else if (kind === fastn_dom.ElementKind.Row) {
             css.push(fastn_dom.InternalClass.FT_ROW);
+        } else if (kind === fastn_dom.ElementKind.Grid) {
+            css.push(fastn_dom.InternalClass.FT_GRID);
         } else if (kind === fastn_dom.ElementKind.IFrame) {
             node = "iframe";
             // To allow fullscreen support
@@ -31,6 +33,37 @@ let fastn_utils = {
             attributes["type"] = "checkbox";
         } else if (kind === fastn_dom.ElementKind.TextInput) {
             node = "input";
+        } else if (kind === fastn_dom.ElementKind.Select) {
+            node = "select";
+        } else if (kind === fastn_dom.ElementKind.RadioGroup) {
+            node = "fieldset";
+            css.push(fastn_dom.InternalClass.FT_RADIO_GROUP);
+            attributes["role"] = "radiogroup";
+        } else if (kind === fastn_dom.ElementKind.Slider) {
+            node = "input";
+            attributes["type"] = "range";
+        } else if (kind === fastn_dom.ElementKind.FileInput) {
+            node = "input";
+            attributes["type"] = "file";
+        } else if (kind === fastn_dom.ElementKind.Table) {
+            node = "table";
+            css.push(fastn_dom.InternalClass.FT_TABLE);
+        } else if (kind === fastn_dom.ElementKind.Form) {
+            node = "form";
+            css.push(fastn_dom.InternalClass.FT_COLUMN);
+            // the fields are validated by `ftd.form` itself
+            attributes["novalidate"] = "";
+        } else if (kind === fastn_dom.ElementKind.Dialog) {
+            node = "dialog";
+            css.push(fastn_dom.InternalClass.FT_COLUMN);
+            css.push(fastn_dom.InternalClass.FT_DIALOG);
+        } else if (kind === fastn_dom.ElementKind.Popover) {
+            css.push(fastn_dom.InternalClass.FT_COLUMN);
+            css.push(fastn_dom.InternalClass.FT_POPOVER);
+            attributes["popover"] = "auto";
+        } else if (kind === fastn_dom.ElementKind.Subscribe) {
+            // `ftd.subscribe` renders nothing
+            node = "template";
         } else if (kind === fastn_dom.ElementKind.Comment) {
             node = fastn_dom.commentNode;
         } else if (kind === fastn_dom.ElementKind.Wrapper) {
@@ -128,6 +161,7 @@ let fastn_utils = {
         if (
             !(obj instanceof fastn.mutableClass) &&
             !(obj instanceof fastn.mutableListClass) &&
+            !(obj instanceof fastn.mutableMapClass) &&
             !(obj instanceof fastn.recordInstanceClass)
         ) {
             if (Array.isArray(obj)) {
@@ -151,6 +185,16 @@ let fastn_utils = {
     },
     getFlattenStaticValue(obj) {
         let staticValue = fastn_utils.getStaticValue(obj);
+        if (staticValue instanceof fastn.mutableMapClass) {
+            return Object.fromEntries(
+                staticValue
+                    .getList()
+                    .map((entry) => [
+                        entry.index.get(),
+                        fastn_utils.getFlattenStaticValue(entry.item),
+                    ]),
+            );
+        }
         if (Array.isArray(staticValue)) {
             return staticValue.map((func) =>
                 fastn_utils.getFlattenStaticValue(func.item),
@@ -166,6 +210,20 @@ let fastn_utils = {
         }*/
         return staticValue;
     },
+    // Same as `fastn_js::utils::name_to_js_`
+    nameToJs(name) {
+        name = `${name}`;
+        if (/^[0-9]/.test(name)) {
+            name = `_${name}`;
+        }
+        return name
+            .replace(/#/g, "__")
+            .replace(/-/g, "_")
+            .replace(/:/g, "___")
+            .replace(/,/g, "$")
+            .replace(/\\/g, "/")
+            .replace(/[/.]/g, "_");
+    },
     getter(value) {
         if (value instanceof fastn.mutableClass) {
             return value.get();
@@ -173,11 +231,23 @@ let fastn_utils = {
             return value;
         }
     },
+    // Items of a list for looping in user defined functions
+    getListItems(value) {
+        value = fastn_utils.getter(value);
+        if (value instanceof fastn.mutableListClass) {
+            return value.getList().map((obj) => fastn_utils.getter(obj.item));
+        }
+        if (Array.isArray(value)) {
+            return value;
+        }
+        return [];
+    },
     // Todo: Merge getterByKey with getter
     getterByKey(value, index) {
         if (
             value instanceof fastn.mutableClass ||
-            value instanceof fastn.recordInstanceClass
+            value instanceof fastn.recordInstanceClass ||
+            value instanceof fastn.mutableMapClass
         ) {
             return value.get(index);
         } else if (value instanceof fastn.mutableListClass) {
@@ -205,6 +275,17 @@ let fastn_utils = {
             desktop.get("weight") === mobile.get("weight")
         );
     },
+    // `template-areas` rows can be written as `a a | b c`, each row is quoted
+    // here. Values that already contain quotes are passed through as is.
+    getGridTemplateAreas(value) {
+        if (fastn_utils.isNull(value) || value.includes('"')) {
+            return value;
+        }
+        return value
+            .split("|")
+            .map((row) => `"${row.trim()}"`)
+            .join(" ");
+    },
     getRoleValues(value) {
         let font_families = fastn_utils.getStaticValue(
             value.get("font_family"),
@@ -229,15 +310,31 @@ let fastn_utils = {
         }
         if (
             value instanceof fastn.mutableClass ||
-            value instanceof fastn.mutableListClass
+            value instanceof fastn.mutableListClass ||
+            value instanceof fastn.mutableMapClass
         ) {
             return value.getClone();
         }
-        if (value instanceof fastn.recordInstanceClass) {
+        if (
+            value instanceof fastn.recordInstanceClass ||
+            value instanceof fastn.orTypeValueClass
+        ) {
             return value.getClone();
         }
         return value;
     },
+    isOrTypeVariant(value, variant) {
+        return (
+            value instanceof fastn.orTypeValueClass &&
+            value.getVariant() === variant
+        );
+    },
+    orTypePayload(value) {
+        if (value instanceof fastn.orTypeValueClass) {
+            return value.getValue();
+        }
+        return value;
+    },
     getListItem(value) {
         if (value === undefined) {
             return null;
@@ -254,7 +351,87 @@ let fastn_utils = {
             return event.key;
         }
     },
+    // `keys` of a `key-down` event, e.g. `["Control", "s"]`: the last one is
+    // the key pressed and the others are the modifiers held down with it
+    matchesKeys(event, keys) {
+        if (keys.length === 0) {
+            return true;
+        }
+        let modifiers = {
+            Control: event.ctrlKey,
+            Alt: event.altKey,
+            Shift: event.shiftKey,
+            Meta: event.metaKey,
+        };
+        let key = keys[keys.length - 1];
+        return (
+            fastn_utils.getEventKey(event) === key &&
+            Object.entries(modifiers).every(
+                ([name, pressed]) =>
+                    name === key || !!pressed === keys.includes(name),
+            )
+        );
+    },
+    // The CSS `transition` for an `ftd.transition`
+    getTransitionValue(transition) {
+        let property = fastn_utils.getStaticValue(transition.get("property"));
+        let duration = fastn_utils.getStaticValue(transition.get("duration"));
+        let delay = fastn_utils.getStaticValue(transition.get("delay"));
+        let easing = fastn_utils.getStaticValue(transition.get("easing"));
+        return `${property} ${duration}ms ${easing || "ease"} ${delay}ms`;
+    },
+    // The CSS `animation` for an `ftd.animation`, its keyframes are added as
+    // `@keyframes` along with the other generated classes
+    getAnimationValue(animation, fillMode) {
+        if (fastn_utils.isNull(animation)) {
+            return undefined;
+        }
+        let rules = fastn_utils
+            .getStaticValue(animation.get("keyframes"))
+            .map((keyframe) => {
+                keyframe = fastn_utils.getStaticValue(keyframe.item);
+                let opacity = fastn_utils.getStaticValue(
+                    keyframe.get("opacity"),
+                );
+                let transform = fastn_utils.getStaticValue(
+                    keyframe.get("transform"),
+                );
+                return `${fastn_utils.getStaticValue(keyframe.get("at"))}% {${
+                    fastn_utils.isNull(opacity) ? "" : ` opacity: ${opacity};`
+                }${
+                    fastn_utils.isNull(transform)
+                        ? ""
+                        : ` transform: ${transform};`
+                } }`;
+            })
+            .join(" ");
+        let name = fastn_dom.getKeyframesName(rules);
+        let duration = fastn_utils.getStaticValue(animation.get("duration"));
+        let delay = fastn_utils.getStaticValue(animation.get("delay"));
+        let easing = fastn_utils.getStaticValue(animation.get("easing"));
+        let repeat = fastn_utils.getStaticValue(animation.get("repeat"));
+        return `${name} ${duration}ms ${easing || "ease"} ${delay}ms ${
+            repeat ? "infinite" : 1
+        } ${fillMode}`;
+    },
+    prefersReducedMotion() {
+        return (
+            !ssr &&
+            window.matchMedia instanceof Function &&
+            window.matchMedia("(prefers-reduced-motion: reduce)").matches
+        );
+    },
+    // The page can't scroll while any modal `ftd.dialog` is open
+    lockScroll(lock) {
+        fastn_dom.scrollLocks += lock ? 1 : -1;
+        document.body.style.overflow =
+            fastn_dom.scrollLocks > 0 ? "hidden" : "";
+    },
     createNestedObject(currentObject, path, value) {
+        if (currentObject === global && ftd.spa_keeps(path)) {
+            // a `$persist$` variable, kept across client side navigations
+            return;
+        }
         const properties = path.split(".");
 
         for (let i = 0; i < properties.length - 1; i++) {
@@ -454,6 +631,13 @@ let fastn_utils = {
         return result;
     },
     getNodeValue(node) {
+        let eventValue = node.getExtraData().eventValue;
+        if (eventValue !== undefined) {
+            return eventValue;
+        }
+        if (node.getNode().tagName === "FIELDSET") {
+            return node.getFormValue();
+        }
         return node.getNode().value;
     },
     setFullHeight() {
@@ -495,8 +679,10 @@ let fastn_utils = {
             onmouseleave: node.onmouseleave,
             onmouseenter: node.onmouseenter,
             oninput: node.oninput,
+            onchange: node.onchange,
             onblur: node.onblur,
             onfocus: node.onfocus,
+            onkeydown: node.onkeydown,
         };
     },
 
@@ -519,6 +705,39 @@ let fastn_utils = {
             })
             .join("");
     },
+    escapeHtml(str) {
+        if (typeof str !== "string") {
+            return str;
+        }
+        return str
+            .replace(/&/g, "&amp;")
+            .replace(/</g, "&lt;")
+            .replace(/>/g, "&gt;")
+            .replace(/"/g, "&quot;");
+    },
+    // The `File`s held by an `ftd.file` record or a list of them, `null` if
+    // `value` holds no file
+    getUploadedFiles(value) {
+        if (value instanceof fastn.mutableClass) {
+            value = value.get();
+        }
+        let items =
+            value instanceof fastn.mutableListClass
+                ? value.getList().map((obj) => obj.item)
+                : [value];
+        let files = items
+            .map((item) => fastn_dom.uploadedFiles.get(item))
+            .filter((file) => !fastn_utils.isNull(file));
+        return files.length === 0 ? null : files;
+    },
+    // Loads the current page again with `params` set in its query string
+    navigateWithQuery(params) {
+        let url = new URL(window.location.href);
+        for (let [key, value] of Object.entries(params)) {
+            url.searchParams.set(key, value);
+        }
+        window.location.href = url.toString();
+    },
     escapeHtmlInCode(str) {
         return str.replace(/[<]/g, "&lt;");
     },
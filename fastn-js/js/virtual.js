@@ -50,6 +50,11 @@ class Node {
         return this.#children;
     }
 
+    replaceChildren() {
+        this.#children = [];
+        this.innerHTML = "";
+    }
+
     setAttribute(attribute, value) {
         this.#attributes[attribute] = value;
     }
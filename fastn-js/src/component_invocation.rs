@@ -25,6 +25,7 @@ pub enum ElementKind {
     Row,
     Column,
     ContainerElement,
+    Grid,
     Integer,
     Decimal,
     Boolean,
@@ -35,6 +36,15 @@ pub enum ElementKind {
     Device,
     CheckBox,
     TextInput,
+    Select,
+    RadioGroup,
+    Slider,
+    FileInput,
+    Form,
+    Dialog,
+    Popover,
+    Subscribe,
+    Table,
     Rive,
     Document,
     Code,
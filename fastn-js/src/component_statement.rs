@@ -10,6 +10,7 @@ pub enum ComponentStatement {
         component_name: String,
     },
     ConditionalComponent(fastn_js::ConditionalComponent),
+    MatchComponent(fastn_js::MatchComponent),
     MutableList(fastn_js::MutableList),
     ForLoop(fastn_js::ForLoop),
     RecordInstance(fastn_js::RecordInstance),
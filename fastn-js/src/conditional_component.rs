@@ -6,3 +6,29 @@ pub struct ConditionalComponent {
     pub parent: String,
     pub should_return: bool,
 }
+
+/// `fastn_dom.matchDom`, renders the statements of the case the value's variant selects
+#[derive(Debug)]
+pub struct MatchComponent {
+    pub deps: Vec<String>,
+    pub value: fastn_js::SetPropertyValue,
+    pub cases: Vec<MatchCase>,
+    pub parent: String,
+    pub should_return: bool,
+}
+
+#[derive(Debug)]
+pub struct MatchCase {
+    /// `None` matches any value
+    pub variant: Option<MatchVariant>,
+    /// The statements of every child, each returns the child it creates
+    pub children: Vec<Vec<fastn_js::ComponentStatement>>,
+}
+
+#[derive(Debug)]
+pub enum MatchVariant {
+    /// A variant with a value, created by `fastn.orTypeValue`
+    Tagged(String),
+    /// A constant variant, compared by its value
+    Constant(fastn_js::SetPropertyValue),
+}
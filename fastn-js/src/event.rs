@@ -17,6 +17,14 @@ pub enum Event {
     Change,
     Blur,
     Focus,
+    Submit,
+    KeyDown(Vec<String>),
+    Scroll,
+    Visible,
+    Hidden,
+    DragStart,
+    Drop,
+    Swipe(String),
 }
 
 #[derive(Debug)]
@@ -26,7 +26,7 @@ pub use component_invocation::{
     ElementKind, InstantiateComponent, InstantiateComponentData, Kernel,
 };
 pub use component_statement::ComponentStatement;
-pub use conditional_component::ConditionalComponent;
+pub use conditional_component::{ConditionalComponent, MatchCase, MatchComponent, MatchVariant};
 pub use constants::*;
 pub use device::{DeviceBlock, DeviceType};
 pub use event::{Event, EventHandler, Function, FunctionData};
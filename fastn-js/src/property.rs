@@ -198,6 +198,9 @@ pub enum Value {
     List {
         value: Vec<SetPropertyValue>,
     },
+    Map {
+        value: Vec<(String, SetPropertyValue)>,
+    },
     Record {
         fields: Vec<(String, SetPropertyValue)>,
         other_references: Vec<String>,
@@ -238,6 +241,17 @@ impl Value {
                     .map(|v| v.to_js_with_element_name(element_name))
                     .join(", ")
             ),
+            Value::Map { value } => format!(
+                "fastn.mutableMap({{{}}})",
+                value
+                    .iter()
+                    .map(|(k, v)| format!(
+                        "{}: {}",
+                        Value::String(k.to_string()).to_js(element_name),
+                        v.to_js_with_element_name(element_name)
+                    ))
+                    .join(", ")
+            ),
             Value::Record {
                 fields,
                 other_references,
@@ -295,6 +309,7 @@ impl Value {
                 .map(|v| v.is_local_value_dependent())
                 .unwrap_or_default(),
             Value::List { value } => value.iter().any(|v| v.is_local_value_dependent()),
+            Value::Map { value } => value.iter().any(|v| v.1.is_local_value_dependent()),
             Value::Record { fields, .. } => fields.iter().any(|v| v.1.is_local_value_dependent()),
             Value::UI { .. } => {
                 //Todo: Check for UI
@@ -376,6 +391,15 @@ pub enum PropertyKind {
     OverflowY,
     Spacing,
     Wrap,
+    GridTemplateColumns,
+    GridTemplateRows,
+    GridTemplateAreas,
+    Gap,
+    RowGap,
+    ColumnGap,
+    GridColumn,
+    GridRow,
+    GridArea,
     TextTransform,
     TextIndent,
     TextAlign,
@@ -400,6 +424,49 @@ pub enum PropertyKind {
     InputMaxLength,
     TextInputValue,
     DefaultTextInputValue,
+    FormValue,
+    Options,
+    Min,
+    Max,
+    Step,
+    Accept,
+    Multiple,
+    Name,
+    AriaLabel,
+    Required,
+    Pattern,
+    MinLength,
+    CustomError,
+    FieldState,
+    Valid,
+    TableData,
+    TableColumns,
+    TableFilter,
+    TablePageSize,
+    TablePage,
+    TableServerPagination,
+    TableTotal,
+    TableStickyHeader,
+    TablePreviousLabel,
+    TableNextLabel,
+    TablePageLabel,
+    TablePageParam,
+    DragData,
+    Open,
+    Modal,
+    Dismissible,
+    PopoverAnchor,
+    PopoverPlacement,
+    Transition,
+    Animation,
+    EnterAnimation,
+    ExitAnimation,
+    SubscribeUrl,
+    SubscribeValue,
+    AriaRole,
+    AriaDescribedBy,
+    AriaLive,
+    TabIndex,
     Loading,
     Alt,
     Src,
@@ -502,6 +569,15 @@ impl PropertyKind {
             PropertyKind::OverflowX => "fastn_dom.PropertyKind.OverflowX",
             PropertyKind::OverflowY => "fastn_dom.PropertyKind.OverflowY",
             PropertyKind::Spacing => "fastn_dom.PropertyKind.Spacing",
+            PropertyKind::GridTemplateColumns => "fastn_dom.PropertyKind.GridTemplateColumns",
+            PropertyKind::GridTemplateRows => "fastn_dom.PropertyKind.GridTemplateRows",
+            PropertyKind::GridTemplateAreas => "fastn_dom.PropertyKind.GridTemplateAreas",
+            PropertyKind::Gap => "fastn_dom.PropertyKind.Gap",
+            PropertyKind::RowGap => "fastn_dom.PropertyKind.RowGap",
+            PropertyKind::ColumnGap => "fastn_dom.PropertyKind.ColumnGap",
+            PropertyKind::GridColumn => "fastn_dom.PropertyKind.GridColumn",
+            PropertyKind::GridRow => "fastn_dom.PropertyKind.GridRow",
+            PropertyKind::GridArea => "fastn_dom.PropertyKind.GridArea",
             PropertyKind::Wrap => "fastn_dom.PropertyKind.Wrap",
             PropertyKind::TextTransform => "fastn_dom.PropertyKind.TextTransform",
             PropertyKind::TextIndent => "fastn_dom.PropertyKind.TextIndent",
@@ -531,6 +607,49 @@ impl PropertyKind {
             PropertyKind::InputMaxLength => "fastn_dom.PropertyKind.InputMaxLength",
             PropertyKind::TextInputValue => "fastn_dom.PropertyKind.TextInputValue",
             PropertyKind::DefaultTextInputValue => "fastn_dom.PropertyKind.DefaultTextInputValue",
+            PropertyKind::FormValue => "fastn_dom.PropertyKind.FormValue",
+            PropertyKind::Options => "fastn_dom.PropertyKind.Options",
+            PropertyKind::Min => "fastn_dom.PropertyKind.Min",
+            PropertyKind::Max => "fastn_dom.PropertyKind.Max",
+            PropertyKind::Step => "fastn_dom.PropertyKind.Step",
+            PropertyKind::Accept => "fastn_dom.PropertyKind.Accept",
+            PropertyKind::Multiple => "fastn_dom.PropertyKind.Multiple",
+            PropertyKind::Name => "fastn_dom.PropertyKind.Name",
+            PropertyKind::AriaLabel => "fastn_dom.PropertyKind.AriaLabel",
+            PropertyKind::Required => "fastn_dom.PropertyKind.Required",
+            PropertyKind::Pattern => "fastn_dom.PropertyKind.Pattern",
+            PropertyKind::MinLength => "fastn_dom.PropertyKind.MinLength",
+            PropertyKind::CustomError => "fastn_dom.PropertyKind.CustomError",
+            PropertyKind::FieldState => "fastn_dom.PropertyKind.FieldState",
+            PropertyKind::Valid => "fastn_dom.PropertyKind.Valid",
+            PropertyKind::TableData => "fastn_dom.PropertyKind.TableData",
+            PropertyKind::TableColumns => "fastn_dom.PropertyKind.TableColumns",
+            PropertyKind::TableFilter => "fastn_dom.PropertyKind.TableFilter",
+            PropertyKind::TablePageSize => "fastn_dom.PropertyKind.TablePageSize",
+            PropertyKind::TablePage => "fastn_dom.PropertyKind.TablePage",
+            PropertyKind::TableServerPagination => "fastn_dom.PropertyKind.TableServerPagination",
+            PropertyKind::TableTotal => "fastn_dom.PropertyKind.TableTotal",
+            PropertyKind::TableStickyHeader => "fastn_dom.PropertyKind.TableStickyHeader",
+            PropertyKind::TablePreviousLabel => "fastn_dom.PropertyKind.TablePreviousLabel",
+            PropertyKind::TableNextLabel => "fastn_dom.PropertyKind.TableNextLabel",
+            PropertyKind::TablePageLabel => "fastn_dom.PropertyKind.TablePageLabel",
+            PropertyKind::TablePageParam => "fastn_dom.PropertyKind.TablePageParam",
+            PropertyKind::DragData => "fastn_dom.PropertyKind.DragData",
+            PropertyKind::Open => "fastn_dom.PropertyKind.Open",
+            PropertyKind::Modal => "fastn_dom.PropertyKind.Modal",
+            PropertyKind::Dismissible => "fastn_dom.PropertyKind.Dismissible",
+            PropertyKind::PopoverAnchor => "fastn_dom.PropertyKind.PopoverAnchor",
+            PropertyKind::PopoverPlacement => "fastn_dom.PropertyKind.PopoverPlacement",
+            PropertyKind::Transition => "fastn_dom.PropertyKind.Transition",
+            PropertyKind::Animation => "fastn_dom.PropertyKind.Animation",
+            PropertyKind::EnterAnimation => "fastn_dom.PropertyKind.EnterAnimation",
+            PropertyKind::ExitAnimation => "fastn_dom.PropertyKind.ExitAnimation",
+            PropertyKind::SubscribeUrl => "fastn_dom.PropertyKind.SubscribeUrl",
+            PropertyKind::SubscribeValue => "fastn_dom.PropertyKind.SubscribeValue",
+            PropertyKind::AriaRole => "fastn_dom.PropertyKind.AriaRole",
+            PropertyKind::AriaDescribedBy => "fastn_dom.PropertyKind.AriaDescribedBy",
+            PropertyKind::AriaLive => "fastn_dom.PropertyKind.AriaLive",
+            PropertyKind::TabIndex => "fastn_dom.PropertyKind.TabIndex",
             PropertyKind::Loading => "fastn_dom.PropertyKind.Loading",
             PropertyKind::Src => "fastn_dom.PropertyKind.Src",
             PropertyKind::ImageSrc => "fastn_dom.PropertyKind.ImageSrc",
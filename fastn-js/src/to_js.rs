@@ -128,6 +128,25 @@ impl fastn_js::Event {
             fastn_js::Event::Change => text("fastn_dom.Event.Change"),
             fastn_js::Event::Blur => text("fastn_dom.Event.Blur"),
             fastn_js::Event::Focus => text("fastn_dom.Event.Focus"),
+            fastn_js::Event::Submit => text("fastn_dom.Event.Submit"),
+            fastn_js::Event::KeyDown(keys) => text(
+                format!(
+                    "fastn_dom.Event.KeyDown([{}])",
+                    keys.iter()
+                        .map(|v| format!("\"{}\"", v))
+                        .collect_vec()
+                        .join(", ")
+                )
+                .as_str(),
+            ),
+            fastn_js::Event::Scroll => text("fastn_dom.Event.Scroll"),
+            fastn_js::Event::Visible => text("fastn_dom.Event.Visible"),
+            fastn_js::Event::Hidden => text("fastn_dom.Event.Hidden"),
+            fastn_js::Event::DragStart => text("fastn_dom.Event.DragStart"),
+            fastn_js::Event::Drop => text("fastn_dom.Event.Drop"),
+            fastn_js::Event::Swipe(direction) => {
+                text(format!("fastn_dom.Event.Swipe(\"{}\")", direction).as_str())
+            }
         }
     }
 }
@@ -180,6 +199,7 @@ impl fastn_js::ElementKind {
                 "fastn_dom.ElementKind.ContainerElement".to_string()
             }
             fastn_js::ElementKind::Column => "fastn_dom.ElementKind.Column".to_string(),
+            fastn_js::ElementKind::Grid => "fastn_dom.ElementKind.Grid".to_string(),
             fastn_js::ElementKind::Integer => "fastn_dom.ElementKind.Integer".to_string(),
             fastn_js::ElementKind::Decimal => "fastn_dom.ElementKind.Decimal".to_string(),
             fastn_js::ElementKind::Boolean => "fastn_dom.ElementKind.Boolean".to_string(),
@@ -190,6 +210,15 @@ impl fastn_js::ElementKind {
             fastn_js::ElementKind::Device => "fastn_dom.ElementKind.Wrapper".to_string(),
             fastn_js::ElementKind::CheckBox => "fastn_dom.ElementKind.CheckBox".to_string(),
             fastn_js::ElementKind::TextInput => "fastn_dom.ElementKind.TextInput".to_string(),
+            fastn_js::ElementKind::Select => "fastn_dom.ElementKind.Select".to_string(),
+            fastn_js::ElementKind::RadioGroup => "fastn_dom.ElementKind.RadioGroup".to_string(),
+            fastn_js::ElementKind::Slider => "fastn_dom.ElementKind.Slider".to_string(),
+            fastn_js::ElementKind::FileInput => "fastn_dom.ElementKind.FileInput".to_string(),
+            fastn_js::ElementKind::Form => "fastn_dom.ElementKind.Form".to_string(),
+            fastn_js::ElementKind::Dialog => "fastn_dom.ElementKind.Dialog".to_string(),
+            fastn_js::ElementKind::Popover => "fastn_dom.ElementKind.Popover".to_string(),
+            fastn_js::ElementKind::Subscribe => "fastn_dom.ElementKind.Subscribe".to_string(),
+            fastn_js::ElementKind::Table => "fastn_dom.ElementKind.Table".to_string(),
             fastn_js::ElementKind::Rive => "fastn_dom.ElementKind.Rive".to_string(),
             fastn_js::ElementKind::Document => "fastn_dom.ElementKind.Document".to_string(),
             fastn_js::ElementKind::Code => "fastn_dom.ElementKind.Code".to_string(),
@@ -226,6 +255,7 @@ impl fastn_js::ComponentStatement {
                 text(&format!("return {component_name};"))
             }
             fastn_js::ComponentStatement::ConditionalComponent(c) => c.to_js(),
+            fastn_js::ComponentStatement::MatchComponent(m) => m.to_js(),
             fastn_js::ComponentStatement::MutableList(ml) => ml.to_js(),
             fastn_js::ComponentStatement::ForLoop(fl) => fl.to_js(),
             fastn_js::ComponentStatement::RecordInstance(ri) => ri.to_js(),
@@ -396,6 +426,91 @@ impl fastn_js::ConditionalComponent {
     }
 }
 
+impl fastn_js::MatchComponent {
+    pub fn to_js(&self) -> pretty::RcDoc<'static> {
+        text(
+            format!(
+                "{}fastn_dom.matchDom(",
+                if self.should_return { "return " } else { "" }
+            )
+            .as_str(),
+        )
+        .append(text(self.parent.as_str()))
+        .append(comma())
+        .append(space())
+        .append(text("["))
+        .append(
+            pretty::RcDoc::intersperse(
+                self.deps
+                    .iter()
+                    .map(|v| text(fastn_js::utils::reference_to_js(v).as_str())),
+                comma().append(space()),
+            )
+            .group(),
+        )
+        .append(text("]"))
+        .append(comma())
+        .append(space())
+        .append(text(
+            format!("function () {{return {};}},", self.value.to_js()).as_str(),
+        ))
+        .append(text("function (value) {"))
+        .append(pretty::RcDoc::intersperse(
+            self.cases.iter().enumerate().map(|(index, case)| {
+                text(
+                    match &case.variant {
+                        Some(fastn_js::MatchVariant::Tagged(variant)) => format!(
+                            "if (fastn_utils.isOrTypeVariant(value, \"{variant}\")) {{return {index};}}"
+                        ),
+                        Some(fastn_js::MatchVariant::Constant(value)) => format!(
+                            "if (value == {}) {{return {index};}}",
+                            value.to_js()
+                        ),
+                        None => format!("return {index};"),
+                    }
+                    .as_str(),
+                )
+            }),
+            pretty::RcDoc::softline(),
+        ))
+        .append(pretty::RcDoc::softline())
+        .append(text("return null;},"))
+        .append(text("["))
+        .append(pretty::RcDoc::intersperse(
+            self.cases.iter().map(|case| {
+                text("[")
+                    .append(pretty::RcDoc::intersperse(
+                        case.children.iter().map(|statements| {
+                            text("function (root, item) {")
+                                .append(
+                                    pretty::RcDoc::intersperse(
+                                        statements.iter().map(|v| v.to_js()),
+                                        pretty::RcDoc::softline(),
+                                    )
+                                    .group(),
+                                )
+                                .append(text("}"))
+                        }),
+                        comma().append(space()),
+                    ))
+                    .append(text("]"))
+            }),
+            comma().append(space()),
+        ))
+        .append(text(
+            format!(
+                "]){};",
+                if self.should_return {
+                    ".getParent()"
+                } else {
+                    ""
+                }
+            )
+            .as_str(),
+        ))
+    }
+}
+
 impl fastn_js::ForLoop {
     pub fn to_js(&self) -> pretty::RcDoc<'static> {
         text(
@@ -730,8 +845,6 @@ impl fastn_js::DeviceType {
 
 impl fastn_js::UDF {
     pub fn to_js(&self, package_name: &str) -> pretty::RcDoc<'static> {
-        use itertools::Itertools;
-
         let body = text("let")
             .append(space())
             .append(text(fastn_js::LOCAL_VARIABLE_MAP))
@@ -757,25 +870,7 @@ impl fastn_js::UDF {
             .append(text("}"))
             .append(format!(", {});", fastn_js::FUNCTION_ARGS))
             .append(pretty::RcDoc::intersperse(
-                self.body.iter().map(|f| {
-                    pretty::RcDoc::text(
-                        fastn_js::to_js::ExpressionGenerator.to_js_(
-                            f,
-                            true,
-                            self.args
-                                .iter()
-                                .map(|v| {
-                                    (
-                                        v.0.to_string(),
-                                        Some(fastn_js::LOCAL_VARIABLE_MAP.to_string()),
-                                    )
-                                })
-                                .collect_vec()
-                                .as_slice(),
-                            false,
-                        ),
-                    )
-                }),
+                self.body_to_js().into_iter().map(pretty::RcDoc::text),
                 pretty::RcDoc::softline(),
             ));
 
@@ -787,99 +882,83 @@ impl fastn_js::UDF {
             self.is_external_js_present,
         )
     }
-}
 
-/*fn binary(op: &str, left: &UDFStatement, right: &UDFStatement) -> pretty::RcDoc<'static> {
-    left.to_js()
-        .append(space())
-        .append(text(op))
-        .append(space())
-        .append(right.to_js())
+    fn body_to_js(&self) -> Vec<String> {
+        use itertools::Itertools;
+
+        // Locals live next to the arguments so that reads and writes go through the same
+        // getters and setters.
+        let arguments = self
+            .args
+            .iter()
+            .map(|v| v.0.to_string())
+            .chain(fastn_js::UDFStatement::local_variables(&self.body))
+            .map(|v| (v, Some(fastn_js::LOCAL_VARIABLE_MAP.to_string())))
+            .collect_vec();
+
+        match self.body.as_slice() {
+            // A plain expression body: its value is returned
+            [fastn_js::UDFStatement::Expression { value }] => {
+                vec![ExpressionGenerator.to_js_(value, true, arguments.as_slice(), false)]
+            }
+            body => body
+                .iter()
+                .map(|statement| statement_to_js(statement, arguments.as_slice()))
+                .collect_vec(),
+        }
+    }
 }
 
-impl UDFStatement {
-    fn to_js(&self) -> pretty::RcDoc<'static> {
-        match self {
-            UDFStatement::Integer { value } => text(&value.to_string()),
-            UDFStatement::Decimal { value } => text(&value.to_string()),
-            UDFStatement::Boolean { value } => text(&value.to_string()),
-            UDFStatement::String { value } => quote(value.as_str()),
-            UDFStatement::Return { value } => text("return")
-                .append(space())
-                .append(value.to_js())
-                .append(text(";")),
-            UDFStatement::VariableDeclaration { name, value } => text("let")
-                .append(space())
-                .append(text(name.as_str()))
-                .append(space())
-                .append(text("="))
-                .append(space())
-                .append(value.to_js())
-                .append(text(";")),
-            UDFStatement::VariableAssignment { name, value } => text(name.as_str())
-                .append(space())
-                .append(text("="))
-                .append(space())
-                .append(value.to_js())
-                .append(text(";")),
-            UDFStatement::Addition { left, right } => binary("+", left, right),
-            UDFStatement::Subtraction { left, right } => binary("-", left, right),
-            UDFStatement::Multiplication { left, right } => binary("*", left, right),
-            UDFStatement::Division { left, right } => binary("/", left, right),
-            UDFStatement::Exponentiation { left, right } => binary("**", left, right),
-            UDFStatement::And { left, right } => binary("&&", left, right),
-            UDFStatement::Or { left, right } => binary("||", left, right),
-            UDFStatement::Not { value } => text("!").append(value.to_js()),
-            UDFStatement::Parens { value } => text("(").append(value.to_js()).append(text(")")),
-            UDFStatement::Variable { name } => text(name.as_str()),
-            UDFStatement::Ternary {
-                condition,
-                then,
-                otherwise,
-            } => condition
-                .to_js()
-                .append(space())
-                .append(text("?"))
-                .append(space())
-                .append(then.to_js())
-                .append(space())
-                .append(text(":"))
-                .append(space())
-                .append(otherwise.to_js()),
-            UDFStatement::If {
-                condition,
-                then,
-                otherwise,
-            } => text("if")
-                .append(space())
-                .append(text("("))
-                .append(condition.to_js())
-                .append(text(")"))
-                .append(space())
-                .append(text("{"))
-                .append(then.to_js())
-                .append(text("}"))
-                .append(space())
-                .append(text("else"))
-                .append(space())
-                .append(text("{"))
-                .append(otherwise.to_js())
-                .append(text("}")),
-            UDFStatement::Call { name, args } => text(name.as_str())
-                .append(text("("))
-                .append(
-                    pretty::RcDoc::intersperse(
-                        args.iter().map(|f| f.to_js()),
-                        comma().append(space()),
-                    )
-                    .group(),
-                )
-                .append(text(")")),
-            UDFStatement::Block { .. } => todo!(),
+fn statement_to_js(
+    statement: &fastn_js::UDFStatement,
+    arguments: &[(String, Option<String>)],
+) -> String {
+    use itertools::Itertools;
+
+    let expression = |node| ExpressionGenerator.to_js_(node, false, arguments, false);
+    let block = |statements: &[fastn_js::UDFStatement]| {
+        statements
+            .iter()
+            .map(|statement| statement_to_js(statement, arguments))
+            .join("\n")
+    };
+
+    match statement {
+        fastn_js::UDFStatement::Expression { value } => format!("{};", expression(value)),
+        fastn_js::UDFStatement::VariableDeclaration { name, value } => format!(
+            "{}.{} = {};",
+            fastn_js::LOCAL_VARIABLE_MAP,
+            name,
+            expression(value)
+        ),
+        fastn_js::UDFStatement::Return { value: Some(value) } => {
+            format!("return {};", expression(value))
         }
+        fastn_js::UDFStatement::Return { value: None } => "return;".to_string(),
+        fastn_js::UDFStatement::If {
+            condition,
+            then,
+            otherwise,
+        } if otherwise.is_empty() => format!("if ({}) {{\n{}\n}}", expression(condition), block(then)),
+        fastn_js::UDFStatement::If {
+            condition,
+            then,
+            otherwise,
+        } => format!(
+            "if ({}) {{\n{}\n}} else {{\n{}\n}}",
+            expression(condition),
+            block(then),
+            block(otherwise)
+        ),
+        fastn_js::UDFStatement::ForEach { name, list, body } => format!(
+            "for (let fastn_utils_item_{name} of fastn_utils.getListItems({list})) {{\n{map}.{name} = fastn_utils_item_{name};\n{body}\n}}",
+            name = name,
+            list = expression(list),
+            map = fastn_js::LOCAL_VARIABLE_MAP,
+            body = block(body)
+        ),
     }
 }
-*/
 
 pub struct ExpressionGenerator;
 
@@ -3,13 +3,13 @@ pub struct UDF {
     pub name: String,
     pub params: Vec<String>,
     pub args: Vec<(String, fastn_js::SetPropertyValue)>,
-    pub body: Vec<fastn_grammar::evalexpr::ExprNode>,
+    pub body: Vec<fastn_js::UDFStatement>,
     pub is_external_js_present: bool,
 }
 
 pub fn udf_with_arguments(
     name: &str,
-    body: Vec<fastn_grammar::evalexpr::ExprNode>,
+    body: Vec<fastn_js::UDFStatement>,
     args: Vec<(String, fastn_js::SetPropertyValue)>,
     is_external_js_present: bool,
 ) -> fastn_js::Ast {
@@ -1,79 +1,84 @@
+#[derive(Debug)]
 pub enum UDFStatement {
-    VariableDeclaration {
-        name: String,
-        value: Box<UDFStatement>,
-    },
-    VariableAssignment {
-        name: String,
-        value: Box<UDFStatement>,
-    },
-    Addition {
-        left: Box<UDFStatement>,
-        right: Box<UDFStatement>,
-    },
-    Subtraction {
-        left: Box<UDFStatement>,
-        right: Box<UDFStatement>,
-    },
-    Multiplication {
-        left: Box<UDFStatement>,
-        right: Box<UDFStatement>,
-    },
-    Division {
-        left: Box<UDFStatement>,
-        right: Box<UDFStatement>,
-    },
-    Exponentiation {
-        left: Box<UDFStatement>,
-        right: Box<UDFStatement>,
-    },
-    Not {
-        value: Box<UDFStatement>,
-    },
-    And {
-        left: Box<UDFStatement>,
-        right: Box<UDFStatement>,
+    Expression {
+        value: fastn_grammar::evalexpr::ExprNode,
     },
-    Or {
-        left: Box<UDFStatement>,
-        right: Box<UDFStatement>,
-    },
-    Ternary {
-        condition: Box<UDFStatement>,
-        then: Box<UDFStatement>,
-        otherwise: Box<UDFStatement>,
-    },
-    Parens {
-        value: Box<UDFStatement>,
-    },
-    Variable {
+    VariableDeclaration {
         name: String,
-    },
-    Integer {
-        value: i64,
-    },
-    Decimal {
-        value: f64,
-    },
-    Boolean {
-        value: bool,
-    },
-    String {
-        value: String,
+        value: fastn_grammar::evalexpr::ExprNode,
     },
     Return {
-        value: Box<UDFStatement>,
+        value: Option<fastn_grammar::evalexpr::ExprNode>,
     },
     If {
-        condition: Box<UDFStatement>,
-        then: Box<UDFStatement>,
-        otherwise: Box<UDFStatement>,
+        condition: fastn_grammar::evalexpr::ExprNode,
+        then: Vec<UDFStatement>,
+        otherwise: Vec<UDFStatement>,
     },
-    Block {
-        statements: Vec<UDFStatement>,
-    },
-    Call {
+    ForEach {
         name: String,
-        args: Vec<UDFStatement>,
+        list: fastn_grammar::evalexpr::ExprNode,
+        body: Vec<UDFStatement>,
     },
 }
+
+impl UDFStatement {
+    pub fn from_statement(statement: fastn_grammar::statement::Statement) -> UDFStatement {
+        use fastn_grammar::statement::Statement;
+        use itertools::Itertools;
+
+        match statement {
+            Statement::Let { name, value, .. } => UDFStatement::VariableDeclaration { name, value },
+            Statement::For {
+                name, list, body, ..
+            } => UDFStatement::ForEach {
+                name,
+                list,
+                body: body
+                    .into_iter()
+                    .map(UDFStatement::from_statement)
+                    .collect_vec(),
+            },
+            Statement::If {
+                condition,
+                then,
+                otherwise,
+                ..
+            } => UDFStatement::If {
+                condition,
+                then: then
+                    .into_iter()
+                    .map(UDFStatement::from_statement)
+                    .collect_vec(),
+                otherwise: otherwise
+                    .into_iter()
+                    .map(UDFStatement::from_statement)
+                    .collect_vec(),
+            },
+            Statement::Return { value, .. } => UDFStatement::Return { value },
+            Statement::Expression { value, .. } => UDFStatement::Expression { value },
+        }
+    }
+
+    /// Names of the local variables declared by `let` and `for` in `statements`.
+    pub(crate) fn local_variables(statements: &[UDFStatement]) -> Vec<String> {
+        let mut names = vec![];
+        for statement in statements {
+            match statement {
+                UDFStatement::VariableDeclaration { name, .. } => names.push(name.to_string()),
+                UDFStatement::ForEach { name, body, .. } => {
+                    names.push(name.to_string());
+                    names.extend(UDFStatement::local_variables(body));
+                }
+                UDFStatement::If {
+                    then, otherwise, ..
+                } => {
+                    names.extend(UDFStatement::local_variables(then));
+                    names.extend(UDFStatement::local_variables(otherwise));
+                }
+                UDFStatement::Expression { .. } | UDFStatement::Return { .. } => {}
+            }
+        }
+        names
+    }
+}
@@ -5,6 +5,10 @@ pub fn is_kernel(s: &str) -> bool {
         "ftd#column",
         "ftd#integer",
         "ftd#container",
+        "ftd#grid",
+        "ftd#form",
+        "ftd#dialog",
+        "ftd#popover",
     ]
     .contains(&s)
 }
@@ -88,6 +88,7 @@ pub struct PackageTemp {
     pub backend: bool,
     #[serde(rename = "backend-headers")]
     pub backend_headers: Option<Vec<BackendHeader>>,
+    pub spa: bool,
     pub icon: Option<ftd::ImageSrc>,
     // This will contain the module name through which this package can
     // be accessed when considered as a system's package
@@ -279,8 +279,21 @@ async fn fastn_core_commands(matches: &clap::ArgMatches) -> fastn_core::Result<(
         return fastn_core::mark_upto_date(&config, source, target).await;
     }
 
-    if matches.subcommand_matches("check").is_some() {
-        return fastn_core::post_build_check(&config).await;
+    if let Some(check) = matches.subcommand_matches("check") {
+        return fastn_core::check(&config, check.get_flag("json")).await;
+    }
+
+    if let Some(fmt) = matches.subcommand_matches("fmt") {
+        let check = fmt.get_flag("check");
+        return if let Some(files) = fmt.get_many::<String>("file") {
+            fastn_core::fmt(&config, Some(files.map(|v| v.to_string()).collect()), check).await
+        } else {
+            fastn_core::fmt(&config, None, check).await
+        };
+    }
+
+    if matches.subcommand_matches("lsp").is_some() {
+        return fastn_core::lsp(&config).await;
     }
 
     Ok(())
@@ -498,8 +511,18 @@ fn app(version: &'static str) -> clap::Command {
         )
         .subcommand(
             clap::Command::new("check")
-                .about("Check if everything is fine with current fastn package")
-                .hide(true) // hidden since the feature is not being released yet.
+                .about("Type-check every document of the package without building it")
+                .arg(clap::arg!(--json "Print the problems found as JSON"))
+        )
+        .subcommand(
+            clap::Command::new("fmt")
+                .about("Format ftd files in the canonical layout")
+                .arg(clap::arg!(file: <FILE>... "The file(s) to format (leave empty to format entire package)").required(false))
+                .arg(clap::arg!(--check "Do not write, fail if any file is not formatted"))
+        )
+        .subcommand(
+            clap::Command::new("lsp")
+                .about("Start the language server for ftd files, talks LSP over stdin and stdout")
         )
         .subcommand(
             clap::Command::new("mark-upto-date")
@@ -3,9 +3,17 @@ use crate::ast::kind::{HeaderValue, HeaderValues};
 #[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct ComponentDefinition {
     pub name: String,
+    /// `T` in `-- component data-table<T>:`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub type_parameters: Vec<String>,
     pub arguments: Vec<Argument>,
     pub definition: Component,
     pub css: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "ftd::p1::header::AccessModifier::is_public"
+    )]
+    pub access_modifier: ftd::p1::header::AccessModifier,
     pub line_number: usize,
 }
 
@@ -14,6 +22,7 @@ pub const COMPONENT: &str = "component";
 impl ComponentDefinition {
     fn new(
         name: &str,
+        type_parameters: Vec<String>,
         arguments: Vec<Argument>,
         definition: Component,
         css: Option<String>,
@@ -21,9 +30,11 @@ impl ComponentDefinition {
     ) -> ComponentDefinition {
         ComponentDefinition {
             name: name.to_string(),
+            type_parameters,
             arguments,
             definition,
             css,
+            access_modifier: Default::default(),
             line_number,
         }
     }
@@ -63,8 +74,15 @@ impl ComponentDefinition {
 
         let definition = Component::from_p1(section.sub_sections.first().unwrap(), doc_id)?;
 
-        Ok(ComponentDefinition::new(
+        let (name, type_parameters) = ftd::ast::utils::get_type_parameters(
             section.name.as_str(),
+            doc_id,
+            section.line_number,
+        )?;
+
+        Ok(ComponentDefinition::new(
+            name.as_str(),
+            type_parameters,
             arguments,
             definition,
             css,
@@ -320,6 +338,11 @@ impl Component {
                 children: vec![],
                 line_number,
             }),
+            ftd::ast::VariableValue::Map { line_number, .. } => ftd::ast::parse_error(
+                format!("Expected component `{}`, found map", key),
+                doc_id,
+                line_number,
+            ),
         }
     }
 
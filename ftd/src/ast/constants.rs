@@ -6,3 +6,4 @@ pub const EXPORT: &str = "export";
 pub const EXPOSING: &str = "exposing";
 pub const EVERYTHING: &str = "*";
 pub const ALWAYS_INCLUDE: &str = "$always-include$";
+pub const PERSIST: &str = "$persist$";
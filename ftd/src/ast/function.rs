@@ -1,8 +1,16 @@
 #[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct Function {
     pub name: String,
+    /// `T` in `-- T first<T>(items):`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub type_parameters: Vec<String>,
     pub kind: ftd::ast::VariableKind,
     pub arguments: Vec<ftd::ast::Argument>,
+    #[serde(
+        default,
+        skip_serializing_if = "ftd::p1::header::AccessModifier::is_public"
+    )]
+    pub access_modifier: ftd::p1::header::AccessModifier,
     pub line_number: usize,
     pub definition: FunctionDefinition,
     pub js: Option<String>,
@@ -13,6 +21,7 @@ pub type FunctionDefinition = ftd::p1::Body;
 impl Function {
     pub(crate) fn new(
         name: &str,
+        type_parameters: Vec<String>,
         kind: ftd::ast::VariableKind,
         arguments: Vec<ftd::ast::Argument>,
         line_number: usize,
@@ -21,8 +30,10 @@ impl Function {
     ) -> Function {
         Function {
             name: name.to_string(),
+            type_parameters,
             kind,
             arguments,
+            access_modifier: Default::default(),
             line_number,
             definition,
             js,
@@ -70,8 +81,14 @@ impl Function {
             doc_id: doc_id.to_string(),
             line_number: section.line_number,
         })?;
+        let (function_name, type_parameters) = ftd::ast::utils::get_type_parameters(
+            function_name.as_str(),
+            doc_id,
+            section.line_number,
+        )?;
         Ok(Function::new(
             function_name.as_str(),
+            type_parameters,
             kind,
             fields,
             section.line_number,
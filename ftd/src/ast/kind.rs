@@ -7,12 +7,14 @@ pub struct VariableKind {
 #[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub enum VariableModifier {
     List,
+    Map,
     Optional,
     Constant,
 }
 
 pub const OPTIONAL: &str = "optional";
 pub const LIST: &str = "list";
+pub const MAP: &str = "map";
 pub const CONSTANT: &str = "constant";
 
 impl VariableModifier {
@@ -32,6 +34,10 @@ impl VariableModifier {
         matches!(self, VariableModifier::List)
     }
 
+    fn is_map(&self) -> bool {
+        matches!(self, VariableModifier::Map)
+    }
+
     fn is_optional(&self) -> bool {
         matches!(self, VariableModifier::Optional)
     }
@@ -64,6 +70,15 @@ impl VariableKind {
         doc_id: &str,
         line_number: usize,
     ) -> ftd::ast::Result<VariableKind> {
+        if let Some(arguments) = kind
+            .trim()
+            .strip_prefix(MAP)
+            .and_then(|k| k.strip_prefix('<'))
+            .and_then(|k| k.strip_suffix('>'))
+        {
+            return VariableKind::get_map_kind(arguments, doc_id, line_number);
+        }
+
         let expr = kind.split_whitespace().collect::<Vec<&str>>();
         if expr.len() > 5 || expr.is_empty() {
             return ftd::ast::parse_error(
@@ -90,6 +105,56 @@ impl VariableKind {
 
         Ok(VariableKind::new(kind.as_str(), modifier))
     }
+
+    /// `map<string, integer>`, `arguments` is `string, integer`. Keys are always strings.
+    fn get_map_kind(
+        arguments: &str,
+        doc_id: &str,
+        line_number: usize,
+    ) -> ftd::ast::Result<VariableKind> {
+        let mut depth = 0;
+        let comma = arguments.char_indices().find(|(_, c)| {
+            match c {
+                '<' => depth += 1,
+                '>' => depth -= 1,
+                _ => {}
+            }
+            *c == ',' && depth == 0
+        });
+        let index = match comma {
+            Some((index, _)) => index,
+            None => {
+                return ftd::ast::parse_error(
+                    format!("Expected `map<string, T>`, found: `map<{}>`", arguments),
+                    doc_id,
+                    line_number,
+                )
+            }
+        };
+        let (key, value) = (arguments[..index].trim(), arguments[index + 1..].trim());
+        if !key.eq("string") {
+            return ftd::ast::parse_error(
+                format!(
+                    "Map keys must be `string`, found: `{}` in `map<{}>`",
+                    key, arguments
+                ),
+                doc_id,
+                line_number,
+            );
+        }
+        if value.is_empty() || VariableModifier::get_modifier(value).is_some() {
+            return ftd::ast::parse_error(
+                format!(
+                    "Invalid map value kind, found: `{}` in `map<{}>`",
+                    value, arguments
+                ),
+                doc_id,
+                line_number,
+            );
+        }
+
+        Ok(VariableKind::new(value, Some(VariableModifier::Map)))
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -107,6 +172,11 @@ pub enum VariableValue {
         value: Vec<VariableKeyValue>,
         line_number: usize,
     },
+    /// `-- integer map scores:` with a header for every key
+    Map {
+        value: Vec<VariableKeyValue>,
+        line_number: usize,
+    },
     Record {
         name: String,
         caption: Box<Option<VariableValue>>,
@@ -374,6 +444,7 @@ impl VariableValue {
             VariableValue::Optional { line_number, .. }
             | VariableValue::Constant { line_number, .. }
             | VariableValue::List { line_number, .. }
+            | VariableValue::Map { line_number, .. }
             | VariableValue::Record { line_number, .. }
             | VariableValue::String { line_number, .. } => *line_number,
         }
@@ -384,6 +455,7 @@ impl VariableValue {
             VariableValue::Optional { line_number, .. }
             | VariableValue::Constant { line_number, .. }
             | VariableValue::List { line_number, .. }
+            | VariableValue::Map { line_number, .. }
             | VariableValue::Record { line_number, .. }
             | VariableValue::String { line_number, .. } => *line_number = new_line_number,
         }
@@ -397,6 +469,23 @@ impl VariableValue {
         matches!(self, VariableValue::List { .. })
     }
 
+    pub(crate) fn is_map(&self) -> bool {
+        matches!(self, VariableValue::Map { .. })
+    }
+
+    pub(crate) fn into_map(self, doc_name: &str) -> ftd::ast::Result<Vec<(String, VariableValue)>> {
+        match self {
+            VariableValue::Map { value, .. } => {
+                Ok(value.into_iter().map(|v| (v.key, v.value)).collect())
+            }
+            t => ftd::ast::parse_error(
+                format!("Expected map, found: `{:?}`", t),
+                doc_name,
+                t.line_number(),
+            ),
+        }
+    }
+
     pub(crate) fn into_list(
         self,
         doc_name: &str,
@@ -539,6 +628,36 @@ impl VariableValue {
                     )
                 }
             }
+            Some(modifier) if modifier.is_map() => match self {
+                t if t.is_null() => Ok(VariableValue::Map {
+                    value: vec![],
+                    line_number: t.line_number(),
+                }),
+                VariableValue::Record {
+                    caption,
+                    headers,
+                    body: None,
+                    values,
+                    line_number,
+                    ..
+                } if caption.is_none() && values.is_empty() => Ok(VariableValue::Map {
+                    value: headers
+                        .0
+                        .into_iter()
+                        .map(|header| VariableKeyValue {
+                            key: header.key,
+                            value: header.value,
+                        })
+                        .collect(),
+                    line_number,
+                }),
+                VariableValue::String { ref value, .. } if value.starts_with('$') => Ok(self),
+                t => ftd::ast::parse_error(
+                    format!("Expected Map, a header for every key, found: `{:?}`", t),
+                    doc_id,
+                    line_number,
+                ),
+            },
             Some(modifier) if modifier.is_optional() => Ok(self.into_optional()),
             _ => Ok(self),
         }
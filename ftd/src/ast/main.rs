@@ -41,6 +41,23 @@ impl AST {
         Ok(di_vec)
     }
 
+    /// Like `from_sections`, but does not stop at the first section with an error, the errors of
+    /// all the sections are returned.
+    pub fn from_sections_with_errors(
+        sections: &[ftd::p1::Section],
+        doc_id: &str,
+    ) -> (Vec<AST>, Vec<ftd::ast::Error>) {
+        let mut di_vec = vec![];
+        let mut errors = vec![];
+        for section in ignore_comments(sections) {
+            match AST::from_section(&section, doc_id) {
+                Ok(ast) => di_vec.push(ast),
+                Err(e) => errors.push(e),
+            }
+        }
+        (di_vec, errors)
+    }
+
     pub fn name(&self) -> String {
         match self {
             AST::Import(i) => i.alias.clone(),
@@ -56,6 +73,57 @@ impl AST {
     }
 
     pub fn from_section(section: &ftd::p1::Section, doc_id: &str) -> ftd::ast::Result<AST> {
+        // `-- private component helper:` can only be used in the document it is defined in
+        let (kind, access_modifier) = match section.kind.as_ref() {
+            Some(kind) => ftd::p1::header::AccessModifier::get_kind_and_modifier(kind),
+            None => return AST::from_section_without_access_modifier(section, doc_id),
+        };
+        let access_modifier = match access_modifier {
+            Some(access_modifier) => access_modifier,
+            None => return AST::from_section_without_access_modifier(section, doc_id),
+        };
+
+        let mut section = section.clone();
+        section.kind = Some(kind).filter(|v| !v.is_empty());
+        let mut ast = AST::from_section_without_access_modifier(&section, doc_id)?;
+        match &mut ast {
+            AST::Record(ftd::ast::Record {
+                access_modifier: a, ..
+            })
+            | AST::OrType(ftd::ast::OrType {
+                access_modifier: a, ..
+            })
+            | AST::VariableDefinition(ftd::ast::VariableDefinition {
+                access_modifier: a, ..
+            })
+            | AST::ComponentDefinition(ftd::ast::ComponentDefinition {
+                access_modifier: a, ..
+            })
+            | AST::FunctionDefinition(ftd::ast::Function {
+                access_modifier: a, ..
+            })
+            | AST::WebComponentDefinition(ftd::ast::WebComponentDefinition {
+                access_modifier: a,
+                ..
+            }) => *a = access_modifier,
+            AST::Import(_) | AST::VariableInvocation(_) | AST::ComponentInvocation(_) => {
+                return ftd::ast::parse_error(
+                    format!(
+                        "Access modifier can only be used on a definition, found: `{}`",
+                        ast.name()
+                    ),
+                    doc_id,
+                    section.line_number,
+                )
+            }
+        }
+        Ok(ast)
+    }
+
+    fn from_section_without_access_modifier(
+        section: &ftd::p1::Section,
+        doc_id: &str,
+    ) -> ftd::ast::Result<AST> {
         Ok(if ftd::ast::Import::is_import(section) {
             AST::Import(ftd::ast::Import::from_p1(section, doc_id)?)
         } else if ftd::ast::Record::is_record(section) {
@@ -233,12 +301,38 @@ impl AST {
         matches!(self, AST::ComponentInvocation(_))
     }
 
+    pub fn is_private(&self) -> bool {
+        match self {
+            AST::Record(ftd::ast::Record {
+                access_modifier, ..
+            })
+            | AST::OrType(ftd::ast::OrType {
+                access_modifier, ..
+            })
+            | AST::VariableDefinition(ftd::ast::VariableDefinition {
+                access_modifier, ..
+            })
+            | AST::ComponentDefinition(ftd::ast::ComponentDefinition {
+                access_modifier, ..
+            })
+            | AST::FunctionDefinition(ftd::ast::Function {
+                access_modifier, ..
+            })
+            | AST::WebComponentDefinition(ftd::ast::WebComponentDefinition {
+                access_modifier,
+                ..
+            }) => !access_modifier.is_public(),
+            AST::Import(_) | AST::VariableInvocation(_) | AST::ComponentInvocation(_) => false,
+        }
+    }
+
     pub fn is_always_included_variable_definition(&self) -> bool {
         matches!(
             self,
             AST::VariableDefinition(ftd::ast::VariableDefinition {
                 flags: ftd::ast::VariableFlags {
-                    always_include: Some(true)
+                    always_include: Some(true),
+                    ..
                 },
                 ..
             })
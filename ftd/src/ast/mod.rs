@@ -46,6 +46,21 @@ pub enum Error {
     ParseBool(#[from] std::str::ParseBoolError),
 }
 
+impl Error {
+    /// Document and line the error was found on, if known.
+    pub fn location(&self) -> Option<(&str, usize)> {
+        match self {
+            Error::P1(e) => e.location(),
+            Error::Parse {
+                doc_id,
+                line_number,
+                ..
+            } => Some((doc_id.as_str(), *line_number)),
+            Error::ParseBool(_) => None,
+        }
+    }
+}
+
 pub type Result<T> = std::result::Result<T, Error>;
 
 pub fn parse_error<T, S1>(m: S1, doc_id: &str, line_number: usize) -> ftd::ast::Result<T>
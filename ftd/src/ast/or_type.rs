@@ -2,6 +2,11 @@
 pub struct OrType {
     pub name: String,
     pub variants: Vec<OrTypeVariant>,
+    #[serde(
+        default,
+        skip_serializing_if = "ftd::p1::header::AccessModifier::is_public"
+    )]
+    pub access_modifier: ftd::p1::header::AccessModifier,
     pub line_number: usize,
 }
 
@@ -12,6 +17,7 @@ impl OrType {
         OrType {
             name: name.to_string(),
             variants,
+            access_modifier: Default::default(),
             line_number,
         }
     }
@@ -117,6 +123,14 @@ impl OrTypeVariant {
         }
     }
 
+    pub fn line_number(&self) -> usize {
+        match self {
+            OrTypeVariant::AnonymousRecord(r) => r.line_number,
+            OrTypeVariant::Regular(f) => f.line_number,
+            OrTypeVariant::Constant(f) => f.line_number,
+        }
+    }
+
     pub(crate) fn is_constant(section: &ftd::p1::Section) -> bool {
         section
             .name
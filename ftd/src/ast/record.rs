@@ -1,15 +1,30 @@
 #[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct Record {
     pub name: String,
+    /// `T` in `-- record pair<T>:`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub type_parameters: Vec<String>,
     pub fields: Vec<Field>,
+    #[serde(
+        default,
+        skip_serializing_if = "ftd::p1::header::AccessModifier::is_public"
+    )]
+    pub access_modifier: ftd::p1::header::AccessModifier,
     pub line_number: usize,
 }
 
 impl Record {
-    fn new(name: &str, fields: Vec<Field>, line_number: usize) -> Record {
+    fn new(
+        name: &str,
+        type_parameters: Vec<String>,
+        fields: Vec<Field>,
+        line_number: usize,
+    ) -> Record {
         Record {
             name: name.to_string(),
+            type_parameters,
             fields,
+            access_modifier: Default::default(),
             line_number,
         }
     }
@@ -31,8 +46,14 @@ impl Record {
         }
 
         let fields = get_fields_from_headers(&section.headers, doc_id)?;
-        Ok(Record::new(
+        let (name, type_parameters) = ftd::ast::utils::get_type_parameters(
             section.name.as_str(),
+            doc_id,
+            section.line_number,
+        )?;
+        Ok(Record::new(
+            name.as_str(),
+            type_parameters,
             fields,
             section.line_number,
         ))
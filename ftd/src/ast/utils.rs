@@ -24,6 +24,56 @@ pub fn get_import_alias(input: &str) -> (String, String) {
     (module.to_string(), module)
 }
 
+/// Splits the type parameters off the name of a generic definition, `data-table<T, U>` gives
+/// `data-table` and `["T", "U"]`
+pub(crate) fn get_type_parameters(
+    name: &str,
+    doc_id: &str,
+    line_number: usize,
+) -> ftd::ast::Result<(String, Vec<String>)> {
+    let (name, type_parameters) = match name.split_once('<') {
+        Some((name, type_parameters)) => match type_parameters.strip_suffix('>') {
+            Some(type_parameters) => (name.trim(), type_parameters),
+            None => {
+                return ftd::ast::parse_error(
+                    format!("Expected `>` after the type parameters of `{}`", name),
+                    doc_id,
+                    line_number,
+                )
+            }
+        },
+        None => return Ok((name.to_string(), vec![])),
+    };
+
+    let mut result: Vec<String> = vec![];
+    for type_parameter in type_parameters.split(',').map(|v| v.trim()) {
+        if type_parameter.is_empty()
+            || !type_parameter
+                .chars()
+                .all(|c| c.is_alphanumeric() || c.eq(&'-') || c.eq(&'_'))
+        {
+            return ftd::ast::parse_error(
+                format!("Invalid type parameter `{}` for `{}`", type_parameter, name),
+                doc_id,
+                line_number,
+            );
+        }
+        if result.iter().any(|v| v.eq(type_parameter)) {
+            return ftd::ast::parse_error(
+                format!(
+                    "Type parameter `{}` is repeated for `{}`",
+                    type_parameter, name
+                ),
+                doc_id,
+                line_number,
+            );
+        }
+        result.push(type_parameter.to_string());
+    }
+
+    Ok((name.to_string(), result))
+}
+
 pub(crate) fn is_variable_mutable(name: &str) -> bool {
     name.starts_with(REFERENCE)
         && !name.eq(ftd::ast::utils::PROCESSOR)
@@ -81,4 +131,7 @@ pub const AS: &str = " as ";
 pub const IN: &str = " in ";
 pub const IF: &str = "if";
 pub const FOR: &str = "for";
+pub const MATCH: &str = "match";
+pub const CASE: &str = "case";
+pub const WILDCARD: &str = "_";
 pub const PROCESSOR: &str = "$processor$";
@@ -6,6 +6,11 @@ pub struct VariableDefinition {
     pub value: ftd::ast::VariableValue,
     pub processor: Option<String>,
     pub flags: VariableFlags,
+    #[serde(
+        default,
+        skip_serializing_if = "ftd::p1::header::AccessModifier::is_public"
+    )]
+    pub access_modifier: ftd::p1::header::AccessModifier,
     pub line_number: usize,
 }
 
@@ -26,6 +31,7 @@ impl VariableDefinition {
             value,
             processor,
             flags,
+            access_modifier: Default::default(),
             line_number,
         }
     }
@@ -149,12 +155,16 @@ impl VariableInvocation {
 #[derive(Debug, PartialEq, Clone, serde::Serialize, Default, serde::Deserialize)]
 pub struct VariableFlags {
     pub always_include: Option<bool>,
+    /// Keep the value across client side navigations of a `spa` package
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub persist: Option<bool>,
 }
 
 impl VariableFlags {
     pub fn new() -> VariableFlags {
         VariableFlags {
             always_include: None,
+            persist: None,
         }
     }
 
@@ -164,14 +174,22 @@ impl VariableFlags {
         variable_flag
     }
 
+    pub fn set_persist(self) -> VariableFlags {
+        let mut variable_flag = self;
+        variable_flag.persist = Some(true);
+        variable_flag
+    }
+
     pub fn from_headers(headers: &ftd::p1::Headers, doc_id: &str) -> VariableFlags {
+        let mut flags = ftd::ast::VariableFlags::new();
         for header in headers.0.iter() {
             if let Ok(flag) = ftd::ast::VariableFlags::from_header(header, doc_id) {
-                return flag;
+                flags.always_include = flags.always_include.or(flag.always_include);
+                flags.persist = flags.persist.or(flag.persist);
             }
         }
 
-        ftd::ast::VariableFlags::new()
+        flags
     }
 
     pub fn from_header(header: &ftd::p1::Header, doc_id: &str) -> ftd::ast::Result<VariableFlags> {
@@ -211,6 +229,22 @@ impl VariableFlags {
                     Ok(VariableFlags::new())
                 }
             }
+            ftd::ast::constants::PERSIST => {
+                let value = kv
+                    .value
+                    .as_ref()
+                    .ok_or(ftd::ast::Error::Parse {
+                        message: "Value expected for `$persist$` flag found `null`".to_string(),
+                        doc_id: doc_id.to_string(),
+                        line_number: kv.line_number,
+                    })?
+                    .parse::<bool>()?;
+                if value {
+                    Ok(VariableFlags::new().set_persist())
+                } else {
+                    Ok(VariableFlags::new())
+                }
+            }
             t => {
                 ftd::ast::parse_error(format!("Unknown flag found`{}`", t), doc_id, kv.line_number)
             }
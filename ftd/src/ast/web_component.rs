@@ -3,6 +3,11 @@ pub struct WebComponentDefinition {
     pub name: String,
     pub arguments: Vec<ftd::ast::Argument>,
     pub js: String,
+    #[serde(
+        default,
+        skip_serializing_if = "ftd::p1::header::AccessModifier::is_public"
+    )]
+    pub access_modifier: ftd::p1::header::AccessModifier,
     pub line_number: usize,
 }
 
@@ -19,6 +24,7 @@ impl WebComponentDefinition {
             name: name.to_string(),
             arguments,
             js,
+            access_modifier: Default::default(),
             line_number,
         }
     }
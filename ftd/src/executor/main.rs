@@ -198,7 +198,15 @@ impl ExecuteDoc<'_> {
         start_index: usize,
         inherited_variables: &mut ftd::VecMap<(String, Vec<usize>)>,
     ) -> ftd::executor::Result<Vec<(Option<String>, Vec<usize>, ftd::interpreter::Component)>> {
-        if instruction.is_loop() {
+        if let Some(match_) = instruction.match_.as_ref() {
+            ExecuteDoc::get_match_instructions(
+                match_,
+                doc,
+                parent_container,
+                start_index,
+                inherited_variables,
+            )
+        } else if instruction.is_loop() {
             ExecuteDoc::get_loop_instructions(
                 instruction,
                 doc,
@@ -348,6 +356,232 @@ impl ExecuteDoc<'_> {
         Ok(component)
     }
 
+    /// The children of the case the value matches take the place of the `-- match:`. A mutable
+    /// value is matched with conditions instead, see `get_conditional_match_instructions`.
+    #[allow(clippy::type_complexity)]
+    fn get_match_instructions(
+        match_: &ftd::interpreter::Match,
+        doc: &mut ftd::executor::TDoc,
+        parent_container: &[usize],
+        start_index: usize,
+        inherited_variables: &mut ftd::VecMap<(String, Vec<usize>)>,
+    ) -> ftd::executor::Result<Vec<(Option<String>, Vec<usize>, ftd::interpreter::Component)>> {
+        if match_.value.is_mutable() {
+            return ExecuteDoc::get_conditional_match_instructions(
+                match_,
+                doc,
+                parent_container,
+                start_index,
+                inherited_variables,
+            );
+        }
+
+        let (variant, value) = match match_
+            .value
+            .clone()
+            .resolve(&doc.itdoc(), match_.line_number)?
+        {
+            ftd::interpreter::Value::OrType { variant, value, .. } => (
+                variant
+                    .rsplit_once('.')
+                    .map_or(variant.to_string(), |(_, v)| v.to_string()),
+                value,
+            ),
+            t => {
+                return ftd::executor::utils::parse_error(
+                    format!("Expected or-type value to match on, found: `{:?}`", t),
+                    doc.name,
+                    match_.line_number,
+                )
+            }
+        };
+
+        let case = match match_
+            .cases
+            .iter()
+            .find(|v| v.variant.is_none() || v.variant.as_ref().eq(&Some(&variant)))
+        {
+            Some(case) => case,
+            None => return Ok(vec![]),
+        };
+
+        // `-- case: circle(c)`: the value of the variant is stored as a variable of its own,
+        // and `c` in the children of the case refers to it
+        let mut children = case.children.clone();
+        if let Some(ref alias) = case.alias {
+            let local_container = {
+                let mut local_container = parent_container.to_vec();
+                local_container.push(start_index);
+                local_container
+            };
+            let variable_name = format!(
+                "{}:{}",
+                alias,
+                ftd::executor::utils::get_string_container(local_container.as_slice())
+            );
+            let variable = ftd::interpreter::Variable {
+                name: variable_name.to_string(),
+                kind: value.kind().into_kind_data(),
+                mutable: false,
+                value: *value,
+                conditional_value: vec![],
+                line_number: case.line_number,
+                is_static: true,
+                persist: false,
+            }
+            .set_static(&doc.itdoc());
+            doc.bag.insert(
+                variable_name.to_string(),
+                ftd::interpreter::Thing::Variable(variable),
+            );
+
+            let map =
+                std::iter::IntoIterator::into_iter([(alias.to_string(), variable_name)]).collect();
+            for child in children.iter_mut() {
+                ftd::executor::utils::update_local_variable_references_in_component(
+                    child,
+                    &map,
+                    inherited_variables,
+                    &Default::default(),
+                    local_container.as_slice(),
+                    doc,
+                );
+            }
+        }
+
+        let mut elements = vec![];
+        let mut count = start_index;
+        for child in children.iter() {
+            let instructions = ExecuteDoc::get_instructions_from_instruction(
+                child,
+                doc,
+                parent_container,
+                count,
+                inherited_variables,
+            )?;
+            count += instructions.iter().filter(|(v, _, _)| v.is_none()).count();
+            elements.extend(instructions);
+        }
+        Ok(elements)
+    }
+
+    /// The children of every case, each with the condition `{ value == variant }` added, like an
+    /// `if:`, so the case shown follows the value. The children of `_` get the condition that the
+    /// value is none of the variants before it. The value of an or-type is the value of its
+    /// variant here, so only constant variants can be told apart this way.
+    #[allow(clippy::type_complexity)]
+    fn get_conditional_match_instructions(
+        match_: &ftd::interpreter::Match,
+        doc: &mut ftd::executor::TDoc,
+        parent_container: &[usize],
+        start_index: usize,
+        inherited_variables: &mut ftd::VecMap<(String, Vec<usize>)>,
+    ) -> ftd::executor::Result<Vec<(Option<String>, Vec<usize>, ftd::interpreter::Component)>> {
+        use fastn_grammar::evalexpr::{ExprNode, Operator};
+
+        const VALUE: &str = "ftd.match-value";
+        // read like the value in an `if:`, the variable it refers to tells if it can change
+        let mut value = match_.value.clone();
+        value.set_mutable(false);
+
+        let or_type = match match_.value.kind().get_or_type() {
+            Some((name, _, _)) => doc.itdoc().get_or_type(name.as_str(), match_.line_number)?,
+            None => {
+                return ftd::executor::utils::parse_error(
+                    format!(
+                        "Expected or-type value to match on, found: `{}`",
+                        match_.value.kind().get_name()
+                    ),
+                    doc.name,
+                    match_.line_number,
+                )
+            }
+        };
+
+        let mut variant_values = vec![];
+        let mut elements = vec![];
+        let mut count = start_index;
+        for case in match_.cases.iter() {
+            let expression = match case.variant.as_ref() {
+                Some(variant) => {
+                    let value = match or_type.variants.iter().find(|v| v.short_name().eq(variant)) {
+                        Some(ftd::interpreter::OrTypeVariant::Constant(
+                            ftd::interpreter::Field {
+                                value: Some(value), ..
+                            },
+                        )) => value
+                            .clone()
+                            .resolve(&doc.itdoc(), case.line_number)?
+                            .into_evalexpr_value(&doc.itdoc())?,
+                        _ => {
+                            return ftd::executor::utils::parse_error(
+                                format!(
+                                    "Can't match on `{}` of a mutable value here, only constant \
+                                    variants can be told apart while the value changes",
+                                    variant
+                                ),
+                                doc.name,
+                                case.line_number,
+                            )
+                        }
+                    };
+                    let expression = ExprNode::new(Operator::Eq).add_children(vec![
+                        ExprNode::new(Operator::VariableIdentifierRead {
+                            identifier: VALUE.to_string(),
+                        }),
+                        ExprNode::new(Operator::Const {
+                            value: value.clone(),
+                        }),
+                    ]);
+                    variant_values.push(value);
+                    Some(expression)
+                }
+                None => variant_values
+                    .iter()
+                    .map(|value| {
+                        ExprNode::new(Operator::Neq).add_children(vec![
+                            ExprNode::new(Operator::VariableIdentifierRead {
+                                identifier: VALUE.to_string(),
+                            }),
+                            ExprNode::new(Operator::Const {
+                                value: value.clone(),
+                            }),
+                        ])
+                    })
+                    .reduce(|a, b| ExprNode::new(Operator::And).add_children(vec![a, b])),
+            };
+            let condition = expression.map(|expression| {
+                ftd::interpreter::Expression::new(
+                    ExprNode::new(Operator::RootNode).add_children(vec![expression]),
+                    std::iter::IntoIterator::into_iter([(VALUE.to_string(), value.clone())])
+                        .collect(),
+                    case.line_number,
+                )
+            });
+
+            for child in case.children.iter() {
+                let mut instructions = ExecuteDoc::get_instructions_from_instruction(
+                    child,
+                    doc,
+                    parent_container,
+                    count,
+                    inherited_variables,
+                )?;
+                if let Some(ref condition) = condition {
+                    for (_, _, instruction) in instructions.iter_mut() {
+                        ftd::executor::utils::update_condition_in_component(
+                            instruction,
+                            condition.clone(),
+                        );
+                    }
+                }
+                count += instructions.iter().filter(|(v, _, _)| v.is_none()).count();
+                elements.extend(instructions);
+            }
+        }
+        Ok(elements)
+    }
+
     #[allow(clippy::type_complexity)]
     fn get_loop_instructions(
         instruction: &ftd::interpreter::Component,
@@ -3304,6 +3304,13 @@ pub enum Region {
     H4,
     H5,
     H6,
+    Main,
+    Nav,
+    Header,
+    Footer,
+    Aside,
+    Section,
+    Article,
 }
 
 impl Region {
@@ -3331,6 +3338,13 @@ impl Region {
             ftd::interpreter::FTD_REGION_H4 => Ok(Region::H4),
             ftd::interpreter::FTD_REGION_H5 => Ok(Region::H5),
             ftd::interpreter::FTD_REGION_H6 => Ok(Region::H6),
+            ftd::interpreter::FTD_REGION_MAIN => Ok(Region::Main),
+            ftd::interpreter::FTD_REGION_NAV => Ok(Region::Nav),
+            ftd::interpreter::FTD_REGION_HEADER => Ok(Region::Header),
+            ftd::interpreter::FTD_REGION_FOOTER => Ok(Region::Footer),
+            ftd::interpreter::FTD_REGION_ASIDE => Ok(Region::Aside),
+            ftd::interpreter::FTD_REGION_SECTION => Ok(Region::Section),
+            ftd::interpreter::FTD_REGION_ARTICLE => Ok(Region::Article),
             t => ftd::executor::utils::parse_error(
                 format!("Unknown variant `{}` for or-type `ftd.region`", t),
                 doc.name,
@@ -3372,6 +3386,14 @@ impl Region {
         matches!(self, Region::H1 | Region::H2 | Region::H3 | Region::H4)
     }
 
+    /// `main`, `nav` and the other regions which are sections of the page, not headings
+    pub fn is_landmark(&self) -> bool {
+        !matches!(
+            self,
+            Region::H1 | Region::H2 | Region::H3 | Region::H4 | Region::H5 | Region::H6
+        )
+    }
+
     pub fn to_css_string(&self) -> String {
         match self {
             Region::H1 => "h1".to_string(),
@@ -3380,6 +3402,13 @@ impl Region {
             Region::H4 => "h4".to_string(),
             Region::H5 => "h5".to_string(),
             Region::H6 => "h6".to_string(),
+            Region::Main => "main".to_string(),
+            Region::Nav => "nav".to_string(),
+            Region::Header => "header".to_string(),
+            Region::Footer => "footer".to_string(),
+            Region::Aside => "aside".to_string(),
+            Region::Section => "section".to_string(),
+            Region::Article => "article".to_string(),
         }
     }
 }
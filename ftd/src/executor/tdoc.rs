@@ -239,6 +239,7 @@ impl TDoc<'_> {
             conditional_value: conditions,
             line_number,
             is_static: true,
+            persist: false,
         }
         .set_static(&self.itdoc());
 
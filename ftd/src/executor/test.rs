@@ -148,3 +148,63 @@ fn filename_with_second_last_extension_replaced_with_json(
         }
     ))
 }
+
+#[test]
+fn match_on_mutable_value() {
+    let source = indoc::indoc!(
+        "
+        -- or-type size:
+
+        -- constant string small: small
+
+        -- constant string medium: medium
+
+        -- constant string large: large
+
+        -- end: size
+
+        -- size $s: small
+
+        -- match: $s
+
+        -- case: small
+
+        -- ftd.text: Smol
+
+        -- end: case
+
+        -- case: _
+
+        -- ftd.text: Big
+
+        -- end: case
+
+        -- end: match
+        "
+    );
+    let doc = interpret_helper("foo", source).unwrap_or_else(|e| panic!("{:?}", e));
+    let rt = ftd::executor::ExecuteDoc::from_interpreter(doc).unwrap_or_else(|e| panic!("{:?}", e));
+
+    // both cases are rendered, each shown by a condition on the value
+    let conditions = rt
+        .main
+        .container
+        .children
+        .iter()
+        .map(|v| match v {
+            ftd::executor::Element::Text(text) => {
+                let condition = text.common.condition.as_ref().unwrap();
+                assert!(condition.references.contains_key("ftd.match-value"));
+                condition.expression.clone()
+            }
+            t => panic!("expected text, found: {:?}", t),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        conditions,
+        vec![
+            fastn_grammar::evalexpr::build_operator_tree("ftd.match-value == \"small\"").unwrap(),
+            fastn_grammar::evalexpr::build_operator_tree("ftd.match-value != \"small\"").unwrap(),
+        ]
+    );
+}
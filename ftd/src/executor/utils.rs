@@ -247,6 +247,29 @@ pub(crate) fn update_local_variable_references_in_component_(
         );
     }
 
+    if let Some(match_) = component.match_.as_mut() {
+        update_local_variable_reference_in_property_value(
+            &mut match_.value,
+            local_variable_map,
+            inherited_variables,
+            replace_property_value,
+            local_container,
+            doc,
+            is_children,
+        );
+        for child in match_.cases.iter_mut().flat_map(|v| v.children.iter_mut()) {
+            update_local_variable_references_in_component_(
+                child,
+                local_variable_map,
+                inherited_variables,
+                &Default::default(),
+                local_container,
+                doc,
+                is_children,
+            );
+        }
+    }
+
     for child in component.children.iter_mut() {
         update_local_variable_references_in_component_(
             child,
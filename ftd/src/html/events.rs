@@ -142,6 +142,14 @@ fn to_event_name(event_name: &ftd::interpreter::EventName) -> String {
         ftd::interpreter::EventName::Change => "onchange".to_string(),
         ftd::interpreter::EventName::Blur => "onblur".to_string(),
         ftd::interpreter::EventName::Focus => "onfocus".to_string(),
+        ftd::interpreter::EventName::Submit => "onsubmit".to_string(),
+        ftd::interpreter::EventName::KeyDown(keys) => format!("onkeydown[{}]", keys.join("-")),
+        ftd::interpreter::EventName::Scroll => "onscroll".to_string(),
+        ftd::interpreter::EventName::Visible => "onvisible".to_string(),
+        ftd::interpreter::EventName::Hidden => "onhidden".to_string(),
+        ftd::interpreter::EventName::DragStart => "ondragstart".to_string(),
+        ftd::interpreter::EventName::Drop => "ondrop".to_string(),
+        ftd::interpreter::EventName::Swipe(direction) => format!("onswipe[{}]", direction),
         ftd::interpreter::EventName::RivePlay(timeline) => format!("onriveplay[{}]", timeline),
         ftd::interpreter::EventName::RiveStateChange(state_change) => {
             format!("onrivestatechange[{}]", state_change)
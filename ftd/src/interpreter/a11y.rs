@@ -0,0 +1,249 @@
+/// An accessibility problem in the components of a document, found by [check].
+#[derive(Debug, Clone, PartialEq)]
+pub struct A11yIssue {
+    /// The name of the check: `missing-alt`, `missing-label`, `low-contrast` or `not-focusable`
+    pub code: &'static str,
+    pub line_number: usize,
+    pub message: String,
+}
+
+/// Kernels which are read out as form controls, they need an `aria-label` (or `label`)
+const INPUTS: [&str; 6] = [
+    "ftd#text-input",
+    "ftd#checkbox",
+    "ftd#select",
+    "ftd#radio",
+    "ftd#slider",
+    "ftd#file-input",
+];
+
+/// WCAG AA contrast for normal text
+const MIN_CONTRAST: f64 = 4.5;
+
+/**
+ * Checks the components of `document`, the ones on the page and the ones in the component
+ * definitions of the document, for:
+ *
+ * - `ftd.image` without `alt`, unless it is decorative (`aria-role: none`)
+ * - form controls without `aria-label`
+ * - `color` with a contrast below 4.5:1 on its `background`, or the one of the nearest ancestor
+ *   which has one, in light or dark mode
+ * - kernels with `$on-click$` which can not be focused with the keyboard: not a link or a form
+ *   control, and no `tab-index`
+ *
+ * Only values known without rendering are checked, a color coming from `$inherited` or from a
+ * component argument is skipped.
+ */
+pub fn check(document: &ftd::interpreter::Document) -> Vec<A11yIssue> {
+    let doc = document.tdoc();
+    let prefix = format!("{}#", document.name);
+    let definitions = document
+        .data
+        .iter()
+        .filter(|(name, _)| name.starts_with(prefix.as_str()))
+        .filter_map(|(_, thing)| match thing {
+            ftd::interpreter::Thing::Component(c) => Some(&c.definition),
+            _ => None,
+        });
+
+    let mut issues = vec![];
+    for component in document.tree.iter().chain(definitions) {
+        check_component(component, None, &doc, &mut issues);
+    }
+    issues.sort_by_key(|issue| issue.line_number);
+    issues
+}
+
+/// `background` is the solid background `component` is drawn on, from its nearest ancestor with
+/// a `background`, `None` if there is none or it is not known without rendering
+fn check_component(
+    component: &ftd::interpreter::Component,
+    background: Option<&ftd::interpreter::Value>,
+    doc: &ftd::interpreter::TDoc,
+    issues: &mut Vec<A11yIssue>,
+) {
+    // `Some(None)` for a background which is not known without rendering
+    let own_background =
+        header(component, "background").map(|_| match static_value(component, "background", doc) {
+            Some(ftd::interpreter::Value::OrType { variant, value, .. })
+                if variant.eq(ftd::interpreter::FTD_BACKGROUND_SOLID) =>
+            {
+                value.resolve(doc, component.line_number).ok()
+            }
+            _ => None,
+        });
+    let background = match own_background {
+        Some(ref own_background) => own_background.as_ref(),
+        None => background,
+    };
+
+    // the children of a kernel on the page are in its `children` property
+    let children = component.get_children(doc).unwrap_or_default();
+    for child in component.children.iter().chain(children.iter()) {
+        check_component(child, background, doc, issues);
+    }
+
+    // the root of a user defined component is checked with its definition
+    let kernel = match component.name.strip_prefix("ftd#") {
+        Some(kernel) => kernel,
+        None => return,
+    };
+    let line_number = component.line_number;
+
+    if component.name.eq("ftd#image")
+        && header(component, "alt").is_none()
+        && !is_variant(
+            header(component, "aria-role"),
+            ftd::interpreter::FTD_ARIA_ROLE_NONE,
+        )
+    {
+        issues.push(A11yIssue {
+            code: "missing-alt",
+            line_number,
+            message: "`ftd.image` has no `alt`, use `aria-role: none` if the image is decorative"
+                .to_string(),
+        });
+    }
+
+    let is_input = INPUTS.contains(&component.name.as_str());
+    if is_input
+        && header(component, "aria-label")
+            .or(header(component, "label"))
+            .is_none()
+    {
+        issues.push(A11yIssue {
+            code: "missing-label",
+            line_number,
+            message: format!("`ftd.{}` has no `aria-label`", kernel),
+        });
+    }
+
+    if let (Some(color), Some(background)) = (static_value(component, "color", doc), background) {
+        for mode in ["light", "dark"] {
+            let ratio = match (
+                mode_color(&color, mode, doc, line_number),
+                mode_color(background, mode, doc, line_number),
+            ) {
+                (Some(color), Some(background)) => contrast(color, background),
+                _ => continue,
+            };
+            if ratio < MIN_CONTRAST {
+                issues.push(A11yIssue {
+                    code: "low-contrast",
+                    line_number,
+                    message: format!(
+                        "contrast of `color` on `background` is {:.1}:1 in {} mode, it should be \
+                        at least {}:1",
+                        ratio, mode, MIN_CONTRAST
+                    ),
+                });
+            }
+        }
+    }
+
+    let has_click = component
+        .events
+        .iter()
+        .any(|e| matches!(e.name, ftd::interpreter::EventName::Click));
+    if has_click
+        && !is_input
+        && header(component, "link").is_none()
+        && header(component, "tab-index").is_none()
+    {
+        issues.push(A11yIssue {
+            code: "not-focusable",
+            line_number,
+            message: format!(
+                "`ftd.{}` has `$on-click$` but can not be reached with the keyboard, give it \
+                `tab-index: 0`",
+                kernel
+            ),
+        });
+    }
+}
+
+/// The first property set for header `name`, conditional or not
+fn header<'a>(
+    component: &'a ftd::interpreter::Component,
+    name: &str,
+) -> Option<&'a ftd::interpreter::Property> {
+    component.properties.iter().find(|p| {
+        matches!(p.source, ftd::interpreter::PropertySource::Header { name: ref n, .. } if n == name)
+    })
+}
+
+fn is_variant(property: Option<&ftd::interpreter::Property>, variant: &str) -> bool {
+    matches!(
+        property.and_then(|p| p.value.value_optional()),
+        Some(ftd::interpreter::Value::OrType { variant: v, .. }) if v == variant
+    )
+}
+
+/// The value of header `name` if it is set without a condition, to a value or a global variable
+fn static_value(
+    component: &ftd::interpreter::Component,
+    name: &str,
+    doc: &ftd::interpreter::TDoc,
+) -> Option<ftd::interpreter::Value> {
+    let property = header(component, name).filter(|p| p.condition.is_none())?;
+    match property.value {
+        ftd::interpreter::PropertyValue::Value { .. }
+        | ftd::interpreter::PropertyValue::Reference { .. } => property
+            .value
+            .clone()
+            .resolve(doc, property.line_number)
+            .ok(),
+        _ => None,
+    }
+}
+
+/// The `light` or `dark` field of an `ftd.color` as red, green and blue
+fn mode_color(
+    color: &ftd::interpreter::Value,
+    mode: &str,
+    doc: &ftd::interpreter::TDoc,
+    line_number: usize,
+) -> Option<(u8, u8, u8)> {
+    let fields = match color {
+        ftd::interpreter::Value::Record { fields, .. } => fields,
+        _ => return None,
+    };
+    let value = fields
+        .get(mode)
+        .or_else(|| fields.get("light"))?
+        .clone()
+        .resolve(doc, line_number)
+        .ok()?;
+    match value {
+        ftd::interpreter::Value::String { text } => parse_color(text.as_str()),
+        _ => None,
+    }
+}
+
+/// Any css color. Colors which are not opaque depend on what is behind them, they are skipped.
+fn parse_color(color: &str) -> Option<(u8, u8, u8)> {
+    use std::str::FromStr;
+
+    css_color_parser::Color::from_str(color)
+        .ok()
+        .filter(|c| c.a >= 1.0)
+        .map(|c| (c.r, c.g, c.b))
+}
+
+/// Contrast ratio as defined by WCAG, from 1 to 21
+fn contrast(a: (u8, u8, u8), b: (u8, u8, u8)) -> f64 {
+    fn luminance((r, g, b): (u8, u8, u8)) -> f64 {
+        let channel = |c: u8| {
+            let c = c as f64 / 255.0;
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * channel(r) + 0.7152 * channel(g) + 0.0722 * channel(b)
+    }
+
+    let (a, b) = (luminance(a), luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
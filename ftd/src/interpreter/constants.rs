@@ -27,6 +27,22 @@ pub const FTD_LENGTH_RESPONSIVE: &str = "ftd#length.responsive";
 pub const FTD_RESPONSIVE_LENGTH: &str = "ftd#responsive-length";
 pub const FTD_RESPONSIVE_LENGTH_DESKTOP: &str = "ftd#responsive-length.desktop";
 
+pub const FTD_RESPONSIVE_STRING: &str = "ftd#responsive-string";
+pub const FTD_RESPONSIVE_STRING_DESKTOP: &str = "ftd#responsive-string.desktop";
+
+pub const FTD_OPTION: &str = "ftd#option";
+pub const FTD_OPTION_VALUE: &str = "ftd#option.value";
+
+pub const FTD_FILE: &str = "ftd#file";
+
+pub const FTD_FIELD_STATE: &str = "ftd#field-state";
+
+pub const FTD_TABLE_COLUMN: &str = "ftd#table-column";
+
+pub const FTD_TRANSITION: &str = "ftd#transition";
+pub const FTD_ANIMATION: &str = "ftd#animation";
+pub const FTD_KEYFRAME: &str = "ftd#keyframe";
+
 pub const FTD_ALIGN: &str = "ftd#align";
 pub const FTD_ALIGN_TOP_LEFT: &str = "ftd#align.top-left";
 pub const FTD_ALIGN_TOP_CENTER: &str = "ftd#align.top-center";
@@ -259,6 +275,43 @@ pub const FTD_REGION_H3: &str = "ftd#region.h3";
 pub const FTD_REGION_H4: &str = "ftd#region.h4";
 pub const FTD_REGION_H5: &str = "ftd#region.h5";
 pub const FTD_REGION_H6: &str = "ftd#region.h6";
+pub const FTD_REGION_MAIN: &str = "ftd#region.main";
+pub const FTD_REGION_NAV: &str = "ftd#region.nav";
+pub const FTD_REGION_HEADER: &str = "ftd#region.header";
+pub const FTD_REGION_FOOTER: &str = "ftd#region.footer";
+pub const FTD_REGION_ASIDE: &str = "ftd#region.aside";
+pub const FTD_REGION_SECTION: &str = "ftd#region.section";
+pub const FTD_REGION_ARTICLE: &str = "ftd#region.article";
+
+pub const FTD_ARIA_ROLE: &str = "ftd#aria-role";
+pub const FTD_ARIA_ROLE_ALERT: &str = "ftd#aria-role.alert";
+pub const FTD_ARIA_ROLE_BUTTON: &str = "ftd#aria-role.button";
+pub const FTD_ARIA_ROLE_CHECKBOX: &str = "ftd#aria-role.checkbox";
+pub const FTD_ARIA_ROLE_DIALOG: &str = "ftd#aria-role.dialog";
+pub const FTD_ARIA_ROLE_GROUP: &str = "ftd#aria-role.group";
+pub const FTD_ARIA_ROLE_IMG: &str = "ftd#aria-role.img";
+pub const FTD_ARIA_ROLE_LINK: &str = "ftd#aria-role.link";
+pub const FTD_ARIA_ROLE_LIST: &str = "ftd#aria-role.list";
+pub const FTD_ARIA_ROLE_LISTITEM: &str = "ftd#aria-role.listitem";
+pub const FTD_ARIA_ROLE_MENU: &str = "ftd#aria-role.menu";
+pub const FTD_ARIA_ROLE_MENUITEM: &str = "ftd#aria-role.menuitem";
+pub const FTD_ARIA_ROLE_NONE: &str = "ftd#aria-role.none";
+pub const FTD_ARIA_ROLE_PROGRESSBAR: &str = "ftd#aria-role.progressbar";
+pub const FTD_ARIA_ROLE_RADIO: &str = "ftd#aria-role.radio";
+pub const FTD_ARIA_ROLE_RADIOGROUP: &str = "ftd#aria-role.radiogroup";
+pub const FTD_ARIA_ROLE_REGION: &str = "ftd#aria-role.region";
+pub const FTD_ARIA_ROLE_SEARCH: &str = "ftd#aria-role.search";
+pub const FTD_ARIA_ROLE_STATUS: &str = "ftd#aria-role.status";
+pub const FTD_ARIA_ROLE_SWITCH: &str = "ftd#aria-role.switch";
+pub const FTD_ARIA_ROLE_TAB: &str = "ftd#aria-role.tab";
+pub const FTD_ARIA_ROLE_TABLIST: &str = "ftd#aria-role.tablist";
+pub const FTD_ARIA_ROLE_TABPANEL: &str = "ftd#aria-role.tabpanel";
+pub const FTD_ARIA_ROLE_TOOLTIP: &str = "ftd#aria-role.tooltip";
+
+pub const FTD_ARIA_LIVE: &str = "ftd#aria-live";
+pub const FTD_ARIA_LIVE_OFF: &str = "ftd#aria-live.off";
+pub const FTD_ARIA_LIVE_POLITE: &str = "ftd#aria-live.polite";
+pub const FTD_ARIA_LIVE_ASSERTIVE: &str = "ftd#aria-live.assertive";
 
 pub const FTD_DISPLAY: &str = "ftd#display";
 pub const FTD_DISPLAY_BLOCK: &str = "ftd#display.block";
@@ -289,6 +342,7 @@ pub const FTD_SPECIAL_VALUE: &str = "$VALUE";
 pub const FTD_SPECIAL_CHECKED: &str = "$CHECKED";
 pub const FTD_INHERITED: &str = "inherited";
 pub const FTD_LOOP_COUNTER: &str = "LOOP.COUNTER";
+pub const FTD_MATCH: &str = "ftd#match";
 pub const FTD_DEFAULT_TYPES: &str = "default-types";
 pub const FTD_DEFAULT_COLORS: &str = "default-colors";
 pub const FTD_NONE: &str = "none";
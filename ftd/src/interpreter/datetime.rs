@@ -0,0 +1,344 @@
+//! Parsing, printing and arithmetic for the `datetime`, `date` and `duration` kinds.
+//!
+//! A `datetime` is kept as seconds since the unix epoch in UTC, a `date` as days since the
+//! unix epoch and a `duration` as seconds. Literals are ISO-8601:
+//!
+//! ```ftd
+//! -- datetime published-on: 2024-01-05T15:04:05+05:30
+//! -- date release: 2024-01-05
+//! -- duration cache-for: P1DT12H
+//! ```
+//!
+//! The formatting here is what the interpreter uses when it has to evaluate a function itself,
+//! it only knows English. Time zones are UTC offsets or IANA names, looked up in the tz database
+//! that comes with `chrono-tz`. The browser and server side rendering go through `ftd.format_datetime` and
+//! friends in `fastn-js`, which use `Intl` when it is available.
+
+pub const DATETIME: &str = "datetime";
+pub const DATE: &str = "date";
+pub const DURATION: &str = "duration";
+
+const SECONDS_IN_DAY: i64 = 86_400;
+
+/// Parses `2024-01-05T15:04:05Z`, `2024-01-05 15:04+05:30` or `2024-01-05`, a missing offset
+/// means UTC. Fractional seconds are accepted and dropped.
+pub fn parse_datetime(s: &str) -> Option<i64> {
+    let s = s.trim();
+    if s.len() <= 10 {
+        return parse_date(s).map(|days| days * SECONDS_IN_DAY);
+    }
+    let days = parse_date(s.get(..10)?)?;
+    let rest = s.get(10..)?;
+    let rest = rest
+        .strip_prefix('T')
+        .or_else(|| rest.strip_prefix('t'))
+        .or_else(|| rest.strip_prefix(' '))?;
+
+    let (time, offset) = match rest.find(['Z', 'z', '+', '-']) {
+        Some(idx) => (&rest[..idx], parse_offset(&rest[idx..])?),
+        None => (rest, 0),
+    };
+    let time = time.split('.').next()?;
+    let mut parts = time.split(':');
+    let hour: i64 = parse_number(parts.next()?, 2)?;
+    let minute: i64 = parse_number(parts.next()?, 2)?;
+    let second: i64 = match parts.next() {
+        Some(second) => parse_number(second, 2)?,
+        None => 0,
+    };
+    if parts.next().is_some() || hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+
+    Some(days * SECONDS_IN_DAY + hour * 3600 + minute * 60 + second - offset)
+}
+
+/// A unix timestamp from JSON. Seconds, milliseconds, microseconds and nanoseconds are told
+/// apart by their size, `file-edit-data.timestamp` for example is in nanoseconds.
+pub fn from_unix_timestamp(timestamp: i64) -> i64 {
+    match timestamp.abs() {
+        t if t < 100_000_000_000 => timestamp,
+        t if t < 100_000_000_000_000 => timestamp / 1_000,
+        t if t < 100_000_000_000_000_000 => timestamp / 1_000_000,
+        _ => timestamp / 1_000_000_000,
+    }
+}
+
+/// Parses `2024-01-05` into days since the unix epoch.
+pub fn parse_date(s: &str) -> Option<i64> {
+    let mut parts = s.trim().split('-');
+    let year: i64 = parse_number(parts.next()?, 4)?;
+    let month: i64 = parse_number(parts.next()?, 2)?;
+    let day: i64 = parse_number(parts.next()?, 2)?;
+    if parts.next().is_some() {
+        return None;
+    }
+    let date = chrono::NaiveDate::from_ymd_opt(year as i32, month as u32, day as u32)?;
+    Some(date.signed_duration_since(utc(0).date_naive()).num_days())
+}
+
+/// Parses ISO-8601 durations like `PT90M`, `P1DT12H` or `-P2W`, or a plain number of seconds.
+/// Years and months are not supported as their length depends on the date they are added to.
+pub fn parse_duration(s: &str) -> Option<i64> {
+    let s = s.trim();
+    if let Ok(seconds) = s.parse::<i64>() {
+        return Some(seconds);
+    }
+    let (sign, s) = match s.strip_prefix('-') {
+        Some(s) => (-1, s),
+        None => (1, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let s = s.strip_prefix('P').or_else(|| s.strip_prefix('p'))?;
+    if s.is_empty() {
+        return None;
+    }
+
+    let mut seconds = 0;
+    let mut in_time = false;
+    let mut number = String::new();
+    for c in s.chars() {
+        match c.to_ascii_uppercase() {
+            'T' if !in_time && number.is_empty() => in_time = true,
+            c if c.is_ascii_digit() => number.push(c),
+            unit => {
+                let value: i64 = number.parse().ok()?;
+                number.clear();
+                seconds += value
+                    * match (unit, in_time) {
+                        ('W', false) => 7 * SECONDS_IN_DAY,
+                        ('D', false) => SECONDS_IN_DAY,
+                        ('H', true) => 3600,
+                        ('M', true) => 60,
+                        ('S', true) => 1,
+                        _ => return None,
+                    };
+            }
+        }
+    }
+    if !number.is_empty() {
+        return None;
+    }
+    Some(sign * seconds)
+}
+
+/// Prints a datetime as `2024-01-05T09:34:05Z`, this is also how it is handed to `fastn-js`.
+pub fn datetime_to_string(seconds: i64) -> String {
+    utc(seconds).format("%Y-%m-%dT%H:%M:%SZ").to_string()
+}
+
+pub fn date_to_string(days: i64) -> String {
+    date(days).format("%Y-%m-%d").to_string()
+}
+
+/// Prints a duration as ISO-8601, `P1DT12H`.
+pub fn duration_to_string(seconds: i64) -> String {
+    if seconds == 0 {
+        return "PT0S".to_string();
+    }
+    let sign = if seconds < 0 { "-" } else { "" };
+    let seconds = seconds.abs();
+    let (days, hours, minutes, seconds) = (
+        seconds / SECONDS_IN_DAY,
+        seconds % SECONDS_IN_DAY / 3600,
+        seconds % 3600 / 60,
+        seconds % 60,
+    );
+    let mut s = format!("{sign}P");
+    if days > 0 {
+        s.push_str(format!("{days}D").as_str());
+    }
+    if hours > 0 || minutes > 0 || seconds > 0 {
+        s.push('T');
+        for (value, unit) in [(hours, 'H'), (minutes, 'M'), (seconds, 'S')] {
+            if value > 0 {
+                s.push_str(format!("{value}{unit}").as_str());
+            }
+        }
+    }
+    s
+}
+
+/// Formats a date in the style of `Intl.DateTimeFormat("en-US", {dateStyle})`. `style` is one
+/// of `short`, `medium`, `long`, `full` or `iso`.
+pub fn format_date(days: i64, style: &str) -> String {
+    format_naive_date(date(days), style)
+}
+
+fn format_naive_date(date: chrono::NaiveDate, style: &str) -> String {
+    let format = match style {
+        "iso" => "%Y-%m-%d",
+        "short" => "%-m/%-d/%y",
+        "long" => "%B %-d, %Y",
+        "full" => "%A, %B %-d, %Y",
+        _ => "%b %-d, %Y",
+    };
+    date.format(format).to_string()
+}
+
+/// Formats a datetime as the date in `style` followed by the time, `Jan 5, 2024, 3:04 PM`.
+/// `timezone` can be `UTC`, an offset like `+05:30` or an IANA name like `Asia/Kolkata`, a zone
+/// which is not found is treated as UTC.
+pub fn format_datetime(seconds: i64, style: &str, timezone: Option<&str>) -> String {
+    if style.eq("iso") {
+        return datetime_to_string(seconds);
+    }
+    let offset = timezone
+        .and_then(|timezone| timezone_offset(timezone, seconds))
+        .unwrap_or_else(|| chrono::FixedOffset::east_opt(0).unwrap());
+    let local = utc(seconds).with_timezone(&offset).naive_local();
+    format!(
+        "{}, {}",
+        format_naive_date(local.date(), style),
+        local.format("%-I:%M %p")
+    )
+}
+
+/// `3 days ago` or `in 2 hours`, relative to `now`.
+pub fn relative_time(seconds: i64, now: i64) -> String {
+    let diff = seconds - now;
+    let (value, unit) = relative_unit(diff.abs());
+    let unit = if value == 1 {
+        unit.to_string()
+    } else {
+        format!("{unit}s")
+    };
+    if diff < 0 {
+        format!("{value} {unit} ago")
+    } else {
+        format!("in {value} {unit}")
+    }
+}
+
+/// The largest unit that fits in `seconds`, this is the same table `ftd.relative_time` uses.
+fn relative_unit(seconds: i64) -> (i64, &'static str) {
+    match seconds {
+        s if s < 60 => (s, "second"),
+        s if s < 3600 => (s / 60, "minute"),
+        s if s < SECONDS_IN_DAY => (s / 3600, "hour"),
+        s if s < 7 * SECONDS_IN_DAY => (s / SECONDS_IN_DAY, "day"),
+        s if s < 30 * SECONDS_IN_DAY => (s / (7 * SECONDS_IN_DAY), "week"),
+        s if s < 365 * SECONDS_IN_DAY => (s / (30 * SECONDS_IN_DAY), "month"),
+        s => (s / (365 * SECONDS_IN_DAY), "year"),
+    }
+}
+
+pub fn now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default()
+}
+
+/// The offset from UTC in `timezone` at `seconds`, for an offset or an IANA name.
+fn timezone_offset(timezone: &str, seconds: i64) -> Option<chrono::FixedOffset> {
+    use chrono::{Offset, TimeZone};
+
+    if let Some(offset) = parse_offset(timezone) {
+        return chrono::FixedOffset::east_opt(offset as i32);
+    }
+    let zone: chrono_tz::Tz = timezone.parse().ok()?;
+    Some(
+        zone.offset_from_utc_datetime(&utc(seconds).naive_utc())
+            .fix(),
+    )
+}
+
+/// `Z`, `+05:30`, `-0800` or `+05` into seconds east of UTC.
+fn parse_offset(s: &str) -> Option<i64> {
+    if s.eq_ignore_ascii_case("z") || s.eq_ignore_ascii_case("utc") {
+        return Some(0);
+    }
+    let sign = match s.get(..1)? {
+        "+" => 1,
+        "-" => -1,
+        _ => return None,
+    };
+    let s = s[1..].replace(':', "");
+    let (hours, minutes) = match s.len() {
+        2 => (parse_number(&s, 2)?, 0),
+        4 => (parse_number(&s[..2], 2)?, parse_number(&s[2..], 2)?),
+        _ => return None,
+    };
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
+fn parse_number(s: &str, digits: usize) -> Option<i64> {
+    if s.len() != digits || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+/// `seconds` since the unix epoch, out of range values are the epoch
+fn utc(seconds: i64) -> chrono::DateTime<chrono::Utc> {
+    chrono::DateTime::from_timestamp(seconds, 0).unwrap_or_default()
+}
+
+fn date(days: i64) -> chrono::NaiveDate {
+    utc(days.saturating_mul(SECONDS_IN_DAY)).date_naive()
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn datetime() {
+        let parse = super::parse_datetime;
+        assert_eq!(parse("1970-01-01T00:00:00Z"), Some(0));
+        assert_eq!(parse("2024-01-05"), Some(1_704_412_800));
+        assert_eq!(parse("2024-01-05T15:04:05+05:30"), Some(1_704_447_245));
+        assert_eq!(parse("2024-01-05 09:34:05.123Z"), Some(1_704_447_245));
+        assert_eq!(parse("2024-01-05T09:34"), Some(1_704_447_240));
+        assert_eq!(parse("2024-02-30"), None);
+        assert_eq!(parse("2024-01-05T25:00"), None);
+        assert_eq!(
+            super::datetime_to_string(1_704_447_245),
+            "2024-01-05T09:34:05Z"
+        );
+        assert_eq!(super::date_to_string(-1), "1969-12-31");
+        assert_eq!(
+            super::from_unix_timestamp(1_638_706_756_293_421_000),
+            1_638_706_756
+        );
+        assert_eq!(super::from_unix_timestamp(1_638_706_756_293), 1_638_706_756);
+    }
+
+    #[test]
+    fn duration() {
+        let parse = super::parse_duration;
+        assert_eq!(parse("P1DT12H"), Some(129_600));
+        assert_eq!(parse("PT90M"), Some(5_400));
+        assert_eq!(parse("-P2W"), Some(-1_209_600));
+        assert_eq!(parse("45"), Some(45));
+        assert_eq!(parse("P1M"), None);
+        assert_eq!(parse("P"), None);
+        assert_eq!(super::duration_to_string(129_600), "P1DT12H");
+        assert_eq!(super::duration_to_string(-5_400), "-PT1H30M");
+    }
+
+    #[test]
+    fn format() {
+        let days = super::parse_date("2024-01-05").unwrap();
+        assert_eq!(super::format_date(days, "short"), "1/5/24");
+        assert_eq!(super::format_date(days, "medium"), "Jan 5, 2024");
+        assert_eq!(super::format_date(days, "full"), "Friday, January 5, 2024");
+        assert_eq!(
+            super::format_datetime(1_704_447_245, "medium", Some("+05:30")),
+            "Jan 5, 2024, 3:04 PM"
+        );
+        assert_eq!(
+            super::format_datetime(1_704_447_245, "medium", Some("Asia/Kolkata")),
+            "Jan 5, 2024, 3:04 PM"
+        );
+        // daylight saving time in July
+        assert_eq!(
+            super::format_datetime(1_720_000_000, "medium", Some("America/New_York")),
+            "Jul 3, 2024, 5:46 AM"
+        );
+        assert_eq!(
+            super::format_datetime(1_704_447_245, "medium", Some("../../etc/passwd")),
+            "Jan 5, 2024, 9:34 AM"
+        );
+        assert_eq!(super::relative_time(0, 3 * 86_400), "3 days ago");
+        assert_eq!(super::relative_time(7_200, 0), "in 2 hours");
+    }
+}
@@ -0,0 +1,238 @@
+/// An error found in a document, with where it was found. The parser and the interpreter keep
+/// going after an error, so all the errors of a document are returned together as
+/// `ftd::interpreter::Error::Diagnostics`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub message: String,
+    pub doc_id: Option<String>,
+    pub line_number: Option<usize>,
+    /// The part of the line the error is about, the whole line if `None`
+    pub mark: Option<Mark>,
+    /// Other places the error is about, like where the component is defined
+    pub labels: Vec<Label>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Label {
+    pub message: String,
+    pub doc_id: String,
+    pub line_number: usize,
+    pub mark: Option<Mark>,
+}
+
+/// The part of its line a diagnostic is about, as known where the error was raised.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Mark {
+    /// Byte range in the line
+    Columns(std::ops::Range<usize>),
+    /// A name used on the line, `module#name` is marked where `name` is
+    Name(String),
+}
+
+/// Where in the source a diagnostic points to: its `Mark` on the line, else the whole line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span {
+    pub line_number: usize,
+    /// Starts from 1, counted in characters
+    pub column: usize,
+    /// Byte range in the source
+    pub range: std::ops::Range<usize>,
+}
+
+impl Diagnostic {
+    pub fn new(error: &ftd::interpreter::Error) -> Diagnostic {
+        let (doc_id, line_number) = match error.location() {
+            Some((doc_id, line_number)) => (doc_id.map(ToString::to_string), Some(line_number)),
+            None => (None, None),
+        };
+        Diagnostic {
+            message: error.to_string(),
+            doc_id,
+            line_number,
+            mark: error.mark(),
+            labels: vec![],
+        }
+    }
+
+    fn is_in(&self, doc_id: &str) -> bool {
+        self.doc_id
+            .as_deref()
+            .map_or(false, |d| same_document(d, doc_id))
+    }
+
+    /// Span of the diagnostic in `source`, the source of `doc_id`.
+    pub fn span(&self, doc_id: &str, source: &str) -> Option<Span> {
+        if !self.is_in(doc_id) {
+            return None;
+        }
+        Span::new(source, self.line_number?, self.mark.as_ref())
+    }
+
+    /**
+     * The diagnostic the way rustc shows errors:
+     *
+     * ```text
+     * error: foo:12 -> `bar` not found
+     *   --> foo:12:14
+     *    |
+     * 12 | -- ftd.text: $bar
+     *    |               ^^^
+     *    |
+     *  3 | -- component baz:
+     *    |    ------------- `baz` is defined here
+     * ```
+     *
+     * `source` is the source of `doc_id`, places in other documents are shown without the code.
+     */
+    pub fn render(&self, doc_id: &str, source: &str) -> String {
+        let span = self.span(doc_id, source);
+        let labels = self
+            .labels
+            .iter()
+            .map(|label| {
+                let span = Some(label)
+                    .filter(|l| same_document(l.doc_id.as_str(), doc_id))
+                    .and_then(|l| Span::new(source, l.line_number, l.mark.as_ref()));
+                (label, span)
+            })
+            .collect::<Vec<_>>();
+        let width = span
+            .iter()
+            .chain(labels.iter().filter_map(|(_, span)| span.as_ref()))
+            .map(|span| span.line_number.to_string().len())
+            .max()
+            .unwrap_or(1);
+        let pad = " ".repeat(width);
+
+        let mut out = vec![format!("error: {}", self.message)];
+        match (&span, self.line_number) {
+            (Some(span), _) => {
+                out.push(format!(
+                    "{pad}--> {}:{}:{}",
+                    self.doc_id.as_deref().unwrap_or(doc_id),
+                    span.line_number,
+                    span.column
+                ));
+                out.push(format!("{pad} |"));
+                frame(&mut out, source, span, '^', "", width);
+            }
+            (None, Some(line_number)) => out.push(format!(
+                "{pad}--> {}:{}",
+                self.doc_id.as_deref().unwrap_or(doc_id),
+                line_number
+            )),
+            (None, None) => {}
+        }
+        for (label, label_span) in labels {
+            match label_span {
+                Some(label_span) => {
+                    out.push(format!("{pad} |"));
+                    frame(
+                        &mut out,
+                        source,
+                        &label_span,
+                        '-',
+                        label.message.as_str(),
+                        width,
+                    );
+                }
+                None => out.push(format!(
+                    "{pad} = note: {}, {}:{}",
+                    label.message, label.doc_id, label.line_number
+                )),
+            }
+        }
+        out.join("\n")
+    }
+}
+
+impl Span {
+    pub fn new(source: &str, line_number: usize, mark: Option<&Mark>) -> Option<Span> {
+        let index = line_number.checked_sub(1)?;
+        let line = source.split('\n').nth(index)?.trim_end_matches('\r');
+        let line_start: usize = source.split('\n').take(index).map(|l| l.len() + 1).sum();
+
+        let columns = match mark {
+            Some(Mark::Columns(columns))
+                if columns.start < columns.end
+                    && line.get(columns.start..columns.end).is_some() =>
+            {
+                Some(columns.clone())
+            }
+            Some(Mark::Name(name)) => {
+                let name = name
+                    .rsplit_once('#')
+                    .map_or(name.as_str(), |(_, name)| name);
+                find_name(line, name).map(|start| start..start + name.len())
+            }
+            _ => None,
+        };
+        let columns = columns.unwrap_or_else(|| {
+            let start = line.len() - line.trim_start().len();
+            start..start + line.trim().len()
+        });
+
+        Some(Span {
+            line_number,
+            column: line[..columns.start].chars().count() + 1,
+            range: line_start + columns.start..line_start + columns.end,
+        })
+    }
+}
+
+/// Start of `name` in `line` as a whole name, not a part of another name.
+fn find_name(line: &str, name: &str) -> Option<usize> {
+    let is_name_char =
+        |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '-' || c == '_');
+    if name.is_empty() {
+        return None;
+    }
+    line.match_indices(name)
+        .map(|(start, _)| start)
+        .find(|start| {
+            !is_name_char(line[..*start].chars().last())
+                && !is_name_char(line[start + name.len()..].chars().next())
+        })
+}
+
+/// Adds the line of `span` with its range marked by `mark`, and `message` after the marks.
+fn frame(
+    out: &mut Vec<String>,
+    source: &str,
+    span: &Span,
+    mark: char,
+    message: &str,
+    width: usize,
+) {
+    let line = source
+        .split('\n')
+        .nth(span.line_number - 1)
+        .unwrap_or_default()
+        .trim_end();
+    let marks = source[span.range.clone()].chars().count().max(1);
+    out.push(format!("{:>width$} | {}", span.line_number, line));
+    out.push(
+        format!(
+            "{} | {}{} {}",
+            " ".repeat(width),
+            " ".repeat(span.column - 1),
+            mark.to_string().repeat(marks),
+            message
+        )
+        .trim_end()
+        .to_string(),
+    );
+}
+
+fn same_document(a: &str, b: &str) -> bool {
+    a.trim_matches('/') == b.trim_matches('/')
+}
+
+/// The messages of the diagnostics, one on each line.
+pub(crate) fn messages(diagnostics: &[Diagnostic]) -> String {
+    diagnostics
+        .iter()
+        .map(|d| d.message.as_str())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -20,6 +20,9 @@
 ///
 /// - `instructions`: a `Vec` of `ftd::interpreter::Component`s that represents the instructions
 /// that the interpreter has processed.
+///
+/// - `errors`: the errors found so far, the things they were found in are skipped and the
+/// interpreter goes on, so all the errors of the document are reported together.
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct InterpreterState {
     pub id: String,
@@ -30,6 +33,7 @@ pub struct InterpreterState {
     pub pending_imports: PendingImports,
     pub parsed_libs: ftd::Map<ParsedDocument>,
     pub instructions: Vec<ftd::interpreter::Component>,
+    pub errors: Vec<ftd::interpreter::Diagnostic>,
 }
 
 #[derive(Debug, Clone, Default, PartialEq)]
@@ -137,286 +141,384 @@ impl InterpreterState {
     #[tracing::instrument(name = "continue_processing", skip_all)]
     pub fn continue_processing(mut self) -> ftd::interpreter::Result<Interpreter> {
         while let Some((doc_name, number_of_scan, ast, exports)) = self.get_next_ast() {
-            if let Some(interpreter) = self.resolve_pending_imports::<ftd::interpreter::Thing>()? {
-                match interpreter {
-                    ftd::interpreter::StateWithThing::State(s) => {
-                        return Ok(s.into_interpreter(self))
-                    }
-                    ftd::interpreter::StateWithThing::Thing(t) => {
-                        self.bag.insert(t.name(), t);
-                    }
-                    ftd::interpreter::StateWithThing::Continue => continue,
+            match self.process_ast(doc_name, number_of_scan, ast, exports) {
+                Ok(StateWithThing::State(s)) => return Ok(s.into_interpreter(self)),
+                Ok(StateWithThing::Thing(())) => self.remove_last(),
+                Ok(StateWithThing::Continue) => {}
+                Err(e) => self.skip_failed(e),
+            }
+        }
+
+        if !self.errors.is_empty() {
+            return Err(ftd::interpreter::Error::Diagnostics {
+                diagnostics: self.errors,
+            });
+        }
+
+        if self.to_process.stack.is_empty() {
+            let document = Document {
+                data: self.bag,
+                aliases: self
+                    .parsed_libs
+                    .get(self.id.as_str())
+                    .unwrap()
+                    .doc_aliases
+                    .clone(),
+                tree: self.instructions,
+                name: self.id,
+                js: self.js,
+                css: self.css,
+            };
+
+            Ok(Interpreter::Done { document })
+        } else {
+            self.continue_processing()
+        }
+    }
+
+    /// Processes `ast`, the thing on top of the stack. `Thing` means it is done and can be removed
+    /// from the stack, `Continue` that something it needs has been added to the stack.
+    fn process_ast(
+        &mut self,
+        doc_name: String,
+        number_of_scan: usize,
+        ast: ftd::ast::AST,
+        exports: Vec<String>,
+    ) -> ftd::interpreter::Result<StateWithThing<()>> {
+        if let Some(interpreter) = self.resolve_pending_imports::<ftd::interpreter::Thing>()? {
+            match interpreter {
+                ftd::interpreter::StateWithThing::State(s) => {
+                    return Ok(StateWithThing::new_state(s))
+                }
+                ftd::interpreter::StateWithThing::Thing(t) => {
+                    self.bag.insert(t.name(), t);
+                }
+                ftd::interpreter::StateWithThing::Continue => {
+                    return Ok(StateWithThing::new_continue())
                 }
             }
+        }
 
-            self.increase_scan_count();
-            let parsed_document = self.parsed_libs.get(doc_name.as_str()).unwrap();
-            let name = parsed_document.name.to_string();
-            let aliases = parsed_document.doc_aliases.clone();
+        self.increase_scan_count();
+        let parsed_document = self.parsed_libs.get(doc_name.as_str()).unwrap();
+        let name = parsed_document.name.to_string();
+        let aliases = parsed_document.doc_aliases.clone();
 
-            let ast_full_name = ftd::interpreter::utils::resolve_name(
-                ast.name().as_str(),
-                &parsed_document.name,
-                &parsed_document.doc_aliases,
+        let ast_full_name = ftd::interpreter::utils::resolve_name(
+            ast.name().as_str(),
+            &parsed_document.name,
+            &parsed_document.doc_aliases,
+        );
+        let is_in_bag = self.bag.contains_key(&ast_full_name);
+
+        if is_in_bag {
+            let line_number = self.bag.get(&ast_full_name).unwrap().line_number();
+            ftd::interpreter::utils::insert_export_thing(
+                exports.as_slice(),
+                ast_full_name.as_str(),
+                &mut self.bag,
+                doc_name.as_str(),
+                line_number,
             );
-            let is_in_bag = self.bag.contains_key(&ast_full_name);
+        }
+        let state = &mut *self;
 
-            if is_in_bag {
-                let line_number = self.bag.get(&ast_full_name).unwrap().line_number();
-                ftd::interpreter::utils::insert_export_thing(
-                    exports.as_slice(),
-                    ast_full_name.as_str(),
-                    &mut self.bag,
-                    doc_name.as_str(),
-                    line_number,
-                );
-            }
-            let state = &mut self;
-
-            let mut doc = ftd::interpreter::TDoc::new_state(&name, &aliases, state);
-            if ast.is_record() {
-                if !is_in_bag {
-                    if number_of_scan.eq(&1) {
-                        ftd::interpreter::Record::scan_ast(ast, &mut doc)?;
-                        continue;
-                    } else {
-                        match ftd::interpreter::Record::from_ast(ast, &mut doc)? {
-                            ftd::interpreter::StateWithThing::State(s) => {
-                                return Ok(s.into_interpreter(self))
-                            }
-                            ftd::interpreter::StateWithThing::Thing(record) => {
-                                ftd::interpreter::utils::insert_export_thing(
-                                    exports.as_slice(),
-                                    record.name.as_str(),
-                                    &mut self.bag,
-                                    doc_name.as_str(),
-                                    record.line_number,
-                                );
-                                self.bag.insert(
-                                    record.name.to_string(),
-                                    ftd::interpreter::Thing::Record(record),
-                                );
-                            }
-                            ftd::interpreter::StateWithThing::Continue => continue,
+        let mut doc = ftd::interpreter::TDoc::new_state(&name, &aliases, state);
+        if ast.is_record() {
+            if !is_in_bag {
+                if number_of_scan.eq(&1) {
+                    ftd::interpreter::Record::scan_ast(ast, &mut doc)?;
+                    return Ok(StateWithThing::new_continue());
+                } else {
+                    match ftd::interpreter::Record::from_ast(ast, &mut doc)? {
+                        ftd::interpreter::StateWithThing::State(s) => {
+                            return Ok(StateWithThing::new_state(s))
+                        }
+                        ftd::interpreter::StateWithThing::Thing(record) => {
+                            ftd::interpreter::utils::insert_export_thing(
+                                exports.as_slice(),
+                                record.name.as_str(),
+                                &mut self.bag,
+                                doc_name.as_str(),
+                                record.line_number,
+                            );
+                            self.bag.insert(
+                                record.name.to_string(),
+                                ftd::interpreter::Thing::Record(record),
+                            );
+                        }
+                        ftd::interpreter::StateWithThing::Continue => {
+                            return Ok(StateWithThing::new_continue())
                         }
                     }
                 }
-            } else if ast.is_or_type() {
-                if !is_in_bag {
-                    if number_of_scan.eq(&1) {
-                        ftd::interpreter::OrType::scan_ast(ast, &mut doc)?;
-                        continue;
-                    } else {
-                        match ftd::interpreter::OrType::from_ast(ast, &mut doc)? {
-                            ftd::interpreter::StateWithThing::State(s) => {
-                                return Ok(s.into_interpreter(self))
-                            }
-                            ftd::interpreter::StateWithThing::Thing(or_type) => {
-                                ftd::interpreter::utils::insert_export_thing(
-                                    exports.as_slice(),
-                                    or_type.name.as_str(),
-                                    &mut self.bag,
-                                    doc_name.as_str(),
-                                    or_type.line_number,
-                                );
-                                self.bag.insert(
-                                    or_type.name.to_string(),
-                                    ftd::interpreter::Thing::OrType(or_type),
-                                );
-                            }
-                            ftd::interpreter::StateWithThing::Continue => continue,
+            }
+        } else if ast.is_or_type() {
+            if !is_in_bag {
+                if number_of_scan.eq(&1) {
+                    ftd::interpreter::OrType::scan_ast(ast, &mut doc)?;
+                    return Ok(StateWithThing::new_continue());
+                } else {
+                    match ftd::interpreter::OrType::from_ast(ast, &mut doc)? {
+                        ftd::interpreter::StateWithThing::State(s) => {
+                            return Ok(StateWithThing::new_state(s))
+                        }
+                        ftd::interpreter::StateWithThing::Thing(or_type) => {
+                            ftd::interpreter::utils::insert_export_thing(
+                                exports.as_slice(),
+                                or_type.name.as_str(),
+                                &mut self.bag,
+                                doc_name.as_str(),
+                                or_type.line_number,
+                            );
+                            self.bag.insert(
+                                or_type.name.to_string(),
+                                ftd::interpreter::Thing::OrType(or_type),
+                            );
+                        }
+                        ftd::interpreter::StateWithThing::Continue => {
+                            return Ok(StateWithThing::new_continue())
                         }
                     }
                 }
-            } else if ast.is_function() {
-                if !is_in_bag {
-                    if number_of_scan.eq(&1) {
-                        ftd::interpreter::Function::scan_ast(ast, &mut doc)?;
-                        continue;
-                    } else {
-                        match ftd::interpreter::Function::from_ast(ast, &mut doc)? {
-                            ftd::interpreter::StateWithThing::State(s) => {
-                                return Ok(s.into_interpreter(self))
-                            }
-                            ftd::interpreter::StateWithThing::Thing(function) => {
-                                if let Some(ref js) = function.js {
-                                    let js = js
-                                        .to_owned()
-                                        .resolve(&doc, function.line_number)?
-                                        .string_list(&doc, function.line_number)?;
-
-                                    for js in js.iter() {
-                                        self.js.insert(js.to_string());
-                                    }
+            }
+        } else if ast.is_function() {
+            if !is_in_bag {
+                if number_of_scan.eq(&1) {
+                    ftd::interpreter::Function::scan_ast(ast, &mut doc)?;
+                    return Ok(StateWithThing::new_continue());
+                } else {
+                    match ftd::interpreter::Function::from_ast(ast, &mut doc)? {
+                        ftd::interpreter::StateWithThing::State(s) => {
+                            return Ok(StateWithThing::new_state(s))
+                        }
+                        ftd::interpreter::StateWithThing::Thing(function) => {
+                            if let Some(ref js) = function.js {
+                                let js = js
+                                    .to_owned()
+                                    .resolve(&doc, function.line_number)?
+                                    .string_list(&doc, function.line_number)?;
+
+                                for js in js.iter() {
+                                    self.js.insert(js.to_string());
                                 }
-                                ftd::interpreter::utils::insert_export_thing(
-                                    exports.as_slice(),
-                                    function.name.as_str(),
-                                    &mut self.bag,
-                                    doc_name.as_str(),
-                                    function.line_number,
-                                );
-                                self.bag.insert(
-                                    function.name.to_string(),
-                                    ftd::interpreter::Thing::Function(function),
-                                );
                             }
-                            ftd::interpreter::StateWithThing::Continue => continue,
+                            ftd::interpreter::utils::insert_export_thing(
+                                exports.as_slice(),
+                                function.name.as_str(),
+                                &mut self.bag,
+                                doc_name.as_str(),
+                                function.line_number,
+                            );
+                            self.bag.insert(
+                                function.name.to_string(),
+                                ftd::interpreter::Thing::Function(function),
+                            );
                         }
-                    }
-                }
-            } else if ast.is_variable_definition() {
-                if !is_in_bag {
-                    if number_of_scan.eq(&1) {
-                        ftd::interpreter::Variable::scan_ast(ast, &mut doc)?;
-                        continue;
-                    } else {
-                        match ftd::interpreter::Variable::from_ast(ast, &mut doc, number_of_scan)? {
-                            ftd::interpreter::StateWithThing::State(s) => {
-                                return Ok(s.into_interpreter(self))
-                            }
-                            ftd::interpreter::StateWithThing::Thing(variable) => {
-                                ftd::interpreter::utils::insert_export_thing(
-                                    exports.as_slice(),
-                                    variable.name.as_str(),
-                                    &mut self.bag,
-                                    doc_name.as_str(),
-                                    variable.line_number,
-                                );
-                                self.bag.insert(
-                                    variable.name.to_string(),
-                                    ftd::interpreter::Thing::Variable(variable),
-                                );
-                            }
-                            ftd::interpreter::StateWithThing::Continue => continue,
+                        ftd::interpreter::StateWithThing::Continue => {
+                            return Ok(StateWithThing::new_continue())
                         }
                     }
                 }
-            } else if ast.is_variable_invocation() {
+            }
+        } else if ast.is_variable_definition() {
+            if !is_in_bag {
                 if number_of_scan.eq(&1) {
-                    ftd::interpreter::Variable::scan_update_from_ast(ast, &mut doc)?;
-                    continue;
+                    ftd::interpreter::Variable::scan_ast(ast, &mut doc)?;
+                    return Ok(StateWithThing::new_continue());
                 } else {
-                    match ftd::interpreter::Variable::update_from_ast(ast, &mut doc)? {
+                    match ftd::interpreter::Variable::from_ast(ast, &mut doc, number_of_scan)? {
                         ftd::interpreter::StateWithThing::State(s) => {
-                            return Ok(s.into_interpreter(self))
+                            return Ok(StateWithThing::new_state(s))
                         }
                         ftd::interpreter::StateWithThing::Thing(variable) => {
+                            ftd::interpreter::utils::insert_export_thing(
+                                exports.as_slice(),
+                                variable.name.as_str(),
+                                &mut self.bag,
+                                doc_name.as_str(),
+                                variable.line_number,
+                            );
                             self.bag.insert(
                                 variable.name.to_string(),
                                 ftd::interpreter::Thing::Variable(variable),
                             );
                         }
-                        ftd::interpreter::StateWithThing::Continue => continue,
+                        ftd::interpreter::StateWithThing::Continue => {
+                            return Ok(StateWithThing::new_continue())
+                        }
                     }
                 }
-            } else if ast.is_component_definition() {
-                if !is_in_bag {
-                    if number_of_scan.eq(&1) {
-                        ftd::interpreter::ComponentDefinition::scan_ast(ast, &mut doc)?;
-                        continue;
-                    } else {
-                        match ftd::interpreter::ComponentDefinition::from_ast(ast, &mut doc)? {
-                            ftd::interpreter::StateWithThing::State(s) => {
-                                return Ok(s.into_interpreter(self))
-                            }
-                            ftd::interpreter::StateWithThing::Thing(component) => {
-                                if let Some(ref css) = component.css {
-                                    let css = css
-                                        .to_owned()
-                                        .resolve(&doc, component.line_number)?
-                                        .string(doc.name, component.line_number)?;
-                                    self.css.insert(css);
-                                }
-
-                                ftd::interpreter::utils::insert_export_thing(
-                                    exports.as_slice(),
-                                    component.name.as_str(),
-                                    &mut self.bag,
-                                    doc_name.as_str(),
-                                    component.line_number,
-                                );
-
-                                self.bag.insert(
-                                    component.name.to_string(),
-                                    ftd::interpreter::Thing::Component(component),
-                                );
-                            }
-                            ftd::interpreter::StateWithThing::Continue => continue,
-                        }
+            }
+        } else if ast.is_variable_invocation() {
+            if number_of_scan.eq(&1) {
+                ftd::interpreter::Variable::scan_update_from_ast(ast, &mut doc)?;
+                return Ok(StateWithThing::new_continue());
+            } else {
+                match ftd::interpreter::Variable::update_from_ast(ast, &mut doc)? {
+                    ftd::interpreter::StateWithThing::State(s) => {
+                        return Ok(StateWithThing::new_state(s))
+                    }
+                    ftd::interpreter::StateWithThing::Thing(variable) => {
+                        self.bag.insert(
+                            variable.name.to_string(),
+                            ftd::interpreter::Thing::Variable(variable),
+                        );
+                    }
+                    ftd::interpreter::StateWithThing::Continue => {
+                        return Ok(StateWithThing::new_continue())
                     }
                 }
-            } else if ast.is_web_component_definition() {
-                if !is_in_bag {
-                    if number_of_scan.eq(&1) {
-                        ftd::interpreter::WebComponentDefinition::scan_ast(ast, &mut doc)?;
-                        continue;
-                    } else {
-                        match ftd::interpreter::WebComponentDefinition::from_ast(ast, &mut doc)? {
-                            ftd::interpreter::StateWithThing::State(s) => {
-                                return Ok(s.into_interpreter(self))
-                            }
-                            ftd::interpreter::StateWithThing::Thing(web_component) => {
-                                let js = web_component
-                                    .js
+            }
+        } else if ast.is_component_definition() {
+            if !is_in_bag {
+                if number_of_scan.eq(&1) {
+                    ftd::interpreter::ComponentDefinition::scan_ast(ast, &mut doc)?;
+                    return Ok(StateWithThing::new_continue());
+                } else {
+                    match ftd::interpreter::ComponentDefinition::from_ast(ast, &mut doc)? {
+                        ftd::interpreter::StateWithThing::State(s) => {
+                            return Ok(StateWithThing::new_state(s))
+                        }
+                        ftd::interpreter::StateWithThing::Thing(component) => {
+                            if let Some(ref css) = component.css {
+                                let css = css
                                     .to_owned()
-                                    .resolve(&doc, web_component.line_number)?
-                                    .string(doc.name, web_component.line_number)?;
-                                self.js.insert(format!("{}:type=\"module\"", js));
-                                ftd::interpreter::utils::insert_export_thing(
-                                    exports.as_slice(),
-                                    web_component.name.as_str(),
-                                    &mut self.bag,
-                                    doc_name.as_str(),
-                                    web_component.line_number,
-                                );
-                                self.bag.insert(
-                                    web_component.name.to_string(),
-                                    ftd::interpreter::Thing::WebComponent(web_component),
-                                );
+                                    .resolve(&doc, component.line_number)?
+                                    .string(doc.name, component.line_number)?;
+                                self.css.insert(css);
                             }
-                            ftd::interpreter::StateWithThing::Continue => continue,
+
+                            ftd::interpreter::utils::insert_export_thing(
+                                exports.as_slice(),
+                                component.name.as_str(),
+                                &mut self.bag,
+                                doc_name.as_str(),
+                                component.line_number,
+                            );
+
+                            self.bag.insert(
+                                component.name.to_string(),
+                                ftd::interpreter::Thing::Component(component),
+                            );
+                        }
+                        ftd::interpreter::StateWithThing::Continue => {
+                            return Ok(StateWithThing::new_continue())
                         }
                     }
                 }
-            } else if ast.is_component() {
+            }
+        } else if ast.is_web_component_definition() {
+            if !is_in_bag {
                 if number_of_scan.eq(&1) {
-                    ftd::interpreter::Component::scan_ast(ast, &mut doc)?;
-                    continue;
+                    ftd::interpreter::WebComponentDefinition::scan_ast(ast, &mut doc)?;
+                    return Ok(StateWithThing::new_continue());
                 } else {
-                    match ftd::interpreter::Component::from_ast(ast, &mut doc)? {
+                    match ftd::interpreter::WebComponentDefinition::from_ast(ast, &mut doc)? {
                         ftd::interpreter::StateWithThing::State(s) => {
-                            return Ok(s.into_interpreter(self))
+                            return Ok(StateWithThing::new_state(s))
                         }
-                        ftd::interpreter::StateWithThing::Thing(component) => {
-                            self.instructions.push(component);
+                        ftd::interpreter::StateWithThing::Thing(web_component) => {
+                            let js = web_component
+                                .js
+                                .to_owned()
+                                .resolve(&doc, web_component.line_number)?
+                                .string(doc.name, web_component.line_number)?;
+                            self.js.insert(format!("{}:type=\"module\"", js));
+                            ftd::interpreter::utils::insert_export_thing(
+                                exports.as_slice(),
+                                web_component.name.as_str(),
+                                &mut self.bag,
+                                doc_name.as_str(),
+                                web_component.line_number,
+                            );
+                            self.bag.insert(
+                                web_component.name.to_string(),
+                                ftd::interpreter::Thing::WebComponent(web_component),
+                            );
+                        }
+                        ftd::interpreter::StateWithThing::Continue => {
+                            return Ok(StateWithThing::new_continue())
                         }
-                        ftd::interpreter::StateWithThing::Continue => continue,
                     }
                 }
             }
-            self.remove_last();
+        } else if ast.is_component() {
+            if number_of_scan.eq(&1) {
+                ftd::interpreter::Component::scan_ast(ast, &mut doc)?;
+                return Ok(StateWithThing::new_continue());
+            } else {
+                match ftd::interpreter::Component::from_ast(ast, &mut doc)? {
+                    ftd::interpreter::StateWithThing::State(s) => {
+                        return Ok(StateWithThing::new_state(s))
+                    }
+                    ftd::interpreter::StateWithThing::Thing(component) => {
+                        self.instructions.push(component);
+                    }
+                    ftd::interpreter::StateWithThing::Continue => {
+                        return Ok(StateWithThing::new_continue())
+                    }
+                }
+            }
         }
+        Ok(StateWithThing::new_thing(()))
+    }
 
-        if self.to_process.stack.is_empty() {
-            let document = Document {
-                data: self.bag,
-                aliases: self
-                    .parsed_libs
-                    .get(self.id.as_str())
-                    .unwrap()
-                    .doc_aliases
-                    .clone(),
-                tree: self.instructions,
-                name: self.id,
-                js: self.js,
-                css: self.css,
-            };
+    /// Records the error and leaves out the thing that failed, with everything that was being
+    /// processed for it, so the rest of the document is still interpreted and all its errors are
+    /// reported together.
+    fn skip_failed(&mut self, error: ftd::interpreter::Error) {
+        let label = self.definition_label();
+        for mut diagnostic in error.diagnostics() {
+            if let Some(ref label) = label {
+                if diagnostic.line_number != Some(label.line_number) {
+                    diagnostic.labels.push(label.clone());
+                }
+            }
+            let found = self.errors.iter().any(|d| {
+                d.message == diagnostic.message
+                    && d.doc_id == diagnostic.doc_id
+                    && d.line_number == diagnostic.line_number
+            });
+            if !found {
+                self.errors.push(diagnostic);
+            }
+        }
 
-            Ok(Interpreter::Done { document })
-        } else {
-            self.continue_processing()
+        self.pending_imports = Default::default();
+        // the bottom of the stack is the document, the rest was added while processing its first
+        // thing
+        while self.to_process.stack.len() > 1 {
+            self.remove_last();
         }
+        self.remove_last();
+    }
+
+    /// Where the component or variable used by the thing on top of the stack is defined.
+    fn definition_label(&self) -> Option<ftd::interpreter::Label> {
+        let (doc_name, _, ast) = self.peek_stack()?;
+        if !ast.is_component() && !ast.is_variable_invocation() {
+            return None;
+        }
+        let document = self.parsed_libs.get(doc_name.as_str())?;
+        let name = ast.name();
+        let full_name = ftd::interpreter::utils::resolve_name(
+            name.as_str(),
+            document.name.as_str(),
+            &document.doc_aliases,
+        );
+        let line_number = self.bag.get(full_name.as_str())?.line_number();
+        let (module, _) = full_name.split_once('#')?;
+        if line_number == 0 {
+            // defined by ftd itself
+            return None;
+        }
+        Some(ftd::interpreter::Label {
+            message: format!("`{}` is defined here", name),
+            doc_id: module.to_string(),
+            line_number,
+            mark: Some(ftd::interpreter::Mark::Name(name)),
+        })
     }
 
     /// Returns (doc_name, number_of_scan, last_ast)
@@ -783,6 +885,7 @@ impl InterpreterState {
             conditional_value: vec![],
             line_number: variable_definition.line_number,
             is_static: true,
+            persist: variable_definition.flags.persist == Some(true),
         }
         .set_static(&doc);
         ftd::interpreter::utils::validate_variable(&variable, &doc)?;
@@ -814,6 +917,7 @@ impl InterpreterState {
             conditional_value: vec![],
             line_number: 0,
             is_static: true,
+            persist: false,
         }
         .set_static(&doc);
         ftd::interpreter::utils::validate_variable(&variable, &doc)?;
@@ -875,6 +979,8 @@ pub struct ParsedDocument {
     pub exposings: ftd::Map<String>,
     pub foreign_variable: Vec<String>,
     pub foreign_function: Vec<String>,
+    /// The names of the things marked `private` in this document
+    pub private_things: std::collections::HashSet<String>,
 }
 
 #[derive(Debug, Clone, Default, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -894,10 +1000,17 @@ impl ParsedDocument {
         source: &str,
         line_number: usize,
     ) -> ftd::interpreter::Result<ParsedDocument> {
-        let ast = ftd::ast::AST::from_sections(
-            ftd::p1::parse_with_line_number(source, id, line_number)?.as_slice(),
-            id,
-        )?;
+        // every syntax error in the document is reported, not just the first one
+        let (sections, p1_errors) = ftd::p1::parse_with_errors(source, id, line_number);
+        let (ast, ast_errors) = ftd::ast::AST::from_sections_with_errors(sections.as_slice(), id);
+        let errors = p1_errors
+            .into_iter()
+            .map(ftd::interpreter::Error::from)
+            .chain(ast_errors.into_iter().map(ftd::interpreter::Error::from))
+            .collect::<Vec<_>>();
+        if !errors.is_empty() {
+            return Err(ftd::interpreter::Error::from_errors(errors));
+        }
         let (doc_aliases, re_exports, exposings) = {
             let mut doc_aliases = ftd::interpreter::default::default_aliases();
             let mut re_exports = ReExport {
@@ -937,6 +1050,11 @@ impl ParsedDocument {
             }
             (doc_aliases, re_exports, exposings)
         };
+        let private_things = ast
+            .iter()
+            .filter(|v| v.is_private())
+            .map(|v| v.name())
+            .collect();
 
         Ok(ParsedDocument {
             name: id.to_string(),
@@ -947,6 +1065,7 @@ impl ParsedDocument {
             exposings,
             foreign_variable: vec![],
             foreign_function: vec![],
+            private_things,
         })
     }
 
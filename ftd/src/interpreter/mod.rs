@@ -31,7 +31,10 @@ macro_rules! try_state {
 #[cfg(test)]
 #[macro_use]
 mod test;
+pub mod a11y;
 mod constants;
+pub mod datetime;
+mod diagnostic;
 mod main;
 pub mod prelude;
 mod tdoc;
@@ -39,6 +42,7 @@ mod things;
 pub mod utils;
 pub use prelude::*;
 
+pub use diagnostic::{Diagnostic, Label, Mark, Span};
 pub use tdoc::{BagOrState, TDoc};
 pub use things::expression;
 
@@ -106,6 +110,91 @@ pub enum Error {
 
     #[error("Invalid access: {message}, line_number: {line_number}")]
     InvalidAccessError { message: String, line_number: usize },
+
+    #[error("{}", diagnostic::messages(.diagnostics))]
+    Diagnostics {
+        diagnostics: Vec<ftd::interpreter::Diagnostic>,
+    },
+
+    /// `error`, about the name `name` used on its line
+    #[error("{error}")]
+    About { error: Box<Error>, name: String },
+}
+
+impl Error {
+    /// Document and line the error was found on, if known. `InvalidAccessError` does not know
+    /// its document, it is `None` there.
+    pub fn location(&self) -> Option<(Option<&str>, usize)> {
+        match self {
+            Error::P1Error(e) => e.location().map(|(d, l)| (Some(d), l)),
+            Error::ASTError(e) => e.location().map(|(d, l)| (Some(d), l)),
+            Error::InvalidKind {
+                doc_id,
+                line_number,
+                ..
+            }
+            | Error::ValueNotFound {
+                doc_id,
+                line_number,
+                ..
+            }
+            | Error::ParseError {
+                doc_id,
+                line_number,
+                ..
+            } => Some((Some(doc_id.as_str()), *line_number)),
+            Error::InvalidAccessError { line_number, .. } => Some((None, *line_number)),
+            Error::Diagnostics { diagnostics } => diagnostics
+                .first()
+                .and_then(|d| Some((d.doc_id.as_deref(), d.line_number?))),
+            Error::About { error, .. } => error.location(),
+            _ => None,
+        }
+    }
+
+    /// The part of its line the error is about, if the place the error was raised knows it.
+    pub fn mark(&self) -> Option<ftd::interpreter::Mark> {
+        match self {
+            Error::P1Error(e) => e.columns().map(ftd::interpreter::Mark::Columns),
+            Error::About { name, .. } => Some(ftd::interpreter::Mark::Name(name.to_string())),
+            _ => None,
+        }
+    }
+
+    /// `self`, about the name `name` used on its line.
+    pub fn about(self, name: &str) -> Error {
+        Error::About {
+            error: Box::new(self),
+            name: name.to_string(),
+        }
+    }
+
+    /// The errors in `self`: the diagnostics of `Diagnostics`, else a diagnostic for `self`.
+    pub fn diagnostics(&self) -> Vec<ftd::interpreter::Diagnostic> {
+        match self {
+            Error::Diagnostics { diagnostics } => diagnostics.clone(),
+            _ => vec![ftd::interpreter::Diagnostic::new(self)],
+        }
+    }
+
+    /// All the errors with code frames, see `ftd::interpreter::Diagnostic::render`.
+    pub fn render(&self, doc_id: &str, source: &str) -> String {
+        self.diagnostics()
+            .iter()
+            .map(|d| d.render(doc_id, source))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    /// One error for all of `errors`, the error itself if there is only one.
+    pub fn from_errors(mut errors: Vec<Error>) -> Error {
+        if errors.len() == 1 {
+            return errors.remove(0);
+        }
+        Error::Diagnostics {
+            diagnostics: errors.iter().flat_map(Error::diagnostics).collect(),
+        }
+    }
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
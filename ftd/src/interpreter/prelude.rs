@@ -7,8 +7,8 @@ pub use ftd::interpreter::main::{
 
 pub use ftd::interpreter::things::{
     component::{
-        Argument, Component, ComponentDefinition, ComponentSource, Event, EventName, Loop,
-        Property, PropertySource,
+        Argument, Case, Component, ComponentDefinition, ComponentSource, Event, EventName, Loop,
+        Match, Property, PropertySource,
     },
     default,
     expression::Expression,
@@ -65,6 +65,11 @@ impl<'a> TDoc<'a> {
     ) -> ftd::interpreter::Result<ftd::interpreter::Record> {
         match self.get_thing(name, line_number)? {
             ftd::interpreter::Thing::Record(r) => Ok(r),
+            // The record of a variant, i.e. `shape.circle` in `-- case: circle(c)`
+            ftd::interpreter::Thing::OrTypeWithVariant {
+                variant: ftd::interpreter::OrTypeVariant::AnonymousRecord(r),
+                ..
+            } => Ok(r),
             t => self.err(
                 format!("Expected Record, found: `{:?}`", t).as_str(),
                 name,
@@ -102,9 +107,13 @@ impl<'a> TDoc<'a> {
             ftd::interpreter::StateWithThing::Continue => {
                 Ok(ftd::interpreter::StateWithThing::new_continue())
             }
-            ftd::interpreter::StateWithThing::Thing(ftd::interpreter::Thing::Record(r)) => {
-                Ok(ftd::interpreter::StateWithThing::new_thing(r))
-            }
+            ftd::interpreter::StateWithThing::Thing(ftd::interpreter::Thing::Record(r))
+            | ftd::interpreter::StateWithThing::Thing(
+                ftd::interpreter::Thing::OrTypeWithVariant {
+                    variant: ftd::interpreter::OrTypeVariant::AnonymousRecord(r),
+                    ..
+                },
+            ) => Ok(ftd::interpreter::StateWithThing::new_thing(r)),
             ftd::interpreter::StateWithThing::Thing(t) => self.err(
                 format!("Expected Record, found: `{:?}`", t).as_str(),
                 name,
@@ -304,6 +313,27 @@ impl<'a> TDoc<'a> {
                     }
                     Ok(value)
                 }
+                ftd::interpreter::Value::Map { data, .. } => {
+                    let value = data
+                        .get(p1.as_str())
+                        .ok_or(ftd::interpreter::Error::ParseError {
+                            message: format!("Can't find key `{}` in map", p1),
+                            doc_id: doc.name.to_string(),
+                            line_number,
+                        })?
+                        .clone()
+                        .resolve_with_inherited(doc, line_number, inherited_variables)?;
+                    if let Some(p2) = p2 {
+                        return resolve_(
+                            p2.as_str(),
+                            &value,
+                            line_number,
+                            doc,
+                            inherited_variables,
+                        );
+                    }
+                    Ok(value)
+                }
                 t => ftd::interpreter::utils::e2(
                     format!("Expected record found `{:?}`", t).as_str(),
                     doc.name,
@@ -670,8 +700,12 @@ impl<'a> TDoc<'a> {
         {
             let (v, remaining) = ftd::interpreter::utils::split_at(name, ".");
             match kind {
-                ftd::interpreter::Kind::Record { name: rec_name } => {
-                    let record = try_ok_state!(doc.search_record(rec_name.as_str(), line_number)?);
+                ftd::interpreter::Kind::Record {
+                    name: rec_name,
+                    type_arguments,
+                } => {
+                    let record = try_ok_state!(doc.search_record(rec_name.as_str(), line_number)?)
+                        .with_type_arguments(type_arguments.as_slice());
                     let field_kind = record.get_field(&v, doc.name, line_number)?.kind.to_owned();
                     if let Some(remaining) = remaining {
                         get_kind_(field_kind.kind, &remaining, doc, line_number)
@@ -679,7 +713,7 @@ impl<'a> TDoc<'a> {
                         Ok(ftd::interpreter::StateWithThing::new_thing(field_kind))
                     }
                 }
-                ftd::interpreter::Kind::List { kind } => {
+                ftd::interpreter::Kind::List { kind } | ftd::interpreter::Kind::Map { kind } => {
                     if let Some(remaining) = remaining {
                         get_kind_(*kind, &remaining, doc, line_number)
                     } else {
@@ -897,6 +931,7 @@ impl<'a> TDoc<'a> {
                     let fields = match value.resolve(doc, line_number)?.inner() {
                         Some(ftd::interpreter::Value::Record { fields, .. }) => fields,
                         Some(ftd::interpreter::Value::Object { values }) => values,
+                        Some(ftd::interpreter::Value::Map { data, .. }) => data,
                         Some(ftd::interpreter::Value::List { data, .. }) => data
                             .into_iter()
                             .enumerate()
@@ -948,6 +983,7 @@ impl<'a> TDoc<'a> {
                                     conditional_value: vec![],
                                     line_number,
                                     is_static: !mutable,
+                                    persist: false,
                                 });
                             if let Some(remaining) = remaining {
                                 return get_thing_(doc, line_number, &remaining, &thing);
@@ -977,6 +1013,7 @@ impl<'a> TDoc<'a> {
                             conditional_value: vec![],
                             line_number: *line_number,
                             is_static: !mutable,
+                            persist: false,
                         }),
                         Some(ftd::interpreter::PropertyValue::Reference { name, .. })
                         | Some(ftd::interpreter::PropertyValue::Clone { name, .. }) => {
@@ -1072,6 +1109,8 @@ impl<'a> TDoc<'a> {
                 .unwrap_or_default()
         );
 
+        self.check_access(doc_name.as_str(), thing_name.as_str(), line_number)?;
+
         let state = if let Some(state) = {
             match &mut self.bag {
                 BagOrState::Bag(_) => None,
@@ -1080,7 +1119,7 @@ impl<'a> TDoc<'a> {
         } {
             state
         } else {
-            return self.err("not found", name, "search_thing", line_number);
+            return self.not_found(name.as_str(), "search_thing", line_number);
         };
 
         if doc_name.eq(ftd::interpreter::FTD_INHERITED) {
@@ -1318,6 +1357,7 @@ impl<'a> TDoc<'a> {
                     let fields = match value.resolve(doc, line_number)?.inner() {
                         Some(ftd::interpreter::Value::Record { fields, .. }) => fields,
                         Some(ftd::interpreter::Value::Object { values }) => values,
+                        Some(ftd::interpreter::Value::Map { data, .. }) => data,
                         Some(ftd::interpreter::Value::List { data, .. }) => data
                             .into_iter()
                             .enumerate()
@@ -1370,6 +1410,7 @@ impl<'a> TDoc<'a> {
                                     conditional_value: vec![],
                                     line_number,
                                     is_static: !mutable,
+                                    persist: false,
                                 });
                             if let Some(remaining) = remaining {
                                 return search_thing_(doc, line_number, &remaining, thing);
@@ -1399,6 +1440,7 @@ impl<'a> TDoc<'a> {
                             conditional_value: vec![],
                             line_number: *line_number,
                             is_static: !mutable,
+                            persist: false,
                         }),
                         Some(ftd::interpreter::PropertyValue::Reference { name, .. })
                         | Some(ftd::interpreter::PropertyValue::Clone { name, .. }) => {
@@ -1427,12 +1469,10 @@ impl<'a> TDoc<'a> {
                     ..
                 }) => {
                     let or_type_name = ftd::interpreter::OrType::or_type_name(name.as_str());
-                    if let Some(thing) = variants.into_iter().find(|or_type_variant| {
-                        let variant_name = or_type_variant.name();
-                        variant_name
-                            .trim_start_matches(format!("{}.", or_type_name).as_str())
-                            .eq(&v)
-                    }) {
+                    if let Some(thing) = variants
+                        .into_iter()
+                        .find(|or_type_variant| or_type_variant.short_name().eq(&v))
+                    {
                         // Todo: Handle remaining
                         ftd::interpreter::Thing::OrTypeWithVariant {
                             or_type: name.clone(),
@@ -1491,6 +1531,8 @@ impl<'a> TDoc<'a> {
                 .unwrap_or_default()
         );
 
+        self.check_access(doc_name.as_str(), thing_name.as_str(), line_number)?;
+
         let state = if let Some(state) = {
             match &mut self.bag {
                 BagOrState::Bag(_) => None,
@@ -1499,7 +1541,7 @@ impl<'a> TDoc<'a> {
         } {
             state
         } else {
-            return self.err("not found", name, "search_thing", line_number);
+            return self.not_found(name.as_str(), "search_thing", line_number);
         };
 
         let current_parsed_document = state.parsed_libs.get(state.id.as_str()).unwrap();
@@ -1542,7 +1584,7 @@ impl<'a> TDoc<'a> {
                 }
             } else if !current_doc_contains_thing.is_empty() && state.peek_stack().unwrap().1.gt(&4)
             {
-                return self.err("not found", name, "search_thing", line_number);
+                return self.not_found(name.as_str(), "search_thing", line_number);
             }
         }
 
@@ -1631,7 +1673,7 @@ impl<'a> TDoc<'a> {
                         return Ok(thing);
                     }
                 }*/
-                return self.err("not found", name, "search_thing", line_number);
+                return self.not_found(name.as_str(), "search_thing", line_number);
             }
 
             state
@@ -1653,7 +1695,7 @@ impl<'a> TDoc<'a> {
         }
 
         if doc_name.eq(self.name) {
-            return self.err("not found", name, "search_thing", line_number);
+            return self.not_found(name.as_str(), "search_thing", line_number);
         }
 
         state
@@ -1762,9 +1804,8 @@ impl<'a> TDoc<'a> {
                     None => match tdoc.bag().get(name).map(|v| (v.to_owned(), None)) {
                         Some(a) => a,
                         None => {
-                            return tdoc.err(
-                                "not found",
-                                splited_name,
+                            return tdoc.not_found(
+                                splited_name.as_str(),
                                 "get_initial_thing",
                                 line_number,
                             );
@@ -1777,10 +1818,44 @@ impl<'a> TDoc<'a> {
                 return Ok((thing_name, remaining));
             }
 
+            if let Some((doc_name, name)) = thing_name.name().split_once('#') {
+                tdoc.check_access(doc_name, name, line_number)?;
+            }
+
             Ok((thing_name, remaining))
         }
     }
 
+    /// Errors if `thing_name` is marked `private` in the module defining it and is being looked up
+    /// from another module. Privacy is only checked while interpreting, the executor works on
+    /// the already resolved bag.
+    fn check_access(
+        &self,
+        doc_name: &str,
+        thing_name: &str,
+        line_number: usize,
+    ) -> ftd::interpreter::Result<()> {
+        let state = match self.state() {
+            Some(state) => state,
+            None => return Ok(()),
+        };
+        if doc_name.eq(self.name) {
+            return Ok(());
+        }
+        let is_private = state
+            .parsed_libs
+            .get(doc_name)
+            .is_some_and(|document| document.private_things.contains(thing_name));
+        if is_private {
+            return ftd::interpreter::utils::e2(
+                format!("`{}` is private to `{}`", thing_name, doc_name),
+                self.name,
+                line_number,
+            );
+        }
+        Ok(())
+    }
+
     pub fn rows_to_value(
         &self,
         rows: &[Vec<serde_json::Value>],
@@ -1850,7 +1925,7 @@ impl<'a> TDoc<'a> {
         kind: &ftd::interpreter::Kind,
         value: &ftd::ast::VariableValue,
     ) -> ftd::interpreter::Result<ftd::interpreter::Value> {
-        if let ftd::interpreter::Kind::Record { name } = kind {
+        if let ftd::interpreter::Kind::Record { name, .. } = kind {
             return self.row_to_record(row, name, value);
         }
 
@@ -2121,6 +2196,59 @@ impl<'a> TDoc<'a> {
                     kind: kind.to_owned().into_kind_data(),
                 }
             }
+            ftd::interpreter::Kind::DateTime
+            | ftd::interpreter::Kind::Date
+            | ftd::interpreter::Kind::Duration => {
+                let value = match json {
+                    serde_json::Value::String(v) => {
+                        ftd::interpreter::Value::from_iso_string(v, kind)
+                    }
+                    serde_json::Value::Number(n) if kind.is_datetime() => {
+                        n.as_i64().map(|v| ftd::interpreter::Value::DateTime {
+                            value: ftd::interpreter::datetime::from_unix_timestamp(v),
+                        })
+                    }
+                    serde_json::Value::Number(n) if kind.is_duration() => n
+                        .as_i64()
+                        .map(|value| ftd::interpreter::Value::Duration { value }),
+                    _ => None,
+                };
+                match value {
+                    Some(value) => value,
+                    None => {
+                        return ftd::interpreter::utils::e2(
+                            format!("Can't parse to {}, found: {json}", kind.get_name()),
+                            self.name,
+                            line_number,
+                        )
+                    }
+                }
+            }
+            ftd::interpreter::Kind::Map { kind } => {
+                let mut data: ftd::Map<ftd::interpreter::PropertyValue> = Default::default();
+                if let serde_json::Value::Object(o) = json {
+                    for (key, item) in o {
+                        data.insert(
+                            key.to_string(),
+                            ftd::interpreter::PropertyValue::Value {
+                                value: self.as_json_(kind, item, None, None, line_number)?,
+                                is_mutable: false,
+                                line_number,
+                            },
+                        );
+                    }
+                } else {
+                    return ftd::interpreter::utils::e2(
+                        format!("expected object of map type, found: {}", json),
+                        self.name,
+                        line_number,
+                    );
+                }
+                ftd::interpreter::Value::Map {
+                    data,
+                    kind: kind.as_ref().to_owned().into_kind_data(),
+                }
+            }
             ftd::interpreter::Kind::Optional { kind, .. } => {
                 let kind = kind.as_ref();
                 match json {
@@ -2164,4 +2292,15 @@ impl<'a> TDoc<'a> {
             line_number,
         )
     }
+
+    /// The error for `name` not being found, it is about `name`.
+    pub(crate) fn not_found<T>(
+        &self,
+        name: &str,
+        f: &str,
+        line_number: usize,
+    ) -> ftd::interpreter::Result<T> {
+        self.err("not found", name, f, line_number)
+            .map_err(|e| e.about(name))
+    }
 }
@@ -258,3 +258,342 @@ fn evalexpr_test() {
         }
             */
 }
+
+#[test]
+fn all_errors() {
+    let source = indoc::indoc!(
+        "
+        -- component foo:
+        caption title:
+
+        -- ftd.text: $foo.title
+
+        -- end: foo
+
+        -- foo: hello
+        bar: 1
+
+        -- ftd.text: $missing
+
+        -- foo: world
+        "
+    );
+    let error = interpret_helper("foo", source).unwrap_err();
+    assert_eq!(error.diagnostics().len(), 2);
+    assert_eq!(
+        error.render("foo", source),
+        indoc::indoc!(
+            r#"
+            error: foo:9 -> Header type `bar` mutable: `false` argument not found for component `foo`
+             --> foo:9:1
+              |
+            9 | bar: 1
+              | ^^^
+              |
+            1 | -- component foo:
+              |              --- `foo` is defined here
+
+            error: foo:11 -> foo: not found ("foo#missing"), f: search_thing
+              --> foo:11:15
+               |
+            11 | -- ftd.text: $missing
+               |               ^^^^^^^"#
+        )
+    );
+}
+
+#[test]
+fn a11y_check() {
+    let source = indoc::indoc!(
+        "
+        -- ftd.color fg:
+        light: #999999
+        dark: #ffffff
+
+        -- ftd.color bg:
+        light: #ffffff
+        dark: #000000
+
+        -- ftd.image: https://example.com/logo.png
+
+        -- ftd.image: https://example.com/line.png
+        aria-role: none
+
+        -- ftd.text-input:
+        placeholder: Name
+
+        -- ftd.text-input:
+        aria-label: Email
+
+        -- ftd.text: Faint
+        color: $fg
+        background.solid: $bg
+
+        -- card:
+
+        -- component card:
+
+        -- ftd.column:
+        $on-click$: $ftd.toggle($a = $open)
+
+        -- ftd.text: Open
+        link: https://example.com
+        $on-click$: $ftd.toggle($a = $open)
+
+        -- ftd.text: Close
+        tab-index: 0
+        $on-click$: $ftd.toggle($a = $open)
+
+        -- end: ftd.column
+
+        -- end: card
+
+        -- boolean $open: false
+
+        -- ftd.column:
+        background.solid: $bg
+
+        -- ftd.text: Inside
+        color: $fg
+
+        -- end: ftd.column
+        "
+    );
+    let document = interpret_helper("foo", source).unwrap();
+    let issues = ftd::interpreter::a11y::check(&document)
+        .into_iter()
+        .map(|issue| format!("{}:{} {}", issue.line_number, issue.code, issue.message))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        issues,
+        vec![
+            "9:missing-alt `ftd.image` has no `alt`, use `aria-role: none` if the image is \
+            decorative",
+            "14:missing-label `ftd.text-input` has no `aria-label`",
+            "20:low-contrast contrast of `color` on `background` is 2.8:1 in light mode, it \
+            should be at least 4.5:1",
+            "28:not-focusable `ftd.column` has `$on-click$` but can not be reached with the \
+            keyboard, give it `tab-index: 0`",
+            "48:low-contrast contrast of `color` on `background` is 2.8:1 in light mode, it \
+            should be at least 4.5:1",
+        ]
+    );
+}
+
+#[test]
+fn map_keys_are_strings() {
+    let source = indoc::indoc!(
+        "
+        -- map<string, integer> scores:
+        alice: 10
+
+        -- map<integer, string> names:
+        1: alice
+        "
+    );
+    let error = interpret_helper("foo", source).unwrap_err();
+    assert_eq!(
+        error
+            .diagnostics()
+            .into_iter()
+            .map(|d| (d.line_number, d.message))
+            .collect::<Vec<_>>(),
+        vec![(
+            Some(4),
+            "ASTError: ASTParseError: foo:4 -> Map keys must be `string`, found: `integer` in \
+            `map<integer,string>`"
+                .to_string()
+        )]
+    );
+}
+
+#[test]
+fn mismatched_type_argument() {
+    let pair = indoc::indoc!(
+        "
+        -- record pair<T>:
+        caption T first:
+        T second:
+
+        "
+    );
+    let messages = |source: &str| {
+        interpret_helper("foo", format!("{}{}", pair, source).as_str())
+            .unwrap_err()
+            .diagnostics()
+            .into_iter()
+            .map(|d| (d.line_number, d.message))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        messages("-- pair<integer> scores: 10\nsecond: twenty\n\n-- ftd.integer: $scores.second\n"),
+        vec![(
+            None,
+            "ParseIntError: invalid digit found in string".to_string()
+        )]
+    );
+    assert_eq!(
+        messages("-- pair<integer> scores: 10\nsecond: 20\n\n-- ftd.text: $scores.first\n"),
+        vec![(
+            Some(8),
+            "foo:8 -> 3.2 Expected kind `KindData { kind: String, caption: true, body: true }`, \
+            found: `KindData { kind: Integer, caption: true, body: false }`"
+                .to_string()
+        )]
+    );
+    assert_eq!(
+        messages(indoc::indoc!(
+            "
+            -- pair<integer> scores: 10
+            second: 20
+
+            -- show: $scores
+            other: twenty
+
+            -- component show<T>:
+            caption pair<T> p:
+            T other:
+
+            -- ftd.column:
+
+            -- end: ftd.column
+
+            -- end: show
+            "
+        )),
+        vec![(
+            Some(9),
+            "foo:9 -> Expected `integer`, found: `twenty`".to_string()
+        )]
+    );
+}
+
+#[test]
+fn private_thing_from_another_module() {
+    let lib = indoc::indoc!(
+        "
+        -- private string greeting: Hello
+
+        -- string name: World
+        "
+    );
+    let source = indoc::indoc!(
+        "
+        -- import: lib
+
+        -- ftd.text: $lib.name
+
+        -- ftd.text: $lib.greeting
+        "
+    );
+    let mut s = ftd::interpreter::interpret("foo", source).unwrap();
+    let error = loop {
+        match s {
+            ftd::interpreter::Interpreter::StuckOnImport {
+                module, state: st, ..
+            } => {
+                let document =
+                    ftd::interpreter::ParsedDocument::parse(module.as_str(), lib).unwrap();
+                match st.continue_after_import(module.as_str(), document, vec![], vec![], 0) {
+                    Ok(state) => s = state,
+                    Err(e) => break e,
+                }
+            }
+            t => panic!("expected an import or an error, found: {:?}", t),
+        }
+    };
+    assert_eq!(
+        error
+            .diagnostics()
+            .into_iter()
+            .map(|d| (d.line_number, d.message))
+            .collect::<Vec<_>>(),
+        vec![(
+            Some(5),
+            "foo:5 -> `greeting` is private to `lib`".to_string()
+        )]
+    );
+}
+
+#[test]
+fn function_statement_error_line() {
+    let source = indoc::indoc!(
+        "
+        -- integer total(items):
+        integer list items:
+
+        let total = 0;
+        for item in items {
+            total = \"x\";
+        }
+        return total;
+
+
+        -- integer list numbers: 1, 2
+
+        -- ftd.integer: $total(items = $numbers)
+        "
+    );
+    assert_eq!(
+        interpret_helper("foo", source)
+            .unwrap_err()
+            .diagnostics()
+            .into_iter()
+            .map(|d| (d.line_number, d.message))
+            .collect::<Vec<_>>(),
+        vec![(
+            Some(6),
+            "foo:6 -> Expected `integer` for `total` in function `total`, found `string`"
+                .to_string()
+        )]
+    );
+}
+
+#[test]
+fn match_expression() {
+    let size = indoc::indoc!(
+        "
+        -- or-type size:
+
+        -- constant string small: small
+
+        -- constant string large: large
+
+        -- end: size
+
+        -- size s: small
+
+        "
+    );
+    let messages =
+        |source: &str| match interpret_helper("foo", format!("{}{}", size, source).as_str()) {
+            Ok(_) => vec![],
+            Err(e) => e
+                .diagnostics()
+                .into_iter()
+                .map(|d| d.message)
+                .collect::<Vec<_>>(),
+        };
+
+    // commas and braces in quoted values are part of the value
+    assert!(messages("-- ftd.text: $match $s { small => \"a, }\", large => b }\n").is_empty());
+    assert_eq!(
+        messages("-- ftd.text: $match $s { small => a b, _ => c }\n"),
+        vec![
+            "foo:11 -> A case can only give a text, a number, a boolean or a reference, quote text \
+            with spaces. Expected `$match $<value> { <variant> => <value>, .. }`, found: \
+            `$match $s { small => a b, _ => c }`"
+                .to_string()
+        ]
+    );
+    assert_eq!(
+        messages("-- ftd.text: $match $s { small => a, large }\n"),
+        vec![
+            "foo:11 -> Expected `<variant> => <value>`, found: `large`. Expected \
+            `$match $<value> { <variant> => <value>, .. }`, found: \
+            `$match $s { small => a, large }`"
+                .to_string()
+        ]
+    );
+}
@@ -1,6 +1,8 @@
 #[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct ComponentDefinition {
     pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub type_parameters: Vec<String>,
     pub arguments: Vec<Argument>,
     pub definition: Component,
     pub css: Option<ftd::interpreter::PropertyValue>,
@@ -10,6 +12,7 @@ pub struct ComponentDefinition {
 impl ComponentDefinition {
     pub(crate) fn new(
         name: &str,
+        type_parameters: Vec<String>,
         arguments: Vec<Argument>,
         definition: Component,
         css: Option<ftd::interpreter::PropertyValue>,
@@ -17,6 +20,7 @@ impl ComponentDefinition {
     ) -> ComponentDefinition {
         ComponentDefinition {
             name: name.to_string(),
+            type_parameters,
             arguments,
             definition,
             css,
@@ -46,7 +50,13 @@ impl ComponentDefinition {
             doc,
         )?;
 
-        Argument::scan_ast_fields(component_definition.arguments, doc, &Default::default())?;
+        Argument::scan_ast_fields(
+            component_definition.arguments,
+            doc,
+            &ftd::interpreter::utils::type_parameters_known_kinds(
+                &component_definition.type_parameters,
+            ),
+        )?;
 
         Ok(())
     }
@@ -79,7 +89,9 @@ impl ComponentDefinition {
             component_definition.name.as_str(),
             component_definition.arguments,
             doc,
-            &Default::default(),
+            &ftd::interpreter::utils::type_parameters_known_kinds(
+                &component_definition.type_parameters
+            ),
         )?);
 
         let definition_name_with_arguments =
@@ -100,6 +112,7 @@ impl ComponentDefinition {
         Ok(ftd::interpreter::StateWithThing::new_thing(
             ComponentDefinition::new(
                 name.as_str(),
+                component_definition.type_parameters,
                 arguments,
                 definition,
                 css,
@@ -129,6 +142,8 @@ pub struct Component {
     pub children: Vec<Component>,
     pub source: ComponentSource,
     pub line_number: usize,
+    #[serde(rename = "match", default, skip_serializing_if = "Option::is_none")]
+    pub match_: Option<Box<Match>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Default, serde::Deserialize, serde::Serialize)]
@@ -149,6 +164,7 @@ impl Component {
             children: vec![],
             source: Default::default(),
             line_number: 0,
+            match_: None,
         }
     }
 
@@ -242,6 +258,25 @@ impl Component {
         definition_name_with_arguments: Option<(&str, &[String])>,
         doc: &mut ftd::interpreter::TDoc,
     ) -> ftd::interpreter::Result<()> {
+        Component::scan_ast_component_with_alias(
+            ast_component,
+            definition_name_with_arguments,
+            None,
+            doc,
+        )
+    }
+
+    /// Same as `scan_ast_component`, `alias` is the name bound by the enclosing `-- case:`
+    fn scan_ast_component_with_alias(
+        ast_component: ftd::ast::Component,
+        definition_name_with_arguments: Option<(&str, &[String])>,
+        alias: Option<String>,
+        doc: &mut ftd::interpreter::TDoc,
+    ) -> ftd::interpreter::Result<()> {
+        if ast_component.name.eq(ftd::ast::utils::MATCH) {
+            return Match::scan_ast_match(ast_component, definition_name_with_arguments, doc);
+        }
+
         Property::scan_ast_children(ast_component.children, definition_name_with_arguments, doc)?;
         match definition_name_with_arguments {
             Some((definition, _))
@@ -252,7 +287,7 @@ impl Component {
             _ => doc.scan_thing(ast_component.name.as_str(), ast_component.line_number)?,
         }
 
-        let mut loop_object_name_and_kind = None;
+        let mut loop_object_name_and_kind = alias;
         if let Some(v) = ast_component.iteration {
             loop_object_name_and_kind = Some(doc.resolve_name(v.alias.as_str()));
             Loop::scan_ast_loop(v, definition_name_with_arguments, doc)?;
@@ -297,6 +332,26 @@ impl Component {
         definition_name_with_arguments: &mut Option<(&str, &mut [Argument])>,
         doc: &mut ftd::interpreter::TDoc,
     ) -> ftd::interpreter::Result<ftd::interpreter::StateWithThing<Component>> {
+        Component::from_ast_component_with_alias(
+            ast_component,
+            definition_name_with_arguments,
+            None,
+            doc,
+        )
+    }
+
+    /// Same as `from_ast_component`, `alias` is the value bound by the enclosing `-- case:`.
+    /// Like a loop alias, it is visible to this component but not to its children.
+    fn from_ast_component_with_alias(
+        ast_component: ftd::ast::Component,
+        definition_name_with_arguments: &mut Option<(&str, &mut [Argument])>,
+        alias: Option<(String, Argument, Option<String>)>,
+        doc: &mut ftd::interpreter::TDoc,
+    ) -> ftd::interpreter::Result<ftd::interpreter::StateWithThing<Component>> {
+        if ast_component.name.eq(ftd::ast::utils::MATCH) {
+            return Match::from_ast_match(ast_component, definition_name_with_arguments, doc);
+        }
+
         let name = doc.resolve_name(ast_component.name.as_str());
 
         // If the component is from `module` type argument
@@ -310,7 +365,7 @@ impl Component {
         )
         .ok();
 
-        let mut loop_object_name_and_kind = None;
+        let mut loop_object_name_and_kind = alias;
         let iteration = if let Some(v) = ast_component.iteration {
             let iteration =
                 try_ok_state!(Loop::from_ast_loop(v, definition_name_with_arguments, doc)?);
@@ -377,6 +432,19 @@ impl Component {
             Self::assert_no_private_properties_while_invocation(&properties, &c.arguments)?;
         }
 
+        let properties = match doc.get_thing(name.as_str(), ast_component.line_number) {
+            Ok(ftd::interpreter::Thing::Component(c)) if !c.type_parameters.is_empty() => {
+                try_ok_state!(Self::bind_type_parameters(
+                    ast_component.name.as_str(),
+                    properties,
+                    &c,
+                    doc,
+                    ast_component.line_number
+                )?)
+            }
+            _ => properties,
+        };
+
         Ok(ftd::interpreter::StateWithThing::new_thing(Component {
             name,
             properties,
@@ -386,6 +454,7 @@ impl Component {
             children: vec![],
             source: Default::default(),
             line_number: ast_component.line_number,
+            match_: None,
         }))
     }
 
@@ -419,6 +488,56 @@ impl Component {
         Ok(())
     }
 
+    /// Checks the properties of a generic component invocation against its type parameters,
+    /// text passed where `T` is expected is read as the kind `T` is bound to
+    fn bind_type_parameters(
+        component_name: &str,
+        properties: Vec<Property>,
+        component_definition: &ComponentDefinition,
+        doc: &mut ftd::interpreter::TDoc,
+        line_number: usize,
+    ) -> ftd::interpreter::Result<ftd::interpreter::StateWithThing<Vec<Property>>> {
+        let arguments = component_definition.arguments.as_slice();
+        let argument_for = |property: &Property| match &property.source {
+            PropertySource::Caption => arguments.iter().find(|v| v.is_caption()),
+            PropertySource::Body => arguments.iter().find(|v| v.is_body()),
+            PropertySource::Header { name, .. } => arguments.iter().find(|v| v.name.eq(name)),
+            PropertySource::Subsection | PropertySource::Default => None,
+        };
+
+        let argument_and_value_kinds = properties
+            .iter()
+            .filter_map(|property| {
+                argument_for(property)
+                    .filter(|argument| !property.value.is_type_parameter_text(&argument.kind.kind))
+                    .map(|argument| (argument, property.value.kind()))
+            })
+            .collect::<Vec<_>>();
+        let mut bindings = ftd::interpreter::utils::infer_type_parameters(
+            component_name,
+            argument_and_value_kinds.as_slice(),
+            doc.name,
+            line_number,
+        )?;
+
+        let mut result = vec![];
+        for mut property in properties {
+            if let Some(ftd::interpreter::Kind::TypeParameter { name }) = argument_for(&property)
+                .filter(|argument| property.value.is_type_parameter_text(&argument.kind.kind))
+                .map(|argument| argument.kind.kind.ref_inner())
+            {
+                let kind = bindings
+                    .entry(name.to_string())
+                    .or_insert(ftd::interpreter::Kind::string());
+                property.value =
+                    try_ok_state!(property.value.bind_type_parameter_text(kind, doc)?);
+            }
+            result.push(property);
+        }
+
+        Ok(ftd::interpreter::StateWithThing::new_thing(result))
+    }
+
     /// Component which is a variable
     /// -- s:
     /// where `s` is a variable of `ftd.ui` type
@@ -519,6 +638,7 @@ impl Component {
                         children: vec![],
                         source: ftd::interpreter::ComponentSource::Variable,
                         line_number,
+                        match_: None,
                     },
                 )));
             }
@@ -782,12 +902,21 @@ impl Property {
         doc: &mut ftd::interpreter::TDoc,
     ) -> ftd::interpreter::Result<()> {
         for property in ast_properties {
-            Property::scan_ast_property(
-                property,
-                definition_name_with_arguments,
-                loop_object_name_and_kind,
-                doc,
-            )?;
+            let properties = match Property::match_expression(&property, doc.name)? {
+                Some(match_expression) => {
+                    let last = match_expression.cases.len() - 1;
+                    match_expression.to_properties(&property, last)
+                }
+                None => vec![property],
+            };
+            for property in properties {
+                Property::scan_ast_property(
+                    property,
+                    definition_name_with_arguments,
+                    loop_object_name_and_kind,
+                    doc,
+                )?;
+            }
         }
         Ok(())
     }
@@ -833,14 +962,28 @@ impl Property {
             line_number,
         )?);
         for property in ast_properties {
-            properties.push(try_ok_state!(Property::from_ast_property(
-                property,
-                component_name,
-                component_arguments.as_slice(),
-                definition_name_with_arguments,
-                loop_object_name_and_kind,
-                doc,
-            )?));
+            let ast_properties = match Property::match_expression(&property, doc.name)? {
+                Some(match_expression) => {
+                    let default = try_ok_state!(match_expression.validate(
+                        &property,
+                        definition_name_with_arguments,
+                        loop_object_name_and_kind,
+                        doc,
+                    )?);
+                    match_expression.to_properties(&property, default)
+                }
+                None => vec![property],
+            };
+            for property in ast_properties {
+                properties.push(try_ok_state!(Property::from_ast_property(
+                    property,
+                    component_name,
+                    component_arguments.as_slice(),
+                    definition_name_with_arguments,
+                    loop_object_name_and_kind,
+                    doc,
+                )?));
+            }
         }
         try_ok_state!(search_things_for_module(
             component_name,
@@ -854,6 +997,99 @@ impl Property {
         Ok(ftd::interpreter::StateWithThing::new_thing(properties))
     }
 
+    /// Reads `text: $match $size { small => Smol, medium => "A, B", _ => Big }`, the expression
+    /// form of `-- match:` for or-types with constant variants
+    fn match_expression(
+        ast_property: &ftd::ast::Property,
+        doc_id: &str,
+    ) -> ftd::interpreter::Result<Option<MatchExpression>> {
+        let value = match &ast_property.value {
+            ftd::ast::VariableValue::String { value, .. } => value.trim(),
+            _ => return Ok(None),
+        };
+
+        let expression = match value.strip_prefix(ftd::interpreter::utils::REFERENCE) {
+            Some(expression)
+                if expression
+                    .strip_prefix(ftd::ast::utils::MATCH)
+                    .is_some_and(|v| v.starts_with(char::is_whitespace)) =>
+            {
+                expression
+            }
+            _ => return Ok(None),
+        };
+
+        let error = |message: String| {
+            ftd::interpreter::utils::e2(
+                format!(
+                    "{}. Expected `$match $<value> {{ <variant> => <value>, .. }}`, found: `{}`",
+                    message, value
+                ),
+                doc_id,
+                ast_property.line_number,
+            )
+        };
+        let match_ = match fastn_grammar::match_expression::build_match(expression) {
+            Ok(match_) => match_,
+            Err(fastn_grammar::evalexpr::EvalexprError::CustomMessage(message)) => {
+                return error(message)
+            }
+            Err(e) => return error(e.to_string()),
+        };
+
+        if ast_property.condition.is_some() {
+            return ftd::interpreter::utils::e2(
+                "A property can't have both a condition and a `match` expression",
+                doc_id,
+                ast_property.line_number,
+            );
+        }
+
+        let subject = match header_value(&match_.subject) {
+            Some(subject) if subject.starts_with(ftd::interpreter::utils::REFERENCE) => subject,
+            _ => return error("The value to match on must be a reference".to_string()),
+        };
+        let mut cases = vec![];
+        for arm in match_.arms {
+            match header_value(&arm.value) {
+                Some(value) => cases.push((arm.variant, value)),
+                None => {
+                    return error(
+                        "A case can only give a text, a number, a boolean or a reference, quote \
+                        text with spaces"
+                            .to_string(),
+                    )
+                }
+            }
+        }
+
+        return Ok(Some(MatchExpression {
+            subject: subject
+                .trim_start_matches(ftd::interpreter::utils::REFERENCE)
+                .to_string(),
+            cases,
+        }));
+
+        /// The header value an arm gives: a string as is, a name as text, `$name` as reference
+        fn header_value(node: &fastn_grammar::evalexpr::ExprNode) -> Option<String> {
+            use fastn_grammar::evalexpr::{Operator, Value};
+
+            match node.operator() {
+                Operator::RootNode if node.children().len() == 1 => {
+                    header_value(&node.children()[0])
+                }
+                Operator::VariableIdentifierRead { identifier } => Some(identifier.to_string()),
+                Operator::Const {
+                    value: Value::String(text),
+                } => Some(text.to_string()),
+                Operator::Const {
+                    value: value @ (Value::Int(_) | Value::Float(_) | Value::Boolean(_)),
+                } => Some(value.to_string()),
+                _ => None,
+            }
+        }
+    }
+
     fn from_ast_property(
         ast_property: ftd::ast::Property,
         component_name: &str,
@@ -960,13 +1196,17 @@ impl Property {
                 let mut argument = component_argument
                     .iter()
                     .find(|v| v.name.eq(name.as_str()))
-                    .ok_or(ftd::interpreter::Error::ParseError {
-                        message: format!(
-                            "Header type `{}` mutable: `{}` argument not found for component `{}`",
-                            name, mutable, component_name
-                        ),
-                        doc_id: doc.name.to_string(),
-                        line_number: ast_property.line_number,
+                    .ok_or_else(|| {
+                        ftd::interpreter::Error::ParseError {
+                            message: format!(
+                                "Header type `{}` mutable: `{}` argument not found for component \
+                                `{}`",
+                                name, mutable, component_name
+                            ),
+                            doc_id: doc.name.to_string(),
+                            line_number: ast_property.line_number,
+                        }
+                        .about(name.as_str())
                     })?
                     .to_owned();
                 if !argument.mutable.eq(mutable) {
@@ -1266,9 +1506,11 @@ impl Loop {
     ) -> ftd::interpreter::Result<ftd::interpreter::Kind> {
         let kind = self.on.kind();
         match kind {
-            ftd::interpreter::Kind::List { kind } => Ok(kind.as_ref().to_owned()),
+            ftd::interpreter::Kind::List { kind } | ftd::interpreter::Kind::Map { kind } => {
+                Ok(kind.as_ref().to_owned())
+            }
             t => ftd::interpreter::utils::e2(
-                format!("Expected list kind, found: {:?}", t),
+                format!("Expected list or map kind, found: {:?}", t),
                 doc_id,
                 self.line_number,
             ),
@@ -1351,6 +1593,511 @@ impl Loop {
     }
 }
 
+/// `-- match: $value` renders the children of the `-- case:` whose variant the or-type
+/// `value` currently holds. Cases must cover every variant, unless there is a `_` case.
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct Match {
+    pub value: ftd::interpreter::PropertyValue,
+    pub cases: Vec<Case>,
+    pub line_number: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct Case {
+    /// The variant name without the or-type prefix, `None` for the `_` case
+    pub variant: Option<String>,
+    /// The name bound to the value of the variant, `-- case: circle(c)`
+    pub alias: Option<String>,
+    pub children: Vec<Component>,
+    pub line_number: usize,
+}
+
+/// `$match $size { small => Smol, _ => Big }` in a header, see `Property::match_expression`
+#[derive(Debug, Clone, PartialEq)]
+struct MatchExpression {
+    subject: String,
+    /// The variant name, `None` for `_`, and the value for it
+    cases: Vec<(Option<String>, String)>,
+}
+
+impl MatchExpression {
+    /// Checks the cases against the or-type of the subject, and returns the index of the case
+    /// that applies when no other does: the `_` case, or the last one if every variant is covered
+    fn validate(
+        &self,
+        ast_property: &ftd::ast::Property,
+        definition_name_with_arguments: &mut Option<(&str, &mut [Argument])>,
+        loop_object_name_and_kind: &Option<(String, ftd::interpreter::Argument, Option<String>)>,
+        doc: &mut ftd::interpreter::TDoc,
+    ) -> ftd::interpreter::Result<ftd::interpreter::StateWithThing<usize>> {
+        let line_number = ast_property.line_number;
+        let kind = try_ok_state!(doc.get_kind_with_argument(
+            self.subject.as_str(),
+            line_number,
+            definition_name_with_arguments,
+            loop_object_name_and_kind,
+        )?)
+        .1;
+
+        let or_type_name = match kind.kind.get_or_type() {
+            Some((name, _, _)) if !name.starts_with("ftd#") => name,
+            _ => {
+                return ftd::interpreter::utils::e2(
+                    format!(
+                        "Can only match on or-types defined in a document, `{}` is `{}`",
+                        self.subject,
+                        kind.kind.get_name()
+                    ),
+                    doc.name,
+                    line_number,
+                )
+            }
+        };
+        let or_type = try_ok_state!(doc.search_or_type(or_type_name.as_str(), line_number)?);
+
+        for (index, (variant, _)) in self.cases.iter().enumerate() {
+            let variant = match variant {
+                Some(variant) => variant,
+                None if index + 1 == self.cases.len() => continue,
+                None => {
+                    return ftd::interpreter::utils::e2(
+                        "Unreachable case, `_` must be the last case in match",
+                        doc.name,
+                        line_number,
+                    )
+                }
+            };
+            if self.cases[..index]
+                .iter()
+                .any(|v| v.0.as_ref().eq(&Some(variant)))
+            {
+                return ftd::interpreter::utils::e2(
+                    format!("Variant `{}` is matched more than once", variant),
+                    doc.name,
+                    line_number,
+                );
+            }
+            match or_type.variants.iter().find(|v| v.short_name().eq(variant)) {
+                Some(ftd::interpreter::OrTypeVariant::Constant(_)) => {}
+                Some(_) => {
+                    return ftd::interpreter::utils::e2(
+                        format!(
+                            "Variant `{}` has a value, use `-- match:` to match on it",
+                            variant
+                        ),
+                        doc.name,
+                        line_number,
+                    )
+                }
+                None => {
+                    return ftd::interpreter::utils::e2(
+                        format!(
+                            "`{}` is not a variant of or-type `{}`",
+                            variant, or_type.name
+                        ),
+                        doc.name,
+                        line_number,
+                    )
+                }
+            }
+        }
+
+        if self.cases.iter().any(|v| v.0.is_none()) {
+            return Ok(ftd::interpreter::StateWithThing::new_thing(
+                self.cases.len() - 1,
+            ));
+        }
+
+        let missing = or_type
+            .variants
+            .iter()
+            .map(|v| v.short_name())
+            .filter(|v| !self.cases.iter().any(|c| c.0.as_ref().eq(&Some(v))))
+            .map(|v| format!("`{}`", v))
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            return ftd::interpreter::utils::e2(
+                format!(
+                    "Non-exhaustive match on `{}`, missing {}. Help: add the missing cases or \
+                    a `_ => <value>` case",
+                    or_type.name,
+                    missing.join(", ")
+                ),
+                doc.name,
+                line_number,
+            );
+        }
+
+        Ok(ftd::interpreter::StateWithThing::new_thing(
+            self.cases.len() - 1,
+        ))
+    }
+
+    /// `text: $match $size { small => Smol, _ => Big }` becomes `text: Big` and
+    /// `text if { size == small }: Smol`
+    fn to_properties(
+        &self,
+        ast_property: &ftd::ast::Property,
+        default: usize,
+    ) -> Vec<ftd::ast::Property> {
+        let source = match &ast_property.value {
+            ftd::ast::VariableValue::String { source, .. } => source.clone(),
+            _ => ftd::ast::ValueSource::Default,
+        };
+        let property = |value: &str, condition: Option<String>| ftd::ast::Property {
+            value: ftd::ast::VariableValue::String {
+                value: value.to_string(),
+                line_number: ast_property.line_number,
+                source: source.clone(),
+            },
+            source: ast_property.source.clone(),
+            condition,
+            line_number: ast_property.line_number,
+        };
+
+        let mut properties = vec![property(self.cases[default].1.as_str(), None)];
+        for (index, (variant, value)) in self.cases.iter().enumerate() {
+            if let Some(variant) = variant {
+                if index != default {
+                    properties.push(property(
+                        value,
+                        Some(format!("{{ {} == {} }}", self.subject, variant)),
+                    ));
+                }
+            }
+        }
+        properties
+    }
+}
+
+impl Match {
+    fn scan_ast_match(
+        ast_component: ftd::ast::Component,
+        definition_name_with_arguments: Option<(&str, &[String])>,
+        doc: &mut ftd::interpreter::TDoc,
+    ) -> ftd::interpreter::Result<()> {
+        let line_number = ast_component.line_number;
+        let (value, ast_cases) = Match::value_and_cases(ast_component, doc.name)?;
+        ftd::interpreter::PropertyValue::scan_string_with_argument(
+            value.as_str(),
+            doc,
+            line_number,
+            definition_name_with_arguments,
+            &None,
+        )?;
+
+        for ast_case in ast_cases {
+            let (_variant, alias) = Case::pattern(&ast_case, doc.name)?;
+            let alias = alias.map(|v| doc.resolve_name(v.as_str()));
+            for child in ast_case.children {
+                Component::scan_ast_component_with_alias(
+                    child,
+                    definition_name_with_arguments,
+                    alias.clone(),
+                    doc,
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn from_ast_match(
+        ast_component: ftd::ast::Component,
+        definition_name_with_arguments: &mut Option<(&str, &mut [Argument])>,
+        doc: &mut ftd::interpreter::TDoc,
+    ) -> ftd::interpreter::Result<ftd::interpreter::StateWithThing<Component>> {
+        let line_number = ast_component.line_number;
+        let (ast_value, ast_cases) = Match::value_and_cases(ast_component, doc.name)?;
+
+        let mut value = try_ok_state!(ftd::interpreter::PropertyValue::from_string_with_argument(
+            ast_value.as_str(),
+            doc,
+            None,
+            false,
+            line_number,
+            definition_name_with_arguments,
+            &None,
+        )?);
+
+        if let Some(reference) = ast_value.strip_prefix(ftd::interpreter::utils::REFERENCE) {
+            if let Ok(ftd::interpreter::StateWithThing::Thing(t)) = doc.get_kind_with_argument(
+                reference,
+                line_number,
+                definition_name_with_arguments,
+                &None,
+            ) {
+                value.set_mutable(t.2);
+            }
+        }
+
+        let or_type_name = match value.kind().get_or_type() {
+            Some((name, _, _)) if !name.starts_with("ftd#") => name,
+            _ => {
+                return ftd::interpreter::utils::e2(
+                    format!(
+                        "Can only match on or-types defined in a document, found: `{}`",
+                        value.kind().get_name()
+                    ),
+                    doc.name,
+                    line_number,
+                )
+            }
+        };
+        let or_type = try_ok_state!(doc.search_or_type(or_type_name.as_str(), line_number)?);
+
+        let mut cases: Vec<Case> = vec![];
+        for ast_case in ast_cases {
+            let case_line_number = ast_case.line_number;
+            let (variant_name, alias) = Case::pattern(&ast_case, doc.name)?;
+
+            if cases.iter().any(|v| v.variant.is_none()) {
+                return ftd::interpreter::utils::e2(
+                    "Unreachable case, `_` already matches every variant",
+                    doc.name,
+                    case_line_number,
+                );
+            }
+
+            let variant = match variant_name {
+                Some(ref variant_name) => {
+                    if cases
+                        .iter()
+                        .any(|v| v.variant.as_ref().eq(&Some(variant_name)))
+                    {
+                        return ftd::interpreter::utils::e2(
+                            format!("Variant `{}` is matched more than once", variant_name),
+                            doc.name,
+                            case_line_number,
+                        );
+                    }
+                    Some(
+                        or_type
+                            .variants
+                            .iter()
+                            .find(|v| v.short_name().eq(variant_name))
+                            .ok_or(ftd::interpreter::Error::ParseError {
+                                message: format!(
+                                    "`{}` is not a variant of or-type `{}`",
+                                    variant_name, or_type.name
+                                ),
+                                doc_id: doc.name.to_string(),
+                                line_number: case_line_number,
+                            })?,
+                    )
+                }
+                None => None,
+            };
+
+            let alias = match (alias, variant) {
+                (None, _) => None,
+                (Some(alias), Some(variant)) => {
+                    let kind = match variant {
+                        ftd::interpreter::OrTypeVariant::AnonymousRecord(record) => {
+                            ftd::interpreter::Kind::record(record.name.as_str()).into_kind_data()
+                        }
+                        ftd::interpreter::OrTypeVariant::Regular(regular) => {
+                            regular.kind.to_owned()
+                        }
+                        ftd::interpreter::OrTypeVariant::Constant(_) => {
+                            return ftd::interpreter::utils::e2(
+                                format!(
+                                    "Constant variant `{}` has no value to bind to `{}`",
+                                    variant.short_name(),
+                                    alias
+                                ),
+                                doc.name,
+                                case_line_number,
+                            )
+                        }
+                    };
+                    let alias = doc.resolve_name(alias.as_str());
+                    Some((
+                        alias.to_string(),
+                        ftd::interpreter::Argument {
+                            name: alias,
+                            kind,
+                            mutable: value.is_mutable(),
+                            value: None,
+                            line_number: case_line_number,
+                            access_modifier: Default::default(),
+                        },
+                        None,
+                    ))
+                }
+                (Some(alias), None) => {
+                    return ftd::interpreter::utils::e2(
+                        format!("`_` matches every variant, can't bind it to `{}`", alias),
+                        doc.name,
+                        case_line_number,
+                    )
+                }
+            };
+
+            let mut children = vec![];
+            for child in ast_case.children {
+                children.push(try_ok_state!(Component::from_ast_component_with_alias(
+                    child,
+                    definition_name_with_arguments,
+                    alias.clone(),
+                    doc,
+                )?));
+            }
+
+            cases.push(Case {
+                variant: variant_name,
+                alias: alias.map(|v| v.0),
+                children,
+                line_number: case_line_number,
+            });
+        }
+
+        if !cases.iter().any(|v| v.variant.is_none()) {
+            let missing = or_type
+                .variants
+                .iter()
+                .map(|v| v.short_name())
+                .filter(|v| !cases.iter().any(|c| c.variant.as_ref().eq(&Some(v))))
+                .map(|v| format!("`{}`", v))
+                .collect::<Vec<_>>();
+            if !missing.is_empty() {
+                return ftd::interpreter::utils::e2(
+                    format!(
+                        "Non-exhaustive match on `{}`, missing {}. Help: add the missing \
+                        cases or a `-- case: _`",
+                        or_type.name,
+                        missing.join(", ")
+                    ),
+                    doc.name,
+                    line_number,
+                );
+            }
+        }
+
+        Ok(ftd::interpreter::StateWithThing::new_thing(Component {
+            name: ftd::interpreter::FTD_MATCH.to_string(),
+            properties: vec![],
+            iteration: Box::new(None),
+            condition: Box::new(None),
+            events: vec![],
+            children: vec![],
+            source: Default::default(),
+            line_number,
+            match_: Some(Box::new(Match {
+                value,
+                cases,
+                line_number,
+            })),
+        }))
+    }
+
+    /// Returns the value being matched and the `-- case:` sections
+    fn value_and_cases(
+        ast_component: ftd::ast::Component,
+        doc_id: &str,
+    ) -> ftd::interpreter::Result<(String, Vec<ftd::ast::Component>)> {
+        if ast_component.iteration.is_some()
+            || ast_component.condition.is_some()
+            || !ast_component.events.is_empty()
+        {
+            return ftd::interpreter::utils::e2(
+                "`match` can't have a loop, condition or event, use them on the cases' children",
+                doc_id,
+                ast_component.line_number,
+            );
+        }
+
+        let value = match ast_component.properties.as_slice() {
+            [ftd::ast::Property {
+                value,
+                source: ftd::ast::PropertySource::Caption,
+                ..
+            }] => value.string(doc_id)?,
+            _ => {
+                return ftd::interpreter::utils::e2(
+                    "Expected the value to match on as caption, `-- match: $value`",
+                    doc_id,
+                    ast_component.line_number,
+                )
+            }
+        };
+
+        if let Some(child) = ast_component
+            .children
+            .iter()
+            .find(|v| v.name.ne(ftd::ast::utils::CASE))
+        {
+            return ftd::interpreter::utils::e2(
+                format!(
+                    "Expected `-- case:` inside `match`, found: `{}`",
+                    child.name
+                ),
+                doc_id,
+                child.line_number,
+            );
+        }
+
+        Ok((value, ast_component.children))
+    }
+}
+
+impl Case {
+    /// Reads the pattern of a `-- case:`, `circle(c)` gives the variant `circle` with its value
+    /// bound to `c`, while `_` matches any variant
+    fn pattern(
+        ast_case: &ftd::ast::Component,
+        doc_id: &str,
+    ) -> ftd::interpreter::Result<(Option<String>, Option<String>)> {
+        let pattern = match ast_case.properties.as_slice() {
+            [ftd::ast::Property {
+                value,
+                source: ftd::ast::PropertySource::Caption,
+                ..
+            }] if ast_case.iteration.is_none()
+                && ast_case.condition.is_none()
+                && ast_case.events.is_empty() =>
+            {
+                value.string(doc_id)?
+            }
+            _ => {
+                return ftd::interpreter::utils::e2(
+                    "Expected only the variant as caption, `-- case: <variant>`",
+                    doc_id,
+                    ast_case.line_number,
+                )
+            }
+        };
+
+        let (variant, alias) = match pattern.split_once('(') {
+            Some((variant, alias)) => match alias.trim().strip_suffix(')') {
+                Some(alias) => (
+                    variant.trim(),
+                    Some(
+                        alias
+                            .trim()
+                            .trim_start_matches(ftd::interpreter::utils::REFERENCE)
+                            .to_string(),
+                    ),
+                ),
+                None => {
+                    return ftd::interpreter::utils::e2(
+                        format!("Expected `)` in case pattern, found: `{}`", pattern),
+                        doc_id,
+                        ast_case.line_number,
+                    )
+                }
+            },
+            None => (pattern.trim(), None),
+        };
+
+        if variant.eq(ftd::ast::utils::WILDCARD) {
+            return Ok((None, alias));
+        }
+
+        Ok((Some(variant.to_string()), alias))
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct Event {
     pub name: ftd::interpreter::EventName,
@@ -1472,6 +2219,14 @@ pub enum EventName {
     Change,
     Blur,
     Focus,
+    Submit,
+    KeyDown(Vec<String>),
+    Scroll,
+    Visible,
+    Hidden,
+    DragStart,
+    Drop,
+    Swipe(String),
     RivePlay(String),
     RiveStateChange(String),
     RivePause(String),
@@ -1494,6 +2249,25 @@ impl EventName {
             "change" => Ok(EventName::Change),
             "blur" => Ok(EventName::Blur),
             "focus" => Ok(EventName::Focus),
+            "submit" => Ok(EventName::Submit),
+            "key-down" => Ok(EventName::KeyDown(vec![])),
+            "scroll" => Ok(EventName::Scroll),
+            "visible" => Ok(EventName::Visible),
+            "hidden" => Ok(EventName::Hidden),
+            "drag-start" => Ok(EventName::DragStart),
+            "drop" => Ok(EventName::Drop),
+            "swipe-left" | "swipe-right" | "swipe-up" | "swipe-down" => {
+                Ok(EventName::Swipe(e.trim_start_matches("swipe-").to_string()))
+            }
+            t if t.starts_with("key-down[") && t.ends_with(']') => {
+                let keys = t
+                    .trim_start_matches("key-down[")
+                    .trim_end_matches(']')
+                    .split('-')
+                    .map(|v| v.to_string())
+                    .collect_vec();
+                Ok(EventName::KeyDown(keys))
+            }
             t if t.starts_with("global-key[") && t.ends_with(']') => {
                 let keys = t
                     .trim_start_matches("global-key[")
@@ -29,6 +29,10 @@ enable light mode in the application.
 4. `enable_system_mode` - This function takes no arguments and returns an empty value. It is used to
 enable system mode in the application, which means the application will use the system's default
 color scheme.
+
+5. `format_datetime`, `format_date`, `relative_time`, `add_duration` and `diff` - These work on
+`datetime`, `date` and `duration` values, see `ftd::interpreter::datetime`. Datetimes are passed
+around as ISO-8601 strings and durations as seconds.
 **/
 pub fn default_functions() -> ftd::Map<fastn_grammar::evalexpr::Function> {
     use fastn_grammar::evalexpr::*;
@@ -114,6 +118,66 @@ pub fn default_functions() -> ftd::Map<fastn_grammar::evalexpr::Function> {
                 }
             }),
         ),
+        (
+            "ftd.format_datetime".to_string(),
+            Function::new(|argument| {
+                let arguments = datetime_arguments(argument, 4)?;
+                let d = datetime_argument(&arguments[0])?;
+                let style = arguments[1].as_string()?;
+                let timezone = arguments[2].as_string().ok();
+                Ok(Value::String(ftd::interpreter::datetime::format_datetime(
+                    d,
+                    style.as_str(),
+                    timezone.as_deref(),
+                )))
+            }),
+        ),
+        (
+            "ftd.format_date".to_string(),
+            Function::new(|argument| {
+                let arguments = datetime_arguments(argument, 3)?;
+                let d = arguments[0].as_string()?;
+                let d = ftd::interpreter::datetime::parse_date(d.as_str()).ok_or_else(|| {
+                    error::EvalexprError::CustomMessage(format!("Expected date, found: {d}"))
+                })?;
+                let style = arguments[1].as_string()?;
+                Ok(Value::String(ftd::interpreter::datetime::format_date(
+                    d,
+                    style.as_str(),
+                )))
+            }),
+        ),
+        (
+            "ftd.relative_time".to_string(),
+            Function::new(|argument| {
+                let arguments = datetime_arguments(argument, 2)?;
+                let d = datetime_argument(&arguments[0])?;
+                Ok(Value::String(ftd::interpreter::datetime::relative_time(
+                    d,
+                    ftd::interpreter::datetime::now(),
+                )))
+            }),
+        ),
+        (
+            "ftd.add_duration".to_string(),
+            Function::new(|argument| {
+                let arguments = datetime_arguments(argument, 2)?;
+                let d = datetime_argument(&arguments[0])?;
+                let by = arguments[1].as_int()?;
+                Ok(Value::String(
+                    ftd::interpreter::datetime::datetime_to_string(d + by),
+                ))
+            }),
+        ),
+        (
+            "ftd.diff".to_string(),
+            Function::new(|argument| {
+                let arguments = datetime_arguments(argument, 2)?;
+                Ok(Value::Int(
+                    datetime_argument(&arguments[0])? - datetime_argument(&arguments[1])?,
+                ))
+            }),
+        ),
         (
             "enable_dark_mode".to_string(),
             Function::new(|_| Ok(Value::Empty)),
@@ -130,6 +194,33 @@ pub fn default_functions() -> ftd::Map<fastn_grammar::evalexpr::Function> {
     .collect()
 }
 
+fn datetime_arguments(
+    argument: &fastn_grammar::evalexpr::Value,
+    expected: usize,
+) -> fastn_grammar::evalexpr::EvalexprResult<Vec<fastn_grammar::evalexpr::Value>> {
+    let arguments = argument.as_tuple()?;
+    if arguments.len().ne(&expected) {
+        return Err(
+            fastn_grammar::evalexpr::error::EvalexprError::WrongFunctionArgumentAmount {
+                expected,
+                actual: arguments.len(),
+            },
+        );
+    }
+    Ok(arguments)
+}
+
+fn datetime_argument(
+    argument: &fastn_grammar::evalexpr::Value,
+) -> fastn_grammar::evalexpr::EvalexprResult<i64> {
+    let d = argument.as_string()?;
+    ftd::interpreter::datetime::parse_datetime(d.as_str()).ok_or_else(|| {
+        fastn_grammar::evalexpr::error::EvalexprError::CustomMessage(format!(
+            "Expected datetime, found: {d}"
+        ))
+    })
+}
+
 pub fn default_context() -> ftd::interpreter::Result<fastn_grammar::evalexpr::HashMapContext> {
     let mut context = fastn_grammar::evalexpr::HashMapContext::new();
     for (key, function) in default_functions() {
@@ -161,6 +252,26 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             "ftd#container".to_string(),
             ftd::interpreter::Thing::Component(container_function()),
         ),
+        (
+            "ftd#grid".to_string(),
+            ftd::interpreter::Thing::Component(grid_function()),
+        ),
+        (
+            "ftd#table".to_string(),
+            ftd::interpreter::Thing::Component(table_function()),
+        ),
+        (
+            "ftd#dialog".to_string(),
+            ftd::interpreter::Thing::Component(dialog_function()),
+        ),
+        (
+            "ftd#popover".to_string(),
+            ftd::interpreter::Thing::Component(popover_function()),
+        ),
+        (
+            "ftd#subscribe".to_string(),
+            ftd::interpreter::Thing::Component(subscribe_function()),
+        ),
         (
             "ftd#desktop".to_string(),
             ftd::interpreter::Thing::Component(desktop_function()),
@@ -209,6 +320,26 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             "ftd#checkbox".to_string(),
             ftd::interpreter::Thing::Component(checkbox_function()),
         ),
+        (
+            "ftd#select".to_string(),
+            ftd::interpreter::Thing::Component(select_function()),
+        ),
+        (
+            "ftd#form".to_string(),
+            ftd::interpreter::Thing::Component(form_function()),
+        ),
+        (
+            "ftd#radio".to_string(),
+            ftd::interpreter::Thing::Component(radio_function()),
+        ),
+        (
+            "ftd#slider".to_string(),
+            ftd::interpreter::Thing::Component(slider_function()),
+        ),
+        (
+            "ftd#file-input".to_string(),
+            ftd::interpreter::Thing::Component(file_input_function()),
+        ),
         (
             "ftd#image".to_string(),
             ftd::interpreter::Thing::Component(image_function()),
@@ -221,6 +352,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             "ftd#set-rive-boolean".to_string(),
             ftd::interpreter::Thing::Function(ftd::interpreter::Function {
                 name: "ftd#set-rive-boolean".to_string(),
+                type_parameters: vec![],
                 return_kind: ftd::interpreter::KindData {
                     kind: ftd::interpreter::Kind::void(),
                     caption: false,
@@ -267,6 +399,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             "ftd#toggle-rive-boolean".to_string(),
             ftd::interpreter::Thing::Function(ftd::interpreter::Function {
                 name: "ftd#toggle-rive-boolean".to_string(),
+                type_parameters: vec![],
                 return_kind: ftd::interpreter::KindData {
                     kind: ftd::interpreter::Kind::void(),
                     caption: false,
@@ -305,6 +438,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             "ftd#set-rive-integer".to_string(),
             ftd::interpreter::Thing::Function(ftd::interpreter::Function {
                 name: "ftd#set-rive-integer".to_string(),
+                type_parameters: vec![],
                 return_kind: ftd::interpreter::KindData {
                     kind: ftd::interpreter::Kind::void(),
                     caption: false,
@@ -351,6 +485,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             "ftd#fire-rive".to_string(),
             ftd::interpreter::Thing::Function(ftd::interpreter::Function {
                 name: "ftd#fire-rive".to_string(),
+                type_parameters: vec![],
                 return_kind: ftd::interpreter::KindData {
                     kind: ftd::interpreter::Kind::void(),
                     caption: false,
@@ -389,6 +524,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             "ftd#play-rive".to_string(),
             ftd::interpreter::Thing::Function(ftd::interpreter::Function {
                 name: "ftd#play-rive".to_string(),
+                type_parameters: vec![],
                 return_kind: ftd::interpreter::KindData {
                     kind: ftd::interpreter::Kind::void(),
                     caption: false,
@@ -427,6 +563,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             "ftd#pause-rive".to_string(),
             ftd::interpreter::Thing::Function(ftd::interpreter::Function {
                 name: "ftd#pause-rive".to_string(),
+                type_parameters: vec![],
                 return_kind: ftd::interpreter::KindData {
                     kind: ftd::interpreter::Kind::void(),
                     caption: false,
@@ -465,6 +602,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             "ftd#toggle-play-rive".to_string(),
             ftd::interpreter::Thing::Function(ftd::interpreter::Function {
                 name: "ftd#toggle-play-rive".to_string(),
+                type_parameters: vec![],
                 return_kind: ftd::interpreter::KindData {
                     kind: ftd::interpreter::Kind::void(),
                     caption: false,
@@ -503,6 +641,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             "ftd#toggle".to_string(),
             ftd::interpreter::Thing::Function(ftd::interpreter::Function {
                 name: "ftd#toggle".to_string(),
+                type_parameters: vec![],
                 return_kind: ftd::interpreter::KindData {
                     kind: ftd::interpreter::Kind::void(),
                     caption: false,
@@ -537,6 +676,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             "ftd#increment".to_string(),
             ftd::interpreter::Thing::Function(ftd::interpreter::Function {
                 name: "ftd#increment".to_string(),
+                type_parameters: vec![],
                 return_kind: ftd::interpreter::KindData {
                     kind: ftd::interpreter::Kind::void(),
                     caption: false,
@@ -571,6 +711,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             "ftd#increment-by".to_string(),
             ftd::interpreter::Thing::Function(ftd::interpreter::Function {
                 name: "ftd#increment-by".to_string(),
+                type_parameters: vec![],
                 return_kind: ftd::interpreter::KindData {
                     kind: ftd::interpreter::Kind::void(),
                     caption: false,
@@ -617,6 +758,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             "ftd#decrement".to_string(),
             ftd::interpreter::Thing::Function(ftd::interpreter::Function {
                 name: "ftd#decrement".to_string(),
+                type_parameters: vec![],
                 return_kind: ftd::interpreter::KindData {
                     kind: ftd::interpreter::Kind::void(),
                     caption: false,
@@ -651,6 +793,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             "ftd#decrement-by".to_string(),
             ftd::interpreter::Thing::Function(ftd::interpreter::Function {
                 name: "ftd#decrement-by".to_string(),
+                type_parameters: vec![],
                 return_kind: ftd::interpreter::KindData {
                     kind: ftd::interpreter::Kind::void(),
                     caption: false,
@@ -697,6 +840,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             "ftd#enable-light-mode".to_string(),
             ftd::interpreter::Thing::Function(ftd::interpreter::Function {
                 name: "ftd#enable-light-mode".to_string(),
+                type_parameters: vec![],
                 return_kind: ftd::interpreter::KindData {
                     kind: ftd::interpreter::Kind::void(),
                     caption: false,
@@ -719,6 +863,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             "ftd#enable-dark-mode".to_string(),
             ftd::interpreter::Thing::Function(ftd::interpreter::Function {
                 name: "ftd#enable-dark-mode".to_string(),
+                type_parameters: vec![],
                 return_kind: ftd::interpreter::KindData {
                     kind: ftd::interpreter::Kind::void(),
                     caption: false,
@@ -741,6 +886,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             "ftd#enable-system-mode".to_string(),
             ftd::interpreter::Thing::Function(ftd::interpreter::Function {
                 name: "ftd#enable-system-mode".to_string(),
+                type_parameters: vec![],
                 return_kind: ftd::interpreter::KindData {
                     kind: ftd::interpreter::Kind::void(),
                     caption: false,
@@ -763,6 +909,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             "ftd#clean-code".to_string(),
             ftd::interpreter::Thing::Function(ftd::interpreter::Function {
                 name: "ftd#clean-code".to_string(),
+                type_parameters: vec![],
                 return_kind: ftd::interpreter::KindData {
                     kind: ftd::interpreter::Kind::string(),
                     caption: false,
@@ -809,6 +956,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             "ftd#copy-to-clipboard".to_string(),
             ftd::interpreter::Thing::Function(ftd::interpreter::Function {
                 name: "ftd#copy-to-clipboard".to_string(),
+                type_parameters: vec![],
                 return_kind: ftd::interpreter::KindData {
                     kind: ftd::interpreter::Kind::void(),
                     caption: false,
@@ -839,10 +987,80 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
                 external_implementation: true
             })
         ),
+        (
+            "ftd#submit-form".to_string(),
+            ftd::interpreter::Thing::Function(ftd::interpreter::Function {
+                name: "ftd#submit-form".to_string(),
+                type_parameters: vec![],
+                return_kind: ftd::interpreter::KindData {
+                    kind: ftd::interpreter::Kind::void(),
+                    caption: false,
+                    body: false,
+                },
+                arguments: vec![],
+                expression: vec![
+                    ftd::interpreter::things::function::Expression {
+                        expression: "ftd.submit_form()".to_string(),
+                        line_number: 0,
+                    }
+                ],
+                js: None,
+                line_number: 0,
+                external_implementation: true
+            })
+        ),
+        (
+            "ftd#show-toast".to_string(),
+            ftd::interpreter::Thing::Function(ftd::interpreter::Function {
+                name: "ftd#show-toast".to_string(),
+                type_parameters: vec![],
+                return_kind: ftd::interpreter::KindData {
+                    kind: ftd::interpreter::Kind::void(),
+                    caption: false,
+                    body: false,
+                },
+                arguments: vec![
+                    ftd::interpreter::Argument {
+                        name: "message".to_string(),
+                        kind: ftd::interpreter::KindData {
+                            kind: ftd::interpreter::Kind::string(),
+                            caption: false,
+                            body: false,
+                        },
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Argument {
+                        name: "duration".to_string(),
+                        kind: ftd::interpreter::KindData {
+                            kind: ftd::interpreter::Kind::integer().into_optional(),
+                            caption: false,
+                            body: false,
+                        },
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                ],
+                expression: vec![
+                    ftd::interpreter::things::function::Expression {
+                        expression: "ftd.show_toast(message, duration)".to_string(),
+                        line_number: 0,
+                    }
+                ],
+                js: None,
+                line_number: 0,
+                external_implementation: true
+            })
+        ),
         (
             "ftd#set-bool".to_string(),
             ftd::interpreter::Thing::Function(ftd::interpreter::Function {
                 name: "ftd#set-bool".to_string(),
+                type_parameters: vec![],
                 return_kind: ftd::interpreter::KindData {
                     kind: ftd::interpreter::Kind::void(),
                     caption: false,
@@ -889,6 +1107,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             "ftd#set-boolean".to_string(),
             ftd::interpreter::Thing::Function(ftd::interpreter::Function {
                 name: "ftd#set-boolean".to_string(),
+                type_parameters: vec![],
                 return_kind: ftd::interpreter::KindData {
                     kind: ftd::interpreter::Kind::void(),
                     caption: false,
@@ -935,6 +1154,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             "ftd#set-string".to_string(),
             ftd::interpreter::Thing::Function(ftd::interpreter::Function {
                 name: "ftd#set-string".to_string(),
+                type_parameters: vec![],
                 return_kind: ftd::interpreter::KindData {
                     kind: ftd::interpreter::Kind::void(),
                     caption: false,
@@ -981,6 +1201,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             "ftd#set-integer".to_string(),
             ftd::interpreter::Thing::Function(ftd::interpreter::Function {
                 name: "ftd#set-integer".to_string(),
+                type_parameters: vec![],
                 return_kind: ftd::interpreter::KindData {
                     kind: ftd::interpreter::Kind::void(),
                     caption: false,
@@ -1023,10 +1244,160 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
                 external_implementation: false
             })
         ),
+        (
+            "ftd#format-datetime".to_string(),
+            ftd::interpreter::Thing::Function(ftd::interpreter::Function {
+                name: "ftd#format-datetime".to_string(),
+                type_parameters: vec![],
+                return_kind: ftd::interpreter::Kind::string().into_kind_data(),
+                arguments: vec![
+                    ftd::interpreter::Argument::default(
+                        "d",
+                        ftd::interpreter::Kind::datetime().into_kind_data(),
+                    ),
+                    ftd::interpreter::Argument::default_with_value(
+                        "style",
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                        ftd::interpreter::Value::new_string("medium").into_property_value(false, 0),
+                    ),
+                    ftd::interpreter::Argument::default(
+                        "timezone",
+                        ftd::interpreter::Kind::string().into_optional().into_kind_data(),
+                    ),
+                    ftd::interpreter::Argument::default(
+                        "locale",
+                        ftd::interpreter::Kind::string().into_optional().into_kind_data(),
+                    ),
+                ],
+                expression: vec![
+                    ftd::interpreter::things::function::Expression {
+                        expression: "ftd.format_datetime(d, style, timezone, locale)".to_string(),
+                        line_number: 0,
+                    }
+                ],
+                js: None,
+                line_number: 0,
+                external_implementation: true
+            })
+        ),
+        (
+            "ftd#format-date".to_string(),
+            ftd::interpreter::Thing::Function(ftd::interpreter::Function {
+                name: "ftd#format-date".to_string(),
+                type_parameters: vec![],
+                return_kind: ftd::interpreter::Kind::string().into_kind_data(),
+                arguments: vec![
+                    ftd::interpreter::Argument::default(
+                        "d",
+                        ftd::interpreter::Kind::date().into_kind_data(),
+                    ),
+                    ftd::interpreter::Argument::default_with_value(
+                        "style",
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                        ftd::interpreter::Value::new_string("medium").into_property_value(false, 0),
+                    ),
+                    ftd::interpreter::Argument::default(
+                        "locale",
+                        ftd::interpreter::Kind::string().into_optional().into_kind_data(),
+                    ),
+                ],
+                expression: vec![
+                    ftd::interpreter::things::function::Expression {
+                        expression: "ftd.format_date(d, style, locale)".to_string(),
+                        line_number: 0,
+                    }
+                ],
+                js: None,
+                line_number: 0,
+                external_implementation: true
+            })
+        ),
+        (
+            "ftd#relative-time".to_string(),
+            ftd::interpreter::Thing::Function(ftd::interpreter::Function {
+                name: "ftd#relative-time".to_string(),
+                type_parameters: vec![],
+                return_kind: ftd::interpreter::Kind::string().into_kind_data(),
+                arguments: vec![
+                    ftd::interpreter::Argument::default(
+                        "d",
+                        ftd::interpreter::Kind::datetime().into_kind_data(),
+                    ),
+                    ftd::interpreter::Argument::default(
+                        "locale",
+                        ftd::interpreter::Kind::string().into_optional().into_kind_data(),
+                    ),
+                ],
+                expression: vec![
+                    ftd::interpreter::things::function::Expression {
+                        expression: "ftd.relative_time(d, locale)".to_string(),
+                        line_number: 0,
+                    }
+                ],
+                js: None,
+                line_number: 0,
+                external_implementation: true
+            })
+        ),
+        (
+            "ftd#add-duration".to_string(),
+            ftd::interpreter::Thing::Function(ftd::interpreter::Function {
+                name: "ftd#add-duration".to_string(),
+                type_parameters: vec![],
+                return_kind: ftd::interpreter::Kind::datetime().into_kind_data(),
+                arguments: vec![
+                    ftd::interpreter::Argument::default(
+                        "d",
+                        ftd::interpreter::Kind::datetime().into_kind_data(),
+                    ),
+                    ftd::interpreter::Argument::default(
+                        "by",
+                        ftd::interpreter::Kind::duration().into_kind_data(),
+                    ),
+                ],
+                expression: vec![
+                    ftd::interpreter::things::function::Expression {
+                        expression: "ftd.add_duration(d, by)".to_string(),
+                        line_number: 0,
+                    }
+                ],
+                js: None,
+                line_number: 0,
+                external_implementation: true
+            })
+        ),
+        (
+            "ftd#diff".to_string(),
+            ftd::interpreter::Thing::Function(ftd::interpreter::Function {
+                name: "ftd#diff".to_string(),
+                type_parameters: vec![],
+                return_kind: ftd::interpreter::Kind::duration().into_kind_data(),
+                arguments: vec![
+                    ftd::interpreter::Argument::default(
+                        "a",
+                        ftd::interpreter::Kind::datetime().into_kind_data(),
+                    ),
+                    ftd::interpreter::Argument::default(
+                        "b",
+                        ftd::interpreter::Kind::datetime().into_kind_data(),
+                    ),
+                ],
+                expression: vec![
+                    ftd::interpreter::things::function::Expression {
+                        expression: "ftd.diff(a, b)".to_string(),
+                        line_number: 0,
+                    }
+                ],
+                js: None,
+                line_number: 0,
+                external_implementation: true
+            })
+        ),
         (
             ftd::interpreter::FTD_IMAGE_SRC.to_string(),
             ftd::interpreter::Thing::Record(ftd::interpreter::Record {
                 name: ftd::interpreter::FTD_IMAGE_SRC.to_string(),
+                type_parameters: vec![],
                 fields: std::iter::IntoIterator::into_iter([
                     ftd::interpreter::Field {
                         name: "light".to_string(),
@@ -1061,6 +1432,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             ftd::interpreter::FTD_VIDEO_SRC.to_string(),
             ftd::interpreter::Thing::Record(ftd::interpreter::Record {
                 name: ftd::interpreter::FTD_VIDEO_SRC.to_string(),
+                type_parameters: vec![],
                 fields: std::iter::IntoIterator::into_iter([
                     ftd::interpreter::Field {
                         name: "light".to_string(),
@@ -1095,6 +1467,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             ftd::interpreter::FTD_RAW_IMAGE_SRC.to_string(),
             ftd::interpreter::Thing::Record(ftd::interpreter::Record {
                 name: ftd::interpreter::FTD_RAW_IMAGE_SRC.to_string(),
+                type_parameters: vec![],
                 fields: std::iter::IntoIterator::into_iter([
                     ftd::interpreter::Field {
                         name: "src".to_string(),
@@ -1113,6 +1486,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             ftd::interpreter::FTD_COLOR.to_string(),
             ftd::interpreter::Thing::Record(ftd::interpreter::Record {
                 name: ftd::interpreter::FTD_COLOR.to_string(),
+                type_parameters: vec![],
                 fields: std::iter::IntoIterator::into_iter([
                     ftd::interpreter::Field {
                         name: "light".to_string(),
@@ -1147,6 +1521,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             ftd::interpreter::FTD_SHADOW.to_string(),
             ftd::interpreter::Thing::Record(ftd::interpreter::Record {
                 name: ftd::interpreter::FTD_SHADOW.to_string(),
+                type_parameters: vec![],
                 fields: std::iter::IntoIterator::into_iter([
                     ftd::interpreter::Field {
                         name: "x-offset".to_string(),
@@ -1383,6 +1758,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             ftd::interpreter::FTD_BACKDROP_MULTI.to_string(),
             ftd::interpreter::Thing::Record(ftd::interpreter::Record {
                 name: ftd::interpreter::FTD_BACKDROP_MULTI.to_string(),
+                type_parameters: vec![],
                 fields: std::iter::IntoIterator::into_iter([
                     ftd::interpreter::Field {
                         name: "blur".to_string(),
@@ -1464,6 +1840,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             ftd::interpreter::FTD_LENGTH_PAIR.to_string(),
             ftd::interpreter::Thing::Record(ftd::interpreter::Record {
                 name: ftd::interpreter::FTD_LENGTH_PAIR.to_string(),
+                type_parameters: vec![],
                 fields: std::iter::IntoIterator::into_iter([
                     ftd::interpreter::Field {
                         name: "x".to_string(),
@@ -1491,6 +1868,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             ftd::interpreter::FTD_BG_IMAGE.to_string(),
             ftd::interpreter::Thing::Record(ftd::interpreter::Record {
                 name: ftd::interpreter::FTD_BG_IMAGE.to_string(),
+                type_parameters: vec![],
                 fields: std::iter::IntoIterator::into_iter([
                     ftd::interpreter::Field {
                         name: "src".to_string(),
@@ -1536,6 +1914,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             ftd::interpreter::FTD_LINEAR_GRADIENT_COLOR.to_string(),
             ftd::interpreter::Thing::Record(ftd::interpreter::Record {
                 name: ftd::interpreter::FTD_LINEAR_GRADIENT_COLOR.to_string(),
+                type_parameters: vec![],
                 fields: std::iter::IntoIterator::into_iter([
                     ftd::interpreter::Field {
                         name: "color".to_string(),
@@ -1686,6 +2065,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             ftd::interpreter::FTD_LINEAR_GRADIENT.to_string(),
             ftd::interpreter::Thing::Record(ftd::interpreter::Record {
                 name: ftd::interpreter::FTD_LINEAR_GRADIENT.to_string(),
+                type_parameters: vec![],
                 fields: std::iter::IntoIterator::into_iter([
                     ftd::interpreter::Field {
                         name: "direction".to_string(),
@@ -1866,6 +2246,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
                     )),
                     ftd::interpreter::OrTypeVariant::AnonymousRecord(ftd::interpreter::Record {
                         name: ftd::interpreter::FTD_BACKGROUND_SIZE_LENGTH.to_string(),
+                        type_parameters: vec![],
                         fields: std::iter::IntoIterator::into_iter([
                             ftd::interpreter::Field {
                                 name: "x".to_string(),
@@ -2019,6 +2400,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
                     )),
                     ftd::interpreter::OrTypeVariant::AnonymousRecord(ftd::interpreter::Record {
                         name: ftd::interpreter::FTD_BACKGROUND_POSITION_LENGTH.to_string(),
+                        type_parameters: vec![],
                         fields: std::iter::IntoIterator::into_iter([
                             ftd::interpreter::Field {
                                 name: "x".to_string(),
@@ -3208,6 +3590,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             ftd::interpreter::FTD_RESPONSIVE_LENGTH.to_string(),
             ftd::interpreter::Thing::Record(ftd::interpreter::Record {
                 name: ftd::interpreter::FTD_RESPONSIVE_LENGTH.to_string(),
+                type_parameters: vec![],
                 fields: std::iter::IntoIterator::into_iter([
                     ftd::interpreter::Field {
                         name: "desktop".to_string(),
@@ -3242,103 +3625,758 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             }),
         ),
         (
-            ftd::interpreter::FTD_FONT_SIZE.to_string(),
-            ftd::interpreter::Thing::OrType(ftd::interpreter::OrType {
-                name: ftd::interpreter::FTD_FONT_SIZE.to_string(),
-                variants: vec![
-                    ftd::interpreter::OrTypeVariant::Regular(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_FONT_SIZE_PX,
-                        ftd::interpreter::Kind::integer()
-                            .into_kind_data()
-                            .caption(),
-                        false,
-                        None,
-                        0,
-                    )),
-                    ftd::interpreter::OrTypeVariant::Regular(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_FONT_SIZE_EM,
-                        ftd::interpreter::Kind::decimal()
-                            .into_kind_data()
-                            .caption(),
-                        false,
-                        None,
-                        0,
-                    )),
-                    ftd::interpreter::OrTypeVariant::Regular(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_FONT_SIZE_REM,
-                        ftd::interpreter::Kind::decimal()
-                            .into_kind_data()
-                            .caption(),
-                        false,
-                        None,
-                        0,
-                    )),
-                ],
-                line_number: 0,
-            }),
-        ),
-        (
-            ftd::interpreter::FTD_REGION.to_string(),
-            ftd::interpreter::Thing::OrType(ftd::interpreter::OrType {
-                name: ftd::interpreter::FTD_REGION.to_string(),
-                variants: vec![
-                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_REGION_H1,
-                        ftd::interpreter::Kind::string()
-                            .into_kind_data()
-                            .caption(),
-                        false,
-                        Some(   ftd::interpreter::Value::new_string("h1")
-                                    .into_property_value(false, 0),),
-                        0,
-                    )),
-                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_REGION_H2,
-                        ftd::interpreter::Kind::string()
-                            .into_kind_data()
+            ftd::interpreter::FTD_RESPONSIVE_STRING.to_string(),
+            ftd::interpreter::Thing::Record(ftd::interpreter::Record {
+                name: ftd::interpreter::FTD_RESPONSIVE_STRING.to_string(),
+                type_parameters: vec![],
+                fields: std::iter::IntoIterator::into_iter([
+                    ftd::interpreter::Field {
+                        name: "desktop".to_string(),
+                        kind: ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "mobile".to_string(),
+                        kind: ftd::interpreter::Kind::string().into_kind_data(),
+                        mutable: false,
+                        access_modifier: Default::default(),
+                        value: Some(ftd::interpreter::PropertyValue::Reference {
+                            name: ftd::interpreter::FTD_RESPONSIVE_STRING_DESKTOP.to_string(),
+                            kind: ftd::interpreter::Kind::string().into_kind_data(),
+                            source: ftd::interpreter::PropertyValueSource::Local(
+                                ftd::interpreter::FTD_RESPONSIVE_STRING.to_string(),
+                            ),
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        line_number: 0,
+                    },
+                ])
+                .collect(),
+                line_number: 0,
+            }),
+        ),
+        (
+            ftd::interpreter::FTD_OPTION.to_string(),
+            ftd::interpreter::Thing::Record(ftd::interpreter::Record {
+                name: ftd::interpreter::FTD_OPTION.to_string(),
+                type_parameters: vec![],
+                fields: std::iter::IntoIterator::into_iter([
+                    ftd::interpreter::Field {
+                        name: "value".to_string(),
+                        kind: ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        mutable: false,
+                        value: None,
+                        access_modifier: Default::default(),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field {
+                        name: "label".to_string(),
+                        kind: ftd::interpreter::Kind::string().into_kind_data(),
+                        mutable: false,
+                        access_modifier: Default::default(),
+                        value: Some(ftd::interpreter::PropertyValue::Reference {
+                            name: ftd::interpreter::FTD_OPTION_VALUE.to_string(),
+                            kind: ftd::interpreter::Kind::string().into_kind_data(),
+                            source: ftd::interpreter::PropertyValueSource::Local(
+                                ftd::interpreter::FTD_OPTION.to_string(),
+                            ),
+                            is_mutable: false,
+                            line_number: 0,
+                        }),
+                        line_number: 0,
+                    },
+                    ftd::interpreter::Field::default_with_value(
+                        "enabled",
+                        ftd::interpreter::Kind::boolean().into_kind_data(),
+                        ftd::interpreter::PropertyValue::Value {
+                            value: ftd::interpreter::Value::Boolean { value: true },
+                            is_mutable: false,
+                            line_number: 0,
+                        },
+                    ),
+                ])
+                .collect(),
+                line_number: 0,
+            }),
+        ),
+        (
+            ftd::interpreter::FTD_FILE.to_string(),
+            ftd::interpreter::Thing::Record(ftd::interpreter::Record {
+                name: ftd::interpreter::FTD_FILE.to_string(),
+                type_parameters: vec![],
+                fields: std::iter::IntoIterator::into_iter([
+                    ftd::interpreter::Field::default(
+                        "name",
+                        ftd::interpreter::Kind::string().into_kind_data().caption(),
+                    ),
+                    ftd::interpreter::Field::default(
+                        "size",
+                        ftd::interpreter::Kind::integer().into_kind_data(),
+                    ),
+                    ftd::interpreter::Field::default(
+                        "type",
+                        ftd::interpreter::Kind::string().into_kind_data(),
+                    ),
+                ])
+                .collect(),
+                line_number: 0,
+            }),
+        ),
+        (
+            ftd::interpreter::FTD_TABLE_COLUMN.to_string(),
+            ftd::interpreter::Thing::Record(ftd::interpreter::Record {
+                name: ftd::interpreter::FTD_TABLE_COLUMN.to_string(),
+                type_parameters: vec![],
+                fields: std::iter::IntoIterator::into_iter([
+                    ftd::interpreter::Field::default(
+                        "header",
+                        ftd::interpreter::Kind::string().into_kind_data().caption(),
+                    ),
+                    ftd::interpreter::Field::default(
+                        "field",
+                        ftd::interpreter::Kind::string()
+                            .into_optional()
+                            .into_kind_data(),
+                    ),
+                    ftd::interpreter::Field::default(
+                        "cell",
+                        ftd::interpreter::Kind::ui().into_optional().into_kind_data(),
+                    ),
+                    ftd::interpreter::Field::default_with_value(
+                        "sortable",
+                        ftd::interpreter::Kind::boolean().into_kind_data(),
+                        ftd::interpreter::PropertyValue::Value {
+                            value: ftd::interpreter::Value::Boolean { value: false },
+                            is_mutable: false,
+                            line_number: 0,
+                        },
+                    ),
+                    ftd::interpreter::Field::default(
+                        "width",
+                        ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_LENGTH)
+                            .into_optional()
+                            .into_kind_data(),
+                    ),
+                ])
+                .collect(),
+                line_number: 0,
+            }),
+        ),
+        (
+            ftd::interpreter::FTD_TRANSITION.to_string(),
+            ftd::interpreter::Thing::Record(ftd::interpreter::Record {
+                name: ftd::interpreter::FTD_TRANSITION.to_string(),
+                type_parameters: vec![],
+                fields: std::iter::IntoIterator::into_iter([
+                    ftd::interpreter::Field::default_with_value(
+                        "property",
+                        ftd::interpreter::Kind::string().into_kind_data().caption(),
+                        ftd::interpreter::PropertyValue::Value {
+                            value: ftd::interpreter::Value::new_string("all"),
+                            is_mutable: false,
+                            line_number: 0,
+                        },
+                    ),
+                    ftd::interpreter::Field::default_with_value(
+                        "duration",
+                        ftd::interpreter::Kind::integer().into_kind_data(),
+                        ftd::interpreter::PropertyValue::Value {
+                            value: ftd::interpreter::Value::Integer { value: 300 },
+                            is_mutable: false,
+                            line_number: 0,
+                        },
+                    ),
+                    ftd::interpreter::Field::default_with_value(
+                        "delay",
+                        ftd::interpreter::Kind::integer().into_kind_data(),
+                        ftd::interpreter::PropertyValue::Value {
+                            value: ftd::interpreter::Value::Integer { value: 0 },
+                            is_mutable: false,
+                            line_number: 0,
+                        },
+                    ),
+                    ftd::interpreter::Field::default(
+                        "easing",
+                        ftd::interpreter::Kind::string()
+                            .into_optional()
+                            .into_kind_data(),
+                    ),
+                ])
+                .collect(),
+                line_number: 0,
+            }),
+        ),
+        (
+            ftd::interpreter::FTD_KEYFRAME.to_string(),
+            ftd::interpreter::Thing::Record(ftd::interpreter::Record {
+                name: ftd::interpreter::FTD_KEYFRAME.to_string(),
+                type_parameters: vec![],
+                fields: std::iter::IntoIterator::into_iter([
+                    ftd::interpreter::Field::default(
+                        "at",
+                        ftd::interpreter::Kind::integer().into_kind_data().caption(),
+                    ),
+                    ftd::interpreter::Field::default(
+                        "opacity",
+                        ftd::interpreter::Kind::decimal()
+                            .into_optional()
+                            .into_kind_data(),
+                    ),
+                    ftd::interpreter::Field::default(
+                        "transform",
+                        ftd::interpreter::Kind::string()
+                            .into_optional()
+                            .into_kind_data(),
+                    ),
+                ])
+                .collect(),
+                line_number: 0,
+            }),
+        ),
+        (
+            ftd::interpreter::FTD_ANIMATION.to_string(),
+            ftd::interpreter::Thing::Record(ftd::interpreter::Record {
+                name: ftd::interpreter::FTD_ANIMATION.to_string(),
+                type_parameters: vec![],
+                fields: std::iter::IntoIterator::into_iter([
+                    ftd::interpreter::Field::default(
+                        "keyframes",
+                        ftd::interpreter::Kind::record(ftd::interpreter::FTD_KEYFRAME)
+                            .into_list()
+                            .into_kind_data(),
+                    ),
+                    ftd::interpreter::Field::default_with_value(
+                        "duration",
+                        ftd::interpreter::Kind::integer().into_kind_data(),
+                        ftd::interpreter::PropertyValue::Value {
+                            value: ftd::interpreter::Value::Integer { value: 300 },
+                            is_mutable: false,
+                            line_number: 0,
+                        },
+                    ),
+                    ftd::interpreter::Field::default_with_value(
+                        "delay",
+                        ftd::interpreter::Kind::integer().into_kind_data(),
+                        ftd::interpreter::PropertyValue::Value {
+                            value: ftd::interpreter::Value::Integer { value: 0 },
+                            is_mutable: false,
+                            line_number: 0,
+                        },
+                    ),
+                    ftd::interpreter::Field::default(
+                        "easing",
+                        ftd::interpreter::Kind::string()
+                            .into_optional()
+                            .into_kind_data(),
+                    ),
+                    ftd::interpreter::Field::default_with_value(
+                        "repeat",
+                        ftd::interpreter::Kind::boolean().into_kind_data(),
+                        ftd::interpreter::PropertyValue::Value {
+                            value: ftd::interpreter::Value::Boolean { value: false },
+                            is_mutable: false,
+                            line_number: 0,
+                        },
+                    ),
+                ])
+                .collect(),
+                line_number: 0,
+            }),
+        ),
+        (
+            ftd::interpreter::FTD_FIELD_STATE.to_string(),
+            ftd::interpreter::Thing::Record(ftd::interpreter::Record {
+                name: ftd::interpreter::FTD_FIELD_STATE.to_string(),
+                type_parameters: vec![],
+                fields: std::iter::IntoIterator::into_iter([
+                    ftd::interpreter::Field::default_with_value(
+                        "dirty",
+                        ftd::interpreter::Kind::boolean().into_kind_data(),
+                        ftd::interpreter::PropertyValue::Value {
+                            value: ftd::interpreter::Value::Boolean { value: false },
+                            is_mutable: false,
+                            line_number: 0,
+                        },
+                    ),
+                    ftd::interpreter::Field::default_with_value(
+                        "touched",
+                        ftd::interpreter::Kind::boolean().into_kind_data(),
+                        ftd::interpreter::PropertyValue::Value {
+                            value: ftd::interpreter::Value::Boolean { value: false },
+                            is_mutable: false,
+                            line_number: 0,
+                        },
+                    ),
+                    ftd::interpreter::Field::default_with_value(
+                        "valid",
+                        ftd::interpreter::Kind::boolean().into_kind_data(),
+                        ftd::interpreter::PropertyValue::Value {
+                            value: ftd::interpreter::Value::Boolean { value: true },
+                            is_mutable: false,
+                            line_number: 0,
+                        },
+                    ),
+                    ftd::interpreter::Field::default(
+                        "error",
+                        ftd::interpreter::Kind::string()
+                            .into_optional()
+                            .into_kind_data(),
+                    ),
+                ])
+                .collect(),
+                line_number: 0,
+            }),
+        ),
+        (
+            ftd::interpreter::FTD_FONT_SIZE.to_string(),
+            ftd::interpreter::Thing::OrType(ftd::interpreter::OrType {
+                name: ftd::interpreter::FTD_FONT_SIZE.to_string(),
+                variants: vec![
+                    ftd::interpreter::OrTypeVariant::Regular(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_FONT_SIZE_PX,
+                        ftd::interpreter::Kind::integer()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        None,
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Regular(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_FONT_SIZE_EM,
+                        ftd::interpreter::Kind::decimal()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        None,
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Regular(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_FONT_SIZE_REM,
+                        ftd::interpreter::Kind::decimal()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        None,
+                        0,
+                    )),
+                ],
+                line_number: 0,
+            }),
+        ),
+        (
+            ftd::interpreter::FTD_REGION.to_string(),
+            ftd::interpreter::Thing::OrType(ftd::interpreter::OrType {
+                name: ftd::interpreter::FTD_REGION.to_string(),
+                variants: vec![
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_REGION_H1,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(   ftd::interpreter::Value::new_string("h1")
+                                    .into_property_value(false, 0),),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_REGION_H2,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("h2")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_REGION_H3,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("h3")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_REGION_H4,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("h4")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_REGION_H5,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("h5")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_REGION_H6,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("h6")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_REGION_MAIN,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("main")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_REGION_NAV,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("nav")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_REGION_HEADER,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("header")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_REGION_FOOTER,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("footer")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_REGION_ASIDE,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("aside")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_REGION_SECTION,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("section")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_REGION_ARTICLE,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("article")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                ],
+                line_number: 0,
+            }),
+        ),
+        (
+            ftd::interpreter::FTD_ARIA_ROLE.to_string(),
+            ftd::interpreter::Thing::OrType(ftd::interpreter::OrType {
+                name: ftd::interpreter::FTD_ARIA_ROLE.to_string(),
+                variants: vec![
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_ARIA_ROLE_ALERT,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("alert")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_ARIA_ROLE_BUTTON,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("button")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_ARIA_ROLE_CHECKBOX,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("checkbox")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_ARIA_ROLE_DIALOG,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("dialog")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_ARIA_ROLE_GROUP,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("group")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_ARIA_ROLE_IMG,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("img")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_ARIA_ROLE_LINK,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("link")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_ARIA_ROLE_LIST,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("list")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_ARIA_ROLE_LISTITEM,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("listitem")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_ARIA_ROLE_MENU,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("menu")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_ARIA_ROLE_MENUITEM,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("menuitem")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_ARIA_ROLE_NONE,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("none")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_ARIA_ROLE_PROGRESSBAR,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("progressbar")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_ARIA_ROLE_RADIO,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("radio")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_ARIA_ROLE_RADIOGROUP,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
                             .caption(),
                         false,
-                        Some(ftd::interpreter::Value::new_string("h2")
+                        Some(ftd::interpreter::Value::new_string("radiogroup")
                             .into_property_value(false, 0)),
                         0,
                     )),
                     ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_REGION_H3,
+                        ftd::interpreter::FTD_ARIA_ROLE_REGION,
                         ftd::interpreter::Kind::string()
                             .into_kind_data()
                             .caption(),
                         false,
-                        Some(ftd::interpreter::Value::new_string("h3")
+                        Some(ftd::interpreter::Value::new_string("region")
                             .into_property_value(false, 0)),
                         0,
                     )),
                     ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_REGION_H4,
+                        ftd::interpreter::FTD_ARIA_ROLE_SEARCH,
                         ftd::interpreter::Kind::string()
                             .into_kind_data()
                             .caption(),
                         false,
-                        Some(ftd::interpreter::Value::new_string("h4")
+                        Some(ftd::interpreter::Value::new_string("search")
                             .into_property_value(false, 0)),
                         0,
                     )),
                     ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_REGION_H5,
+                        ftd::interpreter::FTD_ARIA_ROLE_STATUS,
                         ftd::interpreter::Kind::string()
                             .into_kind_data()
                             .caption(),
                         false,
-                        Some(ftd::interpreter::Value::new_string("h5")
+                        Some(ftd::interpreter::Value::new_string("status")
                             .into_property_value(false, 0)),
                         0,
                     )),
                     ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
-                        ftd::interpreter::FTD_REGION_H6,
+                        ftd::interpreter::FTD_ARIA_ROLE_SWITCH,
                         ftd::interpreter::Kind::string()
                             .into_kind_data()
                             .caption(),
                         false,
-                        Some(ftd::interpreter::Value::new_string("h6")
+                        Some(ftd::interpreter::Value::new_string("switch")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_ARIA_ROLE_TAB,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("tab")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_ARIA_ROLE_TABLIST,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("tablist")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_ARIA_ROLE_TABPANEL,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("tabpanel")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_ARIA_ROLE_TOOLTIP,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("tooltip")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                ],
+                line_number: 0,
+            }),
+        ),
+        (
+            ftd::interpreter::FTD_ARIA_LIVE.to_string(),
+            ftd::interpreter::Thing::OrType(ftd::interpreter::OrType {
+                name: ftd::interpreter::FTD_ARIA_LIVE.to_string(),
+                variants: vec![
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_ARIA_LIVE_OFF,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("off")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_ARIA_LIVE_POLITE,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("polite")
+                            .into_property_value(false, 0)),
+                        0,
+                    )),
+                    ftd::interpreter::OrTypeVariant::Constant(ftd::interpreter::Field::new(
+                        ftd::interpreter::FTD_ARIA_LIVE_ASSERTIVE,
+                        ftd::interpreter::Kind::string()
+                            .into_kind_data()
+                            .caption(),
+                        false,
+                        Some(ftd::interpreter::Value::new_string("assertive")
                             .into_property_value(false, 0)),
                         0,
                     )),
@@ -3773,6 +4811,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             ftd::interpreter::FTD_TYPE.to_string(),
             ftd::interpreter::Thing::Record(ftd::interpreter::Record {
                 name: ftd::interpreter::FTD_TYPE.to_string(),
+                type_parameters: vec![],
                 fields: std::iter::IntoIterator::into_iter([
                     ftd::interpreter::Field {
                         name: "size".to_string(),
@@ -3833,6 +4872,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             ftd::interpreter::FTD_RESPONSIVE_TYPE.to_string(),
             ftd::interpreter::Thing::Record(ftd::interpreter::Record {
                 name: ftd::interpreter::FTD_RESPONSIVE_TYPE.to_string(),
+                type_parameters: vec![],
                 fields: std::iter::IntoIterator::into_iter([
                     ftd::interpreter::Field {
                         name: "desktop".to_string(),
@@ -3880,6 +4920,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
                 conditional_value: vec![],
                 line_number: 0,
                 is_static: false,
+                persist: false,
             }),
         ),
         (
@@ -3896,6 +4937,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
                 conditional_value: vec![],
                 line_number: 0,
                 is_static: false,
+                persist: false,
             }),
         ),
         (
@@ -3912,6 +4954,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
                 conditional_value: vec![],
                 line_number: 0,
                 is_static: false,
+                persist: false,
             }),
         ),
         (
@@ -3928,6 +4971,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
                 conditional_value: vec![],
                 line_number: 0,
                 is_static: false,
+                persist: false,
             }),
         ),
         (
@@ -3944,6 +4988,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
                 conditional_value: vec![],
                 line_number: 0,
                 is_static: false,
+                persist: false,
             }),
         ),
         (
@@ -3960,6 +5005,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
                 conditional_value: vec![],
                 line_number: 0,
                 is_static: false,
+                persist: false,
             }),
         ),
         (
@@ -3976,12 +5022,14 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
                 conditional_value: vec![],
                 line_number: 0,
                 is_static: false,
+                persist: false,
             }),
         ),
         (
             "ftd#redirect".to_string(),
             ftd::interpreter::Thing::Component(ftd::interpreter::ComponentDefinition {
                 name: "ftd#redirect".to_string(),
+                type_parameters: vec![],
                 arguments: vec![
                     ftd::interpreter::Argument::default(
                     "url",
@@ -4008,6 +5056,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             ftd::interpreter::FTD_BACKGROUND_COLOR.to_string(),
             ftd::interpreter::Thing::Record(ftd::interpreter::Record {
                 name: ftd::interpreter::FTD_BACKGROUND_COLOR.to_string(),
+                type_parameters: vec![],
                 fields: vec![
                     ftd::interpreter::Field {
                         name: "base".to_string(),
@@ -4062,6 +5111,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             ftd::interpreter::FTD_CTA_COLOR.to_string(),
             ftd::interpreter::Thing::Record(ftd::interpreter::Record {
                 name: ftd::interpreter::FTD_CTA_COLOR.to_string(),
+                type_parameters: vec![],
                 fields: vec![
                     ftd::interpreter::Field {
                         name: "base".to_string(),
@@ -4152,6 +5202,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             ftd::interpreter::FTD_PST.to_string(),
             ftd::interpreter::Thing::Record(ftd::interpreter::Record {
                 name: ftd::interpreter::FTD_PST.to_string(),
+                type_parameters: vec![],
                 fields: vec![
                     ftd::interpreter::Field {
                         name: "primary".to_string(),
@@ -4188,6 +5239,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             ftd::interpreter::FTD_BTB.to_string(),
             ftd::interpreter::Thing::Record(ftd::interpreter::Record {
                 name: ftd::interpreter::FTD_BTB.to_string(),
+                type_parameters: vec![],
                 fields: vec![
                     ftd::interpreter::Field {
                         name: "base".to_string(),
@@ -4224,6 +5276,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             ftd::interpreter::FTD_CUSTOM_COLORS.to_string(),
             ftd::interpreter::Thing::Record(ftd::interpreter::Record {
                 name: ftd::interpreter::FTD_CUSTOM_COLORS.to_string(),
+                type_parameters: vec![],
                 fields: vec![
                     ftd::interpreter::Field {
                         name: "one".to_string(),
@@ -4323,6 +5376,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             ftd::interpreter::FTD_COLOR_SCHEME.to_string(),
             ftd::interpreter::Thing::Record(ftd::interpreter::Record {
                 name: ftd::interpreter::FTD_COLOR_SCHEME.to_string(),
+                type_parameters: vec![],
                 fields: vec![
                     ftd::interpreter::Field {
                         name: "background".to_string(),
@@ -4475,6 +5529,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             ftd::interpreter::FTD_TYPE_DATA.to_string(),
             ftd::interpreter::Thing::Record(ftd::interpreter::Record {
                 name: ftd::interpreter::FTD_TYPE_DATA.to_string(),
+                type_parameters: vec![],
                 fields: vec![ftd::interpreter::Field {
                     name: "heading-large".to_string(),
                     kind: ftd::interpreter::Kind::record(ftd::interpreter::FTD_RESPONSIVE_TYPE)
@@ -4628,7 +5683,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
                  },
                  conditional_value: vec![],
                  line_number: 0,
-                 is_static: false
+                 is_static: false,
+                 persist: false
              })
         ),
         (
@@ -4644,7 +5700,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
                 },
                 conditional_value: vec![],
                 line_number: 0,
-                is_static: false
+                is_static: false,
+                persist: false
             })
         ),
         (
@@ -4660,7 +5717,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
                 },
                 conditional_value: vec![],
                 line_number: 0,
-                is_static: false
+                is_static: false,
+                persist: false
             })
         ),
         (
@@ -7422,7 +8480,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
                 },
                 conditional_value: vec![],
                 line_number: 0,
-                is_static: false
+                is_static: false,
+                persist: false
             })
         ),
         (
@@ -9753,12 +10812,14 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
                 conditional_value: vec![],
                 line_number: 0,
                 is_static: false,
+                persist: false,
             }),
         ),
         (
             ftd::interpreter::FTD_BREAKPOINT_WIDTH_DATA.to_string(),
             ftd::interpreter::Thing::Record(ftd::interpreter::Record {
                 name: ftd::interpreter::FTD_BREAKPOINT_WIDTH_DATA.to_string(),
+                type_parameters: vec![],
                 fields: vec![ftd::interpreter::Field {
                     name: "mobile".to_string(),
                     kind: ftd::interpreter::Kind::integer().into_kind_data().caption(),
@@ -9798,7 +10859,8 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
                 },
                 conditional_value: vec![],
                 line_number: 0,
-                is_static: false
+                is_static: false,
+                persist: false
             })
         ),
         (
@@ -9850,13 +10912,15 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
                 },
                 conditional_value: vec![],
                 line_number: 0,
-                is_static: false
+                is_static: false,
+                persist: false
             })
         ),
         (
             ftd::interpreter::FTD_MASK_IMAGE_DATA.to_string(),
             ftd::interpreter::Thing::Record(ftd::interpreter::Record {
                 name: ftd::interpreter::FTD_MASK_IMAGE_DATA.to_string(),
+                type_parameters: vec![],
                 fields: std::iter::IntoIterator::into_iter([
                     ftd::interpreter::Field {
                         name: "src".to_string(),
@@ -10129,6 +11193,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
                     )),
                     ftd::interpreter::OrTypeVariant::AnonymousRecord(ftd::interpreter::Record {
                         name: ftd::interpreter::FTD_MASK_POSITION_LENGTH.to_string(),
+                        type_parameters: vec![],
                         fields: std::iter::IntoIterator::into_iter([
                             ftd::interpreter::Field {
                                 name: "x".to_string(),
@@ -10159,6 +11224,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             ftd::interpreter::FTD_MASK_MULTI_DATA.to_string(),
             ftd::interpreter::Thing::Record(ftd::interpreter::Record {
                 name: ftd::interpreter::FTD_MASK_MULTI_DATA.to_string(),
+                type_parameters: vec![],
                 fields: std::iter::IntoIterator::into_iter([
                     ftd::interpreter::Field {
                         name: "image".to_string(),
@@ -10252,6 +11318,7 @@ pub fn default_bag() -> indexmap::IndexMap<String, ftd::interpreter::Thing> {
             "ftd#http-options".to_string(),
             ftd::interpreter::Thing::Record(ftd::interpreter::Record {
                 name: "ftd#http-options".to_string(),
+                type_parameters: vec![],
                 fields: std::iter::IntoIterator::into_iter([
                     ftd::interpreter::Field {
                         name: "method".to_string(),
@@ -10388,6 +11455,7 @@ pub fn get_default_bag() -> &'static indexmap::IndexMap<String, ftd::interpreter
 pub fn image_function() -> ftd::interpreter::ComponentDefinition {
     ftd::interpreter::ComponentDefinition {
         name: "ftd#image".to_string(),
+        type_parameters: vec![],
         arguments: [
             common_arguments(),
             vec![
@@ -10426,54 +11494,215 @@ pub fn image_function() -> ftd::interpreter::ComponentDefinition {
     }
 }
 
-pub fn video_function() -> ftd::interpreter::ComponentDefinition {
+pub fn video_function() -> ftd::interpreter::ComponentDefinition {
+    ftd::interpreter::ComponentDefinition {
+        name: "ftd#video".to_string(),
+        type_parameters: vec![],
+        arguments: [
+            common_arguments(),
+            vec![
+                ftd::interpreter::Argument::default(
+                    "src",
+                    ftd::interpreter::Kind::record(ftd::interpreter::FTD_VIDEO_SRC)
+                        .into_kind_data()
+                        .caption(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "fit",
+                    ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_IMAGE_FIT)
+                        .into_kind_data()
+                        .into_optional(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "controls",
+                    ftd::interpreter::Kind::boolean()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "loop",
+                    ftd::interpreter::Kind::boolean()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "autoplay",
+                    ftd::interpreter::Kind::boolean()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "muted",
+                    ftd::interpreter::Kind::boolean()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "poster",
+                    ftd::interpreter::Kind::record(ftd::interpreter::FTD_IMAGE_SRC)
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+            ],
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: ftd::interpreter::Component::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn boolean_function() -> ftd::interpreter::ComponentDefinition {
+    ftd::interpreter::ComponentDefinition {
+        name: "ftd#boolean".to_string(),
+        type_parameters: vec![],
+        arguments: [
+            text_arguments(),
+            common_arguments(),
+            vec![
+                ftd::interpreter::Argument::default(
+                    "value",
+                    ftd::interpreter::Kind::boolean()
+                        .into_kind_data()
+                        .caption_or_body(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "style",
+                    ftd::interpreter::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "format",
+                    ftd::interpreter::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "text-align",
+                    ftd::interpreter::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+            ],
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: ftd::interpreter::Component::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn checkbox_function() -> ftd::interpreter::ComponentDefinition {
+    ftd::interpreter::ComponentDefinition {
+        name: "ftd#checkbox".to_string(),
+        type_parameters: vec![],
+        arguments: [
+            common_arguments(),
+            field_arguments(),
+            vec![
+                ftd::interpreter::Argument::default(
+                    "checked",
+                    ftd::interpreter::Kind::boolean()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "enabled",
+                    ftd::interpreter::Kind::boolean()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+            ],
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: ftd::interpreter::Component::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn form_function() -> ftd::interpreter::ComponentDefinition {
+    ftd::interpreter::ComponentDefinition {
+        name: "ftd#form".to_string(),
+        type_parameters: vec![],
+        arguments: [
+            container_root_arguments(),
+            container_arguments(),
+            common_arguments(),
+            vec![ftd::interpreter::Argument {
+                name: "valid".to_string(),
+                kind: ftd::interpreter::Kind::boolean()
+                    .into_optional()
+                    .into_kind_data(),
+                mutable: true,
+                value: None,
+                access_modifier: Default::default(),
+                line_number: 0,
+            }],
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: ftd::interpreter::Component::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn select_function() -> ftd::interpreter::ComponentDefinition {
+    ftd::interpreter::ComponentDefinition {
+        name: "ftd#select".to_string(),
+        type_parameters: vec![],
+        arguments: [
+            common_arguments(),
+            form_control_arguments(),
+            vec![
+                ftd::interpreter::Argument::default(
+                    "options",
+                    ftd::interpreter::Kind::record(ftd::interpreter::FTD_OPTION)
+                        .into_list()
+                        .into_kind_data(),
+                ),
+                form_control_value_argument(ftd::interpreter::Kind::string()),
+                ftd::interpreter::Argument::default(
+                    "placeholder",
+                    ftd::interpreter::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+            ],
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: ftd::interpreter::Component::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn radio_function() -> ftd::interpreter::ComponentDefinition {
     ftd::interpreter::ComponentDefinition {
-        name: "ftd#video".to_string(),
+        name: "ftd#radio".to_string(),
+        type_parameters: vec![],
         arguments: [
             common_arguments(),
+            form_control_arguments(),
             vec![
                 ftd::interpreter::Argument::default(
-                    "src",
-                    ftd::interpreter::Kind::record(ftd::interpreter::FTD_VIDEO_SRC)
-                        .into_kind_data()
-                        .caption(),
-                ),
-                ftd::interpreter::Argument::default(
-                    "fit",
-                    ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_IMAGE_FIT)
-                        .into_kind_data()
-                        .into_optional(),
-                ),
-                ftd::interpreter::Argument::default(
-                    "controls",
-                    ftd::interpreter::Kind::boolean()
-                        .into_optional()
-                        .into_kind_data(),
-                ),
-                ftd::interpreter::Argument::default(
-                    "loop",
-                    ftd::interpreter::Kind::boolean()
-                        .into_optional()
-                        .into_kind_data(),
-                ),
-                ftd::interpreter::Argument::default(
-                    "autoplay",
-                    ftd::interpreter::Kind::boolean()
-                        .into_optional()
-                        .into_kind_data(),
-                ),
-                ftd::interpreter::Argument::default(
-                    "muted",
-                    ftd::interpreter::Kind::boolean()
-                        .into_optional()
-                        .into_kind_data(),
-                ),
-                ftd::interpreter::Argument::default(
-                    "poster",
-                    ftd::interpreter::Kind::record(ftd::interpreter::FTD_IMAGE_SRC)
-                        .into_optional()
+                    "options",
+                    ftd::interpreter::Kind::record(ftd::interpreter::FTD_OPTION)
+                        .into_list()
                         .into_kind_data(),
                 ),
+                form_control_value_argument(ftd::interpreter::Kind::string()),
             ],
         ]
         .concat()
@@ -10485,34 +11714,30 @@ pub fn video_function() -> ftd::interpreter::ComponentDefinition {
     }
 }
 
-pub fn boolean_function() -> ftd::interpreter::ComponentDefinition {
+pub fn slider_function() -> ftd::interpreter::ComponentDefinition {
     ftd::interpreter::ComponentDefinition {
-        name: "ftd#boolean".to_string(),
+        name: "ftd#slider".to_string(),
+        type_parameters: vec![],
         arguments: [
-            text_arguments(),
             common_arguments(),
+            form_control_arguments(),
             vec![
+                form_control_value_argument(ftd::interpreter::Kind::decimal()),
                 ftd::interpreter::Argument::default(
-                    "value",
-                    ftd::interpreter::Kind::boolean()
-                        .into_kind_data()
-                        .caption_or_body(),
-                ),
-                ftd::interpreter::Argument::default(
-                    "style",
-                    ftd::interpreter::Kind::string()
+                    "min",
+                    ftd::interpreter::Kind::decimal()
                         .into_optional()
                         .into_kind_data(),
                 ),
                 ftd::interpreter::Argument::default(
-                    "format",
-                    ftd::interpreter::Kind::string()
+                    "max",
+                    ftd::interpreter::Kind::decimal()
                         .into_optional()
                         .into_kind_data(),
                 ),
                 ftd::interpreter::Argument::default(
-                    "text-align",
-                    ftd::interpreter::Kind::string()
+                    "step",
+                    ftd::interpreter::Kind::decimal()
                         .into_optional()
                         .into_kind_data(),
                 ),
@@ -10527,20 +11752,32 @@ pub fn boolean_function() -> ftd::interpreter::ComponentDefinition {
     }
 }
 
-pub fn checkbox_function() -> ftd::interpreter::ComponentDefinition {
+pub fn file_input_function() -> ftd::interpreter::ComponentDefinition {
     ftd::interpreter::ComponentDefinition {
-        name: "ftd#checkbox".to_string(),
+        name: "ftd#file-input".to_string(),
+        type_parameters: vec![],
         arguments: [
             common_arguments(),
+            form_control_arguments(),
             vec![
+                ftd::interpreter::Argument {
+                    name: "value".to_string(),
+                    kind: ftd::interpreter::Kind::record(ftd::interpreter::FTD_FILE)
+                        .into_list()
+                        .into_kind_data(),
+                    mutable: true,
+                    value: None,
+                    access_modifier: Default::default(),
+                    line_number: 0,
+                },
                 ftd::interpreter::Argument::default(
-                    "checked",
-                    ftd::interpreter::Kind::boolean()
+                    "accept",
+                    ftd::interpreter::Kind::string()
                         .into_optional()
                         .into_kind_data(),
                 ),
                 ftd::interpreter::Argument::default(
-                    "enabled",
+                    "multiple",
                     ftd::interpreter::Kind::boolean()
                         .into_optional()
                         .into_kind_data(),
@@ -10559,9 +11796,11 @@ pub fn checkbox_function() -> ftd::interpreter::ComponentDefinition {
 pub fn text_input_function() -> ftd::interpreter::ComponentDefinition {
     ftd::interpreter::ComponentDefinition {
         name: "ftd#text-input".to_string(),
+        type_parameters: vec![],
         arguments: [
             text_arguments(),
             common_arguments(),
+            field_arguments(),
             vec![
                 ftd::interpreter::Argument::default(
                     "placeholder",
@@ -10599,6 +11838,18 @@ pub fn text_input_function() -> ftd::interpreter::ComponentDefinition {
                         .into_optional()
                         .into_kind_data(),
                 ),
+                ftd::interpreter::Argument::default(
+                    "min-length",
+                    ftd::interpreter::Kind::integer()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "pattern",
+                    ftd::interpreter::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
                 ftd::interpreter::Argument::default(
                     "type",
                     ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_TEXT_INPUT_TYPE)
@@ -10619,6 +11870,7 @@ pub fn text_input_function() -> ftd::interpreter::ComponentDefinition {
 pub fn integer_function() -> ftd::interpreter::ComponentDefinition {
     ftd::interpreter::ComponentDefinition {
         name: "ftd#integer".to_string(),
+        type_parameters: vec![],
         arguments: [
             text_arguments(),
             common_arguments(),
@@ -10661,25 +11913,212 @@ pub fn integer_function() -> ftd::interpreter::ComponentDefinition {
 pub fn decimal_function() -> ftd::interpreter::ComponentDefinition {
     ftd::interpreter::ComponentDefinition {
         name: "ftd#decimal".to_string(),
+        type_parameters: vec![],
+        arguments: [
+            text_arguments(),
+            common_arguments(),
+            vec![
+                ftd::interpreter::Argument::default(
+                    "value",
+                    ftd::interpreter::Kind::decimal()
+                        .into_kind_data()
+                        .caption_or_body(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "style",
+                    ftd::interpreter::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "format",
+                    ftd::interpreter::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+            ],
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: ftd::interpreter::Component::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn markup_function() -> ftd::interpreter::ComponentDefinition {
+    ftd::interpreter::ComponentDefinition {
+        name: "ftd#text".to_string(),
+        type_parameters: vec![],
         arguments: [
             text_arguments(),
             common_arguments(),
+            vec![ftd::interpreter::Argument::default(
+                "text",
+                ftd::interpreter::Kind::string()
+                    .into_kind_data()
+                    .caption_or_body(),
+            )],
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: ftd::interpreter::Component::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn row_function() -> ftd::interpreter::ComponentDefinition {
+    ftd::interpreter::ComponentDefinition {
+        name: "ftd#row".to_string(),
+        type_parameters: vec![],
+        arguments: [
+            container_root_arguments(),
+            container_arguments(),
+            common_arguments(),
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: ftd::interpreter::Component::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn rive_function() -> ftd::interpreter::ComponentDefinition {
+    use itertools::Itertools;
+
+    ftd::interpreter::ComponentDefinition {
+        name: "ftd#rive".to_string(),
+        type_parameters: vec![],
+        arguments: [
+            common_arguments()
+                .into_iter()
+                .filter(|v| v.name.ne("id"))
+                .collect_vec(),
+            vec![
+                ftd::interpreter::Argument::default(
+                    "id",
+                    ftd::interpreter::Kind::string().into_kind_data().caption(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "src",
+                    ftd::interpreter::Kind::string().into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "canvas-width",
+                    ftd::interpreter::Kind::integer()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "canvas-height",
+                    ftd::interpreter::Kind::integer()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "state-machine",
+                    ftd::interpreter::Kind::string()
+                        .into_list()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument {
+                    name: "autoplay".to_string(),
+                    kind: ftd::interpreter::Kind::boolean().into_kind_data(),
+                    mutable: false,
+                    value: Some(ftd::interpreter::PropertyValue::Value {
+                        value: ftd::interpreter::Value::Boolean { value: true },
+                        is_mutable: false,
+                        line_number: 0,
+                    }),
+                    access_modifier: Default::default(),
+                    line_number: 0,
+                },
+                ftd::interpreter::Argument::default(
+                    "artboard",
+                    ftd::interpreter::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+            ],
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: ftd::interpreter::Component::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn container_function() -> ftd::interpreter::ComponentDefinition {
+    ftd::interpreter::ComponentDefinition {
+        name: "ftd#container".to_string(),
+        type_parameters: vec![],
+        arguments: [
+            container_root_arguments(),
+            common_arguments(),
+            vec![ftd::interpreter::Argument::default(
+                "display",
+                ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_DISPLAY)
+                    .into_optional()
+                    .into_kind_data(),
+            )],
+        ]
+        .concat()
+        .into_iter()
+        .collect(),
+        definition: ftd::interpreter::Component::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
+
+pub fn grid_function() -> ftd::interpreter::ComponentDefinition {
+    ftd::interpreter::ComponentDefinition {
+        name: "ftd#grid".to_string(),
+        type_parameters: vec![],
+        arguments: [
+            container_root_arguments(),
+            common_arguments(),
             vec![
                 ftd::interpreter::Argument::default(
-                    "value",
-                    ftd::interpreter::Kind::decimal()
-                        .into_kind_data()
-                        .caption_or_body(),
+                    "template-columns",
+                    ftd::interpreter::Kind::record(ftd::interpreter::FTD_RESPONSIVE_STRING)
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "template-rows",
+                    ftd::interpreter::Kind::record(ftd::interpreter::FTD_RESPONSIVE_STRING)
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "template-areas",
+                    ftd::interpreter::Kind::record(ftd::interpreter::FTD_RESPONSIVE_STRING)
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "gap",
+                    ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_LENGTH)
+                        .into_optional()
+                        .into_kind_data(),
                 ),
                 ftd::interpreter::Argument::default(
-                    "style",
-                    ftd::interpreter::Kind::string()
+                    "row-gap",
+                    ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_LENGTH)
                         .into_optional()
                         .into_kind_data(),
                 ),
                 ftd::interpreter::Argument::default(
-                    "format",
-                    ftd::interpreter::Kind::string()
+                    "column-gap",
+                    ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_LENGTH)
                         .into_optional()
                         .into_kind_data(),
                 ),
@@ -10694,18 +12133,47 @@ pub fn decimal_function() -> ftd::interpreter::ComponentDefinition {
     }
 }
 
-pub fn markup_function() -> ftd::interpreter::ComponentDefinition {
+/// A native `<dialog>`, shown while `open` is true. A modal dialog traps
+/// focus and locks the page scroll, and a `dismissible` one is closed by
+/// Escape or a click on its backdrop, which sets `open` back to false.
+pub fn dialog_function() -> ftd::interpreter::ComponentDefinition {
     ftd::interpreter::ComponentDefinition {
-        name: "ftd#text".to_string(),
+        name: "ftd#dialog".to_string(),
+        type_parameters: vec![],
         arguments: [
-            text_arguments(),
+            container_root_arguments(),
+            container_arguments(),
             common_arguments(),
-            vec![ftd::interpreter::Argument::default(
-                "text",
-                ftd::interpreter::Kind::string()
-                    .into_kind_data()
-                    .caption_or_body(),
-            )],
+            vec![
+                ftd::interpreter::Argument {
+                    name: "open".to_string(),
+                    kind: ftd::interpreter::Kind::boolean()
+                        .into_optional()
+                        .into_kind_data(),
+                    mutable: true,
+                    value: None,
+                    access_modifier: Default::default(),
+                    line_number: 0,
+                },
+                ftd::interpreter::Argument::default_with_value(
+                    "modal",
+                    ftd::interpreter::Kind::boolean().into_kind_data(),
+                    ftd::interpreter::PropertyValue::Value {
+                        value: ftd::interpreter::Value::Boolean { value: true },
+                        is_mutable: false,
+                        line_number: 0,
+                    },
+                ),
+                ftd::interpreter::Argument::default_with_value(
+                    "dismissible",
+                    ftd::interpreter::Kind::boolean().into_kind_data(),
+                    ftd::interpreter::PropertyValue::Value {
+                        value: ftd::interpreter::Value::Boolean { value: true },
+                        is_mutable: false,
+                        line_number: 0,
+                    },
+                ),
+            ],
         ]
         .concat()
         .into_iter()
@@ -10716,13 +12184,38 @@ pub fn markup_function() -> ftd::interpreter::ComponentDefinition {
     }
 }
 
-pub fn row_function() -> ftd::interpreter::ComponentDefinition {
+/// Shown next to the element with the id `anchor-id` while `open` is true.
+/// `placement` is one of `bottom` (default), `top`, `left` or `right`.
+pub fn popover_function() -> ftd::interpreter::ComponentDefinition {
     ftd::interpreter::ComponentDefinition {
-        name: "ftd#row".to_string(),
+        name: "ftd#popover".to_string(),
+        type_parameters: vec![],
         arguments: [
             container_root_arguments(),
             container_arguments(),
             common_arguments(),
+            vec![
+                ftd::interpreter::Argument {
+                    name: "open".to_string(),
+                    kind: ftd::interpreter::Kind::boolean()
+                        .into_optional()
+                        .into_kind_data(),
+                    mutable: true,
+                    value: None,
+                    access_modifier: Default::default(),
+                    line_number: 0,
+                },
+                ftd::interpreter::Argument::default(
+                    "anchor-id",
+                    ftd::interpreter::Kind::string().into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "placement",
+                    ftd::interpreter::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+            ],
         ]
         .concat()
         .into_iter()
@@ -10733,61 +12226,122 @@ pub fn row_function() -> ftd::interpreter::ComponentDefinition {
     }
 }
 
-pub fn rive_function() -> ftd::interpreter::ComponentDefinition {
-    use itertools::Itertools;
+/// Keeps `value` in sync with the messages of the stream at `url`: server
+/// sent events, or a WebSocket for `ws://` and `wss://` urls. Every message
+/// is a JSON encoded value of `value`'s kind, and replaces it.
+/// `fastn serve` publishes its topics at `/-/live/<topic>/`. Renders
+/// nothing.
+pub fn subscribe_function() -> ftd::interpreter::ComponentDefinition {
+    ftd::interpreter::ComponentDefinition {
+        name: "ftd#subscribe".to_string(),
+        type_parameters: vec!["T".to_string()],
+        arguments: vec![
+            ftd::interpreter::Argument::default(
+                "url",
+                ftd::interpreter::Kind::string().into_kind_data().caption(),
+            ),
+            ftd::interpreter::Argument {
+                name: "value".to_string(),
+                kind: ftd::interpreter::Kind::type_parameter("T").into_kind_data(),
+                mutable: true,
+                value: None,
+                access_modifier: Default::default(),
+                line_number: 0,
+            },
+        ],
+        definition: ftd::interpreter::Component::from_name("ftd.kernel"),
+        css: None,
+        line_number: 0,
+    }
+}
 
+/// `data` is a list of any record. A column shows the `field` of each row as
+/// text, or renders the component passed as `cell` (e.g. `cell: $stock-cell`)
+/// with the row as its `row` argument.
+///
+/// The pager shows `previous-label`, `next-label` and `page-label`, where
+/// `{page}` and `{pages}` stand for the current page and the page count.
+/// With `server-pagination`, a `sql` processor query with a `page-size`
+/// header returns the page and sort asked for in the query string. A table
+/// and its query given the same `page-param` use their own names in it, so
+/// two tables on a page are paged separately.
+pub fn table_function() -> ftd::interpreter::ComponentDefinition {
     ftd::interpreter::ComponentDefinition {
-        name: "ftd#rive".to_string(),
+        name: "ftd#table".to_string(),
+        type_parameters: vec!["T".to_string()],
         arguments: [
-            common_arguments()
-                .into_iter()
-                .filter(|v| v.name.ne("id"))
-                .collect_vec(),
+            common_arguments(),
             vec![
                 ftd::interpreter::Argument::default(
-                    "id",
-                    ftd::interpreter::Kind::string().into_kind_data().caption(),
+                    "data",
+                    ftd::interpreter::Kind::type_parameter("T")
+                        .into_list()
+                        .into_kind_data(),
                 ),
                 ftd::interpreter::Argument::default(
-                    "src",
-                    ftd::interpreter::Kind::string().into_kind_data(),
+                    "columns",
+                    ftd::interpreter::Kind::record(ftd::interpreter::FTD_TABLE_COLUMN)
+                        .into_list()
+                        .into_kind_data(),
                 ),
                 ftd::interpreter::Argument::default(
-                    "canvas-width",
+                    "filter",
+                    ftd::interpreter::Kind::string()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "page-size",
                     ftd::interpreter::Kind::integer()
                         .into_optional()
                         .into_kind_data(),
                 ),
                 ftd::interpreter::Argument::default(
-                    "canvas-height",
+                    "page",
                     ftd::interpreter::Kind::integer()
                         .into_optional()
                         .into_kind_data(),
                 ),
                 ftd::interpreter::Argument::default(
-                    "state-machine",
-                    ftd::interpreter::Kind::string()
-                        .into_list()
+                    "server-pagination",
+                    ftd::interpreter::Kind::boolean()
+                        .into_optional()
                         .into_kind_data(),
                 ),
-                ftd::interpreter::Argument {
-                    name: "autoplay".to_string(),
-                    kind: ftd::interpreter::Kind::boolean().into_kind_data(),
-                    mutable: false,
-                    value: Some(ftd::interpreter::PropertyValue::Value {
-                        value: ftd::interpreter::Value::Boolean { value: true },
-                        is_mutable: false,
-                        line_number: 0,
-                    }),
-                    access_modifier: Default::default(),
-                    line_number: 0,
-                },
                 ftd::interpreter::Argument::default(
-                    "artboard",
+                    "page-param",
                     ftd::interpreter::Kind::string()
                         .into_optional()
                         .into_kind_data(),
                 ),
+                ftd::interpreter::Argument::default(
+                    "total",
+                    ftd::interpreter::Kind::integer()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default(
+                    "sticky-header",
+                    ftd::interpreter::Kind::boolean()
+                        .into_optional()
+                        .into_kind_data(),
+                ),
+                ftd::interpreter::Argument::default_with_value(
+                    "previous-label",
+                    ftd::interpreter::Kind::string().into_kind_data(),
+                    ftd::interpreter::Value::new_string("Previous").into_property_value(false, 0),
+                ),
+                ftd::interpreter::Argument::default_with_value(
+                    "next-label",
+                    ftd::interpreter::Kind::string().into_kind_data(),
+                    ftd::interpreter::Value::new_string("Next").into_property_value(false, 0),
+                ),
+                ftd::interpreter::Argument::default_with_value(
+                    "page-label",
+                    ftd::interpreter::Kind::string().into_kind_data(),
+                    ftd::interpreter::Value::new_string("Page {page} of {pages}")
+                        .into_property_value(false, 0),
+                ),
             ],
         ]
         .concat()
@@ -10799,31 +12353,10 @@ pub fn rive_function() -> ftd::interpreter::ComponentDefinition {
     }
 }
 
-pub fn container_function() -> ftd::interpreter::ComponentDefinition {
-    ftd::interpreter::ComponentDefinition {
-        name: "ftd#container".to_string(),
-        arguments: [
-            container_root_arguments(),
-            common_arguments(),
-            vec![ftd::interpreter::Argument::default(
-                "display",
-                ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_DISPLAY)
-                    .into_optional()
-                    .into_kind_data(),
-            )],
-        ]
-        .concat()
-        .into_iter()
-        .collect(),
-        definition: ftd::interpreter::Component::from_name("ftd.kernel"),
-        css: None,
-        line_number: 0,
-    }
-}
-
 pub fn desktop_function() -> ftd::interpreter::ComponentDefinition {
     ftd::interpreter::ComponentDefinition {
         name: "ftd#desktop".to_string(),
+        type_parameters: vec![],
         arguments: [container_root_arguments()].concat().into_iter().collect(),
         definition: ftd::interpreter::Component::from_name("ftd.kernel"),
         css: None,
@@ -10834,6 +12367,7 @@ pub fn desktop_function() -> ftd::interpreter::ComponentDefinition {
 pub fn mobile_function() -> ftd::interpreter::ComponentDefinition {
     ftd::interpreter::ComponentDefinition {
         name: "ftd#mobile".to_string(),
+        type_parameters: vec![],
         arguments: [container_root_arguments()].concat().into_iter().collect(),
         definition: ftd::interpreter::Component::from_name("ftd.kernel"),
         css: None,
@@ -10844,6 +12378,7 @@ pub fn mobile_function() -> ftd::interpreter::ComponentDefinition {
 pub fn code_function() -> ftd::interpreter::ComponentDefinition {
     ftd::interpreter::ComponentDefinition {
         name: "ftd#code".to_string(),
+        type_parameters: vec![],
         arguments: [
             text_arguments(),
             common_arguments(),
@@ -10887,6 +12422,7 @@ pub fn code_function() -> ftd::interpreter::ComponentDefinition {
 pub fn iframe_function() -> ftd::interpreter::ComponentDefinition {
     ftd::interpreter::ComponentDefinition {
         name: "ftd#iframe".to_string(),
+        type_parameters: vec![],
         arguments: [
             common_arguments(),
             vec![
@@ -10930,6 +12466,7 @@ pub fn iframe_function() -> ftd::interpreter::ComponentDefinition {
 pub fn column_function() -> ftd::interpreter::ComponentDefinition {
     ftd::interpreter::ComponentDefinition {
         name: "ftd#column".to_string(),
+        type_parameters: vec![],
         arguments: [
             container_root_arguments(),
             container_arguments(),
@@ -10947,6 +12484,7 @@ pub fn column_function() -> ftd::interpreter::ComponentDefinition {
 pub fn document_function() -> ftd::interpreter::ComponentDefinition {
     ftd::interpreter::ComponentDefinition {
         name: "ftd#document".to_string(),
+        type_parameters: vec![],
         arguments: [vec![
             ftd::interpreter::Argument::default(
                 "favicon",
@@ -11153,6 +12691,76 @@ fn container_arguments() -> Vec<ftd::interpreter::Argument> {
     ]
 }
 
+fn form_control_arguments() -> Vec<ftd::interpreter::Argument> {
+    [
+        field_arguments(),
+        vec![
+            ftd::interpreter::Argument::default(
+                "enabled",
+                ftd::interpreter::Kind::boolean()
+                    .into_optional()
+                    .into_kind_data(),
+            ),
+            ftd::interpreter::Argument::default(
+                "label",
+                ftd::interpreter::Kind::string()
+                    .into_optional()
+                    .into_kind_data(),
+            ),
+        ],
+    ]
+    .concat()
+}
+
+/// Validation constraints and the `$state` of an input inside an `ftd.form`.
+/// `error` is a custom rule: the field is invalid while it is set. `name`
+/// keys the field in submitted data and in errors returned by the server.
+fn field_arguments() -> Vec<ftd::interpreter::Argument> {
+    vec![
+        ftd::interpreter::Argument::default(
+            "name",
+            ftd::interpreter::Kind::string()
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "required",
+            ftd::interpreter::Kind::boolean()
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "error",
+            ftd::interpreter::Kind::string()
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument {
+            name: "state".to_string(),
+            kind: ftd::interpreter::Kind::record(ftd::interpreter::FTD_FIELD_STATE)
+                .into_optional()
+                .into_kind_data(),
+            mutable: true,
+            value: None,
+            access_modifier: Default::default(),
+            line_number: 0,
+        },
+    ]
+}
+
+/// The `$value` of a form control is mutable so the control can write the
+/// user's choice back to the variable passed to it.
+fn form_control_value_argument(kind: ftd::interpreter::Kind) -> ftd::interpreter::Argument {
+    ftd::interpreter::Argument {
+        name: "value".to_string(),
+        kind: kind.into_optional().into_kind_data(),
+        mutable: true,
+        value: None,
+        access_modifier: Default::default(),
+        line_number: 0,
+    }
+}
+
 fn common_arguments() -> Vec<ftd::interpreter::Argument> {
     vec![
         ftd::interpreter::Argument::default(
@@ -11251,6 +12859,36 @@ fn common_arguments() -> Vec<ftd::interpreter::Argument> {
                 .into_optional()
                 .into_kind_data(),
         ),
+        ftd::interpreter::Argument::default(
+            "aria-role",
+            ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_ARIA_ROLE)
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "aria-label",
+            ftd::interpreter::Kind::string()
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "aria-described-by",
+            ftd::interpreter::Kind::string()
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "aria-live",
+            ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_ARIA_LIVE)
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "tab-index",
+            ftd::interpreter::Kind::integer()
+                .into_optional()
+                .into_kind_data(),
+        ),
         ftd::interpreter::Argument::default(
             "left",
             ftd::interpreter::Kind::or_type(ftd::interpreter::FTD_LENGTH)
@@ -11587,6 +13225,54 @@ fn common_arguments() -> Vec<ftd::interpreter::Argument> {
                 .into_optional()
                 .into_kind_data(),
         ),
+        ftd::interpreter::Argument::default(
+            "grid-column",
+            ftd::interpreter::Kind::record(ftd::interpreter::FTD_RESPONSIVE_STRING)
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "grid-row",
+            ftd::interpreter::Kind::record(ftd::interpreter::FTD_RESPONSIVE_STRING)
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "grid-area",
+            ftd::interpreter::Kind::record(ftd::interpreter::FTD_RESPONSIVE_STRING)
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "drag-data",
+            ftd::interpreter::Kind::string()
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "transition",
+            ftd::interpreter::Kind::record(ftd::interpreter::FTD_TRANSITION)
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "animation",
+            ftd::interpreter::Kind::record(ftd::interpreter::FTD_ANIMATION)
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "enter-animation",
+            ftd::interpreter::Kind::record(ftd::interpreter::FTD_ANIMATION)
+                .into_optional()
+                .into_kind_data(),
+        ),
+        ftd::interpreter::Argument::default(
+            "exit-animation",
+            ftd::interpreter::Kind::record(ftd::interpreter::FTD_ANIMATION)
+                .into_optional()
+                .into_kind_data(),
+        ),
     ]
 }
 
@@ -11641,6 +13327,7 @@ fn text_arguments() -> Vec<ftd::interpreter::Argument> {
 /*fn kernel_component() -> ftd::interpreter::ComponentDefinition {
     ftd::interpreter::ComponentDefinition {
         name: "ftd.kernel".to_string(),
+        type_parameters: vec![],
         arguments: vec![],
         definition: ftd::interpreter::Component {
             name: "ftd.kernel".to_string(),
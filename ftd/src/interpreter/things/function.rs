@@ -1,6 +1,8 @@
 #[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct Function {
     pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub type_parameters: Vec<String>,
     pub return_kind: ftd::interpreter::KindData,
     pub arguments: Vec<ftd::interpreter::Argument>,
     pub expression: Vec<Expression>,
@@ -12,6 +14,7 @@ pub struct Function {
 impl Function {
     fn new(
         name: &str,
+        type_parameters: Vec<String>,
         return_kind: ftd::interpreter::KindData,
         arguments: Vec<ftd::interpreter::Argument>,
         expression: Vec<Expression>,
@@ -20,6 +23,7 @@ impl Function {
     ) -> Function {
         Function {
             name: name.to_string(),
+            type_parameters,
             return_kind,
             arguments,
             expression,
@@ -34,15 +38,35 @@ impl Function {
         doc: &mut ftd::interpreter::TDoc,
     ) -> ftd::interpreter::Result<()> {
         let function = ast.get_function(doc.name)?;
-        ftd::interpreter::Argument::scan_ast_fields(function.arguments, doc, &Default::default())?;
+        let known_kinds =
+            ftd::interpreter::utils::type_parameters_known_kinds(&function.type_parameters);
+        ftd::interpreter::Argument::scan_ast_fields(function.arguments, doc, &known_kinds)?;
 
         ftd::interpreter::KindData::scan_ast_kind(
             function.kind,
-            &Default::default(),
+            &known_kinds,
             doc,
             function.line_number,
         )?;
 
+        // Errors in the body are reported by `from_ast`
+        if let Ok(statements) =
+            fastn_grammar::statement::build_statements(function.definition.value.as_str())
+        {
+            for kind in local_kind_declarations(&statements) {
+                ftd::interpreter::KindData::scan_ast_kind(
+                    ftd::ast::VariableKind::get_kind(
+                        kind.as_str(),
+                        doc.name,
+                        function.line_number,
+                    )?,
+                    &known_kinds,
+                    doc,
+                    function.line_number,
+                )?;
+            }
+        }
+
         Ok(())
     }
 
@@ -75,20 +99,88 @@ impl Function {
             None
         };
 
+        // the line of the body is where it ends, its first statement is on the line after the
+        // header and the arguments of the function, and a blank line
+        let body_line_number = function
+            .arguments
+            .iter()
+            .map(|v| v.line_number)
+            .filter(|v| *v < function.definition.line_number)
+            .fold(function.line_number, std::cmp::max)
+            + 2;
+
+        let known_kinds =
+            ftd::interpreter::utils::type_parameters_known_kinds(&function.type_parameters);
         let arguments = try_ok_state!(ftd::interpreter::Argument::from_ast_fields(
             function.name.as_str(),
             function.arguments,
             doc,
-            &Default::default(),
+            &known_kinds,
         )?);
 
         let kind = try_ok_state!(ftd::interpreter::KindData::from_ast_kind(
             function.kind,
-            &Default::default(),
+            &known_kinds,
             doc,
             function.line_number,
         )?);
 
+        let statements =
+            match fastn_grammar::statement::build_statements(function.definition.value.as_str()) {
+                Ok(statements) => statements,
+                Err(e) => {
+                    return ftd::interpreter::utils::e2(
+                        format!("Invalid body for function `{}`: {}", function.name, e),
+                        doc.name,
+                        function.definition.line_number,
+                    )
+                }
+            };
+        if !matches!(
+            statements.as_slice(),
+            [fastn_grammar::statement::Statement::Expression { .. }]
+        ) {
+            let mut local_kinds: ftd::Map<ftd::interpreter::Kind> = Default::default();
+            for kind_name in local_kind_declarations(&statements) {
+                let local_kind = try_ok_state!(ftd::interpreter::KindData::from_ast_kind(
+                    ftd::ast::VariableKind::get_kind(
+                        kind_name.as_str(),
+                        doc.name,
+                        function.line_number,
+                    )?,
+                    &known_kinds,
+                    doc,
+                    function.line_number,
+                )?);
+                local_kinds.insert(kind_name, local_kind.kind);
+            }
+
+            let mut scope = arguments
+                .iter()
+                .map(|v| (v.name.to_string(), v.kind.kind.clone()))
+                .collect::<ftd::Map<ftd::interpreter::Kind>>();
+            let checker = StatementChecker {
+                function_name: function.name.as_str(),
+                return_kind: &kind.kind,
+                local_kinds: &local_kinds,
+                doc_id: doc.name,
+                body_line_number,
+            };
+            checker.check(&statements, &mut scope)?;
+            if !kind.is_void() && !fastn_grammar::statement::Statement::always_returns(&statements)
+            {
+                return ftd::interpreter::utils::e2(
+                    format!(
+                        "Function `{}` returns `{}` but its body can end without a `return`",
+                        function.name,
+                        kind_name(&kind.kind)
+                    ),
+                    doc.name,
+                    function.line_number,
+                );
+            }
+        }
+
         let expression = vec![Expression {
             expression: function.definition.value.to_string(),
             line_number: function.definition.line_number,
@@ -96,6 +188,7 @@ impl Function {
 
         Ok(ftd::interpreter::StateWithThing::new_thing(Function::new(
             name.as_str(),
+            function.type_parameters,
             kind,
             arguments,
             expression,
@@ -106,7 +199,7 @@ impl Function {
 
     pub(crate) fn resolve(
         &self,
-        _kind: &ftd::interpreter::KindData,
+        kind: &ftd::interpreter::KindData,
         values: &ftd::Map<ftd::interpreter::PropertyValue>,
         doc: &ftd::interpreter::TDoc,
         line_number: usize,
@@ -174,10 +267,15 @@ impl Function {
 
         let expression = self.convert_to_evalexpr_expression();
 
-        let eval = fastn_grammar::evalexpr::eval_with_context_mut(
-            expression.as_str(),
-            &mut evalexpr_context,
-        )?;
+        let eval = match fastn_grammar::statement::build_statements(expression.as_str())?.as_slice()
+        {
+            [fastn_grammar::statement::Statement::Expression { value, .. }] => {
+                value.eval_with_context_mut(&mut evalexpr_context)?
+            }
+            statements => {
+                execute_statements(statements, &mut evalexpr_context)?.unwrap_or(Value::Empty)
+            }
+        };
 
         for (key, context) in context {
             match context.reference {
@@ -204,9 +302,15 @@ impl Function {
         }
 
         if !self.return_kind.is_void() {
+            // The return kind of a generic function is the one inferred at the call
+            let return_kind = if self.return_kind.kind.has_type_parameters() {
+                &kind.kind
+            } else {
+                &self.return_kind.kind
+            };
             return Ok(Some(ftd::interpreter::Value::from_evalexpr_value(
                 eval,
-                &self.return_kind.kind,
+                return_kind,
                 doc.name,
                 line_number,
             )?));
@@ -225,6 +329,373 @@ impl Function {
     }
 }
 
+/// Runs the statements of a function body, returns the value of the `return` reached, if any.
+fn execute_statements(
+    statements: &[fastn_grammar::statement::Statement],
+    context: &mut fastn_grammar::evalexpr::HashMapContext,
+) -> fastn_grammar::evalexpr::EvalexprResult<Option<fastn_grammar::evalexpr::Value>> {
+    use fastn_grammar::evalexpr::ContextWithMutableVariables;
+    use fastn_grammar::statement::Statement;
+
+    for statement in statements {
+        match statement {
+            Statement::Let { name, value, .. } => {
+                let value = value.eval_with_context_mut(context)?;
+                context.set_value(name.to_string(), value)?;
+            }
+            Statement::Expression { value, .. } => {
+                value.eval_with_context_mut(context)?;
+            }
+            Statement::Return { value, .. } => {
+                return Ok(Some(match value {
+                    Some(value) => value.eval_with_context_mut(context)?,
+                    None => fastn_grammar::evalexpr::Value::Empty,
+                }));
+            }
+            Statement::If {
+                condition,
+                then,
+                otherwise,
+                ..
+            } => {
+                let statements = if condition.eval_boolean_with_context_mut(context)? {
+                    then
+                } else {
+                    otherwise
+                };
+                if let Some(value) = execute_statements(statements, context)? {
+                    return Ok(Some(value));
+                }
+            }
+            Statement::For {
+                name, list, body, ..
+            } => {
+                let items = match list.eval_with_context_mut(context)? {
+                    fastn_grammar::evalexpr::Value::Tuple(items) => items,
+                    fastn_grammar::evalexpr::Value::Empty => vec![],
+                    item => vec![item],
+                };
+                for item in items {
+                    context.set_value(name.to_string(), item)?;
+                    if let Some(value) = execute_statements(body, context)? {
+                        return Ok(Some(value));
+                    }
+                }
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Kinds written in `let name: kind = ..`
+fn local_kind_declarations(statements: &[fastn_grammar::statement::Statement]) -> Vec<String> {
+    use fastn_grammar::statement::Statement;
+
+    let mut kinds = vec![];
+    for statement in statements {
+        match statement {
+            Statement::Let {
+                kind: Some(kind), ..
+            } => kinds.push(kind.to_string()),
+            Statement::For { body, .. } => kinds.extend(local_kind_declarations(body)),
+            Statement::If {
+                then, otherwise, ..
+            } => {
+                kinds.extend(local_kind_declarations(then));
+                kinds.extend(local_kind_declarations(otherwise));
+            }
+            Statement::Let { .. } | Statement::Return { .. } | Statement::Expression { .. } => {}
+        }
+    }
+    kinds
+}
+
+fn kind_name(kind: &ftd::interpreter::Kind) -> String {
+    match kind {
+        ftd::interpreter::Kind::List { kind } => format!("{} list", kind_name(kind)),
+        ftd::interpreter::Kind::Optional { kind } => format!("optional {}", kind_name(kind)),
+        kind => kind.get_name(),
+    }
+}
+
+/// Checks the statements of a function body against the declared kinds of the arguments,
+/// the local variables and the return value.
+///
+/// Kinds of expressions are inferred only from literals, variables and operators, anything
+/// else (like function calls) is taken to be of the expected kind.
+struct StatementChecker<'a> {
+    function_name: &'a str,
+    return_kind: &'a ftd::interpreter::Kind,
+    local_kinds: &'a ftd::Map<ftd::interpreter::Kind>,
+    doc_id: &'a str,
+    /// The line of the first statement, errors are reported at the line of their statement
+    body_line_number: usize,
+}
+
+impl<'a> StatementChecker<'a> {
+    fn check(
+        &self,
+        statements: &[fastn_grammar::statement::Statement],
+        scope: &mut ftd::Map<ftd::interpreter::Kind>,
+    ) -> ftd::interpreter::Result<()> {
+        use fastn_grammar::statement::Statement;
+
+        for statement in statements {
+            let line_number = self.body_line_number + statement.line_number();
+            match statement {
+                Statement::Let {
+                    name, kind, value, ..
+                } => {
+                    if scope.contains_key(name) {
+                        return ftd::interpreter::utils::e2(
+                            format!(
+                                "`{}` is already defined in function `{}`",
+                                name, self.function_name
+                            ),
+                            self.doc_id,
+                            line_number,
+                        );
+                    }
+                    let value_kind = self.expression_kind(value, scope);
+                    let kind = match (kind, value_kind) {
+                        (Some(kind), value_kind) => {
+                            let kind = self.local_kinds.get(kind).unwrap().clone();
+                            if let Some(value_kind) = value_kind {
+                                self.expect(&kind, &value_kind, name, line_number)?;
+                            }
+                            kind
+                        }
+                        (None, Some(value_kind)) => value_kind,
+                        (None, None) => {
+                            return ftd::interpreter::utils::e2(
+                                format!(
+                                "Cannot infer the kind of `{}`, declare it: `let {}: <kind> = ..`",
+                                name, name
+                            ),
+                                self.doc_id,
+                                line_number,
+                            )
+                        }
+                    };
+                    scope.insert(name.to_string(), kind);
+                }
+                Statement::Expression { value, .. } => {
+                    self.check_assignment(value, scope, line_number)?;
+                }
+                Statement::Return { value, .. } => match value {
+                    Some(_) if self.return_kind.is_void() => {
+                        return ftd::interpreter::utils::e2(
+                            format!("Function `{}` does not return a value", self.function_name),
+                            self.doc_id,
+                            line_number,
+                        )
+                    }
+                    Some(value) => {
+                        if let Some(value_kind) = self.expression_kind(value, scope) {
+                            self.expect(self.return_kind, &value_kind, "return", line_number)?;
+                        }
+                    }
+                    None if !self.return_kind.is_void() => {
+                        return ftd::interpreter::utils::e2(
+                            format!(
+                                "Function `{}` must return `{}`",
+                                self.function_name,
+                                kind_name(self.return_kind)
+                            ),
+                            self.doc_id,
+                            line_number,
+                        )
+                    }
+                    None => {}
+                },
+                Statement::If {
+                    condition,
+                    then,
+                    otherwise,
+                    ..
+                } => {
+                    if let Some(kind) = self.expression_kind(condition, scope) {
+                        self.expect(&ftd::interpreter::Kind::boolean(), &kind, "if", line_number)?;
+                    }
+                    self.check(then, &mut scope.clone())?;
+                    self.check(otherwise, &mut scope.clone())?;
+                }
+                Statement::For {
+                    name, list, body, ..
+                } => {
+                    let item_kind = match self.expression_kind(list, scope) {
+                        Some(ftd::interpreter::Kind::List { kind }) => kind.as_ref().clone(),
+                        Some(kind) => {
+                            return ftd::interpreter::utils::e2(
+                                format!(
+                                    "Expected a list to loop over, found `{}`",
+                                    kind_name(&kind)
+                                ),
+                                self.doc_id,
+                                line_number,
+                            )
+                        }
+                        None => {
+                            return ftd::interpreter::utils::e2(
+                                "Cannot infer the kind of the list to loop over, use a list \
+                                argument or variable",
+                                self.doc_id,
+                                line_number,
+                            )
+                        }
+                    };
+                    let mut scope = scope.clone();
+                    if scope.insert(name.to_string(), item_kind).is_some() {
+                        return ftd::interpreter::utils::e2(
+                            format!(
+                                "`{}` is already defined in function `{}`",
+                                name, self.function_name
+                            ),
+                            self.doc_id,
+                            line_number,
+                        );
+                    }
+                    self.check(body, &mut scope)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn check_assignment(
+        &self,
+        node: &fastn_grammar::evalexpr::ExprNode,
+        scope: &ftd::Map<ftd::interpreter::Kind>,
+        line_number: usize,
+    ) -> ftd::interpreter::Result<()> {
+        use fastn_grammar::evalexpr::Operator;
+
+        match node.operator() {
+            Operator::RootNode | Operator::Chain => {
+                for child in node.children() {
+                    self.check_assignment(child, scope, line_number)?;
+                }
+            }
+            Operator::Assign => {
+                let (target, value) = match node.children() {
+                    [target, value] => (target, value),
+                    _ => return Ok(()),
+                };
+                let name = match target.operator() {
+                    Operator::VariableIdentifierWrite { identifier } => identifier,
+                    _ => return Ok(()),
+                };
+                let kind = match scope.get(name) {
+                    Some(kind) => kind,
+                    None => {
+                        return ftd::interpreter::utils::e2(
+                            format!(
+                            "`{}` is not defined in function `{}`, declare it with `let {} = ..`",
+                            name, self.function_name, name
+                        ),
+                            self.doc_id,
+                            line_number,
+                        )
+                    }
+                };
+                if let Some(value_kind) = self.expression_kind(value, scope) {
+                    self.expect(kind, &value_kind, name, line_number)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn expect(
+        &self,
+        expected: &ftd::interpreter::Kind,
+        found: &ftd::interpreter::Kind,
+        name: &str,
+        line_number: usize,
+    ) -> ftd::interpreter::Result<()> {
+        if expected.is_same_as(found) {
+            return Ok(());
+        }
+        ftd::interpreter::utils::e2(
+            format!(
+                "Expected `{}` for `{}` in function `{}`, found `{}`",
+                kind_name(expected),
+                name,
+                self.function_name,
+                kind_name(found)
+            ),
+            self.doc_id,
+            line_number,
+        )
+    }
+
+    fn expression_kind(
+        &self,
+        node: &fastn_grammar::evalexpr::ExprNode,
+        scope: &ftd::Map<ftd::interpreter::Kind>,
+    ) -> Option<ftd::interpreter::Kind> {
+        use fastn_grammar::evalexpr::Operator;
+
+        let child = |index: usize| {
+            node.children()
+                .get(index)
+                .and_then(|v| self.expression_kind(v, scope))
+        };
+        match node.operator() {
+            Operator::RootNode if node.children().len() == 1 => child(0),
+            Operator::Chain => node
+                .children()
+                .last()
+                .and_then(|v| self.expression_kind(v, scope)),
+            Operator::Const { value } => match value {
+                fastn_grammar::evalexpr::Value::String(_) => Some(ftd::interpreter::Kind::string()),
+                fastn_grammar::evalexpr::Value::Int(_) => Some(ftd::interpreter::Kind::integer()),
+                fastn_grammar::evalexpr::Value::Float(_) => Some(ftd::interpreter::Kind::decimal()),
+                fastn_grammar::evalexpr::Value::Boolean(_) => {
+                    Some(ftd::interpreter::Kind::boolean())
+                }
+                _ => None,
+            },
+            Operator::VariableIdentifierRead { identifier } => {
+                scope.get(identifier).map(|v| v.clone().inner())
+            }
+            Operator::Neg => child(0),
+            Operator::Add
+            | Operator::Sub
+            | Operator::Mul
+            | Operator::Div
+            | Operator::Mod
+            | Operator::Exp => match (child(0)?, child(1)?) {
+                (l, r)
+                    if matches!(node.operator(), Operator::Add)
+                        && l.is_string()
+                        && r.is_string() =>
+                {
+                    Some(ftd::interpreter::Kind::string())
+                }
+                (l, r) if l.is_decimal() || r.is_decimal() => {
+                    Some(ftd::interpreter::Kind::decimal())
+                }
+                (l, r) if l.is_integer() && r.is_integer() => {
+                    Some(ftd::interpreter::Kind::integer())
+                }
+                _ => None,
+            },
+            Operator::Eq
+            | Operator::Neq
+            | Operator::Gt
+            | Operator::Lt
+            | Operator::Geq
+            | Operator::Leq
+            | Operator::And
+            | Operator::Or
+            | Operator::Not => Some(ftd::interpreter::Kind::boolean()),
+            _ => None,
+        }
+    }
+}
+
 /*
 Todo: Convert Expression into
     #[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -459,12 +930,50 @@ impl FunctionCall {
             order.push(argument.name.to_string());
         }
 
+        let mut return_kind = function.return_kind.clone();
+        if !function.type_parameters.is_empty() {
+            let argument_and_value_kinds = function
+                .arguments
+                .iter()
+                .filter_map(|argument| {
+                    values
+                        .get(argument.name.as_str())
+                        .filter(|value| !value.is_type_parameter_text(&argument.kind.kind))
+                        .map(|value| (argument, value.kind()))
+                })
+                .collect::<Vec<_>>();
+            let mut bindings = ftd::interpreter::utils::infer_type_parameters(
+                resolved_function_name.as_str(),
+                argument_and_value_kinds.as_slice(),
+                doc.name,
+                line_number,
+            )?;
+            for argument in function.arguments.iter() {
+                let name = match argument.kind.kind.ref_inner() {
+                    ftd::interpreter::Kind::TypeParameter { name } => name,
+                    _ => continue,
+                };
+                if let Some(value) = values.remove(argument.name.as_str()) {
+                    let value = if value.is_type_parameter_text(&argument.kind.kind) {
+                        let kind = bindings
+                            .entry(name.to_string())
+                            .or_insert(ftd::interpreter::Kind::string());
+                        try_ok_state!(value.bind_type_parameter_text(kind, doc)?)
+                    } else {
+                        value
+                    };
+                    values.insert(argument.name.to_string(), value);
+                }
+            }
+            return_kind.kind = return_kind.kind.substitute_type_parameters(&bindings);
+        }
+
         let reference_full_name = source.get_reference_name(function_name.as_str(), doc);
 
         Ok(ftd::interpreter::StateWithThing::new_thing(
             ftd::interpreter::FunctionCall::new(
                 reference_full_name.as_str(),
-                function.return_kind.clone(),
+                return_kind,
                 mutable,
                 line_number,
                 values,
@@ -5,9 +5,15 @@ pub enum Kind {
     Integer,
     Decimal,
     Boolean,
+    DateTime,
+    Date,
+    Duration,
     Record {
-        name: String,
-    }, // the full name of the record (full document name.record name)
+        name: String, // the full name of the record (full document name.record name)
+        /// `integer` in `pair<integer>`, empty for a record without type parameters
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        type_arguments: Vec<Kind>,
+    },
     OrType {
         name: String,
         variant: Option<String>,
@@ -16,6 +22,10 @@ pub enum Kind {
     List {
         kind: Box<Kind>,
     },
+    /// Keys are strings, `kind` is the kind of the values
+    Map {
+        kind: Box<Kind>,
+    },
     Optional {
         kind: Box<Kind>,
     },
@@ -27,6 +37,11 @@ pub enum Kind {
     Constant {
         kind: Box<Kind>,
     },
+    /// `T` in `-- component data-table<T>:`, the kind is only known at the invocation site so
+    /// any kind is accepted for it
+    TypeParameter {
+        name: String,
+    },
     Void,
     Module,
 }
@@ -38,15 +53,20 @@ impl Kind {
             Kind::Integer { .. } => "integer".to_string(),
             Kind::Boolean { .. } => "boolean".to_string(),
             Kind::Decimal { .. } => "decimal".to_string(),
+            Kind::DateTime => ftd::interpreter::datetime::DATETIME.to_string(),
+            Kind::Date => ftd::interpreter::datetime::DATE.to_string(),
+            Kind::Duration => ftd::interpreter::datetime::DURATION.to_string(),
             Kind::Constant { .. } => "constant".to_string(),
             Kind::List { .. } => "list".to_string(),
+            Kind::Map { .. } => "map".to_string(),
             Kind::Object { .. } => "object".to_string(),
             Kind::OrType { name, .. } => name.clone(),
             Kind::Optional { .. } => "optional".to_string(),
             Kind::Void { .. } => "void".to_string(),
             Kind::Module => "module".to_string(),
             Kind::UI { name, .. } => name.clone().unwrap_or("record".to_string()),
-            Kind::Record { name } => name.clone(),
+            Kind::Record { name, .. } => name.clone(),
+            Kind::TypeParameter { name } => name.clone(),
         }
     }
 
@@ -58,9 +78,28 @@ impl Kind {
         match (self, other) {
             (Self::UI { .. }, Self::UI { .. }) => true,
             (Self::OrType { name: n1, .. }, Self::OrType { name: n2, .. }) => n1.eq(n2),
+            (Self::TypeParameter { .. }, _) | (_, Self::TypeParameter { .. }) => true,
             (Self::Optional { kind, .. }, _) => kind.is_same_as(other),
             (_, Self::Optional { kind: other, .. }) => self.is_same_as(other),
             (Self::List { kind: k1 }, Self::List { kind: k2 }) => k1.is_same_as(k2),
+            (Self::Map { kind: k1 }, Self::Map { kind: k2 }) => k1.is_same_as(k2),
+            // `pair` without type arguments is any `pair`
+            (
+                Self::Record {
+                    name: n1,
+                    type_arguments: a1,
+                },
+                Self::Record {
+                    name: n2,
+                    type_arguments: a2,
+                },
+            ) => {
+                n1.eq(n2)
+                    && (a1.is_empty()
+                        || a2.is_empty()
+                        || (a1.len() == a2.len()
+                            && a1.iter().zip(a2).all(|(k1, k2)| k1.is_same_as(k2))))
+            }
             _ => self.eq(other),
         }
     }
@@ -81,6 +120,18 @@ impl Kind {
         Kind::Boolean
     }
 
+    pub fn datetime() -> Kind {
+        Kind::DateTime
+    }
+
+    pub fn date() -> Kind {
+        Kind::Date
+    }
+
+    pub fn duration() -> Kind {
+        Kind::Duration
+    }
+
     pub fn module() -> Kind {
         Kind::Module
     }
@@ -128,6 +179,15 @@ impl Kind {
     pub fn record(name: &str) -> Kind {
         Kind::Record {
             name: name.to_string(),
+            type_arguments: vec![],
+        }
+    }
+
+    /// `pair<integer>`, the record `pair` with `integer` for its type parameter
+    pub fn record_with_type_arguments(name: &str, type_arguments: Vec<Kind>) -> Kind {
+        Kind::Record {
+            name: name.to_string(),
+            type_arguments,
         }
     }
 
@@ -147,12 +207,24 @@ impl Kind {
         }
     }
 
+    pub fn type_parameter(name: &str) -> Kind {
+        Kind::TypeParameter {
+            name: name.to_string(),
+        }
+    }
+
     pub fn into_list(self) -> Kind {
         Kind::List {
             kind: Box::new(self),
         }
     }
 
+    pub fn into_map(self) -> Kind {
+        Kind::Map {
+            kind: Box::new(self),
+        }
+    }
+
     pub fn into_optional(self) -> Kind {
         Kind::Optional {
             kind: Box::new(self),
@@ -198,6 +270,10 @@ impl Kind {
         matches!(self, Kind::List { .. })
     }
 
+    pub fn is_map(&self) -> bool {
+        matches!(self, Kind::Map { .. })
+    }
+
     pub fn is_subsection_ui(&self) -> bool {
         matches!(
             self,
@@ -293,10 +369,101 @@ impl Kind {
         matches!(self, Kind::Decimal { .. })
     }
 
+    pub fn is_datetime(&self) -> bool {
+        matches!(self, Kind::DateTime)
+    }
+
+    pub fn is_date(&self) -> bool {
+        matches!(self, Kind::Date)
+    }
+
+    pub fn is_duration(&self) -> bool {
+        matches!(self, Kind::Duration)
+    }
+
     pub fn is_void(&self) -> bool {
         matches!(self, Kind::Void { .. })
     }
 
+    pub fn is_type_parameter(&self) -> bool {
+        matches!(self, Kind::TypeParameter { .. })
+    }
+
+    pub fn has_type_parameters(&self) -> bool {
+        match self {
+            Kind::TypeParameter { .. } => true,
+            Kind::List { kind }
+            | Kind::Map { kind }
+            | Kind::Optional { kind }
+            | Kind::Constant { kind } => kind.has_type_parameters(),
+            Kind::Record { type_arguments, .. } => {
+                type_arguments.iter().any(|v| v.has_type_parameters())
+            }
+            _ => false,
+        }
+    }
+
+    /// The kinds the type parameters in `self` take when `self` is given a value of `kind`,
+    /// `T list` given `integer list` binds `T` to `integer`
+    pub(crate) fn type_parameter_bindings(&self, kind: &Kind) -> Vec<(String, Kind)> {
+        match (self, kind) {
+            (_, Kind::TypeParameter { .. }) => vec![],
+            (Kind::TypeParameter { name }, Kind::Constant { kind }) => {
+                vec![(name.to_string(), kind.as_ref().clone())]
+            }
+            (Kind::TypeParameter { name }, kind) => vec![(name.to_string(), kind.clone())],
+            (Kind::Optional { kind: k1 }, Kind::Optional { kind: k2 })
+            | (Kind::List { kind: k1 }, Kind::List { kind: k2 })
+            | (Kind::Map { kind: k1 }, Kind::Map { kind: k2 }) => k1.type_parameter_bindings(k2),
+            (Kind::Optional { kind: k1 }, k2) | (Kind::Constant { kind: k1 }, k2) => {
+                k1.type_parameter_bindings(k2)
+            }
+            (k1, Kind::Optional { kind: k2 }) | (k1, Kind::Constant { kind: k2 }) => {
+                k1.type_parameter_bindings(k2)
+            }
+            // `pair<T>` given `pair<integer>` binds `T` to `integer`
+            (
+                Kind::Record {
+                    name: n1,
+                    type_arguments: a1,
+                },
+                Kind::Record {
+                    name: n2,
+                    type_arguments: a2,
+                },
+            ) if n1.eq(n2) => a1
+                .iter()
+                .zip(a2)
+                .flat_map(|(k1, k2)| k1.type_parameter_bindings(k2))
+                .collect(),
+            _ => vec![],
+        }
+    }
+
+    /// Replaces the bound type parameters in `self`, unbound ones are left as they are
+    pub(crate) fn substitute_type_parameters(&self, bindings: &ftd::Map<Kind>) -> Kind {
+        match self {
+            Kind::TypeParameter { name } => bindings.get(name).cloned().unwrap_or(self.clone()),
+            Kind::List { kind } => kind.substitute_type_parameters(bindings).into_list(),
+            Kind::Map { kind } => kind.substitute_type_parameters(bindings).into_map(),
+            Kind::Optional { kind } => kind.substitute_type_parameters(bindings).into_optional(),
+            Kind::Constant { kind } => Kind::Constant {
+                kind: Box::new(kind.substitute_type_parameters(bindings)),
+            },
+            Kind::Record {
+                name,
+                type_arguments,
+            } => Kind::record_with_type_arguments(
+                name,
+                type_arguments
+                    .iter()
+                    .map(|v| v.substitute_type_parameters(bindings))
+                    .collect(),
+            ),
+            t => t.clone(),
+        }
+    }
+
     pub(crate) fn list_type(
         &self,
         doc_name: &str,
@@ -377,6 +544,7 @@ impl KindData {
         match modifier {
             ftd::ast::VariableModifier::Optional => self.optional(),
             ftd::ast::VariableModifier::List => self.list(),
+            ftd::ast::VariableModifier::Map => self.map(),
             ftd::ast::VariableModifier::Constant => self.constant(),
         }
     }
@@ -391,8 +559,25 @@ impl KindData {
         match ast_kind.as_ref() {
             "string" | "object" | "integer" | "decimal" | "boolean" | "void" | "ftd.ui"
             | "children" => Ok(()),
+            ftd::interpreter::datetime::DATETIME
+            | ftd::interpreter::datetime::DATE
+            | ftd::interpreter::datetime::DURATION => Ok(()),
             k if known_kinds.contains_key(k) => Ok(()),
-            k => doc.scan_thing(k, line_number),
+            k => match split_type_arguments(k) {
+                Some((name, type_arguments)) => {
+                    doc.scan_thing(name, line_number)?;
+                    for type_argument in type_arguments {
+                        KindData::scan_ast_kind(
+                            ftd::ast::VariableKind::get_kind(type_argument, doc.name, line_number)?,
+                            known_kinds,
+                            doc,
+                            line_number,
+                        )?;
+                    }
+                    Ok(())
+                }
+                None => doc.scan_thing(k, line_number),
+            },
         }
     }
 
@@ -433,6 +618,9 @@ impl KindData {
             "integer" => Kind::integer(),
             "decimal" => Kind::decimal(),
             "boolean" => Kind::boolean(),
+            ftd::interpreter::datetime::DATETIME => Kind::datetime(),
+            ftd::interpreter::datetime::DATE => Kind::date(),
+            ftd::interpreter::datetime::DURATION => Kind::duration(),
             "void" => Kind::void(),
             "ftd.ui" => Kind::ui(),
             "module" => Kind::module(),
@@ -449,6 +637,14 @@ impl KindData {
                 }
             }
             k if known_kinds.contains_key(k) => known_kinds.get(k).unwrap().to_owned(),
+            k if split_type_arguments(k).is_some() => {
+                try_ok_state!(KindData::from_type_arguments(
+                    k,
+                    known_kinds,
+                    doc,
+                    line_number
+                )?)
+            }
             k => match try_ok_state!(doc.search_thing(k, line_number)?) {
                 ftd::interpreter::Thing::Record(r) => Kind::record(r.name.as_str()),
                 ftd::interpreter::Thing::Component(_) => Kind::ui(),
@@ -484,6 +680,46 @@ impl KindData {
         Ok(ftd::interpreter::StateWithThing::new_thing(kind_data))
     }
 
+    /// `pair<string, integer>` is checked against the type parameters of `pair`, the kind is the
+    /// record `pair` with `string` and `integer` for its type arguments
+    fn from_type_arguments(
+        kind: &str,
+        known_kinds: &ftd::Map<ftd::interpreter::Kind>,
+        doc: &mut ftd::interpreter::TDoc,
+        line_number: usize,
+    ) -> ftd::interpreter::Result<ftd::interpreter::StateWithThing<Kind>> {
+        let (name, type_arguments) = split_type_arguments(kind).unwrap();
+        let record = try_ok_state!(doc.search_record(name, line_number)?);
+        if record.type_parameters.len() != type_arguments.len() {
+            return ftd::interpreter::utils::e2(
+                format!(
+                    "`{}` expects {} type arguments, found {} in `{}`",
+                    name,
+                    record.type_parameters.len(),
+                    type_arguments.len(),
+                    kind
+                ),
+                doc.name,
+                line_number,
+            );
+        }
+        let mut kinds = vec![];
+        for type_argument in type_arguments {
+            kinds.push(
+                try_ok_state!(KindData::from_ast_kind(
+                    ftd::ast::VariableKind::get_kind(type_argument, doc.name, line_number)?,
+                    known_kinds,
+                    doc,
+                    line_number,
+                )?)
+                .kind,
+            );
+        }
+        Ok(ftd::interpreter::StateWithThing::new_thing(
+            Kind::record_with_type_arguments(record.name.as_str(), kinds),
+        ))
+    }
+
     fn optional(self) -> KindData {
         KindData {
             kind: Kind::Optional {
@@ -504,6 +740,16 @@ impl KindData {
         }
     }
 
+    fn map(self) -> KindData {
+        KindData {
+            kind: Kind::Map {
+                kind: Box::new(self.kind),
+            },
+            caption: self.caption,
+            body: self.body,
+        }
+    }
+
     fn constant(self) -> KindData {
         KindData {
             kind: Kind::Constant {
@@ -518,6 +764,10 @@ impl KindData {
         self.kind.is_list()
     }
 
+    pub fn is_map(&self) -> bool {
+        self.kind.is_map()
+    }
+
     pub fn is_or_type(&self) -> bool {
         self.kind.is_or_type()
     }
@@ -582,6 +832,18 @@ impl KindData {
         }
     }
 
+    pub fn inner_map(self) -> KindData {
+        let kind = match self.kind {
+            Kind::Map { kind } => kind.as_ref().to_owned(),
+            t => t,
+        };
+        KindData {
+            kind,
+            caption: self.caption,
+            body: self.body,
+        }
+    }
+
     pub fn inner(self) -> KindData {
         let kind = match self.kind {
             Kind::Optional { kind } => kind.as_ref().to_owned(),
@@ -646,3 +908,24 @@ pub(crate) fn is_caption(s: &str) -> bool {
 pub fn is_body(s: &str) -> bool {
     s.eq("body")
 }
+
+/// Splits `pair<string, pair<integer, boolean>>` into `pair` and its type arguments
+fn split_type_arguments(kind: &str) -> Option<(&str, Vec<&str>)> {
+    let (name, type_arguments) = kind.split_once('<')?;
+    let type_arguments = type_arguments.strip_suffix('>')?;
+    let mut result = vec![];
+    let (mut depth, mut start) = (0, 0);
+    for (index, c) in type_arguments.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            ',' if depth == 0 => {
+                result.push(type_arguments[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    result.push(type_arguments[start..].trim());
+    Some((name.trim(), result))
+}
@@ -94,6 +94,15 @@ impl OrTypeVariant {
         }
     }
 
+    /// The variant name without the or-type prefix, i.e. `circle` for `shape.circle`.
+    pub fn short_name(&self) -> String {
+        let name = self.name();
+        match name.rsplit_once('.') {
+            Some((_, short_name)) => short_name.to_string(),
+            None => name,
+        }
+    }
+
     pub fn ok_constant(&self, doc_id: &str) -> ftd::interpreter::Result<&ftd::interpreter::Field> {
         match self {
             ftd::interpreter::OrTypeVariant::Constant(c) => Ok(c),
@@ -1,14 +1,22 @@
 #[derive(Debug, Default, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct Record {
     pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub type_parameters: Vec<String>,
     pub fields: Vec<Field>,
     pub line_number: usize,
 }
 
 impl Record {
-    fn new(name: &str, fields: Vec<Field>, line_number: usize) -> Record {
+    fn new(
+        name: &str,
+        type_parameters: Vec<String>,
+        fields: Vec<Field>,
+        line_number: usize,
+    ) -> Record {
         Record {
             name: name.to_string(),
+            type_parameters,
             fields,
             line_number,
         }
@@ -27,11 +35,12 @@ impl Record {
         doc: &mut ftd::interpreter::TDoc,
     ) -> ftd::interpreter::Result<()> {
         let name = doc.resolve_name(record.name.as_str());
-        let known_kinds = std::iter::IntoIterator::into_iter([(
+        let mut known_kinds =
+            ftd::interpreter::utils::type_parameters_known_kinds(&record.type_parameters);
+        known_kinds.insert(
             record.name.to_string(),
             ftd::interpreter::Kind::record(name.as_str()),
-        )])
-        .collect::<ftd::Map<ftd::interpreter::Kind>>();
+        );
         Field::scan_ast_fields(record.fields, doc, &known_kinds)
     }
 
@@ -48,13 +57,12 @@ impl Record {
         doc: &mut ftd::interpreter::TDoc,
     ) -> ftd::interpreter::Result<ftd::interpreter::StateWithThing<ftd::interpreter::Record>> {
         let name = doc.resolve_name(record.name.as_str());
-        let known_kinds = std::iter::IntoIterator::into_iter([(
+        let mut known_kinds =
+            ftd::interpreter::utils::type_parameters_known_kinds(&record.type_parameters);
+        known_kinds.insert(
             record.name.to_string(),
-            ftd::interpreter::Kind::Record {
-                name: name.to_string(),
-            },
-        )])
-        .collect::<ftd::Map<ftd::interpreter::Kind>>();
+            ftd::interpreter::Kind::record(name.as_str()),
+        );
         let fields = try_ok_state!(Field::from_ast_fields(
             record.name.as_str(),
             record.fields,
@@ -64,11 +72,33 @@ impl Record {
         validate_record_fields(name.as_str(), &fields, doc.name)?;
         Ok(ftd::interpreter::StateWithThing::new_thing(Record::new(
             name.as_str(),
+            record.type_parameters,
             fields,
             record.line_number,
         )))
     }
 
+    /// The record with `type_arguments` in place of its type parameters, the fields of
+    /// `pair<integer>` are `integer` where the fields of `pair<T>` are `T`
+    pub(crate) fn with_type_arguments(
+        mut self,
+        type_arguments: &[ftd::interpreter::Kind],
+    ) -> Record {
+        if type_arguments.is_empty() {
+            return self;
+        }
+        let bindings = self
+            .type_parameters
+            .iter()
+            .cloned()
+            .zip(type_arguments.iter().cloned())
+            .collect();
+        for field in self.fields.iter_mut() {
+            field.kind.kind = field.kind.kind.substitute_type_parameters(&bindings);
+        }
+        self
+    }
+
     pub(crate) fn get_field(
         &self,
         name: &str,
@@ -634,7 +634,8 @@ impl PropertyValue {
                     loop_object_name_and_kind,
                 )?;
             }
-            ftd::ast::VariableValue::List { value, .. } => {
+            ftd::ast::VariableValue::List { value, .. }
+            | ftd::ast::VariableValue::Map { value, .. } => {
                 for val in value {
                     PropertyValue::scan_ast_value_with_argument(
                         val.value,
@@ -784,7 +785,9 @@ impl PropertyValue {
                         loop_object_name_and_kind,
                     )?
                 }
-                ftd::interpreter::Kind::String => {
+                // The kind of `T` is only known once the invocation is checked, till then the
+                // text is kept as it is
+                ftd::interpreter::Kind::String | ftd::interpreter::Kind::TypeParameter { .. } => {
                     ftd::interpreter::StateWithThing::new_thing(PropertyValue::Value {
                         value: Value::String {
                             text: value.string(doc.name)?,
@@ -820,10 +823,37 @@ impl PropertyValue {
                         line_number: value.line_number(),
                     })
                 }
+                ftd::interpreter::Kind::DateTime
+                | ftd::interpreter::Kind::Date
+                | ftd::interpreter::Kind::Duration => {
+                    let line_number = value.line_number();
+                    let text = value.string(doc.name)?;
+                    match Value::from_iso_string(text.as_str(), &expected_kind.kind) {
+                        Some(value) => {
+                            ftd::interpreter::StateWithThing::new_thing(PropertyValue::Value {
+                                value,
+                                is_mutable,
+                                line_number,
+                            })
+                        }
+                        None => {
+                            return ftd::interpreter::utils::e2(
+                                format!(
+                                    "Expected ISO-8601 {}, found: `{}`",
+                                    expected_kind.kind.get_name(),
+                                    text
+                                ),
+                                doc.name,
+                                line_number,
+                            )
+                        }
+                    }
+                }
                 ftd::interpreter::Kind::List { kind } => {
                     let line_number = value.line_number();
                     let value_list = value.into_list(doc.name, kind)?;
                     let mut values = vec![];
+                    let mut list_kind = expected_kind.clone().inner_list();
                     for (key, value) in value_list {
                         if !try_ok_state!(ftd::interpreter::utils::kind_eq(
                             key.as_str(),
@@ -837,6 +867,37 @@ impl PropertyValue {
                                 value.line_number(),
                             );
                         }
+                        // `-- integer: 1` in a `T list` is an integer
+                        let kind = if kind.is_type_parameter() {
+                            let var_kind = ftd::ast::VariableKind::get_kind(
+                                key.as_str(),
+                                doc.name,
+                                value.line_number(),
+                            )?;
+                            let kind = try_ok_state!(ftd::interpreter::KindData::from_ast_kind(
+                                var_kind,
+                                &Default::default(),
+                                doc,
+                                value.line_number(),
+                            )?)
+                            .kind;
+                            if values.is_empty() {
+                                list_kind.kind = kind.clone();
+                            } else if !list_kind.kind.is_same_as(&kind) {
+                                return ftd::interpreter::utils::e2(
+                                    format!(
+                                        "Expected list of `{}`, found: `{}`",
+                                        list_kind.kind.get_name(),
+                                        key
+                                    ),
+                                    doc.name,
+                                    value.line_number(),
+                                );
+                            }
+                            Box::new(kind)
+                        } else {
+                            kind.clone()
+                        };
                         values.push(if kind.is_ui() {
                             try_ok_state!(PropertyValue::to_ui_value(
                                 &key,
@@ -861,16 +922,45 @@ impl PropertyValue {
                     ftd::interpreter::StateWithThing::new_thing(PropertyValue::Value {
                         value: ftd::interpreter::Value::List {
                             data: values,
-                            kind: expected_kind.clone().inner_list(),
+                            kind: list_kind,
                         },
                         is_mutable,
                         line_number,
                     })
                 }
-                ftd::interpreter::Kind::Record { name }
-                    if value.is_record() || value.is_string() =>
-                {
-                    let record = try_ok_state!(doc.search_record(name, value.line_number())?);
+                ftd::interpreter::Kind::Map { kind } if value.is_map() => {
+                    let line_number = value.line_number();
+                    let mut data = ftd::Map::new();
+                    for (key, value) in value.into_map(doc.name)? {
+                        data.insert(
+                            key,
+                            try_ok_state!(PropertyValue::from_ast_value(
+                                value,
+                                doc,
+                                is_mutable,
+                                Some(&ftd::interpreter::KindData {
+                                    kind: kind.as_ref().clone(),
+                                    caption: expected_kind.caption,
+                                    body: expected_kind.body,
+                                }),
+                            )?),
+                        );
+                    }
+                    ftd::interpreter::StateWithThing::new_thing(PropertyValue::Value {
+                        value: ftd::interpreter::Value::Map {
+                            data,
+                            kind: expected_kind.clone().inner_map(),
+                        },
+                        is_mutable,
+                        line_number,
+                    })
+                }
+                ftd::interpreter::Kind::Record {
+                    name,
+                    type_arguments,
+                } if value.is_record() || value.is_string() => {
+                    let record = try_ok_state!(doc.search_record(name, value.line_number())?)
+                        .with_type_arguments(type_arguments);
                     ftd::interpreter::PropertyValue::from_record(
                         &record,
                         value,
@@ -1300,9 +1390,12 @@ impl PropertyValue {
                         )?
                         .map(Some));
                     }
+                    // `$numbers` for a `T list` is the whole list, not its first item
                     Some(ekind)
                         if ekind.kind.is_list()
-                            && ekind.kind.ref_inner_list().is_same_as(&found_kind.kind) =>
+                            && ekind.kind.ref_inner_list().is_same_as(&found_kind.kind)
+                            && !(ekind.kind.ref_inner_list().is_type_parameter()
+                                && found_kind.kind.is_list()) =>
                     {
                         return Ok(ftd::interpreter::StateWithThing::new_thing(None));
                     }
@@ -1385,6 +1478,52 @@ impl PropertyValue {
         }
     }
 
+    /// Text passed where a bare `T` is expected, its kind is only known once `T` is inferred
+    pub(crate) fn is_type_parameter_text(&self, expected_kind: &ftd::interpreter::Kind) -> bool {
+        expected_kind.ref_inner().is_type_parameter()
+            && matches!(
+                self,
+                PropertyValue::Value {
+                    value: Value::String { .. },
+                    ..
+                }
+            )
+    }
+
+    /// Reads the text passed for a type parameter as the kind it is bound to
+    pub(crate) fn bind_type_parameter_text(
+        self,
+        kind: &ftd::interpreter::Kind,
+        doc: &mut ftd::interpreter::TDoc,
+    ) -> ftd::interpreter::Result<ftd::interpreter::StateWithThing<PropertyValue>> {
+        match self {
+            PropertyValue::Value {
+                value: Value::String { text },
+                is_mutable,
+                line_number,
+            } if !kind.is_string() => match PropertyValue::from_ast_value(
+                ftd::ast::VariableValue::String {
+                    value: text.to_string(),
+                    line_number,
+                    source: ftd::ast::ValueSource::Default,
+                },
+                doc,
+                is_mutable,
+                Some(&kind.clone().into_kind_data()),
+            ) {
+                Err(ftd::interpreter::Error::ParseIntError(_))
+                | Err(ftd::interpreter::Error::ParseFloatError(_))
+                | Err(ftd::interpreter::Error::ParseBoolError(_)) => ftd::interpreter::utils::e2(
+                    format!("Expected `{}`, found: `{}`", kind.get_name(), text),
+                    doc.name,
+                    line_number,
+                ),
+                t => t,
+            },
+            t => Ok(ftd::interpreter::StateWithThing::new_thing(t)),
+        }
+    }
+
     pub(crate) fn new_none(
         kind: ftd::interpreter::KindData,
         line_number: usize,
@@ -1452,6 +1591,18 @@ pub enum Value {
     Boolean {
         value: bool,
     },
+    /// Seconds since the unix epoch, see `ftd::interpreter::datetime`
+    DateTime {
+        value: i64,
+    },
+    /// Days since the unix epoch
+    Date {
+        value: i64,
+    },
+    /// Seconds
+    Duration {
+        value: i64,
+    },
     Object {
         values: ftd::Map<PropertyValue>,
     },
@@ -1469,6 +1620,10 @@ pub enum Value {
         data: Vec<PropertyValue>,
         kind: ftd::interpreter::KindData,
     },
+    Map {
+        data: ftd::Map<PropertyValue>,
+        kind: ftd::interpreter::KindData,
+    },
     Optional {
         data: Box<Option<Value>>,
         kind: ftd::interpreter::KindData,
@@ -1503,12 +1658,11 @@ impl Value {
     }
 
     pub fn is_empty(&self) -> bool {
-        if let Self::List { data, .. } = self {
-            if data.is_empty() {
-                return true;
-            }
+        match self {
+            Self::List { data, .. } => data.is_empty(),
+            Self::Map { data, .. } => data.is_empty(),
+            _ => false,
         }
-        false
     }
 
     pub fn is_record(&self, rec_name: &str) -> bool {
@@ -1593,7 +1747,8 @@ impl Value {
                 is_static
             }
             ftd::interpreter::Value::Record { fields, .. }
-            | ftd::interpreter::Value::Object { values: fields, .. } => {
+            | ftd::interpreter::Value::Object { values: fields, .. }
+            | ftd::interpreter::Value::Map { data: fields, .. } => {
                 let mut is_static = true;
                 for d in fields.values() {
                     if !d.is_static(doc) {
@@ -1613,9 +1768,13 @@ impl Value {
             Value::Integer { .. } => ftd::interpreter::Kind::integer(),
             Value::Decimal { .. } => ftd::interpreter::Kind::decimal(),
             Value::Boolean { .. } => ftd::interpreter::Kind::boolean(),
+            Value::DateTime { .. } => ftd::interpreter::Kind::datetime(),
+            Value::Date { .. } => ftd::interpreter::Kind::date(),
+            Value::Duration { .. } => ftd::interpreter::Kind::duration(),
             Value::Object { .. } => ftd::interpreter::Kind::object(),
             Value::Record { name, .. } => ftd::interpreter::Kind::record(name),
             Value::List { kind, .. } => kind.kind.clone().into_list(),
+            Value::Map { kind, .. } => kind.kind.clone().into_map(),
             Value::Optional { kind, .. } => ftd::interpreter::Kind::Optional {
                 kind: Box::new(kind.kind.clone()),
             },
@@ -1640,6 +1799,10 @@ impl Value {
             Value::Integer { value } => fastn_grammar::evalexpr::Value::Int(*value),
             Value::Decimal { value } => fastn_grammar::evalexpr::Value::Float(*value),
             Value::Boolean { value } => fastn_grammar::evalexpr::Value::Boolean(*value),
+            Value::Duration { value } => fastn_grammar::evalexpr::Value::Int(*value),
+            Value::DateTime { .. } | Value::Date { .. } => {
+                fastn_grammar::evalexpr::Value::String(self.to_iso_string().unwrap())
+            }
             Value::List { data, .. } => {
                 let mut values = vec![];
                 for value in data {
@@ -1681,6 +1844,23 @@ impl Value {
             fastn_grammar::evalexpr::Value::Boolean(value) if expected_kind.is_boolean() => {
                 Value::Boolean { value }
             }
+            fastn_grammar::evalexpr::Value::Int(value) if expected_kind.is_duration() => {
+                Value::Duration { value }
+            }
+            fastn_grammar::evalexpr::Value::String(ref text)
+                if expected_kind.is_datetime() || expected_kind.is_date() =>
+            {
+                match Value::from_iso_string(text.as_str(), expected_kind) {
+                    Some(value) => value,
+                    None => {
+                        return ftd::interpreter::utils::e2(
+                            format!("Expected kind: `{:?}`, found: `{:?}`", expected_kind, value),
+                            doc_name,
+                            line_number,
+                        )
+                    }
+                }
+            }
             fastn_grammar::evalexpr::Value::Tuple(data) if expected_kind.is_list() => {
                 let mut values = vec![];
                 let val_kind = expected_kind.list_type(doc_name, line_number)?;
@@ -1696,6 +1876,23 @@ impl Value {
                     kind: ftd::interpreter::KindData::new(val_kind),
                 }
             }
+            // `T` wasn't bound at the call, the kind comes from the value itself
+            value if expected_kind.is_type_parameter() => {
+                let kind = match value {
+                    fastn_grammar::evalexpr::Value::String(_) => ftd::interpreter::Kind::string(),
+                    fastn_grammar::evalexpr::Value::Float(_) => ftd::interpreter::Kind::decimal(),
+                    fastn_grammar::evalexpr::Value::Int(_) => ftd::interpreter::Kind::integer(),
+                    fastn_grammar::evalexpr::Value::Boolean(_) => ftd::interpreter::Kind::boolean(),
+                    t => {
+                        return ftd::interpreter::utils::e2(
+                            format!("Expected kind: `{:?}`, found: `{:?}`", expected_kind, t),
+                            doc_name,
+                            line_number,
+                        )
+                    }
+                };
+                Value::from_evalexpr_value(value, &kind, doc_name, line_number)?
+            }
             fastn_grammar::evalexpr::Value::Empty if expected_kind.is_optional() => {
                 Value::Optional {
                     data: Box::new(None),
@@ -1712,6 +1909,34 @@ impl Value {
         })
     }
 
+    /// Parses an ISO-8601 string into a value of `kind`, which is `datetime`, `date` or
+    /// `duration`.
+    pub fn from_iso_string(text: &str, kind: &ftd::interpreter::Kind) -> Option<Value> {
+        match kind {
+            ftd::interpreter::Kind::DateTime => ftd::interpreter::datetime::parse_datetime(text)
+                .map(|value| Value::DateTime { value }),
+            ftd::interpreter::Kind::Date => {
+                ftd::interpreter::datetime::parse_date(text).map(|value| Value::Date { value })
+            }
+            ftd::interpreter::Kind::Duration => ftd::interpreter::datetime::parse_duration(text)
+                .map(|value| Value::Duration { value }),
+            _ => None,
+        }
+    }
+
+    pub fn to_iso_string(&self) -> Option<String> {
+        match self {
+            Value::DateTime { value } => {
+                Some(ftd::interpreter::datetime::datetime_to_string(*value))
+            }
+            Value::Date { value } => Some(ftd::interpreter::datetime::date_to_string(*value)),
+            Value::Duration { value } => {
+                Some(ftd::interpreter::datetime::duration_to_string(*value))
+            }
+            _ => None,
+        }
+    }
+
     pub(crate) fn new_none(kind: ftd::interpreter::KindData) -> ftd::interpreter::Value {
         ftd::interpreter::Value::Optional {
             data: Box::new(None),
@@ -1756,6 +1981,12 @@ impl Value {
             ftd::interpreter::Value::Boolean { value } => {
                 Ok(fastn_grammar::evalexpr::Value::Boolean(value))
             }
+            ftd::interpreter::Value::Duration { value } => {
+                Ok(fastn_grammar::evalexpr::Value::Int(value))
+            }
+            ftd::interpreter::Value::DateTime { .. } | ftd::interpreter::Value::Date { .. } => Ok(
+                fastn_grammar::evalexpr::Value::String(self.to_iso_string().unwrap()),
+            ),
             ftd::interpreter::Value::Optional { data, .. } => {
                 if let Some(data) = data.as_ref() {
                     data.clone().into_evalexpr_value(doc)
@@ -1956,6 +2187,10 @@ impl Value {
             Value::Integer { value } => Ok(Some(serde_json::json!(value))),
             Value::Decimal { value } => Ok(Some(serde_json::json!(value))),
             Value::Boolean { value } => Ok(Some(serde_json::Value::Bool(value.to_owned()))),
+            Value::Duration { value } => Ok(Some(serde_json::json!(value))),
+            Value::DateTime { .. } | Value::Date { .. } => {
+                Ok(self.to_iso_string().map(serde_json::Value::String))
+            }
             Value::Optional { data, .. } => {
                 if let Some(data) = data.as_ref() {
                     data.to_serde_value(doc)
@@ -1973,7 +2208,7 @@ impl Value {
                 }
                 Ok(Some(serde_json::to_value(&new_values)?))
             }
-            Value::Record { fields, .. } => {
+            Value::Record { fields, .. } | Value::Map { data: fields, .. } => {
                 let mut new_values: ftd::Map<serde_json::Value> = Default::default();
                 for (k, v) in fields {
                     let resolved_value = v.clone().resolve(doc, 0)?;
@@ -2031,6 +2266,9 @@ impl Value {
             Value::Integer { value } => Ok(Some(value.to_string())),
             Value::Decimal { value } => Ok(Some(value.to_string())),
             Value::Boolean { value } => Ok(Some(value.to_string())),
+            Value::DateTime { .. } | Value::Date { .. } | Value::Duration { .. } => {
+                Ok(self.to_iso_string())
+            }
             Value::Optional { data, .. } => {
                 if let Some(data) = data.as_ref() {
                     data.to_string(doc, use_quotes)
@@ -2038,9 +2276,10 @@ impl Value {
                     Ok(Some("".to_string()))
                 }
             }
-            Value::Object { .. } | Value::Record { .. } | Value::List { .. } => {
-                Ok(Some(serde_json::to_string(&self.to_serde_value(doc)?)?))
-            }
+            Value::Object { .. }
+            | Value::Record { .. }
+            | Value::List { .. }
+            | Value::Map { .. } => Ok(Some(serde_json::to_string(&self.to_serde_value(doc)?)?)),
             _ => Ok(None),
         }
     }
@@ -7,6 +7,10 @@ pub struct Variable {
     pub conditional_value: Vec<ConditionalValue>,
     pub line_number: usize,
     pub is_static: bool,
+    /// Set with `$persist$: true`, the variable keeps its value across
+    /// client side navigations instead of starting afresh with every page.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub persist: bool,
 }
 
 impl Variable {
@@ -178,6 +182,7 @@ impl Variable {
             conditional_value: vec![],
             line_number: variable_definition.line_number,
             is_static: true,
+            persist: variable_definition.flags.persist == Some(true),
         }
         .set_static(doc);
 
@@ -93,6 +93,60 @@ pub(crate) fn kind_eq(
     ))
 }
 
+/// The `known_kinds` of a generic definition, every type parameter `T` is `Kind::TypeParameter`
+pub(crate) fn type_parameters_known_kinds(
+    type_parameters: &[String],
+) -> ftd::Map<ftd::interpreter::Kind> {
+    type_parameters
+        .iter()
+        .map(|name| {
+            (
+                name.to_string(),
+                ftd::interpreter::Kind::type_parameter(name),
+            )
+        })
+        .collect()
+}
+
+/// Infers the type parameters of the generic definition `name` from the kinds of the values
+/// passed to its arguments, a type parameter has to be the same kind everywhere it is used
+pub(crate) fn infer_type_parameters(
+    name: &str,
+    argument_and_value_kinds: &[(&ftd::interpreter::Argument, ftd::interpreter::Kind)],
+    doc_id: &str,
+    line_number: usize,
+) -> ftd::interpreter::Result<ftd::Map<ftd::interpreter::Kind>> {
+    let mut bindings: ftd::Map<(ftd::interpreter::Kind, String)> = Default::default();
+    for (argument, value_kind) in argument_and_value_kinds {
+        for (type_parameter, kind) in argument.kind.kind.type_parameter_bindings(value_kind) {
+            match bindings.get(type_parameter.as_str()) {
+                Some((bound_kind, bound_argument))
+                    if !(bound_kind.is_same_as(&kind) && kind.is_same_as(bound_kind)) =>
+                {
+                    return ftd::interpreter::utils::e2(
+                        format!(
+                            "Type parameter `{}` of `{}` is `{}` for `{}` but `{}` for `{}`",
+                            type_parameter,
+                            name,
+                            bound_kind.get_name(),
+                            bound_argument,
+                            kind.get_name(),
+                            argument.name
+                        ),
+                        doc_id,
+                        line_number,
+                    );
+                }
+                Some(_) => {}
+                None => {
+                    bindings.insert(type_parameter, (kind, argument.name.to_string()));
+                }
+            }
+        }
+    }
+    Ok(bindings.into_iter().map(|(k, (v, _))| (k, v)).collect())
+}
+
 pub const CLONE: &str = "*$";
 pub const REFERENCE: &str = ftd::ast::utils::REFERENCE;
 
@@ -352,6 +406,16 @@ pub fn get_argument_for_reference_and_remaining(
     }
     if let Some((loop_name, loop_argument, loop_counter_alias)) = loop_object_name_and_kind {
         let p2 = ftd::interpreter::utils::split_at(name, ".").1;
+        // Looping over a map gives the key of the entry as the loop counter
+        let loop_counter_kind = if loop_argument
+            .value
+            .as_ref()
+            .map_or(false, |on| on.kind().is_map())
+        {
+            ftd::interpreter::Kind::string()
+        } else {
+            ftd::interpreter::Kind::integer()
+        };
         let name = doc.resolve_name(name);
         if name.starts_with(format!("{}.", loop_name).as_str())
             || name.starts_with(format!("{}#{}.", doc.name, loop_name).as_str())
@@ -369,9 +433,7 @@ pub fn get_argument_for_reference_and_remaining(
             return Ok(Some((
                 ftd::interpreter::Field::default(
                     ftd::interpreter::FTD_LOOP_COUNTER,
-                    ftd::interpreter::Kind::integer()
-                        .into_optional()
-                        .into_kind_data(),
+                    loop_counter_kind.clone().into_optional().into_kind_data(),
                 ),
                 None,
                 ftd::interpreter::PropertyValueSource::Loop(loop_name.to_string()),
@@ -383,9 +445,7 @@ pub fn get_argument_for_reference_and_remaining(
                 return Ok(Some((
                     ftd::interpreter::Field::default(
                         loop_counter_alias,
-                        ftd::interpreter::Kind::integer()
-                            .into_optional()
-                            .into_kind_data(),
+                        loop_counter_kind.into_optional().into_kind_data(),
                     ),
                     None,
                     ftd::interpreter::PropertyValueSource::Loop(loop_name.to_string()),
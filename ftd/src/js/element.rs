@@ -11,11 +11,18 @@ pub enum Element {
     Column(Column),
     Row(Row),
     ContainerElement(ContainerElement),
+    Grid(Grid),
     Image(Image),
     Video(Video),
     Device(Device),
     CheckBox(CheckBox),
     TextInput(TextInput),
+    FormControl(FormControl),
+    Form(Form),
+    Dialog(Dialog),
+    Popover(Popover),
+    Subscribe(Subscribe),
+    Table(Table),
     Iframe(Iframe),
     Code(Code),
     Rive(Rive),
@@ -35,10 +42,19 @@ impl Element {
             "ftd#column" => Element::Column(Column::from(component)),
             "ftd#row" => Element::Row(Row::from(component)),
             "ftd#container" => Element::ContainerElement(ContainerElement::from(component)),
+            "ftd#grid" => Element::Grid(Grid::from(component)),
             "ftd#image" => Element::Image(Image::from(component)),
             "ftd#video" => Element::Video(Video::from(component)),
             "ftd#checkbox" => Element::CheckBox(CheckBox::from(component)),
             "ftd#text-input" => Element::TextInput(TextInput::from(component)),
+            "ftd#select" | "ftd#radio" | "ftd#slider" | "ftd#file-input" => {
+                Element::FormControl(FormControl::from(component))
+            }
+            "ftd#form" => Element::Form(Form::from(component)),
+            "ftd#dialog" => Element::Dialog(Dialog::from(component)),
+            "ftd#popover" => Element::Popover(Popover::from(component)),
+            "ftd#subscribe" => Element::Subscribe(Subscribe::from(component)),
+            "ftd#table" => Element::Table(Table::from(component)),
             "ftd#iframe" => Element::Iframe(Iframe::from(component)),
             "ftd#code" => Element::Code(Code::from(component, doc)),
             "ftd#desktop" | "ftd#mobile" => {
@@ -105,6 +121,14 @@ impl Element {
                 should_return,
                 has_rive_components,
             ),
+            Element::Grid(grid) => grid.to_component_statements(
+                parent,
+                index,
+                doc,
+                &mut rdata,
+                should_return,
+                has_rive_components,
+            ),
             Element::Image(image) => {
                 image.to_component_statements(parent, index, doc, &mut rdata, should_return)
             }
@@ -125,6 +149,39 @@ impl Element {
             Element::TextInput(t) => {
                 t.to_component_statements(parent, index, doc, &mut rdata, should_return)
             }
+            Element::FormControl(f) => {
+                f.to_component_statements(parent, index, doc, &mut rdata, should_return)
+            }
+            Element::Form(form) => form.to_component_statements(
+                parent,
+                index,
+                doc,
+                &mut rdata,
+                should_return,
+                has_rive_components,
+            ),
+            Element::Dialog(dialog) => dialog.to_component_statements(
+                parent,
+                index,
+                doc,
+                &mut rdata,
+                should_return,
+                has_rive_components,
+            ),
+            Element::Popover(popover) => popover.to_component_statements(
+                parent,
+                index,
+                doc,
+                &mut rdata,
+                should_return,
+                has_rive_components,
+            ),
+            Element::Subscribe(s) => {
+                s.to_component_statements(parent, index, doc, &mut rdata, should_return)
+            }
+            Element::Table(t) => {
+                t.to_component_statements(parent, index, doc, &mut rdata, should_return)
+            }
             Element::Iframe(i) => {
                 i.to_component_statements(parent, index, doc, &mut rdata, should_return)
             }
@@ -138,10 +195,321 @@ impl Element {
     }
 }
 
+/// Validation constraints of an input. `pattern` and `min-length` are only
+/// defined by `ftd.text-input`, so they are left as `None` for the others.
+#[derive(Debug)]
+pub struct FieldValidation {
+    pub name: Option<ftd::js::Value>,
+    pub required: Option<ftd::js::Value>,
+    pub pattern: Option<ftd::js::Value>,
+    pub min_length: Option<ftd::js::Value>,
+    pub error: Option<ftd::js::Value>,
+    pub state: Option<ftd::js::Value>,
+}
+
+impl FieldValidation {
+    pub fn from(
+        properties: &[ftd::interpreter::Property],
+        arguments: &[ftd::interpreter::Argument],
+    ) -> FieldValidation {
+        let get = |key: &str| {
+            if arguments.iter().any(|v| v.name.eq(key)) {
+                ftd::js::value::get_optional_js_value(key, properties, arguments)
+            } else {
+                None
+            }
+        };
+
+        FieldValidation {
+            name: get("name"),
+            required: get("required"),
+            pattern: get("pattern"),
+            min_length: get("min-length"),
+            error: get("error"),
+            state: get("state"),
+        }
+    }
+
+    pub fn to_set_properties(
+        &self,
+        element_name: &str,
+        doc: &ftd::interpreter::TDoc,
+        rdata: &ftd::js::ResolverData,
+    ) -> Vec<fastn_js::ComponentStatement> {
+        let mut component_statements = vec![];
+        for (value, property_kind) in [
+            (&self.name, fastn_js::PropertyKind::Name),
+            (&self.state, fastn_js::PropertyKind::FieldState),
+            (&self.required, fastn_js::PropertyKind::Required),
+            (&self.pattern, fastn_js::PropertyKind::Pattern),
+            (&self.min_length, fastn_js::PropertyKind::MinLength),
+            (&self.error, fastn_js::PropertyKind::CustomError),
+        ] {
+            if let Some(value) = value {
+                component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                    value.to_set_property(property_kind, doc, element_name, rdata),
+                ));
+            }
+        }
+        component_statements
+    }
+}
+
+/// `ftd.select`, `ftd.radio`, `ftd.slider` and `ftd.file-input`. Properties
+/// a kernel does not define are left as `None`.
+#[derive(Debug)]
+pub struct FormControl {
+    pub kind: fastn_js::ElementKind,
+    pub options: Option<ftd::js::Value>,
+    pub value: Option<ftd::js::Value>,
+    pub placeholder: Option<ftd::js::Value>,
+    pub min: Option<ftd::js::Value>,
+    pub max: Option<ftd::js::Value>,
+    pub step: Option<ftd::js::Value>,
+    pub accept: Option<ftd::js::Value>,
+    pub multiple: Option<ftd::js::Value>,
+    pub enabled: Option<ftd::js::Value>,
+    pub label: Option<ftd::js::Value>,
+    pub field_validation: FieldValidation,
+    pub common: Common,
+}
+
+impl FormControl {
+    pub fn from(component: &ftd::interpreter::Component) -> FormControl {
+        let component_definition = ftd::interpreter::default::get_default_bag()
+            .get(component.name.as_str())
+            .unwrap()
+            .clone()
+            .component()
+            .unwrap();
+        let properties = component.properties.as_slice();
+        let arguments = component_definition.arguments.as_slice();
+        let get = |key: &str| {
+            if arguments.iter().any(|v| v.name.eq(key)) {
+                ftd::js::value::get_optional_js_value(key, properties, arguments)
+            } else {
+                None
+            }
+        };
+
+        FormControl {
+            kind: match component.name.as_str() {
+                "ftd#select" => fastn_js::ElementKind::Select,
+                "ftd#radio" => fastn_js::ElementKind::RadioGroup,
+                "ftd#slider" => fastn_js::ElementKind::Slider,
+                _ => fastn_js::ElementKind::FileInput,
+            },
+            options: get("options"),
+            value: get("value"),
+            placeholder: get("placeholder"),
+            min: get("min"),
+            max: get("max"),
+            step: get("step"),
+            accept: get("accept"),
+            multiple: get("multiple"),
+            enabled: get("enabled"),
+            label: get("label"),
+            field_validation: FieldValidation::from(properties, arguments),
+            common: Common::from(properties, arguments, component.events.as_slice()),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        rdata: &mut ftd::js::ResolverData,
+        should_return: bool,
+    ) -> Vec<fastn_js::ComponentStatement> {
+        let mut component_statements = vec![];
+        let kernel = create_element(self.kind.clone(), parent, index, rdata);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            rdata,
+        ));
+
+        // `name`, `required`, `placeholder` and `options` come before `value`
+        // so the radio buttons and the selected option are marked when the
+        // options are rendered
+        component_statements.extend(self.field_validation.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            rdata,
+        ));
+        for (value, property_kind) in [
+            (&self.label, fastn_js::PropertyKind::AriaLabel),
+            (&self.placeholder, fastn_js::PropertyKind::Placeholder),
+            (&self.options, fastn_js::PropertyKind::Options),
+            (&self.min, fastn_js::PropertyKind::Min),
+            (&self.max, fastn_js::PropertyKind::Max),
+            (&self.step, fastn_js::PropertyKind::Step),
+            (&self.accept, fastn_js::PropertyKind::Accept),
+            (&self.multiple, fastn_js::PropertyKind::Multiple),
+            (&self.enabled, fastn_js::PropertyKind::Enabled),
+            (&self.value, fastn_js::PropertyKind::FormValue),
+        ] {
+            if let Some(value) = value {
+                component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                    value.to_set_property(property_kind, doc, kernel.name.as_str(), rdata),
+                ));
+            }
+        }
+
+        if should_return {
+            component_statements.push(fastn_js::ComponentStatement::Return {
+                component_name: kernel.name,
+            });
+        }
+        component_statements
+    }
+}
+
+/// `ftd.table` renders its rows and pager itself, so sorting, filtering and
+/// paging happen on the client without a round-trip.
+#[derive(Debug)]
+pub struct Table {
+    pub data: ftd::js::Value,
+    pub columns: ftd::js::Value,
+    pub filter: Option<ftd::js::Value>,
+    pub page_size: Option<ftd::js::Value>,
+    pub page: Option<ftd::js::Value>,
+    pub server_pagination: Option<ftd::js::Value>,
+    pub page_param: Option<ftd::js::Value>,
+    pub total: Option<ftd::js::Value>,
+    pub sticky_header: Option<ftd::js::Value>,
+    pub previous_label: Option<ftd::js::Value>,
+    pub next_label: Option<ftd::js::Value>,
+    pub page_label: Option<ftd::js::Value>,
+    pub common: Common,
+}
+
+impl Table {
+    pub fn from(component: &ftd::interpreter::Component) -> Table {
+        let component_definition = ftd::interpreter::default::get_default_bag()
+            .get("ftd#table")
+            .unwrap()
+            .clone()
+            .component()
+            .unwrap();
+        let properties = component.properties.as_slice();
+        let arguments = component_definition.arguments.as_slice();
+
+        Table {
+            data: ftd::js::value::get_optional_js_value("data", properties, arguments).unwrap(),
+            columns: ftd::js::value::get_optional_js_value("columns", properties, arguments)
+                .unwrap(),
+            filter: ftd::js::value::get_optional_js_value("filter", properties, arguments),
+            page_size: ftd::js::value::get_optional_js_value("page-size", properties, arguments),
+            page: ftd::js::value::get_optional_js_value("page", properties, arguments),
+            server_pagination: ftd::js::value::get_optional_js_value(
+                "server-pagination",
+                properties,
+                arguments,
+            ),
+            page_param: ftd::js::value::get_optional_js_value("page-param", properties, arguments),
+            total: ftd::js::value::get_optional_js_value("total", properties, arguments),
+            sticky_header: ftd::js::value::get_optional_js_value(
+                "sticky-header",
+                properties,
+                arguments,
+            ),
+            previous_label: ftd::js::value::get_optional_js_value_with_default(
+                "previous-label",
+                properties,
+                arguments,
+            ),
+            next_label: ftd::js::value::get_optional_js_value_with_default(
+                "next-label",
+                properties,
+                arguments,
+            ),
+            page_label: ftd::js::value::get_optional_js_value_with_default(
+                "page-label",
+                properties,
+                arguments,
+            ),
+            common: Common::from(properties, arguments, component.events.as_slice()),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        rdata: &mut ftd::js::ResolverData,
+        should_return: bool,
+    ) -> Vec<fastn_js::ComponentStatement> {
+        let mut component_statements = vec![];
+        let kernel = create_element(fastn_js::ElementKind::Table, parent, index, rdata);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            rdata,
+        ));
+
+        // `data` comes last so the rows are only rendered once everything
+        // they depend on is known
+        for (value, property_kind) in [
+            (Some(&self.columns), fastn_js::PropertyKind::TableColumns),
+            (
+                self.page_size.as_ref(),
+                fastn_js::PropertyKind::TablePageSize,
+            ),
+            (self.page.as_ref(), fastn_js::PropertyKind::TablePage),
+            (
+                self.page_param.as_ref(),
+                fastn_js::PropertyKind::TablePageParam,
+            ),
+            (
+                self.server_pagination.as_ref(),
+                fastn_js::PropertyKind::TableServerPagination,
+            ),
+            (self.total.as_ref(), fastn_js::PropertyKind::TableTotal),
+            (
+                self.sticky_header.as_ref(),
+                fastn_js::PropertyKind::TableStickyHeader,
+            ),
+            (
+                self.previous_label.as_ref(),
+                fastn_js::PropertyKind::TablePreviousLabel,
+            ),
+            (
+                self.next_label.as_ref(),
+                fastn_js::PropertyKind::TableNextLabel,
+            ),
+            (
+                self.page_label.as_ref(),
+                fastn_js::PropertyKind::TablePageLabel,
+            ),
+            (self.filter.as_ref(), fastn_js::PropertyKind::TableFilter),
+            (Some(&self.data), fastn_js::PropertyKind::TableData),
+        ] {
+            if let Some(value) = value {
+                component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                    value.to_set_property(property_kind, doc, kernel.name.as_str(), rdata),
+                ));
+            }
+        }
+
+        if should_return {
+            component_statements.push(fastn_js::ComponentStatement::Return {
+                component_name: kernel.name,
+            });
+        }
+        component_statements
+    }
+}
+
 #[derive(Debug)]
 pub struct CheckBox {
     pub enabled: Option<ftd::js::Value>,
     pub checked: Option<ftd::js::Value>,
+    pub field_validation: FieldValidation,
     pub common: Common,
 }
 
@@ -165,6 +533,10 @@ impl CheckBox {
                 component.properties.as_slice(),
                 component_definition.arguments.as_slice(),
             ),
+            field_validation: FieldValidation::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
             common: Common::from(
                 component.properties.as_slice(),
                 component_definition.arguments.as_slice(),
@@ -210,6 +582,11 @@ impl CheckBox {
                 ),
             ));
         }
+        component_statements.extend(self.field_validation.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            rdata,
+        ));
 
         if should_return {
             component_statements.push(fastn_js::ComponentStatement::Return {
@@ -229,6 +606,7 @@ pub struct TextInput {
     pub value: Option<ftd::js::Value>,
     pub default_value: Option<ftd::js::Value>,
     pub enabled: Option<ftd::js::Value>,
+    pub field_validation: FieldValidation,
     pub common: Common,
 }
 
@@ -277,6 +655,10 @@ impl TextInput {
                 component.properties.as_slice(),
                 component_definition.arguments.as_slice(),
             ),
+            field_validation: FieldValidation::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
             common: Common::from(
                 component.properties.as_slice(),
                 component_definition.arguments.as_slice(),
@@ -372,6 +754,11 @@ impl TextInput {
                 ),
             ));
         }
+        component_statements.extend(self.field_validation.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            rdata,
+        ));
 
         if should_return {
             component_statements.push(fastn_js::ComponentStatement::Return {
@@ -1099,6 +1486,112 @@ pub struct ContainerElement {
     pub common: Common,
 }
 
+#[derive(Debug)]
+pub struct Grid {
+    pub container: Container,
+    pub grid_properties: GridProperties,
+    pub common: Common,
+}
+
+#[derive(Debug)]
+pub struct GridProperties {
+    pub template_columns: Option<ftd::js::Value>,
+    pub template_rows: Option<ftd::js::Value>,
+    pub template_areas: Option<ftd::js::Value>,
+    pub gap: Option<ftd::js::Value>,
+    pub row_gap: Option<ftd::js::Value>,
+    pub column_gap: Option<ftd::js::Value>,
+}
+
+impl GridProperties {
+    pub fn from(
+        properties: &[ftd::interpreter::Property],
+        arguments: &[ftd::interpreter::Argument],
+    ) -> GridProperties {
+        GridProperties {
+            template_columns: ftd::js::value::get_optional_js_value(
+                "template-columns",
+                properties,
+                arguments,
+            ),
+            template_rows: ftd::js::value::get_optional_js_value(
+                "template-rows",
+                properties,
+                arguments,
+            ),
+            template_areas: ftd::js::value::get_optional_js_value(
+                "template-areas",
+                properties,
+                arguments,
+            ),
+            gap: ftd::js::value::get_optional_js_value("gap", properties, arguments),
+            row_gap: ftd::js::value::get_optional_js_value("row-gap", properties, arguments),
+            column_gap: ftd::js::value::get_optional_js_value("column-gap", properties, arguments),
+        }
+    }
+
+    pub fn to_set_properties(
+        &self,
+        element_name: &str,
+        doc: &ftd::interpreter::TDoc,
+        rdata: &ftd::js::ResolverData,
+    ) -> Vec<fastn_js::ComponentStatement> {
+        let mut component_statements = vec![];
+        if let Some(ref template_columns) = self.template_columns {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                template_columns.to_set_property(
+                    fastn_js::PropertyKind::GridTemplateColumns,
+                    doc,
+                    element_name,
+                    rdata,
+                ),
+            ));
+        }
+        if let Some(ref template_rows) = self.template_rows {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                template_rows.to_set_property(
+                    fastn_js::PropertyKind::GridTemplateRows,
+                    doc,
+                    element_name,
+                    rdata,
+                ),
+            ));
+        }
+        if let Some(ref template_areas) = self.template_areas {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                template_areas.to_set_property(
+                    fastn_js::PropertyKind::GridTemplateAreas,
+                    doc,
+                    element_name,
+                    rdata,
+                ),
+            ));
+        }
+        // `row-gap` and `column-gap` are set after `gap` so they override it
+        if let Some(ref gap) = self.gap {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                gap.to_set_property(fastn_js::PropertyKind::Gap, doc, element_name, rdata),
+            ));
+        }
+        if let Some(ref row_gap) = self.row_gap {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                row_gap.to_set_property(fastn_js::PropertyKind::RowGap, doc, element_name, rdata),
+            ));
+        }
+        if let Some(ref column_gap) = self.column_gap {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                column_gap.to_set_property(
+                    fastn_js::PropertyKind::ColumnGap,
+                    doc,
+                    element_name,
+                    rdata,
+                ),
+            ));
+        }
+        component_statements
+    }
+}
+
 #[derive(Debug)]
 pub struct Row {
     pub container: Container,
@@ -1868,6 +2361,442 @@ impl ContainerElement {
     }
 }
 
+impl Grid {
+    pub fn from(component: &ftd::interpreter::Component) -> Grid {
+        let component_definition = ftd::interpreter::default::get_default_bag()
+            .get("ftd#grid")
+            .unwrap()
+            .clone()
+            .component()
+            .unwrap();
+
+        Grid {
+            container: Container::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            grid_properties: GridProperties::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            common: Common::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+                component.events.as_slice(),
+            ),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        rdata: &mut ftd::js::ResolverData,
+        should_return: bool,
+        has_rive_components: &mut bool,
+    ) -> Vec<fastn_js::ComponentStatement> {
+        let mut component_statements = vec![];
+        let kernel = create_element(fastn_js::ElementKind::Grid, parent, index, rdata);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            rdata,
+        ));
+
+        component_statements.extend(self.grid_properties.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            rdata,
+        ));
+
+        component_statements.extend(self.container.to_component_statements(
+            doc,
+            rdata,
+            has_rive_components,
+            false,
+        ));
+
+        if should_return {
+            component_statements.push(fastn_js::ComponentStatement::Return {
+                component_name: kernel.name,
+            });
+        }
+        component_statements
+    }
+}
+
+/// `ftd.form` lays out its children like `ftd.column` and tracks whether
+/// all the fields inside it are valid.
+#[derive(Debug)]
+pub struct Form {
+    pub container: Container,
+    pub container_properties: ContainerProperties,
+    pub valid: Option<ftd::js::Value>,
+    pub common: Common,
+}
+
+impl Form {
+    pub fn from(component: &ftd::interpreter::Component) -> Form {
+        let component_definition = ftd::interpreter::default::get_default_bag()
+            .get("ftd#form")
+            .unwrap()
+            .clone()
+            .component()
+            .unwrap();
+
+        Form {
+            container: Container::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            container_properties: ContainerProperties::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            valid: ftd::js::value::get_optional_js_value(
+                "valid",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            common: Common::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+                component.events.as_slice(),
+            ),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        rdata: &mut ftd::js::ResolverData,
+        should_return: bool,
+        has_rive_components: &mut bool,
+    ) -> Vec<fastn_js::ComponentStatement> {
+        let mut component_statements = vec![];
+        let kernel = create_element(fastn_js::ElementKind::Form, parent, index, rdata);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            rdata,
+        ));
+
+        component_statements.extend(self.container_properties.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            rdata,
+        ));
+
+        if let Some(ref valid) = self.valid {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                valid.to_set_property(
+                    fastn_js::PropertyKind::Valid,
+                    doc,
+                    kernel.name.as_str(),
+                    rdata,
+                ),
+            ));
+        }
+
+        component_statements.extend(self.container.to_component_statements(
+            doc,
+            rdata,
+            has_rive_components,
+            false,
+        ));
+
+        if should_return {
+            component_statements.push(fastn_js::ComponentStatement::Return {
+                component_name: kernel.name,
+            });
+        }
+        component_statements
+    }
+}
+
+#[derive(Debug)]
+pub struct Dialog {
+    pub container: Container,
+    pub container_properties: ContainerProperties,
+    pub open: Option<ftd::js::Value>,
+    pub modal: Option<ftd::js::Value>,
+    pub dismissible: Option<ftd::js::Value>,
+    pub common: Common,
+}
+
+impl Dialog {
+    pub fn from(component: &ftd::interpreter::Component) -> Dialog {
+        let component_definition = ftd::interpreter::default::get_default_bag()
+            .get("ftd#dialog")
+            .unwrap()
+            .clone()
+            .component()
+            .unwrap();
+
+        Dialog {
+            container: Container::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            container_properties: ContainerProperties::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            open: ftd::js::value::get_optional_js_value(
+                "open",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            modal: ftd::js::value::get_optional_js_value(
+                "modal",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            dismissible: ftd::js::value::get_optional_js_value(
+                "dismissible",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            common: Common::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+                component.events.as_slice(),
+            ),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        rdata: &mut ftd::js::ResolverData,
+        should_return: bool,
+        has_rive_components: &mut bool,
+    ) -> Vec<fastn_js::ComponentStatement> {
+        let mut component_statements = vec![];
+        let kernel = create_element(fastn_js::ElementKind::Dialog, parent, index, rdata);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            rdata,
+        ));
+
+        component_statements.extend(self.container_properties.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            rdata,
+        ));
+
+        component_statements.extend(self.container.to_component_statements(
+            doc,
+            rdata,
+            has_rive_components,
+            false,
+        ));
+
+        // `open` comes last so the dialog is shown with its content and the
+        // way it is opened is already known
+        for (value, property_kind) in [
+            (self.modal.as_ref(), fastn_js::PropertyKind::Modal),
+            (
+                self.dismissible.as_ref(),
+                fastn_js::PropertyKind::Dismissible,
+            ),
+            (self.open.as_ref(), fastn_js::PropertyKind::Open),
+        ] {
+            if let Some(value) = value {
+                component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                    value.to_set_property(property_kind, doc, kernel.name.as_str(), rdata),
+                ));
+            }
+        }
+
+        if should_return {
+            component_statements.push(fastn_js::ComponentStatement::Return {
+                component_name: kernel.name,
+            });
+        }
+        component_statements
+    }
+}
+
+#[derive(Debug)]
+pub struct Popover {
+    pub container: Container,
+    pub container_properties: ContainerProperties,
+    pub open: Option<ftd::js::Value>,
+    pub anchor_id: ftd::js::Value,
+    pub placement: Option<ftd::js::Value>,
+    pub common: Common,
+}
+
+impl Popover {
+    pub fn from(component: &ftd::interpreter::Component) -> Popover {
+        let component_definition = ftd::interpreter::default::get_default_bag()
+            .get("ftd#popover")
+            .unwrap()
+            .clone()
+            .component()
+            .unwrap();
+
+        Popover {
+            container: Container::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            container_properties: ContainerProperties::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            open: ftd::js::value::get_optional_js_value(
+                "open",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            anchor_id: ftd::js::value::get_optional_js_value(
+                "anchor-id",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            )
+            .unwrap(),
+            placement: ftd::js::value::get_optional_js_value(
+                "placement",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            ),
+            common: Common::from(
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+                component.events.as_slice(),
+            ),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        rdata: &mut ftd::js::ResolverData,
+        should_return: bool,
+        has_rive_components: &mut bool,
+    ) -> Vec<fastn_js::ComponentStatement> {
+        let mut component_statements = vec![];
+        let kernel = create_element(fastn_js::ElementKind::Popover, parent, index, rdata);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+        component_statements.extend(self.common.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            rdata,
+        ));
+
+        component_statements.extend(self.container_properties.to_set_properties(
+            kernel.name.as_str(),
+            doc,
+            rdata,
+        ));
+
+        component_statements.extend(self.container.to_component_statements(
+            doc,
+            rdata,
+            has_rive_components,
+            false,
+        ));
+
+        // `open` comes last so the popover is positioned with its content
+        for (value, property_kind) in [
+            (Some(&self.anchor_id), fastn_js::PropertyKind::PopoverAnchor),
+            (
+                self.placement.as_ref(),
+                fastn_js::PropertyKind::PopoverPlacement,
+            ),
+            (self.open.as_ref(), fastn_js::PropertyKind::Open),
+        ] {
+            if let Some(value) = value {
+                component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                    value.to_set_property(property_kind, doc, kernel.name.as_str(), rdata),
+                ));
+            }
+        }
+
+        if should_return {
+            component_statements.push(fastn_js::ComponentStatement::Return {
+                component_name: kernel.name,
+            });
+        }
+        component_statements
+    }
+}
+
+#[derive(Debug)]
+pub struct Subscribe {
+    pub url: ftd::js::Value,
+    pub value: ftd::js::Value,
+}
+
+impl Subscribe {
+    pub fn from(component: &ftd::interpreter::Component) -> Subscribe {
+        let component_definition = ftd::interpreter::default::get_default_bag()
+            .get("ftd#subscribe")
+            .unwrap()
+            .clone()
+            .component()
+            .unwrap();
+
+        Subscribe {
+            url: ftd::js::value::get_optional_js_value(
+                "url",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            )
+            .unwrap(),
+            value: ftd::js::value::get_optional_js_value(
+                "value",
+                component.properties.as_slice(),
+                component_definition.arguments.as_slice(),
+            )
+            .unwrap(),
+        }
+    }
+
+    pub fn to_component_statements(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        rdata: &mut ftd::js::ResolverData,
+        should_return: bool,
+    ) -> Vec<fastn_js::ComponentStatement> {
+        let mut component_statements = vec![];
+        let kernel = create_element(fastn_js::ElementKind::Subscribe, parent, index, rdata);
+        component_statements.push(fastn_js::ComponentStatement::CreateKernel(kernel.clone()));
+
+        // `url` comes last, it opens the stream
+        for (value, property_kind) in [
+            (&self.value, fastn_js::PropertyKind::SubscribeValue),
+            (&self.url, fastn_js::PropertyKind::SubscribeUrl),
+        ] {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                value.to_set_property(property_kind, doc, kernel.name.as_str(), rdata),
+            ));
+        }
+
+        if should_return {
+            component_statements.push(fastn_js::ComponentStatement::Return {
+                component_name: kernel.name,
+            });
+        }
+        component_statements
+    }
+}
+
 #[derive(Debug)]
 pub struct Device {
     pub container: Container,
@@ -2266,6 +3195,19 @@ pub struct Common {
     pub events: Vec<ftd::interpreter::Event>,
     pub selectable: Option<ftd::js::Value>,
     pub mask: Option<ftd::js::Value>,
+    pub grid_column: Option<ftd::js::Value>,
+    pub grid_row: Option<ftd::js::Value>,
+    pub grid_area: Option<ftd::js::Value>,
+    pub drag_data: Option<ftd::js::Value>,
+    pub transition: Option<ftd::js::Value>,
+    pub animation: Option<ftd::js::Value>,
+    pub enter_animation: Option<ftd::js::Value>,
+    pub exit_animation: Option<ftd::js::Value>,
+    pub aria_role: Option<ftd::js::Value>,
+    pub aria_label: Option<ftd::js::Value>,
+    pub aria_described_by: Option<ftd::js::Value>,
+    pub aria_live: Option<ftd::js::Value>,
+    pub tab_index: Option<ftd::js::Value>,
 }
 
 impl Common {
@@ -2482,6 +3424,35 @@ impl Common {
             shadow: ftd::js::value::get_optional_js_value("shadow", properties, arguments),
             selectable: ftd::js::value::get_optional_js_value("selectable", properties, arguments),
             mask: ftd::js::value::get_optional_js_value("mask", properties, arguments),
+            grid_column: ftd::js::value::get_optional_js_value(
+                "grid-column",
+                properties,
+                arguments,
+            ),
+            grid_row: ftd::js::value::get_optional_js_value("grid-row", properties, arguments),
+            grid_area: ftd::js::value::get_optional_js_value("grid-area", properties, arguments),
+            drag_data: ftd::js::value::get_optional_js_value("drag-data", properties, arguments),
+            transition: ftd::js::value::get_optional_js_value("transition", properties, arguments),
+            animation: ftd::js::value::get_optional_js_value("animation", properties, arguments),
+            enter_animation: ftd::js::value::get_optional_js_value(
+                "enter-animation",
+                properties,
+                arguments,
+            ),
+            exit_animation: ftd::js::value::get_optional_js_value(
+                "exit-animation",
+                properties,
+                arguments,
+            ),
+            aria_role: ftd::js::value::get_optional_js_value("aria-role", properties, arguments),
+            aria_label: ftd::js::value::get_optional_js_value("aria-label", properties, arguments),
+            aria_described_by: ftd::js::value::get_optional_js_value(
+                "aria-described-by",
+                properties,
+                arguments,
+            ),
+            aria_live: ftd::js::value::get_optional_js_value("aria-live", properties, arguments),
+            tab_index: ftd::js::value::get_optional_js_value("tab-index", properties, arguments),
             events: events.to_vec(),
         }
     }
@@ -3079,6 +4050,68 @@ impl Common {
                 mask.to_set_property(fastn_js::PropertyKind::Mask, doc, element_name, rdata),
             ));
         }
+        if let Some(ref grid_area) = self.grid_area {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                grid_area.to_set_property(
+                    fastn_js::PropertyKind::GridArea,
+                    doc,
+                    element_name,
+                    rdata,
+                ),
+            ));
+        }
+        // `grid-column` and `grid-row` are set after `grid-area` so they override it
+        if let Some(ref grid_column) = self.grid_column {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                grid_column.to_set_property(
+                    fastn_js::PropertyKind::GridColumn,
+                    doc,
+                    element_name,
+                    rdata,
+                ),
+            ));
+        }
+        if let Some(ref grid_row) = self.grid_row {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                grid_row.to_set_property(fastn_js::PropertyKind::GridRow, doc, element_name, rdata),
+            ));
+        }
+        if let Some(ref drag_data) = self.drag_data {
+            component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                drag_data.to_set_property(
+                    fastn_js::PropertyKind::DragData,
+                    doc,
+                    element_name,
+                    rdata,
+                ),
+            ));
+        }
+        for (value, property_kind) in [
+            (self.transition.as_ref(), fastn_js::PropertyKind::Transition),
+            (self.animation.as_ref(), fastn_js::PropertyKind::Animation),
+            (
+                self.enter_animation.as_ref(),
+                fastn_js::PropertyKind::EnterAnimation,
+            ),
+            (
+                self.exit_animation.as_ref(),
+                fastn_js::PropertyKind::ExitAnimation,
+            ),
+            (self.aria_role.as_ref(), fastn_js::PropertyKind::AriaRole),
+            (self.aria_label.as_ref(), fastn_js::PropertyKind::AriaLabel),
+            (
+                self.aria_described_by.as_ref(),
+                fastn_js::PropertyKind::AriaDescribedBy,
+            ),
+            (self.aria_live.as_ref(), fastn_js::PropertyKind::AriaLive),
+            (self.tab_index.as_ref(), fastn_js::PropertyKind::TabIndex),
+        ] {
+            if let Some(value) = value {
+                component_statements.push(fastn_js::ComponentStatement::SetProperty(
+                    value.to_set_property(property_kind, doc, element_name, rdata),
+                ));
+            }
+        }
         component_statements
     }
 
@@ -3192,6 +4225,18 @@ impl ftd::interpreter::EventName {
             ftd::interpreter::EventName::Change => Some(fastn_js::Event::Change),
             ftd::interpreter::EventName::Blur => Some(fastn_js::Event::Blur),
             ftd::interpreter::EventName::Focus => Some(fastn_js::Event::Focus),
+            ftd::interpreter::EventName::Submit => Some(fastn_js::Event::Submit),
+            ftd::interpreter::EventName::KeyDown(keys) => Some(fastn_js::Event::KeyDown(
+                keys.iter().map(|v| ftd::js::utils::to_key(v)).collect_vec(),
+            )),
+            ftd::interpreter::EventName::Scroll => Some(fastn_js::Event::Scroll),
+            ftd::interpreter::EventName::Visible => Some(fastn_js::Event::Visible),
+            ftd::interpreter::EventName::Hidden => Some(fastn_js::Event::Hidden),
+            ftd::interpreter::EventName::DragStart => Some(fastn_js::Event::DragStart),
+            ftd::interpreter::EventName::Drop => Some(fastn_js::Event::Drop),
+            ftd::interpreter::EventName::Swipe(direction) => {
+                Some(fastn_js::Event::Swipe(direction.to_string()))
+            }
             ftd::interpreter::EventName::RivePlay(_)
             | ftd::interpreter::EventName::RivePause(_)
             | ftd::interpreter::EventName::RiveStateChange(_) => None,
@@ -3207,11 +4252,21 @@ pub fn is_kernel(s: &str) -> bool {
         "ftd#integer",
         "ftd#decimal",
         "ftd#container",
+        "ftd#grid",
+        "ftd#form",
+        "ftd#dialog",
+        "ftd#popover",
+        "ftd#subscribe",
+        "ftd#table",
         "ftd#boolean",
         "ftd#desktop",
         "ftd#mobile",
         "ftd#checkbox",
         "ftd#text-input",
+        "ftd#select",
+        "ftd#radio",
+        "ftd#slider",
+        "ftd#file-input",
         "ftd#iframe",
         "ftd#code",
         "ftd#image",
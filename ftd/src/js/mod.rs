@@ -182,9 +182,10 @@ impl ftd::interpreter::Function {
             self.name.as_str(),
             self.expression
                 .iter()
-                .map(|e| {
-                    fastn_grammar::evalexpr::build_operator_tree(e.expression.as_str()).unwrap()
+                .flat_map(|e| {
+                    fastn_grammar::statement::build_statements(e.expression.as_str()).unwrap()
                 })
+                .map(fastn_js::UDFStatement::from_statement)
                 .collect_vec(),
             self.arguments
                 .iter()
@@ -219,7 +220,7 @@ impl ftd::interpreter::Variable {
         has_rive_components: &mut bool,
     ) -> fastn_js::Ast {
         if let Ok(value) = self.value.value(doc.name, self.value.line_number()) {
-            if let ftd::interpreter::Kind::Record { name } = &self.kind.kind {
+            if let ftd::interpreter::Kind::Record { name, .. } = &self.kind.kind {
                 let record = doc.get_record(name, self.line_number).unwrap();
                 let record_fields = value
                     .record_fields(doc.name, self.value.line_number())
@@ -351,10 +352,48 @@ impl ftd::interpreter::Component {
         rdata: &ftd::js::ResolverData,
         should_return: bool,
         has_rive_components: &mut bool,
+    ) -> Vec<fastn_js::ComponentStatement> {
+        self.to_component_statements_with_alias(
+            parent,
+            index,
+            doc,
+            rdata,
+            should_return,
+            has_rive_components,
+            &None,
+        )
+    }
+
+    /// `case_alias` is the name a `-- case:` binds the variant's value to, it is `item` in the
+    /// case's children, same as a loop alias
+    #[allow(clippy::too_many_arguments)]
+    fn to_component_statements_with_alias(
+        &self,
+        parent: &str,
+        index: usize,
+        doc: &ftd::interpreter::TDoc,
+        rdata: &ftd::js::ResolverData,
+        should_return: bool,
+        has_rive_components: &mut bool,
+        case_alias: &Option<String>,
     ) -> Vec<fastn_js::ComponentStatement> {
         use itertools::Itertools;
 
-        let loop_alias = self.iteration.clone().map(|v| v.alias);
+        if let Some(match_) = self.match_.as_ref() {
+            return match_.to_component_statements(
+                parent,
+                doc,
+                rdata,
+                should_return,
+                has_rive_components,
+            );
+        }
+
+        let loop_alias = self
+            .iteration
+            .clone()
+            .map(|v| v.alias)
+            .or_else(|| case_alias.clone());
         let loop_counter_alias = self.iteration.clone().and_then(|v| {
             if let Some(ref loop_counter_alias) = v.loop_counter_alias {
                 let (_, loop_counter_alias, _remaining) =
@@ -385,7 +424,7 @@ impl ftd::interpreter::Component {
                 parent,
                 index,
                 doc,
-                &rdata.clone_with_new_loop_alias(&None, &None, doc.name.to_string()),
+                &rdata.clone_with_new_loop_alias(&loop_alias, &None, doc.name.to_string()),
                 should_return,
                 has_rive_components,
             )
@@ -709,6 +748,76 @@ impl ftd::interpreter::Component {
     }
 }
 
+impl ftd::interpreter::Match {
+    fn to_component_statements(
+        &self,
+        parent: &str,
+        doc: &ftd::interpreter::TDoc,
+        rdata: &ftd::js::ResolverData,
+        should_return: bool,
+        has_rive_components: &mut bool,
+    ) -> Vec<fastn_js::ComponentStatement> {
+        let or_type = self
+            .value
+            .kind()
+            .get_or_type()
+            .and_then(|(name, _, _)| doc.get_or_type(name.as_str(), self.line_number).ok());
+
+        let cases = self
+            .cases
+            .iter()
+            .map(|case| {
+                let variant = case.variant.as_ref().map(|variant| {
+                    match or_type.as_ref().and_then(|or_type| {
+                        or_type.variants.iter().find(|v| v.short_name().eq(variant))
+                    }) {
+                        Some(ftd::interpreter::OrTypeVariant::Constant(constant)) => {
+                            fastn_js::MatchVariant::Constant(
+                                constant
+                                    .value
+                                    .as_ref()
+                                    .map(|v| {
+                                        v.to_fastn_js_value_with_none(doc, has_rive_components)
+                                    })
+                                    .unwrap_or(fastn_js::SetPropertyValue::Value(
+                                        fastn_js::Value::Null,
+                                    )),
+                            )
+                        }
+                        _ => fastn_js::MatchVariant::Tagged(variant.to_string()),
+                    }
+                });
+                let children = case
+                    .children
+                    .iter()
+                    .map(|child| {
+                        child.to_component_statements_with_alias(
+                            fastn_js::FUNCTION_PARENT,
+                            0,
+                            doc,
+                            rdata,
+                            true,
+                            has_rive_components,
+                            &case.alias,
+                        )
+                    })
+                    .collect();
+                fastn_js::MatchCase { variant, children }
+            })
+            .collect();
+
+        vec![fastn_js::ComponentStatement::MatchComponent(
+            fastn_js::MatchComponent {
+                deps: self.value.get_deps(rdata),
+                value: self.value.to_fastn_js_value(doc, rdata, false),
+                cases,
+                parent: parent.to_string(),
+                should_return,
+            },
+        )]
+    }
+}
+
 impl ftd::interpreter::WebComponentDefinition {
     pub fn to_ast(&self, doc: &ftd::interpreter::TDoc) -> fastn_js::Ast {
         use itertools::Itertools;
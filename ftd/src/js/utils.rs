@@ -100,6 +100,9 @@ pub(crate) fn to_key(key: &str) -> String {
         "right" => "ArrowRight",
         "left" => "ArrowLeft",
         "esc" => "Escape",
+        "enter" => "Enter",
+        "tab" => "Tab",
+        "meta" => "Meta",
         "dash" => "-",
         "space" => " ",
         t => t,
@@ -189,6 +189,11 @@ impl ftd::interpreter::Argument {
                 data: vec![],
                 kind: self.kind.clone(),
             }))
+        } else if self.kind.is_map() {
+            Some(ftd::js::Value::Data(ftd::interpreter::Value::Map {
+                data: Default::default(),
+                kind: self.kind.clone().inner_map(),
+            }))
         } else if self.kind.is_optional() {
             Some(ftd::js::Value::Data(ftd::interpreter::Value::Optional {
                 data: Box::new(None),
@@ -343,6 +348,14 @@ impl ftd::interpreter::Value {
             ftd::interpreter::Value::Decimal { value } => {
                 fastn_js::SetPropertyValue::Value(fastn_js::Value::Decimal(*value))
             }
+            ftd::interpreter::Value::Duration { value } => {
+                fastn_js::SetPropertyValue::Value(fastn_js::Value::Integer(*value))
+            }
+            ftd::interpreter::Value::DateTime { .. } | ftd::interpreter::Value::Date { .. } => {
+                fastn_js::SetPropertyValue::Value(fastn_js::Value::String(
+                    self.to_iso_string().unwrap(),
+                ))
+            }
             ftd::interpreter::Value::OrType {
                 name,
                 value,
@@ -383,6 +396,24 @@ impl ftd::interpreter::Value {
                         .collect_vec(),
                 })
             }
+            ftd::interpreter::Value::Map { data, .. } => {
+                fastn_js::SetPropertyValue::Value(fastn_js::Value::Map {
+                    value: data
+                        .iter()
+                        .map(|(k, v)| {
+                            (
+                                k.to_string(),
+                                v.to_fastn_js_value_with_ui(
+                                    doc,
+                                    rdata,
+                                    has_rive_components,
+                                    should_return,
+                                ),
+                            )
+                        })
+                        .collect_vec(),
+                })
+            }
             ftd::interpreter::Value::Record { fields, .. } => {
                 fastn_js::SetPropertyValue::Value(fastn_js::Value::Record {
                     fields: fields
@@ -576,18 +607,27 @@ fn ftd_to_js_variant(
                 js_variant.1,
             )
         }
-        t => {
-            if let Ok(value) = value.value(doc_id, line_number) {
-                return match value {
-                    ftd::interpreter::Value::Integer { value } => (value.to_string(), false),
-                    ftd::interpreter::Value::Decimal { value } => (value.to_string(), false),
-                    ftd::interpreter::Value::String { text } => (format!("\"{}\"", text), false),
-                    ftd::interpreter::Value::Boolean { value } => (value.to_string(), false),
-                    _ => todo!("{} {}", t, variant),
-                };
+        _ => {
+            match value.value(doc_id, line_number) {
+                Ok(ftd::interpreter::Value::Integer { value }) => {
+                    return (value.to_string(), false)
+                }
+                Ok(ftd::interpreter::Value::Decimal { value }) => {
+                    return (value.to_string(), false)
+                }
+                Ok(ftd::interpreter::Value::String { text }) => {
+                    return (format!("\"{}\"", text), false)
+                }
+                Ok(ftd::interpreter::Value::Boolean { value }) => {
+                    return (value.to_string(), false)
+                }
+                _ => {}
             }
 
-            todo!("{} {}", t, variant)
+            // A variant that carries a record, like `shape.circle`, keeps its name so that
+            // `-- match:` can tell the variants apart
+            let variant = variant.rsplit_once('.').map_or(variant, |(_, v)| v);
+            (format!("fastn.orTypeValue(\"{}\")", variant), true)
         }
     }
 }
@@ -885,6 +925,13 @@ fn region_variants(name: &str) -> &'static str {
         "h4" => "H4",
         "h5" => "H5",
         "h6" => "H6",
+        "main" => "Main",
+        "nav" => "Nav",
+        "header" => "Header",
+        "footer" => "Footer",
+        "aside" => "Aside",
+        "section" => "Section",
+        "article" => "Article",
         t => todo!("invalid region variant {}", t),
     }
 }
@@ -491,7 +491,7 @@ impl ftd::executor::Text {
         let node = self.common.node();
         let mut n = Node::from_common(node.as_str(), "block", &self.common, doc_id, anchor_ids);
 
-        if self.common.region.value.is_some() {
+        if matches!(self.common.region.value, Some(ref region) if !region.is_landmark()) {
             n.attrs.insert_if_not_contains(
                 "id",
                 ftd::node::Value::from_string(slug::slugify(&self.text.value.original)),
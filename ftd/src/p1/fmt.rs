@@ -0,0 +1,307 @@
+const INDENT: &str = "    ";
+
+/// Kinds of sections whose headers declare something, their order is kept as written.
+const DEFINITIONS: [&str; 4] = ["component", "record", "or-type", "web-component"];
+
+/**
+ * Rewrites an ftd document in the canonical layout, this is what `fastn fmt` does.
+ *
+ * - sections are separated by one blank line, so is `-- end:`
+ * - headers directly follow their section, sorted by key with `if` and `$processor$` first,
+ *   headers of the same key keep their order. Definitions (`component`, `record`, `or-type`,
+ *   `web-component` and functions) keep their headers as written.
+ * - one blank line between headers and the body, caption or header value
+ * - children of a section closed with `-- end:` are indented by four spaces
+ * - bodies keep their relative indentation, trailing white space is removed elsewhere
+ *
+ * Comments are kept: `/-- ` sections are sections, a `;;` line directly above a header or
+ * a section moves with it, other `;;` lines stay where they are.
+ *
+ * Both the document and its formatted version are parsed, and an error is returned if they do
+ * not have the same sections, so formatting never changes what a document means. Formatting a
+ * formatted document does not change it.
+ */
+pub fn format(source: &str, doc_id: &str) -> ftd::p1::Result<String> {
+    let before = ftd::p1::parse(source, doc_id)?;
+    let formatted = format_lines(source);
+    let after = ftd::p1::parse(formatted.as_str(), doc_id)?;
+
+    if comparable(before) != comparable(after) {
+        return Err(ftd::p1::Error::ParseError {
+            message: "can not format, the formatted document would not be the same".to_string(),
+            doc_id: doc_id.to_string(),
+            line_number: 0,
+        });
+    }
+
+    Ok(formatted)
+}
+
+#[derive(Debug)]
+struct Block<'a> {
+    /// The `-- ` or `/-- ` line
+    section: &'a str,
+    /// Lines directly below the section line: headers and comments
+    headers: Vec<&'a str>,
+    /// Lines after the headers till the next section: the body, caption or header value
+    content: Vec<&'a str>,
+}
+
+fn format_lines(source: &str) -> String {
+    let (prelude, blocks) = blocks(source);
+    let depths = depths(&blocks);
+    let mut out: Vec<String> = vec![];
+
+    let (body, mut attached) = split_attached_comments(prelude.as_slice(), !blocks.is_empty());
+    push_body(&mut out, body, "");
+
+    for (i, (block, depth)) in blocks.iter().zip(depths).enumerate() {
+        let indent = INDENT.repeat(depth);
+
+        if !out.is_empty() {
+            out.push("".to_string());
+        }
+        for comment in attached {
+            out.push(format!("{indent}{}", comment.trim()));
+        }
+        out.push(format!("{indent}{}", block.section));
+        for header in headers(block) {
+            out.push(format!("{indent}{header}"));
+        }
+
+        let before_section = i + 1 < blocks.len();
+        let (body, next) = split_attached_comments(block.content.as_slice(), before_section);
+        push_body(&mut out, body, indent.as_str());
+        attached = next;
+    }
+
+    if out.is_empty() {
+        return "".to_string();
+    }
+    let mut formatted = out.join("\n");
+    formatted.push('\n');
+    formatted
+}
+
+/// Lines before the first section, and the sections.
+fn blocks(source: &str) -> (Vec<&str>, Vec<Block<'_>>) {
+    let mut prelude = vec![];
+    let mut blocks: Vec<Block> = vec![];
+    let mut in_headers = false;
+
+    for line in source.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("-- ") || trimmed.starts_with("/-- ") {
+            blocks.push(Block {
+                section: trimmed,
+                headers: vec![],
+                content: vec![],
+            });
+            in_headers = true;
+            continue;
+        }
+        match blocks.last_mut() {
+            None => prelude.push(line),
+            Some(block) if in_headers && !trimmed.is_empty() => block.headers.push(trimmed),
+            Some(block) => {
+                in_headers = false;
+                block.content.push(line);
+            }
+        }
+    }
+
+    (prelude, blocks)
+}
+
+/// Nesting depth of every section. Only sections closed with `-- end:` have children, the same
+/// way the parser decides it: `-- end: x` closes the last open `x`, sections opened after it are
+/// its children.
+fn depths(blocks: &[Block]) -> Vec<usize> {
+    let mut containers = vec![false; blocks.len()];
+    let mut open: Vec<usize> = vec![];
+    for (i, block) in blocks.iter().enumerate() {
+        match end_of(block.section) {
+            Some(name) => {
+                while let Some(j) = open.pop() {
+                    if section_name_and_kind(blocks[j].section).0 == name {
+                        containers[j] = true;
+                        break;
+                    }
+                }
+            }
+            None => open.push(i),
+        }
+    }
+
+    let mut depth: usize = 0;
+    let mut depths = vec![];
+    for (block, container) in blocks.iter().zip(containers) {
+        if end_of(block.section).is_some() {
+            depth = depth.saturating_sub(1);
+        }
+        depths.push(depth);
+        if container {
+            depth += 1;
+        }
+    }
+    depths
+}
+
+/// Name and kind of the section on a `-- ` line.
+fn section_name_and_kind(line: &str) -> (String, Option<String>) {
+    let line = line.strip_prefix('/').unwrap_or(line);
+    let line = line.strip_prefix("-- ").unwrap_or(line);
+    let name_with_kind = line.split_once(':').map(|(n, _)| n).unwrap_or(line);
+    ftd::p1::parser::get_name_and_kind(name_with_kind.trim())
+}
+
+/// Name of the section an `-- end:` line closes.
+fn end_of(line: &str) -> Option<String> {
+    let caption = line.strip_prefix("-- ")?.split_once(':')?;
+    if caption.0.trim() != "end" {
+        return None;
+    }
+    Some(
+        caption
+            .1
+            .split(";;")
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_string(),
+    )
+}
+
+fn is_definition(name: &str, kind: Option<&str>) -> bool {
+    name.contains('(')
+        || kind
+            .and_then(|k| k.split_whitespace().last())
+            .map_or(false, |k| DEFINITIONS.contains(&k))
+}
+
+/// Header lines of the block in the canonical order, a comment moves with the header below it.
+fn headers<'a>(block: &Block<'a>) -> Vec<&'a str> {
+    let mut headers: Vec<(Vec<&str>, &str)> = vec![];
+    let mut comments = vec![];
+    for line in block.headers.iter() {
+        if line.starts_with(";;") {
+            comments.push(*line);
+        } else {
+            headers.push((std::mem::take(&mut comments), *line));
+        }
+    }
+
+    let (name, kind) = section_name_and_kind(block.section);
+    if !is_definition(name.as_str(), kind.as_deref()) {
+        headers.sort_by_key(|(_, header)| header_order(header_key(header)));
+    }
+
+    headers
+        .into_iter()
+        .flat_map(|(comments, header)| comments.into_iter().chain(std::iter::once(header)))
+        .chain(comments)
+        .collect()
+}
+
+/// Key of a header line: `optional string foo.bar if { x }: value` -> `foo.bar`
+fn header_key(line: &str) -> &str {
+    let name = line.split(':').next().unwrap_or(line);
+    let name = name.split(" if ").next().unwrap_or(name);
+    name.split_whitespace().last().unwrap_or(name)
+}
+
+/// Sort key of a header: `if` and `$processor$` first, then by the key without record fields
+/// (`foo.bar` sorts as `foo`) or comment marker.
+fn header_order(key: &str) -> (u8, String) {
+    let key = key.trim_start_matches('/');
+    match key {
+        "if" => (0, "".to_string()),
+        "$processor$" => (1, "".to_string()),
+        _ => (2, key.split('.').next().unwrap_or(key).to_string()),
+    }
+}
+
+/// Trailing `;;` lines right above the next section, these move with the section.
+fn split_attached_comments<'a, 'b>(
+    lines: &'b [&'a str],
+    before_section: bool,
+) -> (&'b [&'a str], Vec<&'a str>) {
+    if !before_section {
+        return (lines, vec![]);
+    }
+    let count = lines
+        .iter()
+        .rev()
+        .take_while(|line| line.trim().starts_with(";;"))
+        .count();
+    let (body, attached) = lines.split_at(lines.len() - count);
+    (body, attached.to_vec())
+}
+
+/// Adds the body after a blank line, indented by `indent` and keeping its relative indentation.
+fn push_body(out: &mut Vec<String>, lines: &[&str], indent: &str) {
+    let is_blank = |line: &&str| line.trim().is_empty();
+    let start = match lines.iter().position(|l| !is_blank(l)) {
+        Some(start) => start,
+        None => return,
+    };
+    let end = lines.len() - lines.iter().rev().position(|l| !is_blank(l)).unwrap_or(0);
+    let lines = &lines[start..end];
+
+    let leading = |line: &str| line.len() - line.trim_start_matches([' ', '\t']).len();
+    let strip = lines
+        .iter()
+        .filter(|l| !is_blank(l))
+        .map(|l| leading(l))
+        .min()
+        .unwrap_or(0);
+
+    if !out.is_empty() {
+        out.push("".to_string());
+    }
+    for line in lines {
+        if is_blank(line) {
+            out.push("".to_string());
+        } else {
+            out.push(format!("{indent}{}", &line[strip..]));
+        }
+    }
+}
+
+/// The parsed sections without what formatting changes: line numbers, header order and trailing
+/// white space.
+fn comparable(sections: Vec<ftd::p1::Section>) -> serde_json::Value {
+    fn sort(section: &mut ftd::p1::Section) {
+        if !is_definition(section.name.as_str(), section.kind.as_deref()) {
+            section
+                .headers
+                .0
+                .sort_by_key(|header| header_order(header.get_key().as_str()));
+        }
+        for header in section.headers.0.iter_mut() {
+            if let ftd::p1::Header::Section(header) = header {
+                header.section.iter_mut().for_each(sort);
+            }
+        }
+        section.sub_sections.iter_mut().for_each(sort);
+    }
+
+    fn clean(value: &mut serde_json::Value) {
+        match value {
+            // numbers in sections are line numbers
+            serde_json::Value::Number(_) => *value = 0.into(),
+            serde_json::Value::String(s) => {
+                *s = s.lines().map(str::trim_end).collect::<Vec<_>>().join("\n")
+            }
+            serde_json::Value::Array(a) => a.iter_mut().for_each(clean),
+            serde_json::Value::Object(o) => o.values_mut().for_each(clean),
+            _ => {}
+        }
+    }
+
+    let mut sections = sections;
+    sections.iter_mut().for_each(sort);
+    let mut value = serde_json::to_value(sections).unwrap_or_default();
+    clean(&mut value);
+    value
+}
@@ -2,13 +2,15 @@
 #[macro_use]
 mod test;
 
+mod fmt;
 pub(crate) mod header;
 mod parser;
 mod section;
 pub mod utils;
 
+pub use fmt::format;
 pub use header::{Header, Headers, Section as HSection, KV};
-pub use parser::{parse, parse_with_line_number};
+pub use parser::{parse, parse_with_errors, parse_with_line_number};
 pub use section::Body;
 pub use section::Section;
 
@@ -73,6 +75,77 @@ pub enum Error {
         #[from]
         source: syntect::Error,
     },
+
+    /// `error`, with the part of its line it is about, `columns` is a byte range in the line
+    #[error("{error}")]
+    Spanned {
+        error: Box<Error>,
+        columns: std::ops::Range<usize>,
+    },
+}
+
+impl Error {
+    /// Document and line the error was found on, if known.
+    pub fn location(&self) -> Option<(&str, usize)> {
+        match self {
+            Error::SectionNotFound {
+                doc_id,
+                line_number,
+            }
+            | Error::MoreThanOneCaption {
+                doc_id,
+                line_number,
+            }
+            | Error::ParseError {
+                doc_id,
+                line_number,
+                ..
+            }
+            | Error::MoreThanOneHeader {
+                doc_id,
+                line_number,
+                ..
+            }
+            | Error::HeaderNotFound {
+                doc_id,
+                line_number,
+                ..
+            }
+            | Error::ForbiddenUsage {
+                doc_id,
+                line_number,
+                ..
+            }
+            | Error::NotFound {
+                doc_id,
+                line_number,
+                ..
+            }
+            | Error::MoreThanOneSubSections {
+                doc_id,
+                line_number,
+                ..
+            } => Some((doc_id.as_str(), *line_number)),
+            Error::Spanned { error, .. } => error.location(),
+            Error::Serde { .. } | Error::Syntect { .. } => None,
+        }
+    }
+
+    /// Byte range, in the line of the error, of the part it is about. `None` if it is about the
+    /// whole line.
+    pub fn columns(&self) -> Option<std::ops::Range<usize>> {
+        match self {
+            Error::Spanned { columns, .. } => Some(columns.clone()),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn with_columns(self, columns: std::ops::Range<usize>) -> Error {
+        Error::Spanned {
+            error: Box::new(self),
+            columns,
+        }
+    }
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
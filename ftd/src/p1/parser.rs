@@ -32,7 +32,12 @@ impl State {
             let mut change_state = None;
             self.end(&mut change_state)?;
 
-            if self.content.trim().is_empty() {
+            // only comments are left, like after the last section of a part of the document
+            if self
+                .content
+                .split('\n')
+                .all(|line| !valid_line(line) || line.trim().is_empty())
+            {
                 let sections = self.state.iter().map(|(v, _)| v.clone()).collect_vec();
                 self.state = vec![];
                 self.sections.extend(sections);
@@ -85,11 +90,23 @@ impl State {
             self.doc_id.as_str(),
         )?;
         if is_end(name.as_str()) {
-            let caption = caption.ok_or_else(|| ftd::p1::Error::ParseError {
-                message: "section name not provided for `end`".to_string(),
-                doc_id: self.doc_id.to_string(),
-                line_number: ftd::p1::utils::i32_to_usize(self.line_number),
+            let end_line_number =
+                ftd::p1::utils::i32_to_usize(self.line_number + (scan_line_number as i32) + 1);
+            // `-- end: <caption>`, the line is indented by `indent`
+            let indent = content.len() - content.trim_start().len();
+            let caption = caption.ok_or_else(|| {
+                ftd::p1::Error::ParseError {
+                    message: "section name not provided for `end`".to_string(),
+                    doc_id: self.doc_id.to_string(),
+                    line_number: end_line_number,
+                }
+                .with_columns(indent + 3..indent + 6)
             })?;
+            let caption_columns = {
+                let (name, rest) = start_line.split_once(':').unwrap_or((start_line, ""));
+                let start = indent + 2 + name.len() + 1 + rest.len() - rest.trim_start().len();
+                start..start + caption.len()
+            };
             let mut sections = vec![];
             loop {
                 let line_number = self.line_number;
@@ -100,14 +117,22 @@ impl State {
                         ftd::p1::Error::ParseError {
                             message: format!("No section found to end: {}", caption),
                             doc_id: self.doc_id.to_string(),
-                            line_number: ftd::p1::utils::i32_to_usize(self.line_number),
+                            line_number: end_line_number,
                         }
+                        .with_columns(caption_columns.clone())
                     })?;
                     sections.push(section);
                     continue;
                 };
                 match state {
-                    ParsingStateReading::Section if caption.eq(section.name.as_str()) => {
+                    // `-- end: data-table` ends `-- component data-table<T>:`
+                    ParsingStateReading::Section
+                        if caption.eq(section.name.as_str())
+                            || section
+                                .name
+                                .split_once('<')
+                                .is_some_and(|(name, _)| caption.eq(name)) =>
+                    {
                         sections.reverse();
                         section.sub_sections.extend(sections);
                         *change_state = None;
@@ -586,7 +611,7 @@ impl State {
         let mut first_line = true;
         let split_content = self.content.as_str().split('\n');
         for (line_number, line) in split_content.enumerate() {
-            if line.starts_with("-- ") || line.starts_with("/-- ") {
+            if line.trim_start().starts_with("-- ") || line.trim_start().starts_with("/-- ") {
                 new_line_number = Some(line_number);
                 break;
             }
@@ -819,6 +844,195 @@ pub fn parse_with_line_number(
     Ok(state.sections)
 }
 
+/// Like `parse_with_line_number`, but does not stop at the first error. The document is parsed
+/// one top level section at a time, a section with an error is left out and the rest of the
+/// document is parsed on from the next section, so every broken section is reported. The
+/// sections are only returned if there are no errors.
+pub fn parse_with_errors(
+    content: &str,
+    doc_id: &str,
+    line_number: usize,
+) -> (Vec<ftd::p1::Section>, Vec<ftd::p1::Error>) {
+    use itertools::Itertools;
+
+    let lines = content.split('\n').collect_vec();
+    let mut sections = vec![];
+    let mut errors = vec![];
+    let all_lines = lines.len();
+    for range in top_level_sections(lines.as_slice()) {
+        let start = range.start;
+        // like in the whole document, a value is read up to the next section, blank lines
+        // included, so a stand-in for the next section is added, and left out of the sections
+        let followed = range.end < all_lines;
+        let mut lines = lines[range].to_vec();
+        if followed {
+            lines.push("-- end-of-part:");
+        }
+        loop {
+            let mut state = State {
+                content: lines.join("\n"),
+                doc_id: doc_id.to_string(),
+                // line numbers are counted from the end of the `line_number` lines
+                line_number: start as i32 - line_number as i32,
+                sections: Default::default(),
+                state: Default::default(),
+            };
+            let error = match state.next() {
+                Ok(()) => {
+                    if followed {
+                        state.sections.pop();
+                    }
+                    sections.extend(state.sections);
+                    break;
+                }
+                Err(e) => e,
+            };
+            // a section nested in this one can be left out, and the rest of it parsed again
+            let index = error
+                .location()
+                .and_then(|(_, l)| (l + line_number).checked_sub(start + 1));
+            errors.push(error);
+            if !index.is_some_and(|index| skip_section(lines.as_mut_slice(), index)) {
+                break;
+            }
+        }
+    }
+    if !errors.is_empty() {
+        sections = vec![];
+    }
+    (sections, errors)
+}
+
+/// The lines of every top level section, with its block headers, its sub-sections and its
+/// `-- end:` line. The lines before the first section go with it.
+fn top_level_sections(lines: &[&str]) -> Vec<std::ops::Range<usize>> {
+    let ends = matching_ends(lines);
+    let mut ranges = vec![];
+    let mut current: Option<(usize, String)> = None;
+    let mut index = 0;
+    while index < lines.len() {
+        let name = match section_line_name(lines[index]) {
+            Some(name) => name,
+            None => {
+                index += 1;
+                continue;
+            }
+        };
+        let is_block_header = current
+            .as_ref()
+            .is_some_and(|(_, section)| name.starts_with(format!("{section}.").as_str()));
+        if !is_block_header {
+            let start = match current {
+                Some((start, _)) => {
+                    ranges.push(start..index);
+                    index
+                }
+                None => 0,
+            };
+            current = Some((start, name));
+        }
+        index = ends.get(&index).map_or(index, |end| *end) + 1;
+    }
+    ranges.push(current.map_or(0, |(start, _)| start)..lines.len());
+    ranges
+}
+
+/// `foo` for `-- foo:` and `/-- foo:`, `None` if the line does not start a section.
+fn section_line_name(line: &str) -> Option<String> {
+    let line = line.trim_start();
+    let line = line
+        .strip_prefix("-- ")
+        .or_else(|| line.strip_prefix("/-- "))?;
+    let name_with_kind = line.split_once(':').map_or(line, |(name, _)| name);
+    Some(get_name_and_kind(name_with_kind.trim()).0)
+}
+
+/// Line of the `-- end:` of every section that has one, see `matching_end`. An `-- end:` that
+/// closes no open section is an error, and is left out here like it is left out after the error
+/// is reported.
+fn matching_ends(lines: &[&str]) -> std::collections::HashMap<usize, usize> {
+    let mut ends = std::collections::HashMap::new();
+    let mut open: Vec<(String, usize)> = vec![];
+    for (i, line) in lines.iter().enumerate() {
+        match section_name(line) {
+            Some(section) => match section.strip_prefix("end:") {
+                Some(closes) if open.iter().any(|(o, _)| o == closes) => {
+                    while let Some((o, start)) = open.pop() {
+                        if o == closes {
+                            ends.insert(start, i);
+                            break;
+                        }
+                    }
+                }
+                Some(_) => {}
+                None => open.push((section, i)),
+            },
+            None => continue,
+        }
+    }
+    ends
+}
+
+/// Blanks the lines of the section line `index` belongs to, and its `-- end:` line if it has one.
+/// Line numbers of the other sections do not change. Returns `false` if `index` is not in a
+/// section.
+fn skip_section(lines: &mut [&str], index: usize) -> bool {
+    let is_section = |line: &str| {
+        let line = line.trim_start();
+        line.starts_with("-- ") || line.starts_with("/-- ")
+    };
+    let index = index.min(lines.len().saturating_sub(1));
+    let start = match (0..=index).rev().find(|i| is_section(lines[*i])) {
+        Some(start) => start,
+        None => return false,
+    };
+    let end = (index + 1..lines.len())
+        .find(|i| is_section(lines[*i]))
+        .unwrap_or(lines.len());
+
+    if let Some(end_line) = matching_end(lines, start) {
+        lines[end_line] = "";
+    }
+    lines[start..end].iter_mut().for_each(|line| *line = "");
+    true
+}
+
+/// The `-- end:` line closing the section on line `start`: the first one not closing a section
+/// opened after it.
+fn matching_end(lines: &[&str], start: usize) -> Option<usize> {
+    let name = section_name(lines[start])?;
+    let mut open = vec![];
+    for (i, line) in lines.iter().enumerate().skip(start + 1) {
+        match section_name(line) {
+            Some(section) => match section.strip_prefix("end:") {
+                Some(closes) if open.iter().any(|o: &String| o == closes) => {
+                    while open.pop().map_or(false, |o| o != closes) {}
+                }
+                Some(closes) => return (closes == name).then_some(i),
+                None => open.push(section),
+            },
+            None => continue,
+        }
+    }
+    None
+}
+
+/// `foo` for `-- foo:` and `/-- foo:`, `end:foo` for `-- end: foo`.
+fn section_name(line: &str) -> Option<String> {
+    let line = line.trim();
+    let (line, is_commented) = match line.strip_prefix("/-- ") {
+        Some(line) => (line, true),
+        None => (line.strip_prefix("-- ")?, false),
+    };
+    let (name_with_kind, caption) = line.split_once(':')?;
+    let (name, _) = get_name_and_kind(name_with_kind.trim());
+    if is_end(name.as_str()) && !is_commented {
+        Some(format!("end:{}", caption.trim()))
+    } else {
+        Some(name)
+    }
+}
+
 fn colon_separated_values(
     line_number: usize,
     line: &str,
@@ -845,7 +1059,7 @@ fn colon_separated_values(
     Ok((name, caption))
 }
 
-fn get_name_and_kind(name_with_kind: &str) -> (String, Option<String>) {
+pub(crate) fn get_name_and_kind(name_with_kind: &str) -> (String, Option<String>) {
     let mut name_with_kind = name_with_kind.to_owned();
 
     // Fix spacing for functional parameters inside parenthesis (if user provides)
@@ -861,6 +1075,16 @@ fn get_name_and_kind(name_with_kind: &str) -> (String, Option<String>) {
         }
     }
 
+    // Same for type parameters, `-- component data-table<T, U>:`
+    if let (Some(si), Some(ei)) = (name_with_kind.find('<'), name_with_kind.rfind('>')) {
+        if si < ei {
+            let before_brackets = &name_with_kind[..si];
+            let mut bracket_content_and_beyond = name_with_kind[si..ei].replace(' ', "");
+            bracket_content_and_beyond.push_str(&name_with_kind[ei..]);
+            name_with_kind = format!("{}{}", before_brackets, bracket_content_and_beyond);
+        }
+    }
+
     if let Some((kind, name)) = name_with_kind.rsplit_once(' ') {
         return (name.to_string(), Some(kind.to_string()));
     }
@@ -77,6 +77,21 @@ fn p1_test_all() {
     }
 }
 
+#[test]
+fn fmt_test_all() {
+    // every file formats to a document with the same sections, and formatting it again does not
+    // change it
+    for (files, _) in find_file_groups() {
+        for f in files {
+            let s = std::fs::read_to_string(&f).unwrap();
+            let formatted =
+                super::format(&s, "foo").unwrap_or_else(|e| panic!("{}: {:?}", f.display(), e));
+            let again = super::format(&formatted, "foo").unwrap();
+            assert_eq!(formatted, again, "{}", f.display());
+        }
+    }
+}
+
 fn find_file_groups() -> Vec<(Vec<std::path::PathBuf>, std::path::PathBuf)> {
     let files = {
         let mut f = ftd::utils::find_all_files_matching_extension_recursively("t/p1", "ftd");
@@ -922,3 +937,117 @@ fn kind() {
             .list(),
     );
 }
+
+#[test]
+fn format() {
+    let source = indoc!(
+        "
+        ;; page header
+
+
+        -- ftd.column:
+        padding.px: 20
+        ;; the id
+        id: main
+        if: { flag }
+        -- ftd.text: hello
+
+        ;; the last one
+        -- ftd.text:
+
+              world
+                indented
+
+        -- end: ftd.column
+        -- component foo:
+        string b:
+        caption a:
+
+        -- end: foo
+        "
+    );
+    let expected = indoc!(
+        "
+        ;; page header
+
+        -- ftd.column:
+        if: { flag }
+        ;; the id
+        id: main
+        padding.px: 20
+
+            -- ftd.text: hello
+
+            ;; the last one
+            -- ftd.text:
+
+            world
+              indented
+
+        -- end: ftd.column
+
+        -- component foo:
+        string b:
+        caption a:
+
+        -- end: foo
+        "
+    );
+
+    let formatted = super::format(source, "foo").unwrap_or_else(|e| panic!("{:?}", e));
+    assert_eq!(expected, formatted);
+    assert_eq!(expected, super::format(expected, "foo").unwrap());
+}
+
+#[test]
+fn all_errors() {
+    let source = indoc!(
+        "
+        -- ftd.text: hello
+        a b c
+
+        -- ftd.column:
+
+        -- ftd.text: world
+
+        -- end: ftd.row
+
+        -- end: ftd.column
+
+        -- ftd.text: fine
+        "
+    );
+    let (sections, errors) = super::parse_with_errors(source, "foo", 0);
+    assert!(sections.is_empty());
+    assert_eq!(
+        errors.iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+        vec![
+            "foo:2 -> start section body 'a b c' after a newline!!",
+            "foo:8 -> No section found to end: ftd.row",
+        ]
+    );
+    // `ftd.row` in `-- end: ftd.row`
+    assert_eq!(errors[1].columns(), Some(8..15));
+
+    let (sections, errors) = super::parse_with_errors("-- ftd.text: fine\n", "foo", 0);
+    assert_eq!(sections.len(), 1);
+    assert!(errors.is_empty());
+
+    // every top level section is parsed on its own, one can end with comments
+    let (sections, errors) = super::parse_with_errors(
+        "-- ftd.text: a\n-- ftd.text.color: red\n;; b\n\n-- ftd.text: b\n",
+        "foo",
+        0,
+    );
+    assert_eq!(sections.len(), 2);
+    assert!(errors.is_empty());
+
+    // the line numbers are the ones of the whole document
+    let source = "-- ftd.text:\n\nhello\n\n\n-- ftd.text: b\n";
+    let (sections, errors) = super::parse_with_errors(source, "foo", 0);
+    assert!(errors.is_empty());
+    assert_eq!(
+        sections,
+        super::parse_with_line_number(source, "foo", 0).unwrap()
+    );
+}